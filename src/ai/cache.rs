@@ -0,0 +1,140 @@
+//! On-disk cache of AI provider completions.
+//!
+//! Completions are stored one file per prompt, named by a SHA-256 of the
+//! model and prompt, so identical requests don't reach the provider twice.
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::core::Git;
+use crate::Error;
+
+/// Directory under `.git` holding cached completions.
+pub const CACHE_DIR: &str = "adr-ai-cache";
+
+/// Cache hits and misses since the cache was opened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Requests answered from the cache.
+    pub hits: usize,
+    /// Requests that went to the provider.
+    pub misses: usize,
+}
+
+/// Completions cached in a directory, keyed by model and prompt.
+#[derive(Debug)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl ResponseCache {
+    /// Open a cache stored in `dir`, created on first write.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Open the repository's cache in `.git/adr-ai-cache`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the git directory cannot be found.
+    pub fn for_repository(git: &Git) -> Result<Self, Error> {
+        Ok(Self::new(git.git_dir()?.join(CACHE_DIR)))
+    }
+
+    /// Directory holding the cached completions.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Look up the completion for `prompt` from `model`, counting a hit or
+    /// a miss.
+    #[must_use]
+    pub fn get(&self, model: &str, prompt: &str) -> Option<String> {
+        let cached = std::fs::read_to_string(self.path(model, prompt)).ok();
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+
+    /// Store the completion for `prompt` from `model`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be written.
+    pub fn put(&self, model: &str, prompt: &str, completion: &str) -> Result<(), Error> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(model, prompt), completion)?;
+        Ok(())
+    }
+
+    /// Hits and misses so far.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn path(&self, model: &str, prompt: &str) -> PathBuf {
+        self.dir.join(cache_key(model, prompt))
+    }
+}
+
+/// Hex-encoded SHA-256 of a model and prompt.
+#[must_use]
+pub fn cache_key(model: &str, prompt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(model.as_bytes());
+    hasher.update([0]);
+    hasher.update(prompt.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cache_round_trip() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let cache = ResponseCache::new(temp_dir.path().join(CACHE_DIR));
+
+        assert_eq!(cache.get("model", "prompt"), None);
+        cache
+            .put("model", "prompt", "completion")
+            .expect("Should write cache");
+        assert_eq!(cache.get("model", "prompt").as_deref(), Some("completion"));
+        assert_eq!(cache.get("other-model", "prompt"), None);
+
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn test_cache_key_separates_model_and_prompt() {
+        assert_ne!(cache_key("ab", "c"), cache_key("a", "bc"));
+        assert_eq!(cache_key("a", "b").len(), 64);
+    }
+}
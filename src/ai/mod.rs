@@ -5,14 +5,25 @@
 //! - Content suggestions
 //! - Summary generation
 //! - Status recommendations
+//! - Related ADR suggestions
+//! - Contradiction review
+//! - Completion caching
 
 use crate::Error;
 
+mod cache;
 mod provider;
+mod review;
 mod service;
+mod similarity;
 
-pub use provider::{AiProvider, ProviderConfig};
-pub use service::AiService;
+pub use cache::{cache_key, CacheStats, ResponseCache, CACHE_DIR};
+pub use provider::{AiProvider, ProviderConfig, TextGenerator};
+pub use review::{decision_text, find_contradictions, parse_contradictions, Contradiction};
+pub use service::{AiService, SUMMARY_FIELD};
+pub use similarity::{
+    guess_relationship, link_candidates, rank_by_similarity, suggest_links, LinkSuggestion,
+};
 
 /// Check if AI features are available.
 #[must_use]
@@ -1,6 +1,7 @@
 //! AI provider abstraction.
 
 use crate::Error;
+use std::future::Future;
 
 /// Supported AI providers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -156,3 +157,36 @@ impl ProviderConfig {
         }
     }
 }
+
+/// A language model that completes prompts.
+pub trait TextGenerator {
+    /// Generate a completion for `prompt`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model cannot be reached or fails.
+    fn generate(&self, prompt: &str) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Name of the model, so cached completions from different models
+    /// don't mix.
+    fn model_name(&self) -> String {
+        String::new()
+    }
+}
+
+impl TextGenerator for ProviderConfig {
+    async fn generate(&self, prompt: &str) -> Result<String, Error> {
+        let _api_key = self.get_api_key()?;
+
+        // TODO: Implement using langchain-rust
+        let _ = prompt;
+        Err(Error::Other(format!(
+            "{} completions are not yet implemented",
+            self.provider
+        )))
+    }
+
+    fn model_name(&self) -> String {
+        format!("{}/{}", self.provider, self.model)
+    }
+}
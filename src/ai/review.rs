@@ -0,0 +1,305 @@
+//! Detection of accepted ADRs that contradict each other.
+//!
+//! The local heuristic looks for opposing choices: two decisions that each
+//! pick a different option from the same set of alternatives, such as REST
+//! and gRPC. An AI provider can add pairs the heuristic can't see.
+
+use serde::Serialize;
+
+use crate::core::{Adr, AdrStatus};
+
+/// Mutually exclusive alternatives: a topic and its options, each option
+/// with the words that name it. All-caps words only match in capitals, so
+/// "REST" doesn't match "the rest of".
+const ALTERNATIVES: &[(&str, &[(&str, &[&str])])] = &[
+    (
+        "API style",
+        &[
+            ("REST", &["REST", "restful"]),
+            ("gRPC", &["grpc"]),
+            ("GraphQL", &["graphql"]),
+            ("SOAP", &["SOAP"]),
+        ],
+    ),
+    (
+        "service architecture",
+        &[
+            ("a monolith", &["monolith", "monolithic"]),
+            ("microservices", &["microservice", "microservices"]),
+        ],
+    ),
+    (
+        "relational database",
+        &[
+            ("PostgreSQL", &["postgres", "postgresql"]),
+            ("MySQL", &["mysql"]),
+            ("MariaDB", &["mariadb"]),
+            ("SQLite", &["sqlite"]),
+        ],
+    ),
+    (
+        "message broker",
+        &[
+            ("Kafka", &["kafka"]),
+            ("RabbitMQ", &["rabbitmq"]),
+            ("NATS", &["NATS"]),
+            ("SQS", &["SQS"]),
+        ],
+    ),
+    (
+        "cloud provider",
+        &[("AWS", &["AWS"]), ("Azure", &["azure"]), ("GCP", &["GCP"])],
+    ),
+    (
+        "frontend framework",
+        &[
+            ("React", &["react"]),
+            ("Angular", &["angular"]),
+            ("Vue", &["vue", "vuejs"]),
+            ("Svelte", &["svelte"]),
+        ],
+    ),
+    (
+        "repository layout",
+        &[
+            ("a monorepo", &["monorepo"]),
+            ("multiple repositories", &["polyrepo", "multirepo"]),
+        ],
+    ),
+];
+
+/// Two accepted ADRs that appear to contradict each other.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Contradiction {
+    /// ID of the first ADR.
+    pub first: String,
+    /// ID of the second ADR.
+    pub second: String,
+    /// Why the two decisions conflict.
+    pub rationale: String,
+}
+
+impl Contradiction {
+    /// Whether this flags the ADRs `a` and `b`, in either order.
+    #[must_use]
+    pub fn involves(&self, a: &str, b: &str) -> bool {
+        (self.first == a && self.second == b) || (self.first == b && self.second == a)
+    }
+}
+
+/// Find pairs of accepted ADRs whose decisions pick different options from
+/// the same set of alternatives.
+///
+/// Only an ADR's title and decision section count, and only when they name
+/// exactly one option of a set, so "REST over gRPC" isn't read as choosing
+/// both.
+#[must_use]
+pub fn find_contradictions(adrs: &[Adr]) -> Vec<Contradiction> {
+    let accepted: Vec<(&Adr, Vec<String>)> = adrs
+        .iter()
+        .filter(|adr| *adr.status() == AdrStatus::Accepted)
+        .map(|adr| (adr, words(&decision_text(adr))))
+        .collect();
+
+    let mut found = Vec::new();
+    for (i, (first, first_words)) in accepted.iter().enumerate() {
+        for (second, second_words) in &accepted[i + 1..] {
+            for (topic, options) in ALTERNATIVES {
+                let (Some(a), Some(b)) = (
+                    chosen_option(options, first_words),
+                    chosen_option(options, second_words),
+                ) else {
+                    continue;
+                };
+                if a != b {
+                    found.push(Contradiction {
+                        first: first.id.clone(),
+                        second: second.id.clone(),
+                        rationale: format!(
+                            "{} decides on {a} and {} on {b} for the {topic}",
+                            first.id, second.id
+                        ),
+                    });
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Title and decision section of an ADR, or its whole body when it has no
+/// decision section.
+#[must_use]
+pub fn decision_text(adr: &Adr) -> String {
+    let mut decision = String::new();
+    let mut in_decision = false;
+    for line in adr.body.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            in_decision = heading.trim().to_lowercase().starts_with("decision");
+            continue;
+        }
+        if in_decision {
+            decision.push_str(line);
+            decision.push('\n');
+        }
+    }
+
+    let decision = if decision.trim().is_empty() {
+        adr.body.as_str()
+    } else {
+        decision.as_str()
+    };
+    format!("{}\n{}", adr.frontmatter.title, decision.trim())
+}
+
+/// Parse a provider reply of `FIRST | SECOND | rationale` lines.
+///
+/// Lines that don't name two different IDs from `ids` are skipped.
+#[must_use]
+pub fn parse_contradictions(reply: &str, ids: &[&str]) -> Vec<Contradiction> {
+    let mut found: Vec<Contradiction> = Vec::new();
+    for line in reply.lines() {
+        let mut parts = line.splitn(3, '|').map(str::trim);
+        let (Some(first), Some(second), Some(rationale)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let first = first.trim_start_matches(['-', '*', ' ']);
+        if first == second || !ids.contains(&first) || !ids.contains(&second) {
+            continue;
+        }
+        if !found.iter().any(|c| c.involves(first, second)) {
+            found.push(Contradiction {
+                first: first.to_string(),
+                second: second.to_string(),
+                rationale: rationale.to_string(),
+            });
+        }
+    }
+    found
+}
+
+/// The single option of a set named in `words`, if exactly one is.
+fn chosen_option<'a>(options: &[(&'a str, &[&str])], words: &[String]) -> Option<&'a str> {
+    let mut named = options.iter().filter(|(_, keywords)| {
+        keywords.iter().any(|keyword| {
+            let acronym = keyword.chars().all(|c| !c.is_lowercase());
+            words.iter().any(|word| {
+                if acronym {
+                    word == *keyword
+                } else {
+                    word.eq_ignore_ascii_case(keyword)
+                }
+            })
+        })
+    });
+    match (named.next(), named.next()) {
+        (Some((option, _)), None) => Some(*option),
+        _ => None,
+    }
+}
+
+/// Split text into words.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adr(id: &str, title: &str, status: AdrStatus, decision: &str) -> Adr {
+        let mut adr = Adr::new(id.to_string(), title.to_string());
+        adr.frontmatter.status = status;
+        adr.body = format!(
+            "## Context\n\nWe compared REST, gRPC and GraphQL.\n\n## Decision\n\n{decision}\n"
+        );
+        adr
+    }
+
+    #[test]
+    fn test_find_contradictions() {
+        let adrs = vec![
+            adr(
+                "ADR-0001",
+                "Public API",
+                AdrStatus::Accepted,
+                "Expose a REST API.",
+            ),
+            adr(
+                "ADR-0002",
+                "Internal calls",
+                AdrStatus::Accepted,
+                "Use gRPC for all services.",
+            ),
+            adr(
+                "ADR-0003",
+                "Partner API",
+                AdrStatus::Accepted,
+                "Prefer REST over gRPC for partners.",
+            ),
+            adr(
+                "ADR-0004",
+                "Reporting API",
+                AdrStatus::Proposed,
+                "Use GraphQL.",
+            ),
+        ];
+
+        let found = find_contradictions(&adrs);
+        assert_eq!(found.len(), 1);
+        assert!(found[0].involves("ADR-0002", "ADR-0001"));
+        assert_eq!(
+            found[0].rationale,
+            "ADR-0001 decides on REST and ADR-0002 on gRPC for the API style"
+        );
+    }
+
+    #[test]
+    fn test_acronyms_match_case_sensitively() {
+        let adrs = vec![
+            adr(
+                "ADR-0001",
+                "Batch jobs",
+                AdrStatus::Accepted,
+                "Run the rest of the jobs with gRPC.",
+            ),
+            adr("ADR-0002", "Sync", AdrStatus::Accepted, "Use GraphQL."),
+        ];
+        let found = find_contradictions(&adrs);
+        assert_eq!(found.len(), 1);
+        assert!(found[0].rationale.contains("gRPC and ADR-0002 on GraphQL"));
+    }
+
+    #[test]
+    fn test_decision_text_falls_back_to_body() {
+        let mut without = Adr::new("ADR-0001".to_string(), "Title".to_string());
+        without.body = "Just some text.".to_string();
+        assert_eq!(decision_text(&without), "Title\nJust some text.");
+
+        let with = adr("ADR-0002", "Title", AdrStatus::Accepted, "Use Kafka.");
+        assert_eq!(decision_text(&with), "Title\nUse Kafka.");
+    }
+
+    #[test]
+    fn test_parse_contradictions() {
+        let reply = "- ADR-0001 | ADR-0002 | Both pick the queue.\n\
+                     ADR-0002 | ADR-0001 | Repeated.\n\
+                     ADR-0001 | ADR-0009 | Unknown.\n\
+                     ADR-0001 | ADR-0001 | Same.\n\
+                     No contradictions here.";
+        let found = parse_contradictions(reply, &["ADR-0001", "ADR-0002"]);
+        assert_eq!(
+            found,
+            [Contradiction {
+                first: "ADR-0001".to_string(),
+                second: "ADR-0002".to_string(),
+                rationale: "Both pick the queue.".to_string(),
+            }]
+        );
+    }
+}
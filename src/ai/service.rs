@@ -1,22 +1,253 @@
 //! AI service for ADR operations.
 
-use crate::ai::ProviderConfig;
-use crate::core::Adr;
+use std::fmt::Write as _;
+
+use crate::ai::similarity::{self, LinkSuggestion};
+use crate::ai::{review, CacheStats, Contradiction, ProviderConfig, ResponseCache, TextGenerator};
+use crate::core::{Adr, AdrStatus};
 use crate::Error;
 
+/// Frontmatter field holding an ADR's AI-written summary.
+pub const SUMMARY_FIELD: &str = "summary";
+
 /// AI service for ADR generation and enhancement.
 #[derive(Debug)]
-pub struct AiService {
-    config: ProviderConfig,
+pub struct AiService<G = ProviderConfig> {
+    config: G,
+    cache: Option<ResponseCache>,
 }
 
 impl AiService {
     /// Create a new AI service with the given configuration.
     #[must_use]
     pub fn new(config: ProviderConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            cache: None,
+        }
+    }
+}
+
+impl<G: TextGenerator> AiService<G> {
+    /// Create an AI service backed by any text generator.
+    #[must_use]
+    pub fn with_generator(generator: G) -> Self {
+        Self {
+            config: generator,
+            cache: None,
+        }
+    }
+
+    /// Answer repeated prompts from `cache` instead of the provider.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Cache hits and misses so far, if caching is on.
+    #[must_use]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(ResponseCache::stats)
+    }
+
+    /// Complete `prompt`, from the cache when it has been seen before.
+    ///
+    /// Failing to store a completion doesn't fail the request.
+    async fn complete(&self, prompt: &str) -> Result<String, Error> {
+        let Some(cache) = &self.cache else {
+            return self.config.generate(prompt).await;
+        };
+
+        let model = self.config.model_name();
+        if let Some(completion) = cache.get(&model, prompt) {
+            return Ok(completion);
+        }
+        let completion = self.config.generate(prompt).await?;
+        if let Err(e) = cache.put(&model, prompt, &completion) {
+            tracing::debug!("Could not cache AI completion: {e}");
+        }
+        Ok(completion)
+    }
+
+    /// Generate a one-paragraph summary of an ADR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider is not configured or fails.
+    pub async fn summarize(&self, adr: &Adr) -> Result<String, Error> {
+        let prompt = format!(
+            "Summarize this architecture decision record in one short paragraph: \
+             what was decided and why. Reply with the paragraph only.\n\n\
+             Title: {}\nStatus: {}\n\n{}",
+            adr.frontmatter.title, adr.frontmatter.status, adr.body
+        );
+        let summary = self.complete(&prompt).await?;
+
+        // Keep it to one paragraph whatever the model returns
+        let summary = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+        if summary.is_empty() {
+            return Err(Error::Other(format!(
+                "AI provider returned an empty summary for {}",
+                adr.id
+            )));
+        }
+        Ok(summary)
+    }
+
+    /// Summarize an ADR and store the summary in its frontmatter.
+    ///
+    /// The summary goes in the `summary` custom field, replacing any
+    /// earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if summarization fails.
+    pub async fn write_summary(&self, adr: &mut Adr) -> Result<String, Error> {
+        let summary = self.summarize(adr).await?;
+        adr.frontmatter.custom.insert(
+            SUMMARY_FIELD.to_string(),
+            serde_yaml::Value::String(summary.clone()),
+        );
+        Ok(summary)
+    }
+
+    /// Ask the provider which of `others` relate to `adr`.
+    ///
+    /// Only ADRs that are not already linked are offered. Returns their IDs,
+    /// most related first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider is not configured or fails.
+    pub async fn rank_related(&self, adr: &Adr, others: &[Adr]) -> Result<Vec<String>, Error> {
+        let candidates = similarity::link_candidates(adr, others);
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut prompt = format!(
+            "Which of the architecture decision records listed below are related to \
+             this one? Reply with their IDs only, one per line, most related first. \
+             Leave out unrelated ones.\n\nTitle: {}\n\n{}\n\nCandidates:\n",
+            adr.frontmatter.title, adr.body
+        );
+        for candidate in &candidates {
+            let _ = writeln!(prompt, "{}: {}", candidate.id, candidate.frontmatter.title);
+        }
+        let reply = self.complete(&prompt).await?;
+
+        // Take the first known ID on each line, ignoring anything else
+        let mut ranked: Vec<String> = Vec::new();
+        for line in reply.lines() {
+            let found = candidates
+                .iter()
+                .filter_map(|candidate| line.find(&candidate.id).map(|pos| (pos, &candidate.id)))
+                .min();
+            if let Some((_, id)) = found {
+                if !ranked.contains(id) {
+                    ranked.push(id.clone());
+                }
+            }
+        }
+        Ok(ranked)
+    }
+
+    /// Suggest up to `limit` links from `adr` to related ADRs in `others`.
+    ///
+    /// The provider ranks the candidates when it can. If it is unavailable,
+    /// fails, or names no known ADR, they are ranked locally by TF-IDF
+    /// similarity instead.
+    pub async fn suggest_links(
+        &self,
+        adr: &Adr,
+        others: &[Adr],
+        limit: usize,
+    ) -> Vec<LinkSuggestion> {
+        let mut local = similarity::suggest_links(adr, others, usize::MAX);
+
+        let ranked = match self.rank_related(adr, others).await {
+            Ok(ranked) if !ranked.is_empty() => ranked,
+            Ok(_) => {
+                tracing::debug!("AI provider named no related ADRs, ranking locally");
+                local.truncate(limit);
+                return local;
+            },
+            Err(e) => {
+                tracing::debug!("AI ranking unavailable ({e}), ranking locally");
+                local.truncate(limit);
+                return local;
+            },
+        };
+
+        ranked
+            .iter()
+            .take(limit)
+            .filter_map(|id| {
+                let candidate = others.iter().find(|other| other.id == *id)?;
+                let score = local
+                    .iter()
+                    .find(|suggestion| suggestion.link.target == *id)
+                    .map_or(0.0, |suggestion| suggestion.score);
+                Some(similarity::suggestion(adr, candidate, score))
+            })
+            .collect()
     }
 
+    /// Ask the provider which accepted ADRs contradict each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider is not configured or fails.
+    pub async fn detect_contradictions(&self, adrs: &[Adr]) -> Result<Vec<Contradiction>, Error> {
+        let accepted: Vec<&Adr> = adrs
+            .iter()
+            .filter(|adr| *adr.status() == AdrStatus::Accepted)
+            .collect();
+        if accepted.len() < 2 {
+            return Ok(Vec::new());
+        }
+
+        let mut prompt = String::from(
+            "Below are accepted architecture decision records. List every pair whose \
+             decisions contradict each other, one per line as \
+             `FIRST-ID | SECOND-ID | one-sentence rationale`. Reply with nothing else, \
+             and with an empty reply if none conflict.\n",
+        );
+        for adr in &accepted {
+            let _ = write!(prompt, "\n### {}\n{}\n", adr.id, review::decision_text(adr));
+        }
+        let reply = self.complete(&prompt).await?;
+
+        let ids: Vec<&str> = accepted.iter().map(|adr| adr.id.as_str()).collect();
+        Ok(review::parse_contradictions(&reply, &ids))
+    }
+
+    /// Review accepted ADRs for contradictions.
+    ///
+    /// Pairs found by the local heuristic come first, followed by any
+    /// further pairs the provider flags. If the provider is unavailable or
+    /// fails, only the heuristic's findings are returned.
+    pub async fn review(&self, adrs: &[Adr]) -> Vec<Contradiction> {
+        let mut found = review::find_contradictions(adrs);
+        match self.detect_contradictions(adrs).await {
+            Ok(flagged) => {
+                for contradiction in flagged {
+                    if !found
+                        .iter()
+                        .any(|c| c.involves(&contradiction.first, &contradiction.second))
+                    {
+                        found.push(contradiction);
+                    }
+                }
+            },
+            Err(e) => tracing::debug!("AI review unavailable ({e}), using local heuristics"),
+        }
+        found
+    }
+}
+
+impl AiService {
     /// Generate an ADR from a title and context.
     ///
     /// # Errors
@@ -51,21 +282,6 @@ impl AiService {
         ])
     }
 
-    /// Generate a summary of an ADR.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if summarization fails.
-    pub async fn summarize(&self, adr: &Adr) -> Result<String, Error> {
-        let _api_key = self.config.get_api_key()?;
-
-        // TODO: Implement using langchain-rust
-        Ok(format!(
-            "ADR {} proposes: {}",
-            adr.id, adr.frontmatter.title
-        ))
-    }
-
     /// Suggest a status for an ADR based on its content.
     ///
     /// # Errors
@@ -97,6 +313,171 @@ impl AiService {
 mod tests {
     use super::*;
     use crate::ai::AiProvider;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    /// A generator that always returns the same text.
+    struct FixedGenerator(&'static str);
+
+    impl TextGenerator for FixedGenerator {
+        async fn generate(&self, prompt: &str) -> Result<String, Error> {
+            assert!(prompt.contains("Use Rust"));
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_summary_stores_custom_field() {
+        let service =
+            AiService::with_generator(FixedGenerator("  We chose Rust\n\nfor speed and safety. "));
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+
+        let summary = service
+            .write_summary(&mut adr)
+            .await
+            .expect("Should summarize");
+
+        assert_eq!(summary, "We chose Rust for speed and safety.");
+        assert_eq!(
+            adr.frontmatter.custom[SUMMARY_FIELD],
+            serde_yaml::Value::String(summary)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summarize_rejects_empty_summary() {
+        let service = AiService::with_generator(FixedGenerator("  \n "));
+        let adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+        assert!(service.summarize(&adr).await.is_err());
+    }
+
+    /// A generator that always fails, like an unconfigured provider.
+    struct FailingGenerator;
+
+    impl TextGenerator for FailingGenerator {
+        async fn generate(&self, _prompt: &str) -> Result<String, Error> {
+            Err(Error::Other("offline".to_string()))
+        }
+    }
+
+    fn related_adrs() -> (Adr, Vec<Adr>) {
+        let mut target = Adr::new("ADR-0003".to_string(), "Use Rust".to_string());
+        target.body = "Rust services talk to the Postgres database.".to_string();
+        let mut postgres = Adr::new("ADR-0001".to_string(), "Use Postgres".to_string());
+        postgres.body = "Postgres is our database.".to_string();
+        let mut tailwind = Adr::new("ADR-0002".to_string(), "Adopt Tailwind".to_string());
+        tailwind.body = "Styling for the frontend.".to_string();
+        let others = vec![postgres, tailwind, target.clone()];
+        (target, others)
+    }
+
+    #[tokio::test]
+    async fn test_suggest_links_follows_provider_ranking() {
+        let service = AiService::with_generator(FixedGenerator(
+            "ADR-0002 (styling)\n- ADR-0009\nADR-0001\nADR-0002",
+        ));
+        let (target, others) = related_adrs();
+
+        let suggestions = service.suggest_links(&target, &others, 5).await;
+        let ids: Vec<&str> = suggestions.iter().map(|s| s.link.target.as_str()).collect();
+        assert_eq!(ids, ["ADR-0002", "ADR-0001"]);
+        assert!(suggestions[0].score.abs() < f64::EPSILON);
+        assert!(suggestions[1].score > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_suggest_links_falls_back_to_similarity() {
+        let service = AiService::with_generator(FailingGenerator);
+        let (target, others) = related_adrs();
+
+        let suggestions = service.suggest_links(&target, &others, 5).await;
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].link.target, "ADR-0001");
+        assert_eq!(suggestions[0].link.rel, "relates-to");
+    }
+
+    fn accepted(id: &str, title: &str, decision: &str) -> Adr {
+        let mut adr = Adr::new(id.to_string(), title.to_string());
+        adr.frontmatter.status = AdrStatus::Accepted;
+        adr.body = format!("## Decision\n\n{decision}\n");
+        adr
+    }
+
+    #[tokio::test]
+    async fn test_review_adds_provider_findings() {
+        let service = AiService::with_generator(FixedGenerator(
+            "ADR-0002 | ADR-0001 | Duplicate of the heuristic.\n\
+             ADR-0003 | ADR-0001 | Rust rules out the JVM.",
+        ));
+        let adrs = vec![
+            accepted("ADR-0001", "Use Rust", "Expose a REST API."),
+            accepted("ADR-0002", "Internal calls", "Use gRPC."),
+            accepted("ADR-0003", "Runtime", "Run everything on the JVM."),
+        ];
+
+        let found = service.review(&adrs).await;
+        assert_eq!(found.len(), 2);
+        assert!(found[0].involves("ADR-0001", "ADR-0002"));
+        assert!(found[0].rationale.contains("API style"));
+        assert!(found[1].involves("ADR-0001", "ADR-0003"));
+        assert_eq!(found[1].rationale, "Rust rules out the JVM.");
+    }
+
+    #[tokio::test]
+    async fn test_review_falls_back_to_heuristics() {
+        let service = AiService::with_generator(FailingGenerator);
+        let adrs = vec![
+            accepted("ADR-0001", "Queue", "Use Kafka."),
+            accepted("ADR-0002", "Jobs", "Use RabbitMQ."),
+        ];
+
+        let found = service.review(&adrs).await;
+        assert_eq!(found.len(), 1);
+        assert!(found[0].rationale.contains("message broker"));
+    }
+
+    /// A generator that counts how often it is called.
+    #[derive(Default)]
+    struct CountingGenerator {
+        calls: AtomicUsize,
+    }
+
+    impl TextGenerator for CountingGenerator {
+        async fn generate(&self, _prompt: &str) -> Result<String, Error> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok("A cached summary.".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_skips_provider_for_identical_prompt() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let service = AiService::with_generator(CountingGenerator::default())
+            .with_cache(ResponseCache::new(temp_dir.path()));
+        let adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+
+        let first = service.summarize(&adr).await.expect("Should summarize");
+        let second = service.summarize(&adr).await.expect("Should summarize");
+
+        assert_eq!(first, second);
+        assert_eq!(service.config.calls.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            service.cache_stats(),
+            Some(CacheStats { hits: 1, misses: 1 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uncached_service_always_calls_provider() {
+        let service = AiService::with_generator(CountingGenerator::default());
+        let adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+
+        service.summarize(&adr).await.expect("Should summarize");
+        service.summarize(&adr).await.expect("Should summarize");
+
+        assert_eq!(service.config.calls.load(Ordering::Relaxed), 2);
+        assert_eq!(service.cache_stats(), None);
+    }
 
     #[tokio::test]
     #[ignore = "requires API key"]
@@ -0,0 +1,179 @@
+//! Local text similarity between ADRs.
+//!
+//! Used to suggest related ADRs without a network call, and as the fallback
+//! when no AI provider can rank them.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::{rank_by_terms, term_counts, Adr, AdrLink};
+
+/// A proposed link from one ADR to a related one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkSuggestion {
+    /// The link to add to the ADR.
+    pub link: AdrLink,
+    /// Title of the target ADR.
+    pub title: String,
+    /// TF-IDF similarity to the target, from 0 to 1.
+    pub score: f64,
+}
+
+/// Rank documents by TF-IDF cosine similarity to `query`.
+///
+/// Documents are `(key, text)` pairs. Returns the keys of documents sharing
+/// at least one term with `query`, most similar first. This is the same
+/// ranking `git adr search --semantic` uses.
+#[must_use]
+pub fn rank_by_similarity<'a>(query: &str, documents: &[(&'a str, &str)]) -> Vec<(&'a str, f64)> {
+    let terms: Vec<HashMap<String, u32>> = documents
+        .iter()
+        .map(|(_, text)| term_counts(text))
+        .collect();
+    let documents: Vec<(&'a str, &HashMap<String, u32>)> = documents
+        .iter()
+        .zip(&terms)
+        .map(|((key, _), terms)| (*key, terms))
+        .collect();
+    rank_by_terms(&term_counts(query), &documents)
+}
+
+/// Text of an ADR used for similarity, with the title counted twice.
+#[must_use]
+pub fn adr_text(adr: &Adr) -> String {
+    format!(
+        "{title} {title} {} {}",
+        adr.frontmatter.tags.join(" "),
+        adr.body,
+        title = adr.frontmatter.title
+    )
+}
+
+/// Guess how `adr` relates to `candidate`.
+///
+/// An ADR that mentions the candidate's ID builds on it; anything else is
+/// only related.
+#[must_use]
+pub fn guess_relationship(adr: &Adr, candidate: &Adr) -> &'static str {
+    if adr.body.contains(&candidate.id) {
+        "depends-on"
+    } else {
+        "relates-to"
+    }
+}
+
+/// ADRs in `others` that `adr` could still be linked to.
+///
+/// Skips the ADR itself and anything it already links to or supersedes.
+#[must_use]
+pub fn link_candidates<'a>(adr: &Adr, others: &'a [Adr]) -> Vec<&'a Adr> {
+    let linked: HashSet<&str> = adr
+        .frontmatter
+        .links
+        .iter()
+        .map(|link| link.target.as_str())
+        .chain(adr.frontmatter.supersedes.as_deref())
+        .chain(adr.frontmatter.superseded_by.as_deref())
+        .collect();
+
+    others
+        .iter()
+        .filter(|other| other.id != adr.id && !linked.contains(other.id.as_str()))
+        .collect()
+}
+
+/// Suggest up to `limit` links from `adr` to the most similar ADRs in
+/// `others`.
+#[must_use]
+pub fn suggest_links(adr: &Adr, others: &[Adr], limit: usize) -> Vec<LinkSuggestion> {
+    let candidates = link_candidates(adr, others);
+    let texts: Vec<String> = candidates.iter().map(|other| adr_text(other)).collect();
+    let documents: Vec<(&str, &str)> = candidates
+        .iter()
+        .zip(&texts)
+        .map(|(other, text)| (other.id.as_str(), text.as_str()))
+        .collect();
+
+    rank_by_similarity(&adr_text(adr), &documents)
+        .into_iter()
+        .take(limit)
+        .filter_map(|(id, score)| {
+            let candidate = candidates.iter().find(|other| other.id == id)?;
+            Some(suggestion(adr, candidate, score))
+        })
+        .collect()
+}
+
+/// A link suggestion from `adr` to `candidate`.
+pub(crate) fn suggestion(adr: &Adr, candidate: &Adr, score: f64) -> LinkSuggestion {
+    LinkSuggestion {
+        link: AdrLink {
+            rel: guess_relationship(adr, candidate).to_string(),
+            target: candidate.id.clone(),
+        },
+        title: candidate.frontmatter.title.clone(),
+        score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adr(id: &str, title: &str, body: &str) -> Adr {
+        let mut adr = Adr::new(id.to_string(), title.to_string());
+        adr.body = body.to_string();
+        adr
+    }
+
+    #[test]
+    fn test_rank_by_similarity() {
+        let documents = [
+            ("a", "postgres database replication and backups"),
+            ("b", "frontend styling with tailwind"),
+            ("c", "database connection pooling"),
+        ];
+        let ranked = rank_by_similarity("choose a database for replication", &documents);
+
+        let keys: Vec<&str> = ranked.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, ["a", "c"]);
+        assert!(ranked[0].1 > ranked[1].1);
+        assert!(ranked
+            .iter()
+            .all(|(_, score)| *score > 0.0 && *score <= 1.0));
+    }
+
+    #[test]
+    fn test_rank_ignores_stop_words() {
+        let documents = [("a", "the and for with this that")];
+        assert!(rank_by_similarity("the and for with this that", &documents).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_links() {
+        let mut target = adr(
+            "ADR-0004",
+            "Cache database reads with Redis",
+            "Builds on ADR-0001 to cut database latency.",
+        );
+        target.frontmatter.links.push(AdrLink {
+            rel: "relates-to".to_string(),
+            target: "ADR-0003".to_string(),
+        });
+        let others = vec![
+            adr(
+                "ADR-0001",
+                "Use Postgres as the database",
+                "Relational database.",
+            ),
+            adr("ADR-0002", "Adopt Tailwind", "Styling for the frontend."),
+            adr("ADR-0003", "Redis for sessions", "Session cache in Redis."),
+            target.clone(),
+        ];
+
+        let suggestions = suggest_links(&target, &others, 5);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].link.target, "ADR-0001");
+        assert_eq!(suggestions[0].link.rel, "depends-on");
+        assert_eq!(suggestions[0].title, "Use Postgres as the database");
+    }
+}
@@ -2,6 +2,14 @@
 
 use anyhow::Result;
 use clap::Args as ClapArgs;
+use colored::Colorize;
+use std::io::{self, Write};
+
+use crate::ai::{
+    default_provider, find_contradictions, suggest_links, AiProvider, AiService, ProviderConfig,
+    ResponseCache,
+};
+use crate::core::{ConfigManager, Git, NotesManager};
 
 /// AI-assisted ADR operations.
 #[derive(ClapArgs, Debug)]
@@ -22,6 +30,20 @@ pub enum AiCommands {
 
     /// Summarize an ADR using AI.
     Summarize(SummarizeArgs),
+
+    /// Suggest links from an ADR to related ADRs.
+    SuggestLinks(SuggestLinksArgs),
+
+    /// Flag accepted ADRs that contradict each other.
+    Review(ReviewArgs),
+}
+
+/// Response cache options shared by AI commands.
+#[derive(ClapArgs, Debug)]
+pub struct CacheArgs {
+    /// Always ask the provider instead of reusing cached completions.
+    #[arg(long)]
+    pub no_cache: bool,
 }
 
 /// Arguments for AI draft generation.
@@ -64,13 +86,71 @@ pub struct SummarizeArgs {
     /// ADR identifier.
     pub id: String,
 
-    /// AI provider to use.
-    #[arg(long, short, default_value = "anthropic")]
-    pub provider: String,
+    /// AI provider to use (default: the first one with credentials set).
+    #[arg(long, short)]
+    pub provider: Option<String>,
+
+    /// Model to use.
+    #[arg(long, short)]
+    pub model: Option<String>,
+
+    /// Store the summary in the ADR's `summary` frontmatter field.
+    #[arg(long, short)]
+    pub write: bool,
+
+    /// With --write, print the summary without saving it.
+    #[arg(long, requires = "write")]
+    pub dry_run: bool,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+/// Arguments for AI link suggestions.
+#[derive(ClapArgs, Debug)]
+pub struct SuggestLinksArgs {
+    /// ADR identifier.
+    pub id: String,
+
+    /// AI provider to use (default: the first one with credentials set;
+    /// without one, ADRs are ranked by text similarity).
+    #[arg(long, short)]
+    pub provider: Option<String>,
+
+    /// Model to use.
+    #[arg(long, short)]
+    pub model: Option<String>,
+
+    /// Maximum number of suggestions.
+    #[arg(long, short = 'n', default_value = "5")]
+    pub limit: usize,
+
+    /// Add every suggested link without asking.
+    #[arg(long, short)]
+    pub yes: bool,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+/// Arguments for AI contradiction review.
+#[derive(ClapArgs, Debug)]
+pub struct ReviewArgs {
+    /// AI provider to use (default: the first one with credentials set;
+    /// without one, only local heuristics run).
+    #[arg(long, short)]
+    pub provider: Option<String>,
 
     /// Model to use.
     #[arg(long, short)]
     pub model: Option<String>,
+
+    /// Output format (text, json).
+    #[arg(long, short, default_value = "text", value_parser = ["text", "json"])]
+    pub format: String,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
 }
 
 /// Run the AI command.
@@ -79,6 +159,8 @@ pub fn run(args: Args) -> Result<()> {
         AiCommands::Draft(draft_args) => run_draft(draft_args),
         AiCommands::Suggest(suggest_args) => run_suggest(suggest_args),
         AiCommands::Summarize(summarize_args) => run_summarize(summarize_args),
+        AiCommands::SuggestLinks(suggest_args) => run_suggest_links(suggest_args),
+        AiCommands::Review(review_args) => run_review(review_args),
     }
 }
 
@@ -107,13 +189,208 @@ fn run_suggest(args: SuggestArgs) -> Result<()> {
 }
 
 fn run_summarize(args: SummarizeArgs) -> Result<()> {
-    println!("Summarizing ADR: {}", args.id);
-    println!("Using provider: {}", args.provider);
+    let git = Git::new();
+    git.check_repository()?;
 
-    // TODO: Implement AI summarization
-    // let service = AiService::new(&args.provider, args.model.as_deref())?;
-    // let summary = service.summarize(&args.id)?;
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git.clone(), config);
+    let mut adr = notes.resolve(&args.id)?;
+
+    let provider_config = provider_config(args.provider.as_deref(), args.model)?;
+    let service = ai_service(&git, provider_config, &args.cache)?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    if !args.write {
+        println!("{}", runtime.block_on(service.summarize(&adr))?);
+        report_cache(&service);
+        return Ok(());
+    }
+
+    let summary = runtime.block_on(service.write_summary(&mut adr))?;
+    println!("{summary}");
+    report_cache(&service);
+    if args.dry_run {
+        tracing::info!(
+            "{} Would store the summary in ADR {} (dry run)",
+            "→".yellow(),
+            adr.id.cyan()
+        );
+    } else {
+        notes.update(&adr)?;
+        tracing::info!("{} Stored summary in ADR {}", "✓".green(), adr.id.cyan());
+    }
+
+    Ok(())
+}
+
+fn run_suggest_links(args: SuggestLinksArgs) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git.clone(), config);
+    let mut adr = notes.resolve(&args.id)?;
+    let others = notes.list()?;
+
+    // An explicit provider must work; otherwise fall back to local ranking
+    let suggestions = match provider_config(args.provider.as_deref(), args.model) {
+        Ok(provider_config) => {
+            let service = ai_service(&git, provider_config, &args.cache)?;
+            let suggestions = tokio::runtime::Runtime::new()?
+                .block_on(service.suggest_links(&adr, &others, args.limit));
+            report_cache(&service);
+            suggestions
+        },
+        Err(_) if args.provider.is_none() => {
+            tracing::info!(
+                "{} No AI provider configured, ranking ADRs by text similarity",
+                "→".blue()
+            );
+            suggest_links(&adr, &others, args.limit)
+        },
+        Err(e) => return Err(e),
+    };
+
+    if suggestions.is_empty() {
+        tracing::info!("{} No related ADRs found for {}", "→".yellow(), adr.id);
+        return Ok(());
+    }
+
+    tracing::info!("{} Related ADRs for {}:", "→".blue(), adr.id.cyan());
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        println!(
+            "  {}. {} {} {} ({:.2})",
+            i + 1,
+            suggestion.link.rel,
+            suggestion.link.target.cyan(),
+            suggestion.title,
+            suggestion.score
+        );
+    }
+    println!();
+
+    let mut added = 0;
+    for suggestion in suggestions {
+        if args.yes || confirm_link(&suggestion.link.rel, &suggestion.link.target)? {
+            adr.frontmatter.links.push(suggestion.link);
+            added += 1;
+        }
+    }
+
+    if added == 0 {
+        tracing::info!("{} No links added", "→".yellow());
+        return Ok(());
+    }
+
+    notes.update(&adr)?;
+    tracing::info!(
+        "{} Added {} link(s) to ADR {}",
+        "✓".green(),
+        added,
+        adr.id.cyan()
+    );
 
-    println!("\n[AI summarization not yet implemented in Rust version]");
     Ok(())
 }
+
+fn run_review(args: ReviewArgs) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git.clone(), config);
+    let adrs = notes.list()?;
+
+    // An explicit provider must work; otherwise fall back to local heuristics
+    let found = match provider_config(args.provider.as_deref(), args.model) {
+        Ok(provider_config) => {
+            let service = ai_service(&git, provider_config, &args.cache)?;
+            let found = tokio::runtime::Runtime::new()?.block_on(service.review(&adrs));
+            report_cache(&service);
+            found
+        },
+        Err(_) if args.provider.is_none() => {
+            if args.format != "json" {
+                tracing::info!(
+                    "{} No AI provider configured, using local heuristics only",
+                    "→".blue()
+                );
+            }
+            find_contradictions(&adrs)
+        },
+        Err(e) => return Err(e),
+    };
+
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&found)?);
+        return Ok(());
+    }
+
+    if found.is_empty() {
+        tracing::info!("{} No contradictions found", "✓".green());
+        return Ok(());
+    }
+
+    for contradiction in &found {
+        println!(
+            "{} {} ↔ {}: {}",
+            "✗".red(),
+            contradiction.first.cyan(),
+            contradiction.second.cyan(),
+            contradiction.rationale
+        );
+    }
+    eprintln!();
+    tracing::warn!("{} possible contradiction(s) found", found.len());
+
+    Ok(())
+}
+
+/// Provider settings from the command line, defaulting to the first
+/// provider with credentials set.
+fn provider_config(provider: Option<&str>, model: Option<String>) -> Result<ProviderConfig> {
+    let provider = match provider {
+        Some(name) => name.parse::<AiProvider>()?,
+        None => default_provider()?,
+    };
+    let mut config = ProviderConfig::new(provider);
+    if let Some(model) = model {
+        config = config.with_model(model);
+    }
+    Ok(config)
+}
+
+/// An AI service that caches completions under `.git` unless `--no-cache`
+/// was given.
+fn ai_service(git: &Git, provider_config: ProviderConfig, cache: &CacheArgs) -> Result<AiService> {
+    let service = AiService::new(provider_config);
+    if cache.no_cache {
+        return Ok(service);
+    }
+    Ok(service.with_cache(ResponseCache::for_repository(git)?))
+}
+
+/// Report how many completions came from the cache (shown with `--verbose`).
+fn report_cache(service: &AiService) {
+    match service.cache_stats() {
+        Some(stats) => tracing::debug!(
+            "{} AI cache: {} hit(s), {} miss(es)",
+            "→".blue(),
+            stats.hits,
+            stats.misses
+        ),
+        None => tracing::debug!("{} AI cache: disabled", "→".blue()),
+    }
+}
+
+/// Ask whether to add a suggested link.
+fn confirm_link(rel: &str, target: &str) -> Result<bool> {
+    print!("Add link {rel} {target}? [y/N] ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    Ok(input.eq_ignore_ascii_case("y") || input.eq_ignore_ascii_case("yes"))
+}
@@ -1,16 +1,23 @@
 //! List artifacts attached to an ADR.
 
 use anyhow::Result;
-use clap::Args as ClapArgs;
+use clap::{Args as ClapArgs, Subcommand};
 use colored::Colorize;
+use std::path::{Path, PathBuf};
 
-use crate::core::{ConfigManager, Git, NotesManager, ARTIFACTS_NOTES_REF};
+use crate::core::{Adr, Artifact, ConfigManager, Git, NotesManager};
 
 /// Arguments for the artifacts command.
 #[derive(ClapArgs, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
+    /// Artifacts subcommand.
+    #[command(subcommand)]
+    pub command: Option<ArtifactsCommand>,
+
     /// ADR ID.
-    pub adr_id: String,
+    #[arg(required = true)]
+    pub adr_id: Option<String>,
 
     /// Output format (text, json).
     #[arg(long, short, default_value = "text")]
@@ -25,6 +32,40 @@ pub struct Args {
     pub remove: bool,
 }
 
+/// Artifacts subcommands.
+#[derive(Subcommand, Debug)]
+pub enum ArtifactsCommand {
+    /// Recompute artifact checksums and report mismatches.
+    Verify {
+        /// ADR ID.
+        adr_id: String,
+    },
+    /// Write stored artifacts back to disk under their original filenames.
+    Extract {
+        /// ADR ID.
+        adr_id: String,
+
+        /// Artifact to extract (defaults to the ADR's only artifact).
+        name: Option<String>,
+
+        /// Directory to write into.
+        #[arg(long, short, default_value = ".")]
+        output: PathBuf,
+
+        /// Extract every artifact of the ADR.
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+    },
+    /// Remove a single artifact from an ADR.
+    Rm {
+        /// ADR ID.
+        adr_id: String,
+
+        /// Filename of the artifact to remove.
+        name: String,
+    },
+}
+
 /// Run the artifacts command.
 ///
 /// # Errors
@@ -37,78 +78,248 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git.clone(), config);
 
-    // Find the ADR
-    let adrs = notes.list()?;
-    let adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    match &args.command {
+        Some(ArtifactsCommand::Verify { adr_id }) => {
+            let adr = notes.resolve(adr_id)?;
+            return run_verify(&notes, &adr);
+        },
+        Some(ArtifactsCommand::Extract {
+            adr_id,
+            name,
+            output,
+            all,
+        }) => {
+            let adr = notes.resolve(adr_id)?;
+            return run_extract(&notes, &adr, name.as_deref(), *all, output);
+        },
+        Some(ArtifactsCommand::Rm { adr_id, name }) => {
+            let adr = notes.resolve(adr_id)?;
+            return run_rm(&notes, &adr, name);
+        },
+        None => {},
+    }
+
+    let adr_id = args
+        .adr_id
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("ADR ID is required"))?;
+    let adr = notes.resolve(adr_id)?;
 
     // Get artifacts for this ADR's commit
-    let artifact_content = git.notes_show(ARTIFACTS_NOTES_REF, &adr.commit)?;
-
-    match artifact_content {
-        Some(content) => {
-            // Parse artifact JSON
-            let artifact: serde_json::Value = serde_json::from_str(&content)?;
-
-            if args.remove {
-                // Remove the artifact from this ADR
-                git.notes_remove(ARTIFACTS_NOTES_REF, &adr.commit)?;
-                eprintln!(
-                    "{} Removed artifact {} from ADR {}",
-                    "✓".green(),
-                    artifact["filename"].as_str().unwrap_or("unknown").cyan(),
-                    adr.id.cyan()
-                );
-            } else if let Some(extract_name) = &args.extract {
-                // Extract the artifact to a file
-                use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
-
-                let encoded = artifact["content"]
-                    .as_str()
-                    .ok_or_else(|| anyhow::anyhow!("No content in artifact"))?;
-
-                let decoded = BASE64.decode(encoded)?;
-                std::fs::write(extract_name, decoded)?;
-
-                eprintln!(
-                    "{} Extracted {} ({} bytes)",
-                    "✓".green(),
-                    extract_name.cyan(),
-                    artifact["size"]
-                );
-            } else if args.format.as_str() == "json" {
-                // Remove content field for listing
-                let mut listing = artifact;
-                if let Some(obj) = listing.as_object_mut() {
-                    obj.remove("content");
-                }
-                println!("{}", serde_json::to_string_pretty(&listing)?);
-            } else {
-                eprintln!("{} Artifacts for ADR {}:", "→".blue(), adr.id.cyan());
-                println!();
-                println!(
-                    "  {} {}",
-                    "Filename:".bold(),
-                    artifact["filename"].as_str().unwrap_or("unknown").cyan()
-                );
-                println!(
-                    "  {} {} bytes",
-                    "Size:".bold(),
-                    artifact["size"].as_u64().unwrap_or(0)
-                );
-                if let Some(desc) = artifact["description"].as_str() {
-                    if !desc.is_empty() {
-                        println!("  {} {}", "Description:".bold(), desc);
-                    }
+    let artifacts = notes.artifacts(&adr)?;
+    if artifacts.is_empty() {
+        tracing::info!("{} No artifacts found for ADR {}", "→".yellow(), adr.id);
+        return Ok(());
+    }
+
+    if args.remove {
+        // Remove every artifact from this ADR
+        git.notes_remove(&notes.config().artifacts_ref(), &adr.commit)?;
+        for artifact in &artifacts {
+            tracing::info!(
+                "{} Removed artifact {} from ADR {}",
+                "✓".green(),
+                artifact.filename.cyan(),
+                adr.id.cyan()
+            );
+        }
+    } else if let Some(extract_name) = &args.extract {
+        // Extract the artifact to a file
+        let [artifact] = artifacts.as_slice() else {
+            anyhow::bail!(
+                "ADR {} has {} artifacts ({}); use 'git adr artifacts extract {} <name>'",
+                adr.id,
+                artifacts.len(),
+                artifact_names(&artifacts),
+                adr.id
+            );
+        };
+        let decoded = artifact.decode()?;
+        std::fs::write(extract_name, decoded)?;
+
+        tracing::info!(
+            "{} Extracted {} ({} bytes)",
+            "✓".green(),
+            extract_name.cyan(),
+            artifact.size
+        );
+    } else if args.format.as_str() == "json" {
+        // Remove content field for listing
+        let mut listing = serde_json::to_value(&artifacts)?;
+        if let Some(items) = listing.as_array_mut() {
+            for item in items.iter_mut().filter_map(|item| item.as_object_mut()) {
+                item.remove("content");
+            }
+        }
+        println!("{}", serde_json::to_string_pretty(&listing)?);
+    } else {
+        tracing::info!("{} Artifacts for ADR {}:", "→".blue(), adr.id.cyan());
+        for artifact in &artifacts {
+            println!();
+            println!("  {} {}", "Filename:".bold(), artifact.filename.cyan());
+            println!("  {} {} bytes", "Size:".bold(), artifact.size);
+            println!(
+                "  {} {}",
+                "SHA-256:".bold(),
+                artifact.sha256.as_deref().unwrap_or("(not recorded)")
+            );
+            if let Some(desc) = &artifact.description {
+                if !desc.is_empty() {
+                    println!("  {} {}", "Description:".bold(), desc);
                 }
             }
+        }
+    }
+
+    Ok(())
+}
+
+/// Recompute the checksums of an ADR's artifacts and report mismatches.
+fn run_verify(notes: &NotesManager, adr: &Adr) -> Result<()> {
+    let artifacts = notes.artifacts(adr)?;
+    if artifacts.is_empty() {
+        tracing::info!("{} No artifacts found for ADR {}", "→".yellow(), adr.id);
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for artifact in &artifacts {
+        let check = artifact.verify()?;
+
+        match check.checksum_ok {
+            Some(true) => println!(
+                "{} {}: checksum OK ({})",
+                "✓".green(),
+                artifact.filename.cyan(),
+                check.actual_sha256
+            ),
+            Some(false) => println!(
+                "{} {}: checksum mismatch (expected {}, got {})",
+                "✗".red(),
+                artifact.filename.cyan(),
+                artifact.sha256.as_deref().unwrap_or_default(),
+                check.actual_sha256
+            ),
+            None => println!(
+                "{} {}: unverified, no checksum recorded (re-attach to add one)",
+                "!".yellow(),
+                artifact.filename.cyan()
+            ),
+        }
+        if !check.size_ok {
+            println!(
+                "{} {}: size mismatch (expected {} bytes, got {})",
+                "✗".red(),
+                artifact.filename.cyan(),
+                artifact.size,
+                check.actual_size
+            );
+        }
+        if !check.is_ok() {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!(
+            "{} of {} artifact(s) for ADR {} failed verification",
+            failed,
+            artifacts.len(),
+            adr.id
+        );
+    }
+
+    Ok(())
+}
+
+/// Write an ADR's artifacts into `output`, warning on checksum mismatches.
+///
+/// Without a name, the ADR's only artifact is written; an ADR with several
+/// needs a name or `all`.
+fn run_extract(
+    notes: &NotesManager,
+    adr: &Adr,
+    name: Option<&str>,
+    all: bool,
+    output: &Path,
+) -> Result<()> {
+    let artifacts = notes.artifacts(adr)?;
+    if artifacts.is_empty() {
+        anyhow::bail!("No artifacts found for ADR {}", adr.id);
+    }
+
+    let selected: Vec<&Artifact> = match name {
+        Some(name) => match artifacts.iter().find(|a| a.filename == name) {
+            Some(artifact) => vec![artifact],
+            None => anyhow::bail!(
+                "No artifact named {} on ADR {} (found {})",
+                name,
+                adr.id,
+                artifact_names(&artifacts)
+            ),
         },
-        None => {
-            eprintln!("{} No artifacts found for ADR {}", "→".yellow(), adr.id);
-        },
+        None if all || artifacts.len() == 1 => artifacts.iter().collect(),
+        None => anyhow::bail!(
+            "ADR {} has {} artifacts ({}); name one or pass --all",
+            adr.id,
+            artifacts.len(),
+            artifact_names(&artifacts)
+        ),
+    };
+
+    std::fs::create_dir_all(output)?;
+    for artifact in selected {
+        extract_artifact(adr, artifact, output)?;
     }
 
     Ok(())
 }
+
+/// Write one artifact into `output` under its original filename.
+fn extract_artifact(adr: &Adr, artifact: &Artifact, output: &Path) -> Result<()> {
+    // Only keep the final path component so a stored name can't escape `output`
+    let filename = Path::new(&artifact.filename)
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid artifact filename: {}", artifact.filename))?;
+    let path = output.join(filename);
+
+    let check = artifact.verify()?;
+    if !check.is_ok() {
+        tracing::warn!("{}: stored content does not match its recorded checksum (run 'git adr artifacts verify {}')", artifact.filename.cyan(),
+            adr.id
+        );
+    }
+
+    let data = artifact.decode()?;
+    std::fs::write(&path, &data)?;
+    tracing::info!(
+        "{} Extracted {} ({} bytes)",
+        "✓".green(),
+        path.display().to_string().cyan(),
+        data.len()
+    );
+
+    Ok(())
+}
+
+/// Filenames of `artifacts`, comma-separated.
+fn artifact_names(artifacts: &[Artifact]) -> String {
+    artifacts
+        .iter()
+        .map(|a| a.filename.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Remove the artifact named `name` from an ADR, keeping its others.
+fn run_rm(notes: &NotesManager, adr: &Adr, name: &str) -> Result<()> {
+    let artifact = notes.remove_artifact(adr, name)?;
+    tracing::info!(
+        "{} Removed artifact {} from ADR {}",
+        "✓".green(),
+        artifact.filename.cyan(),
+        adr.id.cyan()
+    );
+
+    Ok(())
+}
@@ -1,12 +1,11 @@
 //! Attach a file to an ADR.
 
 use anyhow::Result;
-use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use clap::Args as ClapArgs;
 use colored::Colorize;
 use std::path::Path;
 
-use crate::core::{ConfigManager, Git, NotesManager, ARTIFACTS_NOTES_REF};
+use crate::core::{Artifact, ConfigManager, Git, NotesManager};
 
 /// Arguments for the attach command.
 #[derive(ClapArgs, Debug)]
@@ -24,6 +23,10 @@ pub struct Args {
     /// Description/alt text for the attachment.
     #[arg(long)]
     pub description: Option<String>,
+
+    /// Attach even if the file exceeds `adr.maxArtifactSize`.
+    #[arg(long)]
+    pub force: bool,
 }
 
 /// Run the attach command.
@@ -36,14 +39,9 @@ pub fn run(args: Args) -> Result<()> {
     git.check_repository()?;
 
     let config = ConfigManager::new(git.clone()).load()?;
-    let notes = NotesManager::new(git.clone(), config);
+    let notes = NotesManager::new(git, config);
 
-    // Find the ADR
-    let adrs = notes.list()?;
-    let adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    let adr = notes.resolve(&args.adr_id)?;
 
     // Check file exists
     let file_path = Path::new(&args.file);
@@ -59,7 +57,7 @@ pub fn run(args: Args) -> Result<()> {
         )
     });
 
-    eprintln!(
+    tracing::info!(
         "{} Attaching {} to ADR {}",
         "→".blue(),
         filename.cyan(),
@@ -68,28 +66,20 @@ pub fn run(args: Args) -> Result<()> {
 
     // Read file content
     let content = std::fs::read(file_path)?;
-    let encoded = BASE64.encode(&content);
-
-    // Get file metadata
-    let metadata = std::fs::metadata(file_path)?;
-    let size = metadata.len();
-
-    // Create artifact metadata
-    let artifact = serde_json::json!({
-        "filename": filename,
-        "size": size,
-        "adr_id": adr.id,
-        "description": args.description,
-        "content": encoded,
-    });
-
-    // Store as a note on the ADR's commit
-    // Format: JSON blob with filename, size, content (base64)
-    let artifact_content = serde_json::to_string_pretty(&artifact)?;
+    if !args.force {
+        if let Err(e) = notes.config().check_artifact_size(content.len()) {
+            tracing::warn!("Raise adr.maxArtifactSize or pass --force to attach it anyway",);
+            return Err(e.into());
+        }
+    }
 
-    git.notes_add(ARTIFACTS_NOTES_REF, &adr.commit, &artifact_content)?;
+    // Add to the artifact note on the ADR's commit
+    // Format: JSON list of records with filename, size, checksum, content (base64)
+    let artifact = Artifact::new(filename.clone(), adr.id.clone(), args.description, &content);
+    let size = artifact.size;
+    notes.add_artifact(&adr, artifact)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Attached {} ({} bytes) to ADR {}",
         "✓".green(),
         filename.cyan(),
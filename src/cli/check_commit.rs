@@ -0,0 +1,139 @@
+//! Check that a commit message references an ADR.
+//!
+//! Called by the `commit-msg` hooks that `git adr hooks install --commit-msg`
+//! and `git adr hooks install adr-guard` write.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use regex::Regex;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use crate::core::{ConfigManager, Git, NotesManager};
+
+/// Arguments for the check-commit command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// File holding the commit message (the hook's first argument).
+    #[arg(long)]
+    pub message_file: PathBuf,
+
+    /// Only check commits that stage files matching these globs
+    /// (comma-separated, e.g. `src/arch/**,docs/design/*`).
+    #[arg(long, value_delimiter = ',')]
+    pub paths: Vec<String>,
+
+    /// Only check commits that stage files matching `adr.architecturePaths`;
+    /// every commit passes while it is unset.
+    #[arg(long, conflicts_with = "paths")]
+    pub architecture: bool,
+
+    /// Fail instead of warning when no existing ADR is referenced.
+    #[arg(long)]
+    pub strict: bool,
+}
+
+/// Run the check-commit command.
+///
+/// # Errors
+///
+/// With `--strict`, returns an error if the commit touches the given paths
+/// and its message references no ADR or an ADR that doesn't exist.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let paths = if args.architecture {
+        if config.architecture_paths.is_empty() {
+            return Ok(());
+        }
+        &config.architecture_paths
+    } else {
+        &args.paths
+    };
+
+    let touched = staged_matches(&git, paths)?;
+    if !paths.is_empty() && touched.is_empty() {
+        return Ok(());
+    }
+
+    let message = std::fs::read_to_string(&args.message_file)?;
+    let references = find_references(&message, &config.prefix)?;
+    let notes = NotesManager::new(git, config);
+
+    let unknown: Vec<&str> = references
+        .iter()
+        .map(String::as_str)
+        .filter(|reference| notes.resolve_id(reference).is_err())
+        .collect();
+
+    let problem = if references.is_empty() {
+        let mut problem = "Commit message doesn't reference an ADR".to_string();
+        if !touched.is_empty() {
+            let _ = write!(problem, " but changes {}", touched.join(", "));
+        }
+        problem
+    } else if !unknown.is_empty() {
+        format!(
+            "Commit message references unknown ADR(s): {}",
+            unknown.join(", ")
+        )
+    } else {
+        return Ok(());
+    };
+
+    if args.strict {
+        anyhow::bail!(problem);
+    }
+    tracing::warn!("{}", problem);
+    Ok(())
+}
+
+/// Staged files matching any of the globs, or none if there are no globs.
+fn staged_matches(git: &Git, globs: &[String]) -> Result<Vec<String>> {
+    if globs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pathspecs: Vec<String> = globs.iter().map(|glob| format!(":(glob){glob}")).collect();
+    let mut command = vec!["diff", "--cached", "--name-only", "--"];
+    command.extend(pathspecs.iter().map(String::as_str));
+    Ok(git
+        .run_output(&command)?
+        .lines()
+        .map(str::to_string)
+        .collect())
+}
+
+/// ADR IDs such as `ADR-0001` mentioned in a commit message, skipping the
+/// `#` comment lines git adds to the message template.
+fn find_references(message: &str, prefix: &str) -> Result<Vec<String>> {
+    let pattern = Regex::new(&format!(r"(?i)\b{}\d+\b", regex::escape(prefix)))?;
+    let mut references: Vec<String> = Vec::new();
+    for line in message.lines().filter(|line| !line.starts_with('#')) {
+        for found in pattern.find_iter(line) {
+            let reference = found.as_str().to_uppercase();
+            if !references.contains(&reference) {
+                references.push(reference);
+            }
+        }
+    }
+    Ok(references)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_references() {
+        let message = "Move to gRPC (ADR-0004, adr-12)\n\nSee ADR-0004.\n# ADR-0009 in a comment\n";
+        assert_eq!(
+            find_references(message, "ADR-").unwrap(),
+            ["ADR-0004", "ADR-12"]
+        );
+        assert!(find_references("Fix typo", "ADR-").unwrap().is_empty());
+        assert!(find_references("BADR-1", "ADR-").unwrap().is_empty());
+    }
+}
@@ -1,12 +1,13 @@
-//! CI/CD workflow generation for ADR integration.
+//! CI/CD workflow generation and checks for ADR integration.
 
 use anyhow::Result;
 use clap::{Args as ClapArgs, Subcommand};
 use colored::Colorize;
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 
-use crate::core::Git;
+use crate::core::{ConfigManager, Git, NotesManager};
 
 /// Arguments for the CI command.
 #[derive(ClapArgs, Debug)]
@@ -24,6 +25,12 @@ pub enum CiCommand {
 
     /// Generate GitLab CI configuration.
     Gitlab(GitlabArgs),
+
+    /// Run every ADR check, failing if any finds a problem.
+    Check(CheckArgs),
+
+    /// Generate a pipeline that runs `ci check` for a CI provider.
+    Generate(GenerateArgs),
 }
 
 /// Arguments for GitHub Actions generation.
@@ -66,6 +73,51 @@ pub struct GitlabArgs {
     pub sync: bool,
 }
 
+/// Arguments for check pipeline generation.
+#[derive(ClapArgs, Debug)]
+pub struct GenerateArgs {
+    /// CI provider (github, gitlab, azure, jenkins).
+    #[arg(long, short, value_parser = ["github", "gitlab", "azure", "jenkins"])]
+    pub provider: String,
+
+    /// Output file path (default: the provider's usual location).
+    #[arg(long, short)]
+    pub output: Option<String>,
+
+    /// Force overwrite existing file.
+    #[arg(long, short)]
+    pub force: bool,
+}
+
+/// Arguments for the CI check.
+#[derive(ClapArgs, Debug)]
+pub struct CheckArgs {
+    /// Output format (text, json).
+    #[arg(long, short, default_value = "text", value_parser = ["text", "json"])]
+    pub format: String,
+
+    /// Report a category of problems as warnings instead of failing
+    /// (repeatable).
+    #[arg(long, value_parser = CHECK_CATEGORIES)]
+    pub allow: Vec<String>,
+}
+
+/// Categories of problems found by `ci check`.
+const CHECK_CATEGORIES: [&str; 4] = ["links", "sections", "invalid", "gaps"];
+
+/// A problem found by `ci check`.
+#[derive(Debug, Serialize)]
+struct Finding {
+    /// One of [`CHECK_CATEGORIES`].
+    category: &'static str,
+    /// ADR the problem is in.
+    adr_id: String,
+    /// Human-readable description.
+    message: String,
+    /// Whether `--allow` downgraded the problem to a warning.
+    warning: bool,
+}
+
 /// Run the CI command.
 ///
 /// # Errors
@@ -78,6 +130,8 @@ pub fn run(args: Args) -> Result<()> {
     match args.command {
         CiCommand::Github(github_args) => run_github(github_args),
         CiCommand::Gitlab(gitlab_args) => run_gitlab(gitlab_args),
+        CiCommand::Check(check_args) => run_check(&check_args, git),
+        CiCommand::Generate(generate_args) => run_generate(&generate_args),
     }
 }
 
@@ -87,7 +141,7 @@ fn run_github(args: GithubArgs) -> Result<()> {
 
     if !output_dir.exists() {
         fs::create_dir_all(output_dir)?;
-        eprintln!("{} Created {}", "✓".green(), args.output.cyan());
+        tracing::info!("{} Created {}", "✓".green(), args.output.cyan());
     }
 
     let workflow_path = output_dir.join("adr.yml");
@@ -102,19 +156,19 @@ fn run_github(args: GithubArgs) -> Result<()> {
     let workflow = generate_github_workflow(args.validation, args.sync);
     fs::write(&workflow_path, workflow)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Generated GitHub Actions workflow: {}",
         "✓".green(),
         workflow_path.display().to_string().cyan()
     );
 
-    eprintln!();
-    eprintln!("{} Workflow includes:", "→".blue());
+    tracing::info!("");
+    tracing::info!("{} Workflow includes:", "→".blue());
     if args.validation {
-        eprintln!("  • ADR validation on pull requests");
+        tracing::info!("  • ADR validation on pull requests");
     }
     if args.sync {
-        eprintln!("  • ADR sync on push to main branch");
+        tracing::info!("  • ADR sync on push to main branch");
     }
 
     Ok(())
@@ -134,24 +188,144 @@ fn run_gitlab(args: GitlabArgs) -> Result<()> {
     let config = generate_gitlab_ci(args.validation, args.sync);
     fs::write(output_path, config)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Generated GitLab CI configuration: {}",
         "✓".green(),
         output_path.display().to_string().cyan()
     );
 
-    eprintln!();
-    eprintln!("{} Configuration includes:", "→".blue());
+    tracing::info!("");
+    tracing::info!("{} Configuration includes:", "→".blue());
     if args.validation {
-        eprintln!("  • ADR validation job");
+        tracing::info!("  • ADR validation job");
     }
     if args.sync {
-        eprintln!("  • ADR sync job");
+        tracing::info!("  • ADR sync job");
     }
 
     Ok(())
 }
 
+/// Generate a check pipeline for a CI provider.
+fn run_generate(args: &GenerateArgs) -> Result<()> {
+    let (default_output, content, name) = match args.provider.as_str() {
+        "gitlab" => (".gitlab-ci.yml", GITLAB_CHECK_CI, "GitLab CI configuration"),
+        "azure" => (
+            "azure-pipelines.yml",
+            AZURE_CHECK_PIPELINE,
+            "Azure Pipelines configuration",
+        ),
+        "jenkins" => ("Jenkinsfile", JENKINS_CHECK_PIPELINE, "Jenkinsfile"),
+        _ => (
+            ".github/workflows/adr.yml",
+            GITHUB_CHECK_WORKFLOW,
+            "GitHub Actions workflow",
+        ),
+    };
+    let output_path = Path::new(args.output.as_deref().unwrap_or(default_output));
+
+    // Create parent directories if needed
+    if let Some(parent) = output_path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    if output_path.exists() && !args.force {
+        anyhow::bail!(
+            "{} already exists: {}. Use --force to overwrite.",
+            name,
+            output_path.display()
+        );
+    }
+
+    fs::write(output_path, content)?;
+
+    tracing::info!(
+        "{} Generated {}: {}",
+        "✓".green(),
+        name,
+        output_path.display().to_string().cyan()
+    );
+
+    Ok(())
+}
+
+/// Run every ADR check.
+fn run_check(args: &CheckArgs, git: Git) -> Result<()> {
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git, config);
+
+    let mut findings = Vec::new();
+    let mut add = |category: &'static str, adr_id: String, message: String| {
+        findings.push(Finding {
+            category,
+            adr_id,
+            message,
+            warning: args.allow.iter().any(|allowed| allowed == category),
+        });
+    };
+
+    for issue in notes.validate_links()? {
+        add("links", issue.adr_id, issue.message);
+    }
+    for issue in notes.validate_sections()? {
+        add("sections", issue.adr_id, issue.message);
+    }
+    for (id, reason) in notes.invalid_notes()? {
+        let message = format!("{id} can't be read: {reason}");
+        add("invalid", id, message);
+    }
+    for number in notes.id_gaps()? {
+        let id = notes.format_id(number);
+        let message = format!("{id} is missing from the ID sequence");
+        add("gaps", id, message);
+    }
+
+    let failures = findings.iter().filter(|finding| !finding.warning).count();
+    let warnings = findings.len() - failures;
+
+    if args.format == "json" {
+        let report = serde_json::json!({
+            "passed": failures == 0,
+            "failures": failures,
+            "warnings": warnings,
+            "findings": findings,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for finding in &findings {
+            let marker = if finding.warning {
+                "!".yellow()
+            } else {
+                "✗".red()
+            };
+            println!(
+                "{} {} [{}] {}",
+                marker,
+                finding.adr_id.cyan(),
+                finding.category,
+                finding.message
+            );
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("ADR checks failed: {failures} problem(s), {warnings} warning(s)");
+    }
+    if args.format != "json" {
+        if warnings > 0 {
+            eprintln!(
+                "{} ADR checks passed with {warnings} warning(s)",
+                "!".yellow()
+            );
+        } else {
+            println!("{} All ADR checks passed", "✓".green());
+        }
+    }
+    Ok(())
+}
+
 /// Generate GitHub Actions workflow content.
 fn generate_github_workflow(validation: bool, sync: bool) -> String {
     let mut workflow = String::new();
@@ -311,3 +485,141 @@ sync-adrs:
 
     config
 }
+
+/// GitHub Actions workflow that fetches ADR notes and runs `ci check`.
+const GITHUB_CHECK_WORKFLOW: &str = r#"# ADR (Architecture Decision Records) check workflow
+# Generated by git-adr
+
+name: ADR
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+env:
+  GIT_ADR_VERSION: "1.0.0"
+
+jobs:
+  check:
+    name: Check ADRs
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+        with:
+          fetch-depth: 0
+
+      - name: Install git-adr
+        run: |
+          curl -sSL https://github.com/zircote/git-adr/releases/download/v${{ env.GIT_ADR_VERSION }}/git-adr-x86_64-unknown-linux-gnu.tar.gz | tar xz
+          sudo mv git-adr /usr/local/bin/
+
+      - name: Fetch ADR notes
+        run: git fetch origin 'refs/notes/*:refs/notes/*'
+
+      - name: Check ADRs
+        run: git-adr ci check
+"#;
+
+/// GitLab CI job that fetches ADR notes and runs `ci check`.
+const GITLAB_CHECK_CI: &str = r#"# ADR (Architecture Decision Records) check job
+# Generated by git-adr
+
+stages:
+  - validate
+
+variables:
+  GIT_ADR_VERSION: "1.0.0"
+  GIT_DEPTH: 0
+
+check-adrs:
+  stage: validate
+  image: ubuntu:latest
+  before_script:
+    - apt-get update && apt-get install -y curl git
+    - curl -sSL https://github.com/zircote/git-adr/releases/download/v${GIT_ADR_VERSION}/git-adr-x86_64-unknown-linux-gnu.tar.gz | tar xz
+    - mv git-adr /usr/local/bin/
+    - git fetch origin 'refs/notes/*:refs/notes/*'
+  script:
+    - git-adr ci check
+"#;
+
+/// Azure Pipelines stage that fetches ADR notes and runs `ci check`.
+const AZURE_CHECK_PIPELINE: &str = r#"# ADR (Architecture Decision Records) check pipeline
+# Generated by git-adr
+
+trigger:
+  branches:
+    include: [main, master]
+
+pr:
+  branches:
+    include: ['*']
+
+variables:
+  GIT_ADR_VERSION: "1.0.0"
+
+stages:
+  - stage: ValidateADRs
+    displayName: Validate ADRs
+    jobs:
+      - job: check
+        displayName: Check ADRs
+        pool:
+          vmImage: ubuntu-latest
+        steps:
+          - checkout: self
+            fetchDepth: 0
+
+          - script: |
+              curl -sSL https://github.com/zircote/git-adr/releases/download/v$(GIT_ADR_VERSION)/git-adr-x86_64-unknown-linux-gnu.tar.gz | tar xz
+              sudo mv git-adr /usr/local/bin/
+            displayName: Install git-adr
+
+          - script: git fetch origin 'refs/notes/*:refs/notes/*'
+            displayName: Fetch ADR notes
+
+          - script: git-adr ci check
+            displayName: Check ADRs
+"#;
+
+/// Jenkins declarative pipeline that fetches ADR notes, runs `ci check` and
+/// archives an HTML export of the ADRs.
+const JENKINS_CHECK_PIPELINE: &str = r#"// ADR (Architecture Decision Records) check pipeline
+// Generated by git-adr
+
+pipeline {
+    agent any
+
+    environment {
+        GIT_ADR_VERSION = '1.0.0'
+    }
+
+    stages {
+        stage('Install git-adr') {
+            steps {
+                sh 'curl -sSL https://github.com/zircote/git-adr/releases/download/v${GIT_ADR_VERSION}/git-adr-x86_64-unknown-linux-gnu.tar.gz | tar xz'
+            }
+        }
+
+        stage('Fetch ADR notes') {
+            steps {
+                sh "git fetch origin 'refs/notes/*:refs/notes/*'"
+            }
+        }
+
+        stage('Check ADRs') {
+            steps {
+                sh './git-adr ci check'
+            }
+        }
+
+        stage('Export ADRs') {
+            steps {
+                sh './git-adr export --format html --output adr-export'
+                archiveArtifacts artifacts: 'adr-export/**', allowEmptyArchive: true
+            }
+        }
+    }
+}
+"#;
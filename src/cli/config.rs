@@ -3,6 +3,7 @@
 use anyhow::Result;
 use clap::{Args as ClapArgs, Subcommand};
 use colored::Colorize;
+use std::fmt::Write as _;
 
 use crate::core::{ConfigManager, Git};
 
@@ -28,6 +29,9 @@ pub enum ConfigCommand {
         key: String,
         /// Configuration value.
         value: String,
+        /// Skip validation and allow unknown keys.
+        #[arg(long)]
+        raw: bool,
     },
     /// Unset a configuration value.
     Unset {
@@ -45,6 +49,65 @@ const CONFIG_KEYS: &[(&str, &str)] = &[
     ("digits", "Number of digits in ADR IDs (default: 4)"),
     ("template", "Default template name"),
     ("format", "Default ADR format (nygard, madr, etc.)"),
+    (
+        "namespace",
+        "Git notes namespace for ADRs (default: adr)",
+    ),
+    ("sign", "GPG-sign ADR notes when writing (true/false)"),
+    (
+        "autoFetch",
+        "Fetch notes from the default remote before list/show/search/stats (true/false)",
+    ),
+    (
+        "templatesDir",
+        "Directory of custom ADR templates (*.md, *.tera)",
+    ),
+    (
+        "editor",
+        "Editor for ADR content (after $GIT_EDITOR, before core.editor)",
+    ),
+    (
+        "default-deciders",
+        "Deciders for new ADRs when none are given (comma-separated)",
+    ),
+    (
+        "architecturePaths",
+        "Globs of paths whose commits must reference an ADR (comma-separated)",
+    ),
+    (
+        "maxArtifactSize",
+        "Largest file attach accepts, in bytes (default: 5 MiB)",
+    ),
+    (
+        "maxBodySize",
+        "Largest ADR body new/edit accept, in bytes (default: 10 MiB)",
+    ),
+    (
+        "pager",
+        "Pager for long output (default: $GIT_PAGER, $PAGER, less)",
+    ),
+    (
+        "staleDays",
+        "Days before a proposed ADR counts as stale (default: 90)",
+    ),
+    (
+        "idScheme",
+        "ID scheme for new ADRs: numeric, slug or date-slug (default: numeric)",
+    ),
+    (
+        "wiki.platform",
+        "Wiki platform: github, gitlab, confluence or auto (default: auto)",
+    ),
+    (
+        "wiki.repository",
+        "Wiki repository, owner/repo or project path (default: from the remote)",
+    ),
+    (
+        "wiki.url",
+        "Wiki base URL: self-hosted GitHub/GitLab or the Confluence site",
+    ),
+    ("wiki.spaceKey", "Confluence space key"),
+    ("wiki.username", "Confluence username"),
 ];
 
 /// Run the config command.
@@ -63,27 +126,24 @@ pub fn run(args: Args) -> Result<()> {
             if let Some(v) = config_manager.get(&key)? {
                 println!("{}", v);
             } else {
-                eprintln!("{} Config key not set: adr.{}", "!".yellow(), key);
+                tracing::warn!("Config key not set: adr.{}", key);
                 std::process::exit(1);
             }
         },
-        ConfigCommand::Set { key, value } => {
-            // Validate known keys
-            if !CONFIG_KEYS.iter().any(|(k, _)| *k == key) {
-                eprintln!(
-                    "{} Unknown config key: {}. Known keys are:",
-                    "!".yellow(),
-                    key
-                );
-                for (k, desc) in CONFIG_KEYS {
-                    eprintln!("  {} - {}", k.cyan(), desc);
+        ConfigCommand::Set { key, value, raw } => {
+            if raw {
+                config_manager.set_raw(&key, &value)?;
+            } else {
+                if !CONFIG_KEYS.iter().any(|(k, _)| *k == key) {
+                    let mut known = String::new();
+                    for (k, desc) in CONFIG_KEYS {
+                        let _ = write!(known, "\n  {} - {}", k.cyan(), desc);
+                    }
+                    tracing::warn!("Unknown config key: {}. Known keys are:{}", key, known);
                 }
-                eprintln!();
-                eprintln!("Setting anyway...");
+                config_manager.set(&key, &value)?;
             }
-
-            config_manager.set(&key, &value)?;
-            eprintln!(
+            tracing::info!(
                 "{} Set adr.{} = {}",
                 "✓".green(),
                 key.cyan(),
@@ -92,20 +152,21 @@ pub fn run(args: Args) -> Result<()> {
         },
         ConfigCommand::Unset { key } => {
             // Use git directly to unset the key
-            git.config_unset(&format!("adr.{key}"), false)?;
-            eprintln!("{} Unset adr.{}", "✓".green(), key.cyan());
+            git.config_unset(&format!("adr.{key}"), true)?;
+            tracing::info!("{} Unset adr.{}", "✓".green(), key.cyan());
         },
         ConfigCommand::List => {
-            eprintln!("{} ADR Configuration:", "→".blue());
-            eprintln!();
+            tracing::info!("{} ADR Configuration:", "→".blue());
+            tracing::info!("");
 
-            let config = config_manager.load()?;
-
-            println!("{} = {}", "adr.initialized".cyan(), config.initialized);
-            println!("{} = {}", "adr.prefix".cyan(), config.prefix);
-            println!("{} = {}", "adr.digits".cyan(), config.digits);
-            println!("{} = {}", "adr.template".cyan(), config.template);
-            println!("{} = {}", "adr.format".cyan(), config.format);
+            for (key, value, source) in config_manager.effective()? {
+                println!(
+                    "{} = {} {}",
+                    format!("adr.{key}").cyan(),
+                    value,
+                    format!("({source})").dimmed()
+                );
+            }
         },
     }
 
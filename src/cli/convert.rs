@@ -4,13 +4,14 @@ use anyhow::Result;
 use clap::Args as ClapArgs;
 use colored::Colorize;
 
-use crate::core::{ConfigManager, Git, NotesManager, TemplateEngine};
+use crate::core::{Adr, AdrStatus, ConfigManager, Git, NotesManager, TemplateEngine};
 
 /// Arguments for the convert command.
 #[derive(ClapArgs, Debug)]
 pub struct Args {
     /// ADR ID to convert.
-    pub adr_id: String,
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub adr_id: Option<String>,
 
     /// Target format (nygard, madr, y-statement, alexandrian).
     #[arg(long, short)]
@@ -19,6 +20,22 @@ pub struct Args {
     /// Save in place (update the ADR).
     #[arg(long)]
     pub in_place: bool,
+
+    /// Convert every ADR and save the results.
+    #[arg(long)]
+    pub all: bool,
+
+    /// With --all, only convert ADRs with this status.
+    #[arg(long, short, requires = "all", conflicts_with = "adr_id")]
+    pub status: Option<String>,
+
+    /// With --all, only convert ADRs with this tag.
+    #[arg(long, short = 'g', requires = "all", conflicts_with = "adr_id")]
+    pub tag: Option<String>,
+
+    /// With --all, show what would be converted without saving.
+    #[arg(long, requires = "all", conflicts_with = "adr_id")]
+    pub dry_run: bool,
 }
 
 /// Supported formats.
@@ -45,25 +62,24 @@ pub fn run(args: Args) -> Result<()> {
         );
     }
 
-    // Find the ADR
-    let adrs = notes.list()?;
-    let mut adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    if args.all {
+        return run_all(&notes, &args);
+    }
+
+    let adr_id = args
+        .adr_id
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("ADR ID is required"))?;
+    let mut adr = notes.resolve(adr_id)?;
 
     let current_format = adr.frontmatter.format.as_deref().unwrap_or("nygard");
 
     if current_format == args.to {
-        eprintln!(
-            "{} ADR is already in {} format",
-            "!".yellow(),
-            args.to.cyan()
-        );
+        tracing::warn!("ADR is already in {} format", args.to.cyan());
         return Ok(());
     }
 
-    eprintln!(
+    tracing::info!(
         "{} Converting ADR {} from {} to {}",
         "→".blue(),
         adr.id.cyan(),
@@ -71,27 +87,97 @@ pub fn run(args: Args) -> Result<()> {
         args.to.green()
     );
 
-    // Re-render the body with the new template
-    let template_engine = TemplateEngine::new();
-    let mut context = std::collections::HashMap::new();
-    context.insert("title".to_string(), adr.frontmatter.title.clone());
-    context.insert("status".to_string(), adr.frontmatter.status.to_string());
-    let new_body = template_engine.render(&args.to, &context)?;
-
-    // Update format metadata
-    adr.frontmatter.format = Some(args.to.clone());
-    adr.body = new_body;
+    convert_adr(&mut adr, &args.to)?;
 
     if args.in_place {
         // Save the converted ADR
         notes.update(&adr)?;
-        eprintln!("{} ADR {} converted and saved", "✓".green(), adr.id.cyan());
+        tracing::info!("{} ADR {} converted and saved", "✓".green(), adr.id.cyan());
     } else {
         // Just print the converted content
         println!("{}", adr.to_markdown()?);
-        eprintln!();
-        eprintln!("{} Use {} to save changes", "→".blue(), "--in-place".cyan());
+        tracing::info!("");
+        tracing::info!("{} Use {} to save changes", "→".blue(), "--in-place".cyan());
+    }
+
+    Ok(())
+}
+
+/// Convert every ADR matching the filters and save the results.
+fn run_all(notes: &NotesManager, args: &Args) -> Result<()> {
+    let mut adrs = notes.list()?;
+
+    // Archived ADRs are left alone unless asked for, as in `list`
+    if let Some(status_filter) = &args.status {
+        let target_status: AdrStatus = status_filter.parse()?;
+        adrs.retain(|adr| *adr.status() == target_status);
+    } else {
+        adrs.retain(|adr| *adr.status() != AdrStatus::Archived);
+    }
+    if let Some(tag_filter) = &args.tag {
+        adrs.retain(|adr| adr.has_tag(tag_filter));
     }
 
+    let mut converted = 0;
+    let mut skipped = 0;
+    for mut adr in adrs {
+        let current_format = adr
+            .frontmatter
+            .format
+            .clone()
+            .unwrap_or_else(|| "nygard".to_string());
+        if current_format == args.to {
+            skipped += 1;
+            continue;
+        }
+
+        convert_adr(&mut adr, &args.to)?;
+        if args.dry_run {
+            tracing::info!(
+                "  {} would convert {} from {} to {}",
+                "→".blue(),
+                adr.id.cyan(),
+                current_format.yellow(),
+                args.to.green()
+            );
+        } else {
+            notes.update(&adr)?;
+            tracing::info!(
+                "  {} {} converted from {} to {}",
+                "✓".green(),
+                adr.id.cyan(),
+                current_format.yellow(),
+                args.to.green()
+            );
+        }
+        converted += 1;
+    }
+
+    let verb = if args.dry_run {
+        "would be converted"
+    } else {
+        "converted"
+    };
+    tracing::info!(
+        "{} {} {}, {} already in {} format",
+        "✓".green(),
+        converted,
+        verb,
+        skipped,
+        args.to.cyan()
+    );
+
+    Ok(())
+}
+
+/// Convert an ADR to another format in place.
+///
+/// Only the body and the `format` field change; every other frontmatter
+/// field (status, tags, authors, deciders, links, custom fields) is carried
+/// over untouched, and body sections are remapped onto the target format.
+fn convert_adr(adr: &mut Adr, to: &str) -> Result<()> {
+    let template_engine = TemplateEngine::new();
+    adr.body = template_engine.render_converted(to, adr)?;
+    adr.frontmatter.format = Some(to.to_string());
     Ok(())
 }
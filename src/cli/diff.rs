@@ -0,0 +1,170 @@
+//! Diff two revisions of an ADR.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Write as _;
+
+use crate::cli::pager;
+use crate::core::{ConfigManager, Git, NotesManager};
+
+/// Arguments for the diff command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// ADR ID.
+    pub adr_id: String,
+
+    /// Older revision: `~N` revisions before the latest, or a notes commit hash.
+    #[arg(long, default_value = "~1")]
+    pub from: String,
+
+    /// Newer revision: `~N` revisions before the latest, or a notes commit hash.
+    #[arg(long, default_value = "~0")]
+    pub to: String,
+
+    /// Output format (text, json).
+    #[arg(long, short, default_value = "text")]
+    pub format: String,
+
+    /// Don't pipe output through a pager.
+    #[arg(long)]
+    pub no_pager: bool,
+}
+
+/// One hunk of a unified diff.
+#[derive(Debug, Serialize)]
+struct Hunk {
+    old_start: u32,
+    old_lines: u32,
+    new_start: u32,
+    new_lines: u32,
+    lines: Vec<HunkLine>,
+}
+
+/// One line of a hunk.
+#[derive(Debug, Serialize)]
+struct HunkLine {
+    /// `context`, `add` or `remove`.
+    kind: &'static str,
+    text: String,
+}
+
+/// Run the diff command.
+///
+/// # Errors
+///
+/// Returns an error if either revision cannot be resolved or diffed.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git.clone(), config.clone());
+
+    let adr = notes.resolve(&args.adr_id)?;
+    let from = notes.revision(&adr, &args.from)?;
+    let to = notes.revision(&adr, &args.to)?;
+
+    let diff = git.diff_blobs(from.blob.as_deref(), to.blob.as_deref())?;
+
+    if args.format == "json" {
+        let output = serde_json::json!({
+            "id": adr.id,
+            "from": from.notes_commit,
+            "to": to.notes_commit,
+            "hunks": parse_hunks(&diff),
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        tracing::info!(
+            "{} No changes between {} and {}",
+            "→".blue(),
+            &from.notes_commit[..8],
+            &to.notes_commit[..8]
+        );
+        return Ok(());
+    }
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "{}",
+        format!("--- {} {}", adr.id, &from.notes_commit[..8]).bold()
+    );
+    let _ = writeln!(
+        output,
+        "{}",
+        format!("+++ {} {}", adr.id, &to.notes_commit[..8]).bold()
+    );
+    output.push_str(&colorize(&diff));
+
+    pager::page(&output, config.pager.as_deref(), !args.no_pager)
+}
+
+/// Color unified diff lines the way `git diff` does.
+#[must_use]
+pub fn colorize(diff: &str) -> String {
+    let mut out = String::new();
+    for line in diff.lines() {
+        let line = if line.starts_with("@@") {
+            line.cyan()
+        } else if line.starts_with('+') {
+            line.green()
+        } else if line.starts_with('-') {
+            line.red()
+        } else {
+            line.normal()
+        };
+        let _ = writeln!(out, "{line}");
+    }
+    out
+}
+
+/// Split unified diff output into structured hunks.
+fn parse_hunks(diff: &str) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = Vec::new();
+
+    for line in diff.lines() {
+        if let Some(header) = line.strip_prefix("@@ ") {
+            let mut ranges = header.split_whitespace();
+            let (old_start, old_lines) = parse_range(ranges.next(), '-');
+            let (new_start, new_lines) = parse_range(ranges.next(), '+');
+            hunks.push(Hunk {
+                old_start,
+                old_lines,
+                new_start,
+                new_lines,
+                lines: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(hunk) = hunks.last_mut() else {
+            continue;
+        };
+        let (kind, text) = match line.chars().next() {
+            Some('+') => ("add", &line[1..]),
+            Some('-') => ("remove", &line[1..]),
+            Some(' ') => ("context", &line[1..]),
+            // `\ No newline at end of file`
+            _ => continue,
+        };
+        hunk.lines.push(HunkLine {
+            kind,
+            text: text.to_string(),
+        });
+    }
+
+    hunks
+}
+
+/// Parse a `-start,count` or `+start,count` hunk range (count defaults to 1).
+fn parse_range(range: Option<&str>, sign: char) -> (u32, u32) {
+    let range = range.and_then(|r| r.strip_prefix(sign)).unwrap_or_default();
+    let (start, count) = range.split_once(',').unwrap_or((range, "1"));
+    (start.parse().unwrap_or(0), count.parse().unwrap_or(0))
+}
@@ -1,33 +1,82 @@
-//! Edit an existing ADR.
+//! Edit an existing ADR, or the status and tags of every ADR matching a
+//! filter.
 
 use anyhow::Result;
 use clap::Args as ClapArgs;
 use colored::Colorize;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Input, MultiSelect, Select};
+use std::io::{self, IsTerminal, Write};
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use crate::cli::list::filter_adrs;
+use crate::core::{
+    open_in_editor, split_list, Adr, AdrStatus, ConfigManager, Git, IndexManager, NotesManager,
+};
+use crate::Error;
 
 /// Arguments for the edit command.
 #[derive(ClapArgs, Debug)]
 pub struct Args {
-    /// ADR ID to edit.
-    pub adr_id: String,
+    /// ADR ID to edit (omit to edit every ADR matching the filters).
+    #[arg(required_unless_present_any = ["tag", "filter_status", "since", "until"])]
+    pub adr_id: Option<String>,
 
-    /// Quick edit: change status.
+    /// Bulk edit: only ADRs with this tag.
+    #[arg(long, short = 'g', conflicts_with = "adr_id")]
+    pub tag: Option<String>,
+
+    /// Bulk edit: only ADRs with this status.
+    #[arg(long, conflicts_with = "adr_id")]
+    pub filter_status: Option<String>,
+
+    /// Bulk edit: only ADRs dated on or after this date.
+    #[arg(long, conflicts_with = "adr_id")]
+    pub since: Option<String>,
+
+    /// Bulk edit: only ADRs dated on or before this date.
+    #[arg(long, conflicts_with = "adr_id")]
+    pub until: Option<String>,
+
+    /// Bulk edit: apply without asking for confirmation.
     #[arg(long, short)]
+    pub yes: bool,
+
+    /// Quick edit: change status.
+    #[arg(long, short, visible_alias = "set-status")]
     pub status: Option<String>,
 
+    /// Quick edit: mark as accepted (shorthand for `--status accepted`).
+    #[arg(long, conflicts_with = "status")]
+    pub accept: bool,
+
+    /// Allow any status change, bypassing transition validation.
+    #[arg(long, visible_alias = "force")]
+    pub allow_any_transition: bool,
+
     /// Quick edit: add tag.
     #[arg(long)]
     pub add_tag: Option<String>,
 
-    /// Quick edit: remove tag.
+    /// Quick edit: remove tag (case-insensitive).
     #[arg(long)]
     pub remove_tag: Option<String>,
 
+    /// Quick edit: set a custom frontmatter field (`key=value`, value parsed as YAML).
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub set_field: Vec<String>,
+
+    /// Quick edit: remove a custom frontmatter field.
+    #[arg(long, value_name = "KEY")]
+    pub remove_field: Vec<String>,
+
     /// Quick edit: change title.
     #[arg(long, short)]
     pub title: Option<String>,
 
+    /// Open the ADR body in your editor.
+    #[arg(long)]
+    pub body: bool,
+
     /// Quick edit: add decider.
     #[arg(long)]
     pub add_decider: Option<String>,
@@ -35,6 +84,17 @@ pub struct Args {
     /// Quick edit: remove decider.
     #[arg(long)]
     pub remove_decider: Option<String>,
+
+    /// Prompt for status, tags and deciders instead of using quick edit flags.
+    #[arg(
+        long,
+        short,
+        conflicts_with_all = [
+            "status", "accept", "add_tag", "remove_tag", "set_field", "remove_field",
+            "title", "body", "add_decider", "remove_decider",
+        ]
+    )]
+    pub interactive: bool,
 }
 
 /// Run the edit command.
@@ -50,57 +110,40 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git, config);
 
-    // Find the ADR
-    let adrs = notes.list()?;
-    let mut adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    let Some(adr_id) = &args.adr_id else {
+        return run_bulk(&args, &notes);
+    };
+    let mut adr = notes.resolve(adr_id)?;
 
-    eprintln!("{} Editing ADR: {}", "→".blue(), adr.id);
+    tracing::info!("{} Editing ADR: {}", "→".blue(), adr.id);
 
     let mut modified = false;
 
+    if args.interactive {
+        modified = interactive_edit(&mut adr, &args, &notes)?;
+    }
+
     // Apply quick edits
-    if let Some(status_str) = &args.status {
-        let status: AdrStatus = status_str.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
-        eprintln!("  Status: {} → {}", adr.frontmatter.status, status);
-        adr.frontmatter.status = status;
+    if apply_status_and_tags(&mut adr, &args, notes.git())? {
         modified = true;
     }
 
     if let Some(title) = &args.title {
-        eprintln!("  Title: {} → {}", adr.frontmatter.title, title);
+        tracing::info!("  Title: {} → {}", adr.frontmatter.title, title);
         adr.frontmatter.title.clone_from(title);
         modified = true;
     }
 
-    if let Some(tag) = &args.add_tag {
-        if adr.frontmatter.tags.contains(tag) {
-            eprintln!("  Tag already exists: {}", tag);
-        } else {
-            adr.frontmatter.tags.push(tag.clone());
-            eprintln!("  Added tag: {}", tag);
-            modified = true;
-        }
-    }
-
-    if let Some(tag) = &args.remove_tag {
-        if let Some(pos) = adr.frontmatter.tags.iter().position(|t| t == tag) {
-            adr.frontmatter.tags.remove(pos);
-            eprintln!("  Removed tag: {}", tag);
-            modified = true;
-        } else {
-            eprintln!("  Tag not found: {}", tag);
-        }
+    if apply_fields(&mut adr, &args)? {
+        modified = true;
     }
 
     if let Some(decider) = &args.add_decider {
         if adr.frontmatter.deciders.contains(decider) {
-            eprintln!("  Decider already exists: {}", decider);
+            tracing::info!("  Decider already exists: {}", decider);
         } else {
             adr.frontmatter.deciders.push(decider.clone());
-            eprintln!("  Added decider: {}", decider);
+            tracing::info!("  Added decider: {}", decider);
             modified = true;
         }
     }
@@ -108,22 +151,395 @@ pub fn run(args: Args) -> Result<()> {
     if let Some(decider) = &args.remove_decider {
         if let Some(pos) = adr.frontmatter.deciders.iter().position(|d| d == decider) {
             adr.frontmatter.deciders.remove(pos);
-            eprintln!("  Removed decider: {}", decider);
+            tracing::info!("  Removed decider: {}", decider);
             modified = true;
         } else {
-            eprintln!("  Decider not found: {}", decider);
+            tracing::info!("  Decider not found: {}", decider);
+        }
+    }
+
+    if args.body {
+        let edited = open_in_editor(notes.git(), notes.config(), &adr.body)?;
+        let edited = edited.trim().to_string();
+        if edited == adr.body.trim() {
+            tracing::info!("  Body unchanged");
+        } else {
+            adr.body = edited;
+            tracing::info!("  Updated body");
+            modified = true;
         }
     }
 
     if !modified {
-        eprintln!("{} No changes specified", "!".yellow());
+        tracing::warn!("No changes specified");
         return Ok(());
     }
 
     // Save changes
+    notes.config().check_body_size(&adr.body)?;
     notes.update(&adr)?;
 
-    eprintln!("{} ADR updated: {}", "✓".green(), adr.id);
+    tracing::info!("{} ADR updated: {}", "✓".green(), adr.id);
 
     Ok(())
 }
+
+/// Apply `--status`/`--accept`, `--add-tag` and `--remove-tag`, the edits
+/// that also work on several ADRs. Returns whether anything changed.
+fn apply_status_and_tags(adr: &mut Adr, args: &Args, git: &Git) -> Result<bool> {
+    let mut modified = false;
+
+    let status_arg = if args.accept {
+        Some("accepted")
+    } else {
+        args.status.as_deref()
+    };
+    if let Some(status_str) = status_arg {
+        apply_status(adr, status_str, args, git)?;
+        modified = true;
+    }
+
+    if let Some(tag) = &args.add_tag {
+        if adr.frontmatter.tags.contains(tag) {
+            tracing::info!("  Tag already exists: {}", tag);
+        } else {
+            adr.frontmatter.tags.push(tag.clone());
+            tracing::info!("  Added tag: {}", tag);
+            modified = true;
+        }
+    }
+
+    if let Some(tag) = &args.remove_tag {
+        if let Some(pos) = adr
+            .frontmatter
+            .tags
+            .iter()
+            .position(|t| t.eq_ignore_ascii_case(tag))
+        {
+            let removed = adr.frontmatter.tags.remove(pos);
+            tracing::info!("  Removed tag: {}", removed);
+            modified = true;
+        } else {
+            tracing::warn!("Tag not found: {}", tag);
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Apply the status and tag edits to every ADR matching the filters, after
+/// showing the changes and asking for confirmation (unless `--yes`).
+///
+/// ADRs moved to the archive ref by `rm --archive` are only matched by
+/// `--filter-status archived`; they take tag edits but need `restore` before
+/// a status change.
+/// Every status change is validated before anything is written.
+fn run_bulk(args: &Args, notes: &NotesManager) -> Result<()> {
+    if args.interactive
+        || args.title.is_some()
+        || args.body
+        || !args.set_field.is_empty()
+        || !args.remove_field.is_empty()
+        || args.add_decider.is_some()
+        || args.remove_decider.is_some()
+    {
+        anyhow::bail!(
+            "Only --status, --accept, --add-tag and --remove-tag can edit several ADRs; \
+             give an ADR ID for other edits"
+        );
+    }
+
+    let filter = |adrs: &mut Vec<Adr>| {
+        filter_adrs(
+            adrs,
+            args.filter_status.as_deref(),
+            args.tag.as_deref(),
+            args.since.as_deref(),
+            args.until.as_deref(),
+        )
+    };
+
+    let mut adrs = notes.list()?;
+    if args.filter_status.is_none() {
+        adrs.retain(|adr| *adr.status() != AdrStatus::Archived);
+    }
+    filter(&mut adrs)?;
+
+    // ADRs moved out by `rm --archive` live in their own ref
+    let mut moved = Vec::new();
+    let filter_status: Option<AdrStatus> =
+        args.filter_status.as_deref().map(str::parse).transpose()?;
+    if filter_status == Some(AdrStatus::Archived) {
+        moved = notes.list_archived()?;
+        filter(&mut moved)?;
+    }
+
+    let mut matches: Vec<(Adr, bool)> = adrs
+        .into_iter()
+        .map(|adr| (adr, false))
+        .chain(moved.into_iter().map(|adr| (adr, true)))
+        .collect();
+    matches.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
+    if matches.is_empty() {
+        tracing::warn!("No ADRs match the filters");
+        return Ok(());
+    }
+
+    let mut changed = Vec::new();
+    for (mut adr, in_archive) in matches {
+        tracing::info!("{} {}: {}", "→".blue(), adr.id, adr.frontmatter.title);
+        if in_archive && (args.accept || args.status.is_some()) {
+            anyhow::bail!(
+                "{} is archived; run 'git adr restore {}' before changing its status",
+                adr.id,
+                adr.id
+            );
+        }
+        let before = adr.to_markdown()?;
+        if apply_status_and_tags(&mut adr, args, notes.git())? && adr.to_markdown()? != before {
+            changed.push((adr, in_archive));
+        }
+    }
+    if changed.is_empty() {
+        tracing::warn!("No changes specified");
+        return Ok(());
+    }
+
+    if !args.yes {
+        eprint!("Apply these changes to {} ADR(s)? [y/N] ", changed.len());
+        io::stderr().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+
+        if !input.trim().eq_ignore_ascii_case("y") {
+            tracing::warn!("Aborted");
+            return Ok(());
+        }
+    }
+
+    for (adr, in_archive) in &changed {
+        if *in_archive {
+            notes.update_archived(adr)?;
+        } else {
+            notes.update(adr)?;
+        }
+    }
+
+    // Keep search results in step with the new tags, as `tag rename` does
+    IndexManager::with_config(notes.git().clone(), notes.config()).rebuild(notes)?;
+
+    tracing::info!("{} Updated {} ADR(s)", "✓".green(), changed.len());
+
+    Ok(())
+}
+
+/// Frontmatter keys with dedicated fields; `--set-field` must not shadow them.
+const RESERVED_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "date",
+    "tags",
+    "authors",
+    "deciders",
+    "links",
+    "format",
+    "supersedes",
+    "superseded_by",
+];
+
+/// Apply `--set-field` and `--remove-field` to the custom frontmatter.
+///
+/// Values are parsed as YAML scalars, so `priority=2` stores a number and
+/// `reviewed=true` a boolean. Returns whether anything changed.
+fn apply_fields(adr: &mut Adr, args: &Args) -> Result<bool> {
+    let mut modified = false;
+
+    for field in &args.set_field {
+        let (key, raw) = field
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid field '{}': expected key=value", field))?;
+        let key = key.trim();
+        if key.is_empty() {
+            anyhow::bail!("Invalid field '{}': key is empty", field);
+        }
+        if RESERVED_FIELDS.contains(&key) {
+            anyhow::bail!(
+                "Cannot set '{}' with --set-field; use the dedicated option instead",
+                key
+            );
+        }
+
+        let value = match serde_yaml::from_str::<serde_yaml::Value>(raw) {
+            Ok(
+                value @ (serde_yaml::Value::Bool(_)
+                | serde_yaml::Value::Number(_)
+                | serde_yaml::Value::String(_)),
+            ) => value,
+            _ => serde_yaml::Value::String(raw.to_string()),
+        };
+        tracing::info!("  Set field: {} = {}", key, raw);
+        adr.frontmatter.custom.insert(key.to_string(), value);
+        modified = true;
+    }
+
+    for key in &args.remove_field {
+        if adr.frontmatter.custom.remove(key).is_some() {
+            tracing::info!("  Removed field: {}", key);
+            modified = true;
+        } else {
+            tracing::warn!("Field not found: {}", key);
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Change the ADR status, enforcing the transition rules.
+///
+/// When an ADR becomes accepted, the current git user is recorded as a
+/// decider unless `--add-decider` was given explicitly.
+fn apply_status(adr: &mut Adr, status_str: &str, args: &Args, git: &Git) -> Result<()> {
+    let status: AdrStatus = status_str.parse()?;
+    if !args.allow_any_transition && !adr.frontmatter.status.can_transition_to(&status) {
+        let allowed: Vec<String> = adr
+            .frontmatter
+            .status
+            .allowed_transitions()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let allowed = if allowed.is_empty() {
+            "none".to_string()
+        } else {
+            allowed.join(", ")
+        };
+        return Err(Error::validation(format!(
+            "cannot change status of {} from {} to {} (allowed: {}); use --force to override",
+            adr.id, adr.frontmatter.status, status, allowed
+        ))
+        .into());
+    }
+    tracing::info!("  Status: {} → {}", adr.frontmatter.status, status);
+
+    if status == AdrStatus::Accepted
+        && adr.frontmatter.status != AdrStatus::Accepted
+        && args.add_decider.is_none()
+    {
+        if let Ok((name, email)) = git.user_identity() {
+            let decider = format!("{name} <{email}>");
+            if !adr.frontmatter.deciders.contains(&decider) {
+                tracing::info!("  Added decider: {}", decider);
+                adr.frontmatter.deciders.push(decider);
+            }
+        }
+    }
+
+    adr.frontmatter.status = status;
+    Ok(())
+}
+
+/// Prompt for a new status, tags and deciders, starting from the current
+/// values.
+///
+/// Status is picked from the allowed transitions, tags are toggled from every
+/// tag in use (with new ones typed in), and deciders are edited as a
+/// comma-separated line. Returns whether anything changed.
+fn interactive_edit(adr: &mut Adr, args: &Args, notes: &NotesManager) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Err(Error::validation(
+            "--interactive needs a terminal; use --status, --add-tag and --remove-tag in scripts",
+        )
+        .into());
+    }
+
+    let theme = ColorfulTheme::default();
+    let mut modified = false;
+
+    let choices: Vec<AdrStatus> = if args.allow_any_transition {
+        AdrStatus::ALL
+            .into_iter()
+            .filter(|s| *s != adr.frontmatter.status && *s != AdrStatus::Archived)
+            .collect()
+    } else {
+        adr.frontmatter.status.allowed_transitions()
+    };
+    if choices.is_empty() {
+        eprintln!(
+            "{} {} (no other status allowed)",
+            "Status:".bold(),
+            adr.frontmatter.status
+        );
+    } else {
+        let picked = Select::with_theme(&theme)
+            .with_prompt("Status")
+            .item(format!("{} (unchanged)", adr.frontmatter.status))
+            .items(&choices)
+            .default(0)
+            .interact()?;
+        if let Some(status) = picked.checked_sub(1).and_then(|i| choices.get(i)) {
+            apply_status(adr, &status.to_string(), args, notes.git())?;
+            modified = true;
+        }
+    }
+
+    // The ADR's own tags come first, checked
+    let mut known = adr.frontmatter.tags.clone();
+    for tag in notes.tag_counts()?.into_keys() {
+        if !known.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            known.push(tag);
+        }
+    }
+    let current = adr.frontmatter.tags.len();
+    let mut tags: Vec<String> = if known.is_empty() {
+        Vec::new()
+    } else {
+        MultiSelect::with_theme(&theme)
+            .with_prompt("Tags (space toggles)")
+            .items_checked(known.iter().enumerate().map(|(i, tag)| (tag, i < current)))
+            .interact()?
+            .into_iter()
+            .map(|i| known[i].clone())
+            .collect()
+    };
+    let added: String = Input::with_theme(&theme)
+        .with_prompt("New tags (comma-separated)")
+        .allow_empty(true)
+        .interact_text()?;
+    for tag in split_list(&added) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            tags.push(tag);
+        }
+    }
+    modified |= replace_list(&mut adr.frontmatter.tags, tags, "tag");
+
+    let deciders: String = Input::with_theme(&theme)
+        .with_prompt("Deciders (comma-separated)")
+        .with_initial_text(adr.frontmatter.deciders.join(", "))
+        .allow_empty(true)
+        .interact_text()?;
+    modified |= replace_list(
+        &mut adr.frontmatter.deciders,
+        split_list(&deciders),
+        "decider",
+    );
+
+    Ok(modified)
+}
+
+/// Replace `list` with `items`, logging what was added and removed. Returns
+/// whether anything changed.
+fn replace_list(list: &mut Vec<String>, items: Vec<String>, what: &str) -> bool {
+    if *list == items {
+        return false;
+    }
+    for removed in list.iter().filter(|i| !items.contains(i)) {
+        tracing::info!("  Removed {}: {}", what, removed);
+    }
+    for added in items.iter().filter(|i| !list.contains(i)) {
+        tracing::info!("  Added {}: {}", what, added);
+    }
+    *list = items;
+    true
+}
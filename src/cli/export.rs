@@ -1,13 +1,18 @@
 //! Export ADRs to various formats.
 
 use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
 use clap::Args as ClapArgs;
 use colored::Colorize;
+use std::cmp::Reverse;
 use std::fmt::Write;
 use std::fs;
 use std::path::Path;
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use crate::core::{
+    dot_graph, markdown_to_html, mermaid_graph, Adr, AdrJson, AdrStatus, ConfigManager,
+    FlexibleDate, Git, NotesManager,
+};
 
 /// Arguments for the export command.
 #[derive(ClapArgs, Debug)]
@@ -16,7 +21,7 @@ pub struct Args {
     #[arg(long, short, default_value = "./adr-export")]
     pub output: String,
 
-    /// Export format (markdown, json, html).
+    /// Export format (markdown, json, html, dot, mermaid, atom).
     #[arg(long, short, default_value = "markdown")]
     pub format: String,
 
@@ -31,6 +36,15 @@ pub struct Args {
     /// Generate index file.
     #[arg(long, default_value = "true")]
     pub index: bool,
+
+    /// Write all ADRs to a single file with a table of contents
+    /// (markdown and html only).
+    #[arg(long)]
+    pub single_file: bool,
+
+    /// Only include the N most recent ADRs (atom only).
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
 }
 
 /// Run the export command.
@@ -50,7 +64,7 @@ pub fn run(args: Args) -> Result<()> {
 
     // Filter by status
     if let Some(status_str) = &args.status {
-        let status: AdrStatus = status_str.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+        let status: AdrStatus = status_str.parse()?;
         adrs.retain(|a| a.frontmatter.status == status);
     }
 
@@ -59,12 +73,20 @@ pub fn run(args: Args) -> Result<()> {
         adrs.retain(|a| a.frontmatter.tags.iter().any(|t| t.contains(tag)));
     }
 
+    if args.single_file && !matches!(args.format.as_str(), "markdown" | "html") {
+        anyhow::bail!("--single-file is only supported for markdown and html export");
+    }
+
+    if args.limit.is_some() && args.format != "atom" {
+        anyhow::bail!("--limit is only supported for atom export");
+    }
+
     if adrs.is_empty() {
-        eprintln!("{} No ADRs to export", "!".yellow());
+        tracing::warn!("No ADRs to export");
         return Ok(());
     }
 
-    eprintln!(
+    tracing::info!(
         "{} Exporting {} ADR(s) to {} format in {}",
         "→".blue(),
         adrs.len(),
@@ -76,6 +98,59 @@ pub fn run(args: Args) -> Result<()> {
     let output_path = Path::new(&args.output);
     fs::create_dir_all(output_path)?;
 
+    // The feed is a single file of the most recent ADRs
+    if args.format == "atom" {
+        let entries = args.limit.map_or(adrs.len(), |limit| limit.min(adrs.len()));
+        fs::write(
+            output_path.join("atom.xml"),
+            export_atom_feed(&adrs, args.limit),
+        )?;
+        tracing::info!("  {} atom.xml", "✓".green());
+        tracing::info!(
+            "{} Exported feed of {} ADR(s) to {}",
+            "✓".green(),
+            entries,
+            args.output.cyan()
+        );
+        return Ok(());
+    }
+
+    // Graph formats write a single file covering every ADR
+    if matches!(args.format.as_str(), "dot" | "mermaid") {
+        let (filename, content) = if args.format == "dot" {
+            ("graph.dot", dot_graph(&adrs))
+        } else {
+            ("graph.mmd", mermaid_graph(&adrs))
+        };
+        fs::write(output_path.join(filename), content)?;
+        tracing::info!("  {} {}", "✓".green(), filename);
+        tracing::info!(
+            "{} Exported graph of {} ADR(s) to {}",
+            "✓".green(),
+            adrs.len(),
+            args.output.cyan()
+        );
+        return Ok(());
+    }
+
+    // A single combined document replaces the per-ADR files and the index
+    if args.single_file {
+        let (filename, content) = if args.format == "html" {
+            ("adrs.html", export_html_combined(&adrs))
+        } else {
+            ("adrs.md", export_markdown_combined(&adrs))
+        };
+        fs::write(output_path.join(filename), content)?;
+        tracing::info!("  {} {}", "✓".green(), filename);
+        tracing::info!(
+            "{} Exported {} ADR(s) to {}",
+            "✓".green(),
+            adrs.len(),
+            output_path.join(filename).display().to_string().cyan()
+        );
+        return Ok(());
+    }
+
     let extension = if args.format == "json" {
         "json"
     } else if args.format == "html" {
@@ -90,18 +165,7 @@ pub fn run(args: Args) -> Result<()> {
         let filepath = output_path.join(&filename);
 
         let content = if args.format == "json" {
-            let json = serde_json::json!({
-                "id": adr.id,
-                "title": adr.frontmatter.title,
-                "status": adr.frontmatter.status.to_string(),
-                "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
-                "tags": adr.frontmatter.tags,
-                "authors": adr.frontmatter.authors,
-                "deciders": adr.frontmatter.deciders,
-                "commit": adr.commit,
-                "body": adr.body,
-            });
-            serde_json::to_string_pretty(&json)?
+            serde_json::to_string_pretty(&AdrJson::from_adr(adr))?
         } else if args.format == "html" {
             export_html_single(adr)?
         } else {
@@ -109,7 +173,7 @@ pub fn run(args: Args) -> Result<()> {
         };
 
         fs::write(&filepath, content)?;
-        eprintln!("  {} {}", "✓".green(), filename);
+        tracing::info!("  {} {}", "✓".green(), filename);
     }
 
     // Generate index file
@@ -126,10 +190,10 @@ pub fn run(args: Args) -> Result<()> {
         };
 
         fs::write(&index_path, index_content)?;
-        eprintln!("  {} {}", "✓".green(), index_filename);
+        tracing::info!("  {} {}", "✓".green(), index_filename);
     }
 
-    eprintln!(
+    tracing::info!(
         "{} Exported {} ADR(s) to {}",
         "✓".green(),
         adrs.len(),
@@ -141,20 +205,6 @@ pub fn run(args: Args) -> Result<()> {
 
 /// Export a single ADR to HTML.
 fn export_html_single(adr: &crate::core::Adr) -> Result<String> {
-    let tags_html = if adr.frontmatter.tags.is_empty() {
-        String::new()
-    } else {
-        let tags = adr
-            .frontmatter
-            .tags
-            .iter()
-            .fold(String::new(), |mut acc, t| {
-                let _ = write!(acc, "<span class=\"tag\">{}</span>", html_escape(t));
-                acc
-            });
-        format!("<div class=\"tags\">{tags}</div>")
-    };
-
     Ok(format!(
         r#"<!DOCTYPE html>
 <html>
@@ -185,11 +235,27 @@ fn export_html_single(adr: &crate::core::Adr) -> Result<String> {
         html_escape(&adr.frontmatter.title),
         adr.frontmatter.status,
         adr.frontmatter.status,
-        tags_html,
+        tags_html(adr),
         markdown_to_html(&adr.body)
     ))
 }
 
+/// Render an ADR's tags as HTML, or nothing when it has none.
+fn tags_html(adr: &Adr) -> String {
+    if adr.frontmatter.tags.is_empty() {
+        return String::new();
+    }
+    let tags = adr
+        .frontmatter
+        .tags
+        .iter()
+        .fold(String::new(), |mut acc, t| {
+            let _ = write!(acc, "<span class=\"tag\">{}</span>", html_escape(t));
+            acc
+        });
+    format!("<div class=\"tags\">{tags}</div>")
+}
+
 /// Export JSON index.
 fn export_json_index(adrs: &[crate::core::Adr]) -> Result<String> {
     let index: Vec<_> = adrs
@@ -270,58 +336,215 @@ fn export_markdown_index(adrs: &[crate::core::Adr]) -> String {
     content
 }
 
-/// Escape HTML special characters.
-fn html_escape(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&#39;")
+/// Build the anchor for an ADR section heading.
+///
+/// Follows GitHub's heading slug rules (lowercase, punctuation dropped,
+/// spaces to hyphens) so table of contents links resolve in rendered
+/// markdown as well as in the generated HTML `id` attributes.
+fn heading_anchor(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
 }
 
-/// Simple markdown to HTML conversion (basic support).
-fn markdown_to_html(md: &str) -> String {
-    let mut html = String::new();
-    let mut in_code_block = false;
-
-    for line in md.lines() {
-        if line.starts_with("```") {
-            if in_code_block {
-                html.push_str("</code></pre>\n");
-                in_code_block = false;
-            } else {
-                html.push_str("<pre><code>");
-                in_code_block = true;
-            }
-            continue;
-        }
+/// Export all ADRs as one markdown document with a table of contents.
+fn export_markdown_combined(adrs: &[Adr]) -> String {
+    let mut content = String::from(
+        "# Architecture Decision Records
 
-        if in_code_block {
-            html.push_str(&html_escape(line));
-            html.push('\n');
-            continue;
+## Contents
+
+",
+    );
+    for adr in adrs {
+        let heading = format!("{}: {}", adr.id, adr.frontmatter.title);
+        let _ = writeln!(content, "- [{}](#{})", heading, heading_anchor(&heading));
+    }
+
+    for adr in adrs {
+        let _ = write!(
+            content,
+            "\n---\n\n## {}: {}\n\n**Status:** {}",
+            adr.id, adr.frontmatter.title, adr.frontmatter.status
+        );
+        if !adr.frontmatter.tags.is_empty() {
+            let _ = write!(content, "  \n**Tags:** {}", adr.frontmatter.tags.join(", "));
         }
+        let _ = writeln!(content, "\n\n{}", adr.body.trim());
+    }
+    content
+}
 
-        let line = line.trim();
-
-        if line.is_empty() {
-            html.push_str("<p></p>\n");
-        } else if let Some(heading) = line.strip_prefix("### ") {
-            let _ = writeln!(html, "<h3>{}</h3>", html_escape(heading));
-        } else if let Some(heading) = line.strip_prefix("## ") {
-            let _ = writeln!(html, "<h2>{}</h2>", html_escape(heading));
-        } else if let Some(heading) = line.strip_prefix("# ") {
-            let _ = writeln!(html, "<h1>{}</h1>", html_escape(heading));
-        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
-            let _ = writeln!(html, "<li>{}</li>", html_escape(item));
-        } else {
-            let _ = writeln!(html, "<p>{}</p>", html_escape(line));
+/// Export all ADRs as one HTML document with a table of contents.
+fn export_html_combined(adrs: &[Adr]) -> String {
+    let mut toc = String::new();
+    let mut sections = String::new();
+    for adr in adrs {
+        let heading = format!("{}: {}", adr.id, adr.frontmatter.title);
+        let anchor = heading_anchor(&heading);
+        let _ = write!(
+            toc,
+            "<li><a href=\"#{}\">{}</a></li>",
+            html_escape(&anchor),
+            html_escape(&heading)
+        );
+        let _ = write!(
+            sections,
+            "<section>\n<h2 id=\"{}\">{}</h2>\n<p><span class=\"status {}\">{}</span></p>\n{}\n{}</section>\n",
+            html_escape(&anchor),
+            html_escape(&heading),
+            adr.frontmatter.status,
+            adr.frontmatter.status,
+            tags_html(adr),
+            markdown_to_html(&adr.body)
+        );
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>Architecture Decision Records</title>
+    <style>
+        body {{ font-family: system-ui, sans-serif; max-width: 800px; margin: 0 auto; padding: 2rem; }}
+        section {{ border-top: 1px solid #ddd; margin-top: 2rem; }}
+        .status {{ display: inline-block; padding: 0.25rem 0.5rem; border-radius: 4px; background: #e0e0e0; }}
+        .status.accepted {{ background: #c8e6c9; }}
+        .status.rejected {{ background: #ffcdd2; }}
+        .status.superseded {{ background: #fff9c4; }}
+        .tags {{ margin: 1rem 0; }}
+        .tag {{ display: inline-block; padding: 0.25rem 0.5rem; margin-right: 0.5rem; border-radius: 4px; background: #e3f2fd; }}
+        pre {{ background: #f5f5f5; padding: 1rem; overflow-x: auto; }}
+    </style>
+</head>
+<body>
+    <h1>Architecture Decision Records</h1>
+    <nav>
+        <h2>Contents</h2>
+        <ul>{toc}</ul>
+    </nav>
+{sections}</body>
+</html>"#
+    )
+}
+
+/// Export the most recent ADRs as an Atom feed, newest first.
+///
+/// Entries are ordered by the ADR's date, with undated ADRs last, and link
+/// to the file names the HTML export uses.
+fn export_atom_feed(adrs: &[Adr], limit: Option<usize>) -> String {
+    /// When an ADR was last decided on, for ordering and `<updated>`.
+    fn entry_time(adr: &Adr) -> Option<DateTime<Utc>> {
+        adr.frontmatter
+            .date
+            .as_ref()
+            .map(FlexibleDate::datetime)
+            .or(adr.last_modified)
+    }
+
+    fn timestamp(time: DateTime<Utc>) -> String {
+        time.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+
+    let mut recent: Vec<&Adr> = adrs.iter().collect();
+    recent.sort_by_key(|adr| Reverse(entry_time(adr)));
+    recent.truncate(limit.unwrap_or(recent.len()));
+
+    let updated = recent
+        .iter()
+        .copied()
+        .filter_map(entry_time)
+        .max()
+        .unwrap_or_else(Utc::now);
+
+    let mut feed = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    feed.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    feed.push_str("  <title>Architecture Decision Records</title>\n");
+    feed.push_str("  <id>urn:git-adr:feed</id>\n");
+    let _ = writeln!(feed, "  <updated>{}</updated>", timestamp(updated));
+    feed.push_str("  <author><name>git-adr</name></author>\n");
+    feed.push_str("  <generator>git-adr</generator>\n");
+
+    for adr in recent {
+        let status = adr.frontmatter.status.to_string();
+        feed.push_str("  <entry>\n");
+        let _ = writeln!(
+            feed,
+            "    <title>{}</title>",
+            html_escape(&adr.frontmatter.title)
+        );
+        let _ = writeln!(feed, "    <id>urn:git-adr:{}</id>", html_escape(&adr.id));
+        let _ = writeln!(
+            feed,
+            "    <updated>{}</updated>",
+            timestamp(entry_time(adr).unwrap_or(DateTime::UNIX_EPOCH))
+        );
+        let _ = writeln!(
+            feed,
+            "    <link rel=\"alternate\" type=\"text/html\" href=\"{}.html\"/>",
+            html_escape(&adr.id)
+        );
+        for author in &adr.frontmatter.authors {
+            let _ = writeln!(
+                feed,
+                "    <author><name>{}</name></author>",
+                html_escape(author)
+            );
+        }
+        let _ = writeln!(feed, "    <category term=\"{status}\" label=\"Status\"/>");
+        for tag in &adr.frontmatter.tags {
+            let _ = writeln!(feed, "    <category term=\"{}\"/>", html_escape(tag));
         }
+        let _ = writeln!(
+            feed,
+            "    <summary>{}</summary>",
+            html_escape(&feed_summary(adr, &status))
+        );
+        feed.push_str("  </entry>\n");
     }
 
-    if in_code_block {
-        html.push_str("</code></pre>\n");
+    feed.push_str("</feed>\n");
+    feed
+}
+
+/// Status plus the opening paragraph of an ADR, for feed summaries.
+fn feed_summary(adr: &Adr, status: &str) -> String {
+    /// Longest opening paragraph kept, in characters.
+    const MAX_SUMMARY_CHARS: usize = 280;
+
+    let paragraph = adr
+        .body
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty() || line.starts_with('#'))
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut summary = format!("Status: {status}.");
+    if !paragraph.is_empty() {
+        summary.push(' ');
+        if paragraph.chars().count() > MAX_SUMMARY_CHARS {
+            summary.extend(paragraph.chars().take(MAX_SUMMARY_CHARS));
+            summary.push('…');
+        } else {
+            summary.push_str(&paragraph);
+        }
     }
+    summary
+}
 
-    html
+/// Escape HTML special characters.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
 }
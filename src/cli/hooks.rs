@@ -3,13 +3,14 @@
 use anyhow::Result;
 use clap::{Args as ClapArgs, Subcommand};
 use colored::Colorize;
+use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
-use crate::core::Git;
+use crate::core::{ConfigManager, Git};
 
 /// Arguments for the hooks command.
 #[derive(ClapArgs, Debug)]
@@ -35,6 +36,12 @@ pub enum HooksCommand {
 /// Arguments for hooks install.
 #[derive(ClapArgs, Debug)]
 pub struct InstallArgs {
+    /// Install only this hook preset. `adr-guard` installs a commit-msg hook
+    /// that blocks commits touching `adr.architecturePaths` unless they
+    /// reference an existing ADR.
+    #[arg(value_parser = ["adr-guard"], conflicts_with_all = ["commit_msg", "post_merge"])]
+    pub preset: Option<String>,
+
     /// Force overwrite existing hooks.
     #[arg(long, short)]
     pub force: bool,
@@ -46,6 +53,19 @@ pub struct InstallArgs {
     /// Install post-merge hook for ADR sync.
     #[arg(long)]
     pub post_merge: bool,
+
+    /// Install commit-msg hook requiring commits to reference an ADR.
+    #[arg(long)]
+    pub commit_msg: bool,
+
+    /// With --commit-msg, only check commits touching these paths
+    /// (comma-separated globs).
+    #[arg(long, value_delimiter = ',', requires = "commit_msg")]
+    pub paths: Vec<String>,
+
+    /// With --commit-msg, block commits instead of warning.
+    #[arg(long, requires = "commit_msg")]
+    pub strict: bool,
 }
 
 /// Arguments for hooks uninstall.
@@ -80,13 +100,17 @@ fn run_install(args: InstallArgs, git: &Git) -> Result<()> {
         fs::create_dir_all(&hooks_dir)?;
     }
 
-    eprintln!("{} Installing ADR git hooks...", "→".blue());
+    if args.preset.as_deref() == Some("adr-guard") {
+        return install_adr_guard(&hooks_dir, git, args.force);
+    }
+
+    tracing::info!("{} Installing ADR git hooks...", "→".blue());
     let mut installed = 0;
 
     if args.pre_push {
         let hook_path = hooks_dir.join("pre-push");
         if install_hook(&hook_path, PRE_PUSH_HOOK, args.force)? {
-            eprintln!("  {} Installed pre-push hook", "✓".green());
+            tracing::info!("  {} Installed pre-push hook", "✓".green());
             installed += 1;
         }
     }
@@ -94,19 +118,25 @@ fn run_install(args: InstallArgs, git: &Git) -> Result<()> {
     if args.post_merge {
         let hook_path = hooks_dir.join("post-merge");
         if install_hook(&hook_path, POST_MERGE_HOOK, args.force)? {
-            eprintln!("  {} Installed post-merge hook", "✓".green());
+            tracing::info!("  {} Installed post-merge hook", "✓".green());
+            installed += 1;
+        }
+    }
+
+    if args.commit_msg {
+        let hook_path = hooks_dir.join("commit-msg");
+        let content = commit_msg_hook(&args.paths, args.strict);
+        if install_hook(&hook_path, &content, args.force)? {
+            tracing::info!("  {} Installed commit-msg hook", "✓".green());
             installed += 1;
         }
     }
 
     if installed == 0 {
-        eprintln!(
-            "{} No hooks were installed. Use --force to overwrite existing hooks.",
-            "!".yellow()
-        );
+        tracing::warn!("No hooks were installed. Use --force to overwrite existing hooks.");
     } else {
-        eprintln!();
-        eprintln!(
+        tracing::info!("");
+        tracing::info!(
             "{} Installed {} hook(s)",
             "✓".green(),
             installed.to_string().cyan()
@@ -116,33 +146,61 @@ fn run_install(args: InstallArgs, git: &Git) -> Result<()> {
     Ok(())
 }
 
+/// Install the commit-msg hook that requires commits touching architecture
+/// paths to reference an existing ADR.
+fn install_adr_guard(hooks_dir: &Path, git: &Git, force: bool) -> Result<()> {
+    let hook_path = hooks_dir.join("commit-msg");
+    if !install_hook(&hook_path, ADR_GUARD_HOOK, force)? {
+        tracing::warn!(
+            "adr-guard was not installed. Use --force to overwrite the commit-msg hook."
+        );
+        return Ok(());
+    }
+    tracing::info!("{} Installed adr-guard commit-msg hook", "✓".green());
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    if config.architecture_paths.is_empty() {
+        tracing::warn!(
+            "adr.architecturePaths is not set, so no commit is checked yet. Set it with:\n  \
+             git adr config set architecturePaths 'src/core/**,docs/design/**'"
+        );
+    } else {
+        tracing::info!(
+            "  Commits touching {} must reference an ADR",
+            config.architecture_paths.join(", ").cyan()
+        );
+    }
+
+    Ok(())
+}
+
 /// Uninstall git hooks.
 fn run_uninstall(args: UninstallArgs, git: &Git) -> Result<()> {
     let hooks_dir = git.repo_root()?.join(".git/hooks");
 
     let hooks_to_remove = if args.all {
-        vec!["pre-push", "post-merge"]
+        vec!["pre-push", "post-merge", "commit-msg"]
     } else {
         vec!["pre-push"]
     };
 
-    eprintln!("{} Uninstalling ADR git hooks...", "→".blue());
+    tracing::info!("{} Uninstalling ADR git hooks...", "→".blue());
     let mut removed = 0;
 
     for hook_name in hooks_to_remove {
         let hook_path = hooks_dir.join(hook_name);
         if hook_path.exists() && is_adr_hook(&hook_path)? {
             fs::remove_file(&hook_path)?;
-            eprintln!("  {} Removed {} hook", "✓".green(), hook_name);
+            tracing::info!("  {} Removed {} hook", "✓".green(), hook_name);
             removed += 1;
         }
     }
 
     if removed == 0 {
-        eprintln!("{} No ADR hooks found to remove", "→".yellow());
+        tracing::info!("{} No ADR hooks found to remove", "→".yellow());
     } else {
-        eprintln!();
-        eprintln!(
+        tracing::info!("");
+        tracing::info!(
             "{} Removed {} hook(s)",
             "✓".green(),
             removed.to_string().cyan()
@@ -156,12 +214,13 @@ fn run_uninstall(args: UninstallArgs, git: &Git) -> Result<()> {
 fn run_status(git: &Git) -> Result<()> {
     let hooks_dir = git.repo_root()?.join(".git/hooks");
 
-    eprintln!("{} ADR Hook Status:", "→".blue());
+    tracing::info!("{} ADR Hook Status:", "→".blue());
     println!();
 
     let hooks = [
         ("pre-push", "Validates ADR references before push"),
         ("post-merge", "Syncs ADRs after merge"),
+        ("commit-msg", "Requires commits to reference an ADR"),
     ];
 
     for (name, description) in &hooks {
@@ -186,15 +245,14 @@ fn run_status(git: &Git) -> Result<()> {
 fn install_hook(path: &Path, content: &str, force: bool) -> Result<bool> {
     if path.exists() && !force {
         if is_adr_hook(path)? {
-            eprintln!(
+            tracing::info!(
                 "  {} {} already installed (use --force to reinstall)",
                 "→".yellow(),
                 path.file_name().unwrap_or_default().to_string_lossy()
             );
         } else {
-            eprintln!(
-                "  {} {} exists but is not an ADR hook (use --force to overwrite)",
-                "!".yellow(),
+            tracing::warn!(
+                "{} exists but is not an ADR hook (use --force to overwrite)",
                 path.file_name().unwrap_or_default().to_string_lossy()
             );
         }
@@ -220,6 +278,24 @@ fn is_adr_hook(path: &Path) -> Result<bool> {
     Ok(content.contains("git-adr"))
 }
 
+/// Commit-msg hook content, checking commits that touch `paths` (all
+/// commits if empty).
+fn commit_msg_hook(paths: &[String], strict: bool) -> String {
+    let mut options = String::new();
+    if !paths.is_empty() {
+        let _ = write!(options, " --paths {}", shell_quote(&paths.join(",")));
+    }
+    if strict {
+        options.push_str(" --strict");
+    }
+    COMMIT_MSG_HOOK.replace("@OPTIONS@", &options)
+}
+
+/// Quote a value for a POSIX shell.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
 /// Pre-push hook content.
 const PRE_PUSH_HOOK: &str = r#"#!/bin/sh
 # git-adr pre-push hook
@@ -277,3 +353,30 @@ fi
 
 exit 0
 "#;
+
+/// Commit-msg hook content; `@OPTIONS@` is replaced with the check-commit
+/// options.
+const COMMIT_MSG_HOOK: &str = r#"#!/bin/sh
+# git-adr commit-msg hook
+# Checks that the commit message references an existing ADR
+
+if command -v git-adr >/dev/null 2>&1; then
+    exec git-adr check-commit --message-file "$1"@OPTIONS@
+fi
+
+exit 0
+"#;
+
+/// Commit-msg hook installed by the `adr-guard` preset. The globs are read
+/// from `adr.architecturePaths` on every commit, so changing them needs no
+/// reinstall.
+const ADR_GUARD_HOOK: &str = r#"#!/bin/sh
+# git-adr adr-guard commit-msg hook
+# Commits touching adr.architecturePaths must reference an existing ADR
+
+if command -v git-adr >/dev/null 2>&1; then
+    exec git-adr check-commit --message-file "$1" --architecture --strict
+fi
+
+exit 0
+"#;
@@ -1,12 +1,19 @@
 //! Import ADRs from files.
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use clap::Args as ClapArgs;
 use colored::Colorize;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::core::{Adr, AdrStatus, ConfigManager, Git, NotesManager};
+use crate::core::{
+    split_list, Adr, AdrFrontmatter, AdrLink, AdrStatus, ConfigManager, FlexibleDate, Git,
+    NotesManager,
+};
 
 /// Arguments for the import command.
 #[derive(ClapArgs, Debug)]
@@ -14,7 +21,7 @@ pub struct Args {
     /// Path to import from (file or directory).
     pub path: String,
 
-    /// Import format (auto, markdown, json, adr-tools).
+    /// Import format (auto, markdown, json, adr-tools, madr).
     #[arg(long, short, default_value = "auto")]
     pub format: String,
 
@@ -25,6 +32,24 @@ pub struct Args {
     /// Preview import without saving.
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Map an adr-tools number to an ADR ID (can be specified multiple times).
+    #[arg(long, value_name = "NUMBER=ID")]
+    pub prefix_map: Vec<String>,
+
+    /// What to do with files whose ID or title matches an existing ADR.
+    #[arg(long, default_value = "skip", value_parser = ["skip", "update", "error"])]
+    pub on_duplicate: String,
+}
+
+/// What happened to one imported file.
+enum Outcome {
+    /// Created as a new ADR.
+    Imported(Adr),
+    /// Written over the existing ADR it duplicates.
+    Updated(Adr),
+    /// Left alone because it duplicates the ADR with this ID.
+    Duplicate(Adr, String),
 }
 
 /// Run the import command.
@@ -32,6 +57,7 @@ pub struct Args {
 /// # Errors
 ///
 /// Returns an error if import fails.
+#[allow(clippy::too_many_lines)]
 pub fn run(args: Args) -> Result<()> {
     let git = Git::new();
     git.check_repository()?;
@@ -45,6 +71,8 @@ pub fn run(args: Args) -> Result<()> {
         anyhow::bail!("Path not found: {}", args.path);
     }
 
+    let prefix_map = parse_prefix_map(&args.prefix_map)?;
+
     let files = if path.is_dir() {
         // Find markdown files in directory
         find_adr_files(path)?
@@ -53,31 +81,35 @@ pub fn run(args: Args) -> Result<()> {
     };
 
     if files.is_empty() {
-        eprintln!("{} No ADR files found to import", "!".yellow());
+        tracing::warn!("No ADR files found to import");
         return Ok(());
     }
 
-    eprintln!(
+    tracing::info!(
         "{} Found {} file(s) to import",
         "→".blue(),
         files.len().to_string().cyan()
     );
 
+    // Files imported earlier in this run count as existing too
+    let mut existing = notes.list()?;
     let mut imported = 0;
+    let mut updated = 0;
+    let mut deduped = 0;
     let mut skipped = 0;
 
     for file in &files {
-        match import_file(file, &args, &notes, &config) {
-            Ok(adr) => {
+        let adr = match import_file(file, &args, &notes, &config, &prefix_map, &existing) {
+            Ok(Outcome::Imported(adr)) => {
                 if args.dry_run {
-                    eprintln!(
+                    tracing::info!(
                         "  {} Would import: {} - {}",
                         "→".blue(),
                         adr.id.cyan(),
                         adr.frontmatter.title
                     );
                 } else {
-                    eprintln!(
+                    tracing::info!(
                         "  {} Imported: {} - {}",
                         "✓".green(),
                         adr.id.cyan(),
@@ -85,43 +117,125 @@ pub fn run(args: Args) -> Result<()> {
                     );
                 }
                 imported += 1;
+                adr
+            },
+            Ok(Outcome::Updated(adr)) => {
+                let verb = if args.dry_run {
+                    "Would update"
+                } else {
+                    "Updated"
+                };
+                tracing::info!(
+                    "  {} {}: {} - {}",
+                    "✓".green(),
+                    verb,
+                    adr.id.cyan(),
+                    adr.frontmatter.title
+                );
+                updated += 1;
+                continue;
+            },
+            Ok(Outcome::Duplicate(adr, existing_id)) => {
+                if args.on_duplicate == "error" {
+                    anyhow::bail!(
+                        "{} duplicates existing ADR {} ({})",
+                        file.display(),
+                        existing_id,
+                        adr.frontmatter.title
+                    );
+                }
+                tracing::info!(
+                    "  {} Duplicate of {}: {}",
+                    "→".blue(),
+                    existing_id.cyan(),
+                    file.display()
+                );
+                deduped += 1;
+                continue;
             },
             Err(e) => {
-                eprintln!("  {} Skipped {}: {}", "!".yellow(), file.display(), e);
+                tracing::warn!("Skipped {}: {}", file.display(), e);
                 skipped += 1;
+                continue;
             },
-        }
+        };
+        existing.push(adr);
     }
 
-    eprintln!();
-    if args.dry_run {
-        eprintln!(
-            "{} Dry run complete: {} would be imported, {} would be skipped",
-            "→".blue(),
-            imported.to_string().green(),
-            skipped.to_string().yellow()
-        );
+    tracing::info!("");
+    let (marker, summary, prefix) = if args.dry_run {
+        ("→".blue(), "Dry run complete", "would be ")
     } else {
-        eprintln!(
-            "{} Import complete: {} imported, {} skipped",
-            "✓".green(),
-            imported.to_string().green(),
-            skipped.to_string().yellow()
-        );
-    }
+        ("✓".green(), "Import complete", "")
+    };
+    tracing::info!(
+        "{marker} {summary}: {} {prefix}imported, {} {prefix}updated, {} deduped, {} {prefix}skipped",
+        imported.to_string().green(),
+        updated.to_string().green(),
+        deduped.to_string().blue(),
+        skipped.to_string().yellow()
+    );
 
     Ok(())
 }
 
-/// Find ADR files in a directory.
-fn find_adr_files(dir: &Path) -> Result<Vec<std::path::PathBuf>> {
+/// Existing ADR with the same ID or normalized title as `adr`.
+fn find_duplicate<'a>(adr: &Adr, existing: &'a [Adr]) -> Option<&'a Adr> {
+    let title = adr.normalized_title();
+    existing
+        .iter()
+        .find(|other| other.id == adr.id || other.normalized_title() == title)
+}
+
+/// Parse `--prefix-map NUMBER=ID` arguments.
+fn parse_prefix_map(entries: &[String]) -> Result<HashMap<u32, String>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let (number, id) = entry
+                .split_once('=')
+                .filter(|(_, id)| !id.trim().is_empty())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Invalid --prefix-map '{entry}': expected NUMBER=ID")
+                })?;
+            let number = number.trim().parse().map_err(|_| {
+                anyhow::anyhow!("Invalid --prefix-map '{entry}': '{number}' is not a number")
+            })?;
+            Ok((number, id.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Find ADR files in a directory and its subdirectories.
+///
+/// Numbered adr-tools files come first in numeric order, so `10-...` sorts
+/// after `9-...`; other files follow sorted by path.
+fn find_adr_files(dir: &Path) -> Result<Vec<PathBuf>> {
     let mut files = Vec::new();
+    collect_adr_files(dir, &mut files)?;
 
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+    files.sort_by_cached_key(|path| {
+        let number = adr_tools_number(path);
+        (number.is_none(), number, path.clone())
+    });
+
+    Ok(files)
+}
 
-        if path.is_file() {
+/// Recursively collect markdown and JSON files, skipping hidden directories.
+fn collect_adr_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            let hidden = path
+                .file_name()
+                .and_then(|s| s.to_str())
+                .is_some_and(|name| name.starts_with('.'));
+            if !hidden {
+                collect_adr_files(&path, files)?;
+            }
+        } else if path.is_file() {
             let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
             if ext == "md" || ext == "markdown" || ext == "json" {
                 files.push(path);
@@ -129,33 +243,90 @@ fn find_adr_files(dir: &Path) -> Result<Vec<std::path::PathBuf>> {
         }
     }
 
-    // Sort by filename for consistent ordering
-    files.sort();
+    Ok(())
+}
 
-    Ok(files)
+/// Number of an adr-tools file (`0007-use-kafka.md` is 7).
+fn adr_tools_number(path: &Path) -> Option<u32> {
+    let stem = path.file_stem()?.to_str()?;
+    let (number, _) = stem.split_once('-')?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
 }
 
 /// Import a single file.
+///
+/// The ADR is attached to the commit that added the file when the file is
+/// tracked in this repository, otherwise to `HEAD`. When that commit already
+/// carries an ADR, say because several files were added together, the ADR
+/// goes on a fresh empty commit instead.
 fn import_file(
     path: &Path,
     args: &Args,
     notes: &NotesManager,
     config: &crate::core::AdrConfig,
-) -> Result<Adr> {
+    prefix_map: &HashMap<u32, String>,
+    existing: &[Adr],
+) -> Result<Outcome> {
     let content = fs::read_to_string(path)?;
     let format = detect_format(path, &args.format, &content);
 
-    let adr = match format.as_str() {
+    let mut adr = match format.as_str() {
         "json" => import_json(&content)?,
-        "adr-tools" => import_adr_tools(path, &content, config, notes)?,
+        "adr-tools" => import_adr_tools(path, &content, config, notes, prefix_map)?,
+        "madr" => import_madr(path, &content, notes, prefix_map)?,
         _ => import_markdown(&content, config, notes)?,
     };
 
-    if !args.dry_run {
+    if let Some(duplicate) = find_duplicate(&adr, existing) {
+        if args.on_duplicate != "update" {
+            return Ok(Outcome::Duplicate(adr, duplicate.id.clone()));
+        }
+
+        // Keep the existing ADR's identity and commit, take everything else
+        let mut updated = duplicate.clone();
+        let id = updated.frontmatter.id.take();
+        updated.frontmatter = adr.frontmatter;
+        updated.frontmatter.id = id;
+        updated.body = adr.body;
+        if !args.dry_run {
+            notes.update(&updated)?;
+        }
+        return Ok(Outcome::Updated(updated));
+    }
+
+    let git = notes.git();
+    adr.commit = match adding_commit(git, path) {
+        Some(commit) => commit,
+        None => git.head()?,
+    };
+
+    if args.dry_run {
+        // Nothing is written, so commits claimed earlier in this run are
+        // only known from `existing`; a real run would make a fresh commit
+        let taken = existing.iter().any(|other| other.commit == adr.commit)
+            || git.notes_show(config.notes_ref(), &adr.commit)?.is_some();
+        if taken {
+            adr.commit.clear();
+        }
+    } else {
+        notes.claim_commit(&mut adr)?;
         notes.create(&adr)?;
     }
 
-    Ok(adr)
+    Ok(Outcome::Imported(adr))
+}
+
+/// Commit that added `path`, if it is tracked in this repository.
+fn adding_commit(git: &Git, path: &Path) -> Option<String> {
+    let path = path.to_str()?;
+    let output = git
+        .run_output(&["log", "--diff-filter=A", "--format=%H", "-1", "--", path])
+        .ok()?;
+    let commit = output.trim();
+    (!commit.is_empty()).then(|| commit.to_string())
 }
 
 /// Detect file format.
@@ -170,6 +341,12 @@ fn detect_format(path: &Path, hint: &str, content: &str) -> String {
         return "json".to_string();
     }
 
+    // MADR files are often numbered too, so check their structure first.
+    // git-adr's own MADR exports carry full frontmatter and import as markdown.
+    if is_madr(content) && Adr::from_markdown(String::new(), String::new(), content).is_err() {
+        return "madr".to_string();
+    }
+
     // Check for adr-tools format (numbered prefix like "0001-")
     let filename = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
     if filename.len() >= 5 && filename[..4].chars().all(|c| c.is_ascii_digit()) {
@@ -184,81 +361,344 @@ fn detect_format(path: &Path, hint: &str, content: &str) -> String {
     "markdown".to_string()
 }
 
+/// JSON representation of an imported ADR, as written by `JsonExporter`.
+///
+/// Everything besides `commit` and `body` is frontmatter, including any
+/// custom fields, so exported ADRs round-trip without losing metadata.
+#[derive(Debug, Deserialize)]
+struct AdrJson {
+    /// Commit in the exporting repository; imports attach to `HEAD` instead.
+    #[serde(default, rename = "commit")]
+    _commit: Option<String>,
+    #[serde(default, alias = "content")]
+    body: String,
+    #[serde(flatten)]
+    frontmatter: AdrFrontmatter,
+}
+
 /// Import from JSON format.
 fn import_json(content: &str) -> Result<Adr> {
-    let data: serde_json::Value = serde_json::from_str(content)?;
+    let data: AdrJson = serde_json::from_str(content)?;
 
-    let id = data["id"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("Missing 'id' field"))?
-        .to_string();
+    let id = data
+        .frontmatter
+        .id
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("Missing 'id' field"))?;
 
-    let title = data["title"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("Missing 'title' field"))?
-        .to_string();
-
-    let status_str = data["status"].as_str().unwrap_or("proposed");
-    let status: AdrStatus = status_str.parse().unwrap_or_default();
-
-    let body = data["body"]
-        .as_str()
-        .or_else(|| data["content"].as_str())
-        .unwrap_or("")
-        .to_string();
-
-    let mut adr = Adr::new(id, title);
-    adr.frontmatter.status = status;
-    adr.body = body;
-
-    // Import tags if present
-    if let Some(tags) = data["tags"].as_array() {
-        adr.frontmatter.tags = tags
-            .iter()
-            .filter_map(|v| v.as_str().map(String::from))
-            .collect();
-    }
+    let mut adr = Adr::new(id, String::new());
+    adr.frontmatter = data.frontmatter;
+    adr.body = data.body;
 
     Ok(adr)
 }
 
 /// Import from adr-tools format (numbered markdown files).
+///
+/// The title, `Date:` line and Status section become frontmatter; the
+/// remaining sections (Context, Decision, Consequences, ...) become the body.
+/// Links to other numbered files resolve through `prefix_map` first.
 fn import_adr_tools(
     path: &Path,
     content: &str,
     config: &crate::core::AdrConfig,
     notes: &NotesManager,
+    prefix_map: &HashMap<u32, String>,
 ) -> Result<Adr> {
-    let filename = path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .unwrap_or("unknown");
+    let to_id = |number: u32| mapped_id(number, prefix_map, notes);
+
+    let number = adr_tools_number(path).unwrap_or(1);
+    let doc = AdrToolsDoc::parse(content);
+
+    // Prefer the heading's title; the filename slug loses capitalization
+    let title = doc.title.clone().unwrap_or_else(|| {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|stem| stem.split_once('-'))
+            .map_or_else(
+                || "Untitled".to_string(),
+                |(_, slug)| slug.replace('-', " "),
+            )
+    });
+
+    let mut adr = Adr::new(to_id(number), title);
+    adr.frontmatter.status = doc.status();
+    adr.body.clone_from(&doc.body);
+    adr.frontmatter.format = Some(config.format.clone());
+    adr.frontmatter.date.clone_from(&doc.date);
 
-    // Parse number and title from filename (e.g., "0001-use-postgresql")
-    let parts: Vec<&str> = filename.splitn(2, '-').collect();
-    let number: u32 = parts.first().unwrap_or(&"1").parse().unwrap_or(1);
+    for (rel, number) in extract_adr_tools_links(&doc.status_lines) {
+        let target = to_id(number);
+        match rel.as_str() {
+            "supersedes" if adr.frontmatter.supersedes.is_none() => {
+                adr.frontmatter.supersedes = Some(target);
+            },
+            "superseded-by" if adr.frontmatter.superseded_by.is_none() => {
+                adr.frontmatter.superseded_by = Some(target);
+            },
+            _ => adr.frontmatter.links.push(AdrLink { rel, target }),
+        }
+    }
 
-    let id = notes.format_id(number);
+    Ok(adr)
+}
 
-    // Extract title from filename or first heading
-    let title = if parts.len() > 1 {
-        parts[1].replace('-', " ")
-    } else {
-        extract_title_from_content(content).unwrap_or_else(|| "Untitled".to_string())
+/// An adr-tools document split into its standard parts.
+#[derive(Debug, Default)]
+struct AdrToolsDoc {
+    /// Title from the `# N. Title` heading, without the number.
+    title: Option<String>,
+    /// `Date:` line under the title.
+    date: Option<FlexibleDate>,
+    /// Non-empty lines of the `## Status` section.
+    status_lines: Vec<String>,
+    /// Every other section.
+    body: String,
+}
+
+impl AdrToolsDoc {
+    /// Split adr-tools markdown into title, date, status and body.
+    fn parse(content: &str) -> Self {
+        let mut doc = Self::default();
+        let mut body = Vec::new();
+        let mut in_status = false;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+
+            if let Some(heading) = trimmed.strip_prefix("## ") {
+                in_status = heading.trim().eq_ignore_ascii_case("status");
+                if !in_status {
+                    body.push(line);
+                }
+            } else if in_status {
+                if !trimmed.is_empty() {
+                    doc.status_lines.push(trimmed.to_string());
+                }
+            } else if let Some(title) = trimmed
+                .strip_prefix("# ")
+                .filter(|_| body.is_empty() && doc.title.is_none())
+            {
+                doc.title = Some(strip_number(title).to_string());
+            } else if body.is_empty() && doc.date.is_none() && trimmed.starts_with("Date:") {
+                doc.date = extract_date_from_content(trimmed);
+            } else if !body.is_empty() || !trimmed.is_empty() {
+                body.push(line);
+            }
+        }
+
+        doc.body = format!("{}\n", body.join("\n").trim_end());
+        doc
+    }
+
+    /// Status from the first line of the Status section.
+    ///
+    /// adr-tools replaces the status with `Superseded by [...]` when an ADR
+    /// is superseded, and writes other relationships on later lines.
+    fn status(&self) -> AdrStatus {
+        self.status_lines
+            .first()
+            .map(String::as_str)
+            .map_or_else(AdrStatus::default, parse_status)
+    }
+}
+
+/// ID for an imported file's number, through `--prefix-map` if it is mapped.
+fn mapped_id(number: u32, prefix_map: &HashMap<u32, String>, notes: &NotesManager) -> String {
+    prefix_map
+        .get(&number)
+        .cloned()
+        .unwrap_or_else(|| notes.format_id(number))
+}
+
+/// Status from the first word of a status line (`Superseded by ...` is superseded).
+///
+/// `draft` is treated as proposed; anything unrecognized defaults to proposed.
+fn parse_status(line: &str) -> AdrStatus {
+    let first_word = line
+        .split_whitespace()
+        .next()
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphabetic())
+                .to_lowercase()
+        })
+        .unwrap_or_default();
+
+    match first_word.as_str() {
+        "draft" => AdrStatus::Proposed,
+        word => word.parse().unwrap_or_default(),
+    }
+}
+
+/// Strip the `N. ` numbering adr-tools puts in titles.
+fn strip_number(title: &str) -> &str {
+    match title.trim().split_once(". ") {
+        Some((number, rest))
+            if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            rest.trim()
+        },
+        _ => title.trim(),
+    }
+}
+
+/// Extract links to other ADRs from adr-tools link lines.
+///
+/// adr-tools writes relationships as `Superseded by [5. Use PostgreSQL](0005-use-postgresql.md)`
+/// or `Amends [2. ...](0002-....md)`. Returns `(rel, number)` pairs where
+/// `rel` is the lowercased, hyphenated verb (`superseded-by`, `amends`).
+fn extract_adr_tools_links(lines: &[String]) -> Vec<(String, u32)> {
+    let re = Regex::new(r"^([A-Za-z][A-Za-z ]*?)\s+\[[^\]]*\]\((?:\./)?(\d+)-[^)]*\.md\)")
+        .expect("adr-tools link pattern is valid");
+
+    lines
+        .iter()
+        .filter_map(|line| {
+            let caps = re.captures(line)?;
+            let rel = caps[1].trim().to_lowercase().replace(' ', "-");
+            let number = caps[2].parse().ok()?;
+            Some((rel, number))
+        })
+        .collect()
+}
+
+/// Extract the `Date: YYYY-MM-DD` line adr-tools writes under the title.
+fn extract_date_from_content(content: &str) -> Option<FlexibleDate> {
+    content
+        .lines()
+        .find_map(|line| parse_date(line.trim().strip_prefix("Date:")?))
+}
+
+/// Parse a `YYYY-MM-DD` date.
+fn parse_date(value: &str) -> Option<FlexibleDate> {
+    let date = NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()?;
+    Some(FlexibleDate(date.and_hms_opt(0, 0, 0)?.and_utc()))
+}
+
+/// Section headings MADR adds that other formats don't use.
+const MADR_HEADINGS: &[&str] = &[
+    "## Context and Problem Statement",
+    "## Decision Outcome",
+    "## Considered Options",
+];
+
+/// Metadata MADR keeps in frontmatter or in bullets under the title.
+const MADR_FIELDS: &[&str] = &["status", "date", "deciders", "tags"];
+
+/// Whether `content` looks like a MADR (or Log4brains) record.
+fn is_madr(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| MADR_HEADINGS.contains(&line.trim_end()))
+}
+
+/// Import from MADR markdown, as written by MADR 2/3 and Log4brains.
+///
+/// Metadata comes from YAML frontmatter (`status`, `date`, `deciders`, `tags`)
+/// or from the `* Status:` / `- Deciders:` bullets under the title; the title
+/// heading and metadata are dropped from the body.
+fn import_madr(
+    path: &Path,
+    content: &str,
+    notes: &NotesManager,
+    prefix_map: &HashMap<u32, String>,
+) -> Result<Adr> {
+    let content = content.replace("\r\n", "\n");
+    let (metadata, rest) = split_madr_frontmatter(&content)?;
+
+    let mut title = None;
+    let mut body = Vec::new();
+    let mut bullets = HashMap::new();
+    for line in rest.lines() {
+        let trimmed = line.trim();
+        if body.is_empty() {
+            if let Some(heading) = trimmed.strip_prefix("# ").filter(|_| title.is_none()) {
+                title = Some(heading.trim().to_string());
+                continue;
+            }
+            // Metadata bullets only appear between the title and the first section
+            if let Some((key, value)) = trimmed
+                .strip_prefix("* ")
+                .or_else(|| trimmed.strip_prefix("- "))
+                .and_then(|item| item.split_once(':'))
+                .map(|(key, value)| (key.trim().to_lowercase(), value.trim()))
+                .filter(|(key, _)| MADR_FIELDS.contains(&key.as_str()))
+            {
+                bullets.insert(key, value.to_string());
+                continue;
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+        }
+        body.push(line);
+    }
+
+    let title = title.ok_or_else(|| anyhow::anyhow!("Could not determine ADR title"))?;
+    let field = |key: &str| {
+        metadata
+            .get(key)
+            .map(yaml_to_string)
+            .or_else(|| bullets.get(key).cloned())
+            .filter(|value| !value.is_empty())
+    };
+    let list = |key: &str| match metadata.get(key) {
+        Some(serde_yaml::Value::Sequence(items)) => items.iter().map(yaml_to_string).collect(),
+        _ => field(key).map(|v| split_list(&v)).unwrap_or_default(),
     };
 
-    // Parse status from content
-    let status_str = extract_status_from_content(content).unwrap_or_else(|| "proposed".to_string());
-    let status: AdrStatus = status_str.parse().unwrap_or_default();
+    let id = match adr_tools_number(path) {
+        Some(number) => mapped_id(number, prefix_map, notes),
+        None => notes.new_id(&title)?,
+    };
 
     let mut adr = Adr::new(id, title);
-    adr.frontmatter.status = status;
-    adr.body = content.to_string();
-    adr.frontmatter.format = Some(config.format.clone());
+    adr.frontmatter.status = field("status").map_or_else(AdrStatus::default, |s| parse_status(&s));
+    adr.frontmatter.date = field("date").and_then(|d| parse_date(&d));
+    adr.frontmatter.deciders = list("deciders");
+    adr.frontmatter.tags = list("tags");
+    adr.frontmatter.format = Some("madr".to_string());
+    adr.body = format!("{}\n", body.join("\n").trim_end());
 
     Ok(adr)
 }
 
+/// Split optional YAML frontmatter from a MADR file.
+fn split_madr_frontmatter(content: &str) -> Result<(serde_yaml::Mapping, &str)> {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return Ok((serde_yaml::Mapping::new(), content));
+    };
+    let Some(end) = rest.find("\n---") else {
+        anyhow::bail!("Unterminated YAML frontmatter");
+    };
+
+    let yaml = &rest[..end];
+    let after = rest[end + 4..].trim_start_matches(['-', '\n']);
+    let metadata = match serde_yaml::from_str(yaml)? {
+        serde_yaml::Value::Mapping(mapping) => mapping,
+        serde_yaml::Value::Null => serde_yaml::Mapping::new(),
+        _ => anyhow::bail!("YAML frontmatter must be a mapping"),
+    };
+    Ok((metadata, after))
+}
+
+/// Render a scalar YAML value as text.
+fn yaml_to_string(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.trim().to_string(),
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .map(yaml_to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        serde_yaml::Value::Null => String::new(),
+        other => serde_yaml::to_string(other)
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
 /// Import from markdown with YAML frontmatter.
 fn import_markdown(
     content: &str,
@@ -267,12 +707,10 @@ fn import_markdown(
 ) -> Result<Adr> {
     // Try to parse as full ADR with frontmatter
     if let Ok(adr) = Adr::from_markdown("temp".to_string(), String::new(), content) {
-        // Generate new ID if needed
-        let id = if adr.id == "temp" || adr.id.is_empty() {
-            let next_num = notes.next_number()?;
-            notes.format_id(next_num)
-        } else {
-            adr.id
+        // Keep the frontmatter ID so re-imports are recognized; generate one otherwise
+        let id = match adr.frontmatter.id.clone().filter(|id| !id.is_empty()) {
+            Some(id) => id,
+            None => notes.new_id(&adr.frontmatter.title)?,
         };
 
         return Ok(Adr { id, ..adr });
@@ -282,8 +720,7 @@ fn import_markdown(
     let title = extract_title_from_content(content)
         .ok_or_else(|| anyhow::anyhow!("Could not determine ADR title"))?;
 
-    let next_num = notes.next_number()?;
-    let id = notes.format_id(next_num);
+    let id = notes.new_id(&title)?;
 
     let mut adr = Adr::new(id, title);
     adr.body = content.to_string();
@@ -302,35 +739,3 @@ fn extract_title_from_content(content: &str) -> Option<String> {
     }
     None
 }
-
-/// Extract status from markdown content.
-fn extract_status_from_content(content: &str) -> Option<String> {
-    let content_lower = content.to_lowercase();
-
-    // Look for "## Status" section
-    if let Some(idx) = content_lower.find("## status") {
-        let after = &content[idx..];
-        for line in after.lines().skip(1) {
-            let trimmed = line.trim().to_lowercase();
-            if trimmed.is_empty() {
-                continue;
-            }
-            // Common statuses
-            for status in &[
-                "accepted",
-                "proposed",
-                "deprecated",
-                "superseded",
-                "rejected",
-                "draft",
-            ] {
-                if trimmed.contains(status) {
-                    return Some((*status).to_string());
-                }
-            }
-            break;
-        }
-    }
-
-    None
-}
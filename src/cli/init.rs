@@ -4,28 +4,32 @@ use anyhow::Result;
 use clap::Args as ClapArgs;
 use colored::Colorize;
 
-use crate::core::{ConfigManager, Git};
+use crate::core::{AdrConfig, ConfigManager, Git, PROJECT_CONFIG_FILE};
 
 /// Arguments for the init command.
 #[derive(ClapArgs, Debug)]
 pub struct Args {
-    /// Notes namespace (default: adr).
-    #[arg(long, default_value = "adr")]
-    pub namespace: String,
+    /// Notes namespace (default: adr, or the value in .adr.toml).
+    #[arg(long)]
+    pub namespace: Option<String>,
 
-    /// Default ADR template format.
-    #[arg(long, short, default_value = "madr")]
-    pub template: String,
+    /// Default ADR template format (default: madr, or the value in .adr.toml).
+    #[arg(long, short)]
+    pub template: Option<String>,
+
+    /// ADR ID prefix (default: ADR-, or the value in .adr.toml).
+    #[arg(long)]
+    pub prefix: Option<String>,
 
-    /// ADR ID prefix.
-    #[arg(long, default_value = "ADR-")]
-    pub prefix: String,
+    /// Number of digits in ADR ID (default: 4, or the value in .adr.toml).
+    #[arg(long)]
+    pub digits: Option<u8>,
 
-    /// Number of digits in ADR ID.
-    #[arg(long, default_value = "4")]
-    pub digits: u8,
+    /// Write the shared settings to .adr.toml at the repository root.
+    #[arg(long)]
+    pub write_config: bool,
 
-    /// Force reinitialization.
+    /// Force reinitialization (and overwrite an existing .adr.toml).
     #[arg(long, short)]
     pub force: bool,
 }
@@ -44,39 +48,118 @@ pub fn run(args: Args) -> Result<()> {
     let config_manager = ConfigManager::new(git.clone());
 
     // Check if already initialized
-    if config_manager.is_initialized()? && !args.force {
-        eprintln!(
-            "{} git-adr is already initialized. Use --force to reinitialize.",
-            "!".yellow()
-        );
-        return Ok(());
+    if !args.force {
+        if config_manager.is_initialized()? {
+            if args.write_config {
+                // Move the shared settings out of local git config into the file
+                let existing = config_manager.load()?;
+                write_project_config(&config_manager, &existing, false)?;
+                config_manager.save(&existing)?;
+                return Ok(());
+            }
+            tracing::warn!("git-adr is already initialized. Use --force to reinitialize.",);
+            return Ok(());
+        }
+
+        // Config without its notes ref: keep the config, recreate the ref
+        let existing = config_manager.load()?;
+        if existing.initialized {
+            tracing::warn!(
+                "git-adr is configured but refs/notes/{} is missing; repairing...",
+                existing.notes_ref()
+            );
+            config_manager.initialize(&existing)?;
+            tracing::info!("{} git-adr repaired", "✓".green());
+            return Ok(());
+        }
     }
 
-    eprintln!("{} Initializing git-adr...", "→".blue());
+    // Options win over the shared project settings, which win over defaults
+    let project = config_manager.project_config()?;
+    let namespace = args
+        .namespace
+        .or(project.namespace)
+        .unwrap_or_else(|| "adr".to_string());
+
+    // The namespace becomes part of a ref name, so it must be valid there
+    let namespace_ref = format!("refs/notes/{namespace}");
+    if namespace.is_empty()
+        || git
+            .run_silent(&["check-ref-format", &namespace_ref])
+            .is_err()
+    {
+        anyhow::bail!("Invalid notes namespace: {}", namespace);
+    }
+
+    tracing::info!("{} Initializing git-adr...", "→".blue());
 
     // Build configuration
-    let config = crate::core::AdrConfig {
-        prefix: args.prefix,
-        digits: args.digits,
-        template: args.template.clone(),
-        format: args.template,
+    let config = AdrConfig {
+        prefix: args
+            .prefix
+            .or(project.prefix)
+            .unwrap_or_else(|| "ADR-".to_string()),
+        digits: args.digits.or(project.digits).unwrap_or(4),
+        template: args
+            .template
+            .clone()
+            .or(project.template)
+            .unwrap_or_else(|| "madr".to_string()),
+        format: args
+            .template
+            .or(project.format)
+            .unwrap_or_else(|| "madr".to_string()),
         initialized: true,
+        namespace,
+        templates_dir: project.templates_dir,
+        ..Default::default()
     };
 
-    // Save configuration
-    config_manager.save(&config)?;
+    if args.write_config {
+        write_project_config(&config_manager, &config, args.force)?;
+    }
+
+    // Save configuration and create the notes ref
+    config_manager.initialize(&config)?;
 
     // Configure notes fetch/push refspecs for automatic sync
     let _ = git.config_set("remote.origin.fetch", "+refs/notes/*:refs/notes/*");
 
-    eprintln!("  Prefix: {}", config.prefix);
-    eprintln!("  Digits: {}", config.digits);
-    eprintln!("  Template: {}", config.template);
+    tracing::info!("  Namespace: refs/notes/{}", config.namespace);
+    tracing::info!("  Prefix: {}", config.prefix);
+    tracing::info!("  Digits: {}", config.digits);
+    tracing::info!("  Template: {}", config.template);
 
-    eprintln!("{} git-adr initialized successfully!", "✓".green());
-    eprintln!();
-    eprintln!("Next steps:");
-    eprintln!("  git adr new \"Your First Decision\"");
+    tracing::info!("{} git-adr initialized successfully!", "✓".green());
+    tracing::info!("");
+    tracing::info!("Next steps:");
+    tracing::info!("  git adr new \"Your First Decision\"");
+
+    Ok(())
+}
+
+/// Write the shared settings of `config` to `.adr.toml`, refusing to
+/// replace an existing file unless `overwrite` is set.
+fn write_project_config(
+    config_manager: &ConfigManager,
+    config: &AdrConfig,
+    overwrite: bool,
+) -> Result<()> {
+    let exists = config_manager
+        .project_config_path()
+        .is_some_and(|path| path.exists());
+    if exists && !overwrite {
+        anyhow::bail!(
+            "{} already exists. Use --force to overwrite it.",
+            PROJECT_CONFIG_FILE
+        );
+    }
 
+    let path = config_manager.write_project_config(config)?;
+    tracing::info!(
+        "{} Wrote shared settings to {}",
+        "✓".green(),
+        path.display().to_string().cyan()
+    );
     Ok(())
 }
@@ -28,14 +28,9 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git.clone(), config);
 
-    // Find the ADR
-    let adrs = notes.list()?;
-    let adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    let adr = notes.resolve(&args.adr_id)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Linking ADR {} to commit {}",
         "→".blue(),
         adr.id.cyan(),
@@ -47,7 +42,7 @@ pub fn run(args: Args) -> Result<()> {
     let full_commit = full_commit.trim().to_string();
 
     if adr.commit == full_commit {
-        eprintln!("{} ADR is already linked to this commit", "!".yellow());
+        tracing::warn!("ADR is already linked to this commit");
         return Ok(());
     }
 
@@ -59,7 +54,7 @@ pub fn run(args: Args) -> Result<()> {
     new_adr.commit.clone_from(&full_commit);
     notes.create(&new_adr)?;
 
-    eprintln!(
+    tracing::info!(
         "{} ADR {} moved from {} to {}",
         "✓".green(),
         adr.id,
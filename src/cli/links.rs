@@ -0,0 +1,189 @@
+//! Show how ADRs relate through supersession and links.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write as _;
+
+use crate::core::{dot_graph, graph_edges, Adr, ConfigManager, Git, NotesManager};
+
+/// Arguments for the links command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// ADR to start from (default: all ADRs).
+    pub adr_id: Option<String>,
+
+    /// Print an indented tree instead of one line per link.
+    #[arg(long)]
+    pub tree: bool,
+
+    /// Output format (text, dot).
+    #[arg(long, short, default_value = "text", value_parser = ["text", "dot"])]
+    pub format: String,
+}
+
+/// Run the links command.
+///
+/// # Errors
+///
+/// Returns an error if ADRs cannot be listed or the starting ADR is not found.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git, config);
+
+    let mut adrs = notes.list()?;
+    let mut edges = graph_edges(&adrs);
+
+    // Starting from one ADR, keep only what it leads to
+    let root = match &args.adr_id {
+        Some(id) => {
+            let root = notes.resolve(id)?.id;
+            let keep = reachable(&root, &edges);
+            adrs.retain(|adr| keep.contains(&adr.id));
+            edges.retain(|(from, _, _)| keep.contains(from));
+            Some(root)
+        },
+        None => None,
+    };
+
+    if args.format == "dot" {
+        print!("{}", dot_graph(&adrs));
+        return Ok(());
+    }
+
+    if edges.is_empty() && root.is_none() {
+        tracing::info!("{} No links between ADRs", "→".yellow());
+        return Ok(());
+    }
+
+    if args.tree {
+        print!("{}", format_tree(&adrs, &edges, root.as_deref()));
+    } else {
+        for (from, to, rel) in &edges {
+            println!("{} {} {}", from.cyan(), rel, to.cyan());
+        }
+    }
+
+    Ok(())
+}
+
+/// IDs reachable from `root` by following edges, including `root` itself.
+fn reachable(root: &str, edges: &[(String, String, String)]) -> HashSet<String> {
+    let mut seen = HashSet::from([root.to_string()]);
+    let mut stack = vec![root.to_string()];
+    while let Some(id) = stack.pop() {
+        for (from, to, _) in edges {
+            if *from == id && seen.insert(to.clone()) {
+                stack.push(to.clone());
+            }
+        }
+    }
+    seen
+}
+
+/// Render edges as indented trees.
+///
+/// Trees start at `root`, or else at every ADR nothing points to. ADRs only
+/// reachable through a cycle start trees of their own afterwards, a link
+/// back into the current path is marked as a cycle, and an ADR whose
+/// subtree was already printed is marked instead of repeated.
+fn format_tree(adrs: &[Adr], edges: &[(String, String, String)], root: Option<&str>) -> String {
+    let mut children: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+    for (from, to, rel) in edges {
+        children
+            .entry(from.as_str())
+            .or_default()
+            .push((rel.as_str(), to.as_str()));
+    }
+    for list in children.values_mut() {
+        list.sort_by(|a, b| a.1.cmp(b.1).then(a.0.cmp(b.0)));
+    }
+
+    let mut tree = TreeWriter {
+        children,
+        adrs: adrs.iter().map(|adr| (adr.id.as_str(), adr)).collect(),
+        printed: HashSet::new(),
+        out: String::new(),
+    };
+
+    if let Some(root) = root {
+        tree.root(root);
+        return tree.out;
+    }
+
+    let sources: BTreeSet<&str> = edges.iter().map(|(from, _, _)| from.as_str()).collect();
+    let targets: HashSet<&str> = edges.iter().map(|(_, to, _)| to.as_str()).collect();
+    for id in sources.iter().copied().filter(|id| !targets.contains(id)) {
+        tree.root(id);
+    }
+    for id in sources.iter().copied() {
+        if !tree.printed.contains(id) {
+            tree.root(id);
+        }
+    }
+
+    tree.out
+}
+
+/// Writes link trees, printing each ADR's subtree at most once.
+struct TreeWriter<'a> {
+    children: HashMap<&'a str, Vec<(&'a str, &'a str)>>,
+    adrs: HashMap<&'a str, &'a Adr>,
+    printed: HashSet<&'a str>,
+    out: String,
+}
+
+impl<'a> TreeWriter<'a> {
+    /// Write a tree rooted at `id`.
+    fn root(&mut self, id: &'a str) {
+        let label = self.label(id);
+        let _ = writeln!(self.out, "{label}");
+        self.printed.insert(id);
+        self.branches(id, "", &mut vec![id]);
+    }
+
+    /// Write the children of `id`, with `path` holding its ancestors.
+    fn branches(&mut self, id: &'a str, prefix: &str, path: &mut Vec<&'a str>) {
+        let children = self.children.get(id).cloned().unwrap_or_default();
+        for (i, &(rel, child)) in children.iter().enumerate() {
+            let (branch, indent) = if i + 1 == children.len() {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let label = self.label(child);
+
+            if path.contains(&child) {
+                let _ = writeln!(self.out, "{prefix}{branch}{rel} {label} (cycle)");
+            } else if self.printed.insert(child) {
+                let _ = writeln!(self.out, "{prefix}{branch}{rel} {label}");
+                path.push(child);
+                self.branches(child, &format!("{prefix}{indent}"), path);
+                path.pop();
+            } else if self.children.contains_key(child) {
+                let _ = writeln!(self.out, "{prefix}{branch}{rel} {label} (see above)");
+            } else {
+                let _ = writeln!(self.out, "{prefix}{branch}{rel} {label}");
+            }
+        }
+    }
+
+    /// One-line description of an ADR, or a marker if it doesn't exist.
+    fn label(&self, id: &str) -> String {
+        self.adrs.get(id).map_or_else(
+            || format!("{} (missing)", id.red()),
+            |adr| {
+                format!(
+                    "{} {} [{}]",
+                    id.cyan(),
+                    adr.frontmatter.title,
+                    adr.frontmatter.status
+                )
+            },
+        )
+    }
+}
@@ -1,11 +1,15 @@
 //! List all ADRs.
 
 use anyhow::Result;
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::Utc;
 use clap::Args as ClapArgs;
 use colored::Colorize;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
 
-use crate::core::{Adr, AdrStatus, ConfigManager, Git, NotesManager};
+use crate::cli::pager;
+use crate::core::{Adr, AdrStatus, ConfigManager, DateWindow, FlexibleDate, Git, NotesManager};
 
 /// Arguments for the list command.
 #[derive(ClapArgs, Debug)]
@@ -26,13 +30,45 @@ pub struct Args {
     #[arg(long)]
     pub until: Option<String>,
 
-    /// Output format (table, json, csv, oneline).
+    /// Output format (table, json, csv, oneline, toml, yaml).
     #[arg(long, short, default_value = "table")]
     pub format: String,
 
+    /// Sort by field (id, date, status, title).
+    #[arg(long, default_value = "id", value_parser = ["id", "date", "status", "title"])]
+    pub sort: String,
+
     /// Reverse sort order.
     #[arg(long, short)]
     pub reverse: bool,
+
+    /// Show at most this many ADRs (after filtering and sorting).
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Skip this many ADRs before listing (after filtering and sorting).
+    #[arg(long)]
+    pub offset: Option<usize>,
+
+    /// Group ADRs under a header per status or tag.
+    #[arg(long, value_parser = ["status", "tag"])]
+    pub group_by: Option<String>,
+
+    /// Don't pipe output through a pager.
+    #[arg(long)]
+    pub no_pager: bool,
+
+    /// Show only archived ADRs (hidden by default).
+    #[arg(long, conflicts_with = "status")]
+    pub archived: bool,
+
+    /// Show only proposed ADRs older than adr.staleDays.
+    #[arg(long, conflicts_with_all = ["status", "archived"])]
+    pub stale: bool,
+
+    /// Fetch ADR notes from the default remote first.
+    #[arg(long)]
+    pub fetch: bool,
 }
 
 /// Run the list command.
@@ -46,88 +82,227 @@ pub fn run(args: Args) -> Result<()> {
     git.check_repository()?;
 
     let config = ConfigManager::new(git.clone()).load()?;
-    let notes = NotesManager::new(git, config);
-
-    // Get all ADRs
-    let mut adrs = notes.list()?;
+    let notes = NotesManager::new(git, config.clone());
 
-    // Apply filters
-    if let Some(status_filter) = &args.status {
-        let target_status: AdrStatus = status_filter
-            .parse()
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
-        adrs.retain(|adr| *adr.status() == target_status);
+    if args.fetch || notes.config().auto_fetch {
+        notes.fetch_for_read();
     }
 
-    if let Some(tag_filter) = &args.tag {
-        adrs.retain(|adr| adr.has_tag(tag_filter));
+    // Get all ADRs; archived ones live in their own ref
+    let mut adrs = notes.list()?;
+    let status: Option<AdrStatus> = args.status.as_deref().map(str::parse).transpose()?;
+    if args.archived || status == Some(AdrStatus::Archived) {
+        adrs.extend(notes.list_archived()?);
+        adrs.sort_by(|a, b| a.id.cmp(&b.id));
     }
 
-    if let Some(since) = &args.since {
-        let since_date = parse_date(since)?;
-        adrs.retain(|adr| {
-            adr.frontmatter
-                .date
-                .as_ref()
-                .is_none_or(|d| d.datetime() >= since_date)
-        });
+    // Apply filters; archived ADRs only show when asked for
+    if args.status.is_none() {
+        let archived = args.archived;
+        adrs.retain(|adr| (*adr.status() == AdrStatus::Archived) == archived);
     }
-
-    if let Some(until) = &args.until {
-        let until_date = parse_date(until)?;
+    filter_adrs(
+        &mut adrs,
+        args.status.as_deref(),
+        args.tag.as_deref(),
+        args.since.as_deref(),
+        args.until.as_deref(),
+    )?;
+
+    if args.stale {
+        let now = Utc::now();
+        let stale_days = i64::from(config.stale_days);
         adrs.retain(|adr| {
-            adr.frontmatter
-                .date
-                .as_ref()
-                .is_none_or(|d| d.datetime() <= until_date)
+            adr.proposal_age_days(now)
+                .is_some_and(|days| days > stale_days)
         });
     }
 
-    // Apply sort order
+    // Apply sort order; ties keep ID order
+    sort_adrs(&mut adrs, &args.sort);
     if args.reverse {
         adrs.reverse();
     }
 
-    // Check if empty
+    // Structured formats print an empty document rather than nothing
+    let structured = matches!(args.format.as_str(), "json" | "toml" | "yaml");
     if adrs.is_empty() {
-        eprintln!(
+        tracing::info!(
             "{} No ADRs found. Create one with: git adr new \"Title\"",
             "→".yellow()
         );
+        if !structured {
+            return Ok(());
+        }
+    }
+
+    // Paginate last so the window applies to filtered, sorted results
+    let total = adrs.len();
+    let paginated = args.limit.is_some() || args.offset.is_some();
+    let offset = args.offset.unwrap_or(0).min(total);
+    if paginated {
+        let end = args
+            .limit
+            .map_or(total, |limit| offset.saturating_add(limit).min(total));
+        adrs.truncate(end);
+        adrs.drain(..offset);
+    }
+    let page_summary = || {
+        // Count on stderr so stdout stays parseable
+        if adrs.is_empty() && total > 0 {
+            tracing::warn!(
+                "No ADRs past offset {} ({} total)",
+                args.offset.unwrap_or(0),
+                total
+            );
+        } else if paginated {
+            tracing::info!(
+                "Showing {}–{} of {} ADR(s)",
+                offset + 1,
+                offset + adrs.len(),
+                total
+            );
+        }
+    };
+
+    if adrs.is_empty() && !structured {
+        page_summary();
         return Ok(());
     }
 
     // Format output
-    match args.format.as_str() {
-        "json" => print_json(&adrs)?,
-        "csv" => print_csv(&adrs),
-        "oneline" => print_oneline(&adrs),
-        _ => print_table(&adrs),
+    let output = if let Some(field) = &args.group_by {
+        let groups = group_adrs(&adrs, field);
+        match args.format.as_str() {
+            "json" => format_grouped_json(&groups)?,
+            "toml" => toml::to_string(&Groups(&groups))?,
+            "yaml" => serde_yaml::to_string(&Groups(&groups))?,
+            "csv" => anyhow::bail!("--group-by is not supported with --format csv"),
+            "oneline" => format_grouped_oneline(&groups),
+            _ => format_grouped_table(&groups, adrs.len()),
+        }
+    } else {
+        match args.format.as_str() {
+            "json" => format_json(&adrs)?,
+            "toml" => format_toml(&adrs)?,
+            "yaml" => serde_yaml::to_string(&list_entries(&adrs))?,
+            "csv" => format_csv(&adrs),
+            "oneline" => format_oneline(&adrs),
+            _ => format_table(&adrs),
+        }
+    };
+
+    pager::page(&output, config.pager.as_deref(), !args.no_pager)?;
+    page_summary();
+
+    Ok(())
+}
+
+/// Keep the ADRs with `status` and `tag` that are dated within `since` and
+/// `until`. Undated ADRs pass the date filters.
+///
+/// # Errors
+///
+/// Returns an error if the status or a date is invalid.
+pub fn filter_adrs(
+    adrs: &mut Vec<Adr>,
+    status: Option<&str>,
+    tag: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<()> {
+    if let Some(status) = status {
+        let target_status: AdrStatus = status.parse()?;
+        adrs.retain(|adr| *adr.status() == target_status);
+    }
+
+    if let Some(tag) = tag {
+        adrs.retain(|adr| adr.has_tag(tag));
     }
 
+    DateWindow::parse(since, until)?.retain(adrs);
     Ok(())
 }
 
-/// Parse a date string into a DateTime.
-fn parse_date(s: &str) -> Result<DateTime<Utc>> {
-    // Try ISO 8601 format first
-    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-        return Ok(dt.with_timezone(&Utc));
+/// Sort ADRs by `field`: dates chronologically (undated last), statuses by
+/// lifecycle stage, titles case-insensitively, otherwise by ID.
+fn sort_adrs(adrs: &mut [Adr], field: &str) {
+    match field {
+        "date" => adrs.sort_by_key(|adr| {
+            let date = adr.frontmatter.date.as_ref().map(FlexibleDate::datetime);
+            (date.is_none(), date)
+        }),
+        "status" => adrs.sort_by(|a, b| a.status().cmp(b.status())),
+        "title" => adrs.sort_by_cached_key(|adr| adr.frontmatter.title.to_lowercase()),
+        _ => adrs.sort_by(|a, b| a.id.cmp(&b.id)),
     }
+}
 
-    // Try YYYY-MM-DD format
-    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+/// Split ADRs into named groups, keeping their order within each group.
+///
+/// Status groups follow the lifecycle. Tag groups are alphabetical, an ADR
+/// with several tags appears under each, and untagged ADRs come last.
+fn group_adrs(adrs: &[Adr], field: &str) -> Vec<(String, Vec<Adr>)> {
+    if field == "status" {
+        let mut groups: BTreeMap<&AdrStatus, Vec<Adr>> = BTreeMap::new();
+        for adr in adrs {
+            groups.entry(adr.status()).or_default().push(adr.clone());
+        }
+        return groups
+            .into_iter()
+            .map(|(status, adrs)| (status.to_string(), adrs))
+            .collect();
+    }
+
+    let mut groups: BTreeMap<&str, Vec<Adr>> = BTreeMap::new();
+    let mut untagged = Vec::new();
+    for adr in adrs {
+        if adr.frontmatter.tags.is_empty() {
+            untagged.push(adr.clone());
+        }
+        for tag in &adr.frontmatter.tags {
+            let group = groups.entry(tag.as_str()).or_default();
+            // A tag listed twice still shows the ADR once
+            if !group.iter().any(|other| other.id == adr.id) {
+                group.push(adr.clone());
+            }
+        }
     }
+    let mut groups: Vec<(String, Vec<Adr>)> = groups
+        .into_iter()
+        .map(|(tag, adrs)| (tag.to_string(), adrs))
+        .collect();
+    if !untagged.is_empty() {
+        groups.push(("(untagged)".to_string(), untagged));
+    }
+    groups
+}
+
+/// Format ADRs as a table.
+fn format_table(adrs: &[Adr]) -> String {
+    let mut out = table_rows(adrs);
+    out.push('\n');
+    let _ = writeln!(out, "{} ADR(s) found", adrs.len().to_string().bold());
+    out
+}
 
-    Err(anyhow::anyhow!(
-        "Invalid date format: {}. Use YYYY-MM-DD or RFC3339.",
-        s
-    ))
+/// Format grouped ADRs as a table per group; `total` counts distinct ADRs.
+fn format_grouped_table(groups: &[(String, Vec<Adr>)], total: usize) -> String {
+    let mut out = String::new();
+    for (name, adrs) in groups {
+        let _ = writeln!(out, "{} ({})", name.bold(), adrs.len());
+        out.push('\n');
+        out.push_str(&table_rows(adrs));
+        out.push('\n');
+    }
+    let _ = writeln!(out, "{} ADR(s) found", total.to_string().bold());
+    out
 }
 
-/// Print ADRs as a table.
-fn print_table(adrs: &[Adr]) {
+/// Table header and one row per ADR.
+fn table_rows(adrs: &[Adr]) -> String {
+    let mut out = String::new();
+
     // Calculate column widths
     let id_width = adrs.iter().map(|a| a.id.len()).max().unwrap_or(10).max(4);
     let status_width = adrs
@@ -138,19 +313,21 @@ fn print_table(adrs: &[Adr]) {
         .max(6);
     let title_width = 50;
 
-    // Print header
-    println!(
+    // Header
+    let _ = writeln!(
+        out,
         "{:id_width$}  {:status_width$}  {}",
         "ID".bold(),
         "STATUS".bold(),
         "TITLE".bold()
     );
-    println!(
+    let _ = writeln!(
+        out,
         "{:-<id_width$}  {:-<status_width$}  {:-<title_width$}",
         "", "", ""
     );
 
-    // Print rows
+    // Rows
     for adr in adrs {
         let status_str = adr.status().to_string();
         let status_colored = match adr.status() {
@@ -159,6 +336,7 @@ fn print_table(adrs: &[Adr]) {
             AdrStatus::Deprecated => status_str.dimmed(),
             AdrStatus::Superseded => status_str.magenta(),
             AdrStatus::Rejected => status_str.red(),
+            AdrStatus::Archived => status_str.bright_black(),
         };
 
         let title = if adr.title().len() > title_width {
@@ -167,41 +345,105 @@ fn print_table(adrs: &[Adr]) {
             adr.title().to_string()
         };
 
-        println!(
+        let _ = writeln!(
+            out,
             "{:id_width$}  {:status_width$}  {}",
             adr.id.cyan(),
             status_colored,
             title
         );
     }
+    out
+}
 
-    println!();
-    println!("{} ADR(s) found", adrs.len().to_string().bold());
+/// Format ADRs as JSON.
+fn format_json(adrs: &[Adr]) -> Result<String> {
+    let output: Vec<serde_json::Value> = adrs.iter().map(json_entry).collect();
+    Ok(format!("{}\n", serde_json::to_string_pretty(&output)?))
 }
 
-/// Print ADRs as JSON.
-fn print_json(adrs: &[Adr]) -> Result<()> {
-    let output: Vec<serde_json::Value> = adrs
+/// Format grouped ADRs as a JSON array of `{"group", "adrs"}` objects, in
+/// group order.
+fn format_grouped_json(groups: &[(String, Vec<Adr>)]) -> Result<String> {
+    let output: Vec<serde_json::Value> = groups
         .iter()
-        .map(|adr| {
-            serde_json::json!({
-                "id": adr.id,
-                "title": adr.title(),
-                "status": adr.status().to_string(),
-                "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
-                "tags": adr.frontmatter.tags,
-                "commit": adr.commit,
-            })
+        .map(|(name, adrs)| {
+            let adrs: Vec<serde_json::Value> = adrs.iter().map(json_entry).collect();
+            serde_json::json!({ "group": name, "adrs": adrs })
         })
         .collect();
+    Ok(format!("{}\n", serde_json::to_string_pretty(&output)?))
+}
 
-    println!("{}", serde_json::to_string_pretty(&output)?);
-    Ok(())
+/// JSON fields listed for an ADR.
+fn json_entry(adr: &Adr) -> serde_json::Value {
+    serde_json::json!({
+        "id": adr.id,
+        "title": adr.title(),
+        "status": adr.status().to_string(),
+        "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
+        "tags": adr.frontmatter.tags,
+        "commit": adr.commit,
+    })
+}
+
+/// The JSON formatter's fields for TOML and YAML output, with dates as
+/// `YYYY-MM-DD`.
+#[derive(Serialize)]
+struct ListEntry<'a> {
+    id: &'a str,
+    title: &'a str,
+    status: String,
+    // TOML has no null, so undated ADRs leave the field out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<&'a FlexibleDate>,
+    tags: &'a [String],
+    commit: &'a str,
 }
 
-/// Print ADRs as CSV.
-fn print_csv(adrs: &[Adr]) {
-    println!("id,status,title,date,tags,commit");
+impl<'a> From<&'a Adr> for ListEntry<'a> {
+    fn from(adr: &'a Adr) -> Self {
+        Self {
+            id: &adr.id,
+            title: adr.title(),
+            status: adr.status().to_string(),
+            date: adr.frontmatter.date.as_ref(),
+            tags: &adr.frontmatter.tags,
+            commit: &adr.commit,
+        }
+    }
+}
+
+/// Entries for TOML and YAML output.
+fn list_entries(adrs: &[Adr]) -> Vec<ListEntry<'_>> {
+    adrs.iter().map(ListEntry::from).collect()
+}
+
+/// Format ADRs as TOML, an `[[adrs]]` table per ADR.
+fn format_toml(adrs: &[Adr]) -> Result<String> {
+    #[derive(Serialize)]
+    struct Document<'a> {
+        adrs: Vec<ListEntry<'a>>,
+    }
+
+    Ok(toml::to_string(&Document {
+        adrs: list_entries(adrs),
+    })?)
+}
+
+/// Grouped ADRs serialized as a map from group name to entries, in group
+/// order.
+struct Groups<'a>(&'a [(String, Vec<Adr>)]);
+
+impl Serialize for Groups<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(name, adrs)| (name, list_entries(adrs))))
+    }
+}
+
+/// Format ADRs as CSV.
+fn format_csv(adrs: &[Adr]) -> String {
+    let mut out = String::from("id,status,title,date,tags,commit\n");
     for adr in adrs {
         let date = adr
             .frontmatter
@@ -212,7 +454,8 @@ fn print_csv(adrs: &[Adr]) {
         let tags = adr.frontmatter.tags.join(";");
         // Escape title for CSV (double quotes)
         let title = adr.title().replace('"', "\"\"");
-        println!(
+        let _ = writeln!(
+            out,
             "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\"",
             adr.id,
             adr.status(),
@@ -222,10 +465,12 @@ fn print_csv(adrs: &[Adr]) {
             adr.commit
         );
     }
+    out
 }
 
-/// Print ADRs in one-line format.
-fn print_oneline(adrs: &[Adr]) {
+/// Format ADRs in one-line format.
+fn format_oneline(adrs: &[Adr]) -> String {
+    let mut out = String::new();
     for adr in adrs {
         let status = match adr.status() {
             AdrStatus::Proposed => "[P]".yellow(),
@@ -233,7 +478,22 @@ fn print_oneline(adrs: &[Adr]) {
             AdrStatus::Deprecated => "[D]".dimmed(),
             AdrStatus::Superseded => "[S]".magenta(),
             AdrStatus::Rejected => "[R]".red(),
+            AdrStatus::Archived => "[X]".dimmed(),
         };
-        println!("{} {} {}", adr.id.cyan(), status, adr.title());
+        let _ = writeln!(out, "{} {} {}", adr.id.cyan(), status, adr.title());
+    }
+    out
+}
+
+/// Format grouped ADRs in one-line format under a heading per group.
+fn format_grouped_oneline(groups: &[(String, Vec<Adr>)]) -> String {
+    let mut out = String::new();
+    for (i, (name, adrs)) in groups.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(out, "{}", format!("{name}:").bold());
+        out.push_str(&format_oneline(adrs));
     }
+    out
 }
@@ -100,10 +100,10 @@ pub fn run(args: Args) -> Result<()> {
     }
 
     if displayed == 0 {
-        eprintln!("{} No commits found", "!".yellow());
+        tracing::warn!("No commits found");
     } else {
-        eprintln!();
-        eprintln!(
+        tracing::info!("");
+        tracing::info!(
             "{} {} commit(s) shown, {} with ADRs",
             "→".blue(),
             displayed,
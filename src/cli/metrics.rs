@@ -1,14 +1,15 @@
-//! Export ADR metrics in JSON format.
+//! Export ADR metrics in JSON or Prometheus text format.
 
 use anyhow::Result;
 use chrono::{Datelike, Utc};
 use clap::Args as ClapArgs;
 use colored::Colorize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
 use std::fs;
 use std::path::Path;
 
-use crate::core::{ConfigManager, Git, NotesManager};
+use crate::core::{decision_lead_time, AdrStatus, ConfigManager, Git, NotesManager};
 
 /// Arguments for the metrics command.
 #[derive(ClapArgs, Debug)]
@@ -24,6 +25,14 @@ pub struct Args {
     /// Pretty print JSON output.
     #[arg(long)]
     pub pretty: bool,
+
+    /// Output format (json, prometheus).
+    #[arg(long, short, default_value = "json", value_parser = ["json", "prometheus"])]
+    pub format: String,
+
+    /// Days after which a proposed ADR counts as stale (default: adr.staleDays).
+    #[arg(long)]
+    pub stale_days: Option<i64>,
 }
 
 /// Run the metrics command.
@@ -31,6 +40,7 @@ pub struct Args {
 /// # Errors
 ///
 /// Returns an error if metrics export fails.
+#[allow(clippy::too_many_lines)]
 pub fn run(args: Args) -> Result<()> {
     let git = Git::new();
     git.check_repository()?;
@@ -45,6 +55,14 @@ pub fn run(args: Args) -> Result<()> {
     let mut tag_counts: HashMap<String, usize> = HashMap::new();
     let mut monthly_counts: HashMap<String, usize> = HashMap::new();
     let mut adr_metrics = Vec::new();
+    let mut lead_times = Vec::new();
+    let mut open = 0;
+    let mut with_links: u32 = 0;
+    let mut stale_proposed: u32 = 0;
+    let now = Utc::now();
+    let stale_days = args
+        .stale_days
+        .unwrap_or_else(|| i64::from(config.stale_days));
 
     for adr in &adrs {
         // Count by status
@@ -63,6 +81,28 @@ pub fn run(args: Args) -> Result<()> {
             *monthly_counts.entry(month_key).or_insert(0) += 1;
         }
 
+        if adr
+            .proposal_age_days(now)
+            .is_some_and(|days| days > stale_days)
+        {
+            stale_proposed += 1;
+        }
+
+        if !adr.frontmatter.links.is_empty()
+            || adr.frontmatter.supersedes.is_some()
+            || adr.frontmatter.superseded_by.is_some()
+        {
+            with_links += 1;
+        }
+
+        // Decision lead time from the note history
+        let lead_time = decision_lead_time(&notes.status_history(adr)?).map(days);
+        if let Some(lead_time) = lead_time {
+            lead_times.push(lead_time);
+        } else if adr.frontmatter.status == AdrStatus::Proposed {
+            open += 1;
+        }
+
         // Individual ADR metrics
         if args.include_adrs {
             adr_metrics.push(serde_json::json!({
@@ -73,6 +113,7 @@ pub fn run(args: Args) -> Result<()> {
                 "tags": adr.frontmatter.tags,
                 "authors": adr.frontmatter.authors,
                 "commit": if adr.commit.is_empty() { None } else { Some(&adr.commit) },
+                "lead_time_days": lead_time,
             }));
         }
     }
@@ -98,6 +139,49 @@ pub fn run(args: Args) -> Result<()> {
         ((deprecated + superseded) as f64 / adrs.len() as f64) * 100.0
     };
 
+    if args.format == "prometheus" {
+        #[allow(clippy::cast_precision_loss)]
+        let gauges = [
+            Gauge::single("adr_total", "Total number of ADRs.", adrs.len() as f64),
+            Gauge::labeled(
+                "adr_by_status",
+                "Number of ADRs in each status.",
+                "status",
+                AdrStatus::ALL.iter().map(|status| {
+                    let status = status.to_string();
+                    let count = status_counts.get(&status).copied().unwrap_or(0);
+                    (status, count as f64)
+                }),
+            ),
+            Gauge::labeled(
+                "adr_by_tag",
+                "Number of ADRs carrying each tag.",
+                "tag",
+                tag_counts
+                    .iter()
+                    .collect::<BTreeMap<_, _>>()
+                    .into_iter()
+                    .map(|(tag, count)| (tag.clone(), *count as f64)),
+            ),
+            Gauge::single(
+                "adr_with_links_total",
+                "Number of ADRs linking to or superseding another ADR.",
+                f64::from(with_links),
+            ),
+            Gauge::single(
+                "adr_stale_proposed_total",
+                "Number of proposed ADRs older than the stale threshold.",
+                f64::from(stale_proposed),
+            ),
+            Gauge::single(
+                "adr_acceptance_rate_percent",
+                "Share of decided ADRs that were accepted.",
+                (acceptance_rate * 10.0).round() / 10.0,
+            ),
+        ];
+        return write_output(args.output.as_deref(), &format_prometheus(&gauges));
+    }
+
     // Build metrics JSON
     let mut metrics = serde_json::json!({
         "metadata": {
@@ -109,12 +193,15 @@ pub fn run(args: Args) -> Result<()> {
             "total_adrs": adrs.len(),
             "acceptance_rate": format!("{:.1}", acceptance_rate),
             "churn_rate": format!("{:.1}", churn_rate),
+            "with_links": with_links,
+            "stale_proposed": stale_proposed,
         },
         "status_breakdown": status_counts,
         "tags": {
             "total_unique": tag_counts.len(),
             "counts": tag_counts,
         },
+        "lead_time_days": lead_time_summary(&mut lead_times, open),
         "timeline": {
             "monthly_counts": monthly_counts,
             "first_adr_date": get_first_date(&adrs),
@@ -132,26 +219,124 @@ pub fn run(args: Args) -> Result<()> {
         serde_json::to_string(&metrics)?
     };
 
-    if let Some(output_path) = args.output {
-        let path = Path::new(&output_path);
+    write_output(args.output.as_deref(), &format!("{output}\n"))
+}
+
+/// Write metrics to `output_path`, or to stdout if none was given.
+fn write_output(output_path: Option<&str>, output: &str) -> Result<()> {
+    if let Some(output_path) = output_path {
+        let path = Path::new(output_path);
         if let Some(parent) = path.parent() {
             if !parent.exists() {
                 fs::create_dir_all(parent)?;
             }
         }
-        fs::write(&output_path, &output)?;
-        eprintln!(
+        fs::write(output_path, output)?;
+        tracing::info!(
             "{} Metrics exported to: {}",
             "✓".green(),
             output_path.cyan()
         );
     } else {
-        println!("{output}");
+        print!("{output}");
     }
 
     Ok(())
 }
 
+/// A Prometheus gauge and its samples, each with an optional label.
+struct Gauge {
+    name: &'static str,
+    help: &'static str,
+    samples: Vec<(Option<(&'static str, String)>, f64)>,
+}
+
+impl Gauge {
+    /// A gauge with a single unlabeled sample.
+    fn single(name: &'static str, help: &'static str, value: f64) -> Self {
+        Self {
+            name,
+            help,
+            samples: vec![(None, value)],
+        }
+    }
+
+    /// A gauge with one sample per value of `label`.
+    fn labeled(
+        name: &'static str,
+        help: &'static str,
+        label: &'static str,
+        samples: impl IntoIterator<Item = (String, f64)>,
+    ) -> Self {
+        Self {
+            name,
+            help,
+            samples: samples
+                .into_iter()
+                .map(|(value, sample)| (Some((label, value)), sample))
+                .collect(),
+        }
+    }
+}
+
+/// Render gauges in the Prometheus text exposition format.
+fn format_prometheus(gauges: &[Gauge]) -> String {
+    let mut out = String::new();
+    for gauge in gauges {
+        let _ = writeln!(out, "# HELP {} {}", gauge.name, gauge.help);
+        let _ = writeln!(out, "# TYPE {} gauge", gauge.name);
+        for (label, value) in &gauge.samples {
+            let _ = match label {
+                Some((key, label_value)) => writeln!(
+                    out,
+                    "{}{{{}=\"{}\"}} {}",
+                    gauge.name,
+                    key,
+                    escape_label_value(label_value),
+                    value
+                ),
+                None => writeln!(out, "{} {}", gauge.name, value),
+            };
+        }
+    }
+    out
+}
+
+/// Escape a label value: backslashes, double quotes and newlines.
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Duration in days, to one decimal place.
+#[allow(clippy::cast_precision_loss)]
+fn days(duration: chrono::Duration) -> f64 {
+    (duration.num_seconds() as f64 / 86_400.0 * 10.0).round() / 10.0
+}
+
+/// Mean and median of decision lead times, plus how many ADRs are still open.
+#[allow(clippy::cast_precision_loss)]
+fn lead_time_summary(lead_times: &mut [f64], open: usize) -> serde_json::Value {
+    lead_times.sort_by(f64::total_cmp);
+    let count = lead_times.len();
+
+    let mean = (count > 0).then(|| lead_times.iter().sum::<f64>() / count as f64);
+    let median = match count {
+        0 => None,
+        n if n % 2 == 1 => Some(lead_times[n / 2]),
+        n => Some(f64::midpoint(lead_times[n / 2 - 1], lead_times[n / 2])),
+    };
+
+    serde_json::json!({
+        "mean": mean.map(|m| (m * 10.0).round() / 10.0),
+        "median": median.map(|m| (m * 10.0).round() / 10.0),
+        "decided": count,
+        "open": open,
+    })
+}
+
 /// Get the earliest ADR date.
 fn get_first_date(adrs: &[crate::core::Adr]) -> Option<String> {
     adrs.iter()
@@ -6,28 +6,36 @@ use clap::{Parser, Subcommand};
 
 pub mod artifacts;
 pub mod attach;
+pub mod check_commit;
 pub mod ci;
 pub mod config;
 pub mod convert;
+pub mod diff;
 pub mod edit;
 pub mod export;
 pub mod hooks;
 pub mod import;
 pub mod init;
 pub mod link;
+pub mod links;
 pub mod list;
 pub mod log;
 pub mod metrics;
 pub mod new;
 pub mod onboard;
+pub mod pager;
 pub mod report;
+pub mod restore;
 pub mod rm;
 pub mod search;
 pub mod show;
 pub mod stats;
 pub mod supersede;
 pub mod sync;
+pub mod tag;
 pub mod templates;
+pub mod validate;
+pub mod verify;
 
 #[cfg(feature = "ai")]
 pub mod ai;
@@ -44,6 +52,24 @@ pub mod wiki;
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    /// Also show debug output, including every git command run.
+    #[arg(long, short, global = true, conflicts_with = "quiet")]
+    pub verbose: bool,
+
+    /// Only print command output and errors, no progress messages.
+    #[arg(long, short, global = true)]
+    pub quiet: bool,
+
+    /// Disable colored output (also set by the `NO_COLOR` environment
+    /// variable).
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// How to print errors (text, json). JSON errors go to stderr as
+    /// `{"error": ..., "kind": ...}`.
+    #[arg(long, global = true, default_value = "text", value_parser = ["text", "json"])]
+    pub error_format: String,
+
     /// Subcommand to execute.
     #[command(subcommand)]
     pub command: Commands,
@@ -74,6 +100,9 @@ pub enum Commands {
     /// Remove an ADR.
     Rm(rm::Args),
 
+    /// Restore an archived ADR.
+    Restore(restore::Args),
+
     /// Search ADRs.
     Search(search::Args),
 
@@ -86,12 +115,21 @@ pub enum Commands {
     /// Link ADR to commits.
     Link(link::Args),
 
+    /// Show how ADRs supersede and link to each other.
+    Links(links::Args),
+
     /// Create a superseding ADR.
     Supersede(supersede::Args),
 
+    /// List, rename or merge tags across all ADRs.
+    Tag(tag::Args),
+
     /// Show git log with ADR annotations.
     Log(log::Args),
 
+    /// Diff two revisions of an ADR.
+    Diff(diff::Args),
+
     /// Show ADR statistics.
     Stats(stats::Args),
 
@@ -113,6 +151,9 @@ pub enum Commands {
     /// Manage git hooks for ADR workflows.
     Hooks(hooks::Args),
 
+    /// Check that a commit message references an existing ADR.
+    CheckCommit(check_commit::Args),
+
     /// Generate CI/CD workflows for ADR integration.
     Ci(ci::Args),
 
@@ -128,6 +169,12 @@ pub enum Commands {
     /// Interactive onboarding wizard for new team members.
     Onboard(onboard::Args),
 
+    /// Validate ADR links, and optionally titles, numbering, sections and metadata.
+    Validate(validate::Args),
+
+    /// Verify the GPG signature of an ADR.
+    Verify(verify::Args),
+
     /// AI-assisted ADR operations.
     #[cfg(feature = "ai")]
     Ai(ai::Args),
@@ -5,7 +5,10 @@ use chrono::Utc;
 use clap::Args as ClapArgs;
 use colored::Colorize;
 
-use crate::core::{Adr, AdrStatus, ConfigManager, FlexibleDate, Git, NotesManager, TemplateEngine};
+use crate::core::{
+    open_in_editor, Adr, AdrStatus, ConfigManager, FlexibleDate, Git, NotesManager, TemplateEngine,
+};
+use crate::Error;
 
 /// Arguments for the new command.
 #[derive(ClapArgs, Debug)]
@@ -21,11 +24,16 @@ pub struct Args {
     #[arg(long, short = 'g')]
     pub tag: Vec<String>,
 
-    /// Deciders (can be specified multiple times).
+    /// Authors (can be specified multiple times; defaults to your git identity).
+    #[arg(long)]
+    pub author: Vec<String>,
+
+    /// Deciders (can be specified multiple times; defaults to `adr.default-deciders`).
     #[arg(long, short)]
     pub deciders: Vec<String>,
 
-    /// Link to commit SHA.
+    /// Link to commit SHA (default: HEAD, or a new empty commit if HEAD
+    /// already has an ADR).
     #[arg(long, short)]
     pub link: Option<String>,
 
@@ -33,10 +41,18 @@ pub struct Args {
     #[arg(long)]
     pub template: Option<String>,
 
+    /// Template field (`key=value`, value parsed as JSON; can be specified multiple times).
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub field: Vec<String>,
+
     /// Read content from file.
     #[arg(long, short)]
     pub file: Option<String>,
 
+    /// Open the rendered ADR in your editor before saving.
+    #[arg(long, conflicts_with = "no_edit")]
+    pub edit: bool,
+
     /// Don't open editor.
     #[arg(long)]
     pub no_edit: bool,
@@ -44,6 +60,10 @@ pub struct Args {
     /// Preview without saving.
     #[arg(long)]
     pub preview: bool,
+
+    /// ADR this decision replaces; it is marked superseded by the new ADR.
+    #[arg(long, value_name = "ID")]
+    pub supersedes: Option<String>,
 }
 
 /// Run the new command.
@@ -58,45 +78,60 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
 
     if !config.initialized {
-        anyhow::bail!("git-adr not initialized. Run 'git adr init' first.");
+        return Err(Error::NotInitialized.into());
     }
 
     let notes = NotesManager::new(git.clone(), config.clone());
 
-    // Generate ADR ID
-    let next_num = notes.next_number()?;
-    let adr_id = notes.format_id(next_num);
+    // Validate the input before doing anything else
+    let status: AdrStatus = args.status.parse()?;
+    let superseded = match &args.supersedes {
+        Some(id) => Some(notes.resolve(id)?),
+        None => None,
+    };
 
-    eprintln!("{} Creating new ADR: {}", "→".blue(), adr_id);
+    // Generate ADR ID
+    let adr_id = notes.new_id(&args.title)?;
 
-    // Parse status
-    let status: AdrStatus = args.status.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+    tracing::info!("{} Creating new ADR: {}", "→".blue(), adr_id);
 
     // Determine template format
     let format = args.template.as_deref().unwrap_or(&config.format);
 
-    // Get commit to attach to
-    let commit = match &args.link {
-        Some(sha) => sha.clone(),
-        None => git.head()?,
-    };
+    // Commit to attach to; without --link one is claimed when saving
+    let commit = args.link.clone().unwrap_or_default();
 
     // Create ADR struct
     let mut adr = Adr::new(adr_id.clone(), args.title.clone());
     adr.commit = commit;
     adr.frontmatter.status = status;
     adr.frontmatter.tags.clone_from(&args.tag);
-    adr.frontmatter.deciders.clone_from(&args.deciders);
+    if args.deciders.is_empty() {
+        adr.frontmatter
+            .deciders
+            .clone_from(&config.default_deciders);
+    } else {
+        adr.frontmatter.deciders.clone_from(&args.deciders);
+    }
+    if args.author.is_empty() {
+        if let Ok((name, email)) = git.user_identity() {
+            adr.frontmatter.authors.push(format!("{name} <{email}>"));
+        }
+    } else {
+        adr.frontmatter.authors.clone_from(&args.author);
+    }
     adr.frontmatter.date = Some(FlexibleDate(Utc::now()));
     adr.frontmatter.format = Some(format.to_string());
 
     // Render template for body
-    let template_engine = TemplateEngine::new();
-    let mut context = std::collections::HashMap::new();
-    context.insert("title".to_string(), adr.frontmatter.title.clone());
-    context.insert("status".to_string(), adr.frontmatter.status.to_string());
-    let body = template_engine.render(format, &context)?;
-    adr.body = body;
+    let template_engine = TemplateEngine::for_repo(&git, &config)?;
+    adr.body = if args.field.is_empty() {
+        template_engine.render_with_adr(format, &adr)?
+    } else {
+        let mut context = TemplateEngine::adr_context(&adr)?;
+        add_fields(&mut context, &args.field)?;
+        template_engine.render(format, &context)?
+    };
 
     // Read content from file if provided
     if let Some(file_path) = &args.file {
@@ -105,12 +140,23 @@ pub fn run(args: Args) -> Result<()> {
         if file_content.trim().starts_with("---") {
             let parsed = Adr::from_markdown(adr_id.clone(), adr.commit.clone(), &file_content)?;
             adr.frontmatter = parsed.frontmatter;
+            adr.frontmatter.id = Some(adr_id.clone());
             adr.body = parsed.body;
         } else {
             adr.body = file_content;
         }
     }
 
+    // Let the user fill out the ADR in their editor
+    if args.edit {
+        let edited = open_in_editor(&git, &config, &adr.to_markdown()?)?;
+        adr = Adr::from_markdown(adr_id.clone(), adr.commit.clone(), &edited)?;
+    }
+
+    if let Some(old) = &superseded {
+        adr.frontmatter.supersedes = Some(old.id.clone());
+    }
+
     // Preview mode
     if args.preview {
         eprintln!("{} Preview mode - not saving", "!".yellow());
@@ -119,13 +165,56 @@ pub fn run(args: Args) -> Result<()> {
     }
 
     // Save ADR
-    notes.create(&adr)?;
+    config.check_body_size(&adr.body)?;
+    if args.link.is_none() {
+        notes.claim_commit(&mut adr)?;
+    }
+    match &superseded {
+        Some(old) => {
+            notes.supersede(&old.id, &adr)?;
+        },
+        None => notes.create(&adr)?,
+    }
 
-    eprintln!("{} Created ADR: {}", "✓".green(), adr_id);
-    eprintln!("  Title: {}", args.title);
-    eprintln!("  Status: {}", adr.frontmatter.status);
+    tracing::info!("{} Created ADR: {}", "✓".green(), adr_id);
+    tracing::info!("  Title: {}", args.title);
+    tracing::info!("  Status: {}", adr.frontmatter.status);
     if !args.tag.is_empty() {
-        eprintln!("  Tags: {}", args.tag.join(", "));
+        tracing::info!("  Tags: {}", args.tag.join(", "));
+    }
+    if let Some(old) = &superseded {
+        tracing::info!(
+            "{} Updated {} status to superseded",
+            "✓".green(),
+            old.id.cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Add `--field` arguments to the template context.
+///
+/// Values are parsed as JSON so `options='["A","B"]'` becomes a list the
+/// template can loop over; anything that isn't valid JSON is kept as a string.
+/// Fields never override the ADR's own frontmatter.
+fn add_fields(context: &mut serde_json::Value, fields: &[String]) -> Result<()> {
+    let Some(context) = context.as_object_mut() else {
+        anyhow::bail!("Template context must be an object");
+    };
+
+    for field in fields {
+        let (key, raw) = field
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid field '{}': expected key=value", field))?;
+        let key = key.trim();
+        if key.is_empty() {
+            anyhow::bail!("Invalid field '{}': key is empty", field);
+        }
+
+        let value = serde_json::from_str(raw)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+        context.entry(key).or_insert(value);
     }
 
     Ok(())
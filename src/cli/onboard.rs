@@ -6,7 +6,12 @@ use colored::Colorize;
 use std::collections::HashMap;
 use std::io::{self, Write};
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use chrono::Utc;
+
+use crate::core::{
+    Adr, AdrConfig, AdrStatus, ConfigManager, FlexibleDate, Git, NotesManager, TemplateEngine,
+};
+use crate::Error;
 
 /// Arguments for the onboard command.
 #[derive(ClapArgs, Debug)]
@@ -26,8 +31,38 @@ pub struct Args {
     /// Limit number of ADRs to show.
     #[arg(long, short, default_value = "10")]
     pub limit: usize,
+
+    /// Create the starter ADR in a repository without ADRs, without asking.
+    #[arg(long, short, conflicts_with = "no_seed")]
+    pub yes: bool,
+
+    /// Don't offer to create a starter ADR.
+    #[arg(long)]
+    pub no_seed: bool,
 }
 
+/// Title of the starter ADR offered in a repository without ADRs.
+const SEED_TITLE: &str = "Use Architecture Decision Records";
+
+/// Body of the starter ADR, carried into the configured template.
+const SEED_BODY: &str = "## Context
+
+We need to record the architectural decisions made on this project, along
+with the reasoning behind them, so that new contributors can understand why
+things are the way they are.
+
+## Decision
+
+We will use Architecture Decision Records, as described by Michael Nygard,
+stored in git notes alongside the code and managed with git-adr.
+
+## Consequences
+
+Decisions are versioned with the code and travel with the repository.
+Significant decisions get a new ADR (`git adr new <title>`), and a decision
+that is revisited is superseded rather than edited away.
+";
+
 /// Run the onboard command.
 ///
 /// # Errors
@@ -38,7 +73,7 @@ pub fn run(args: Args) -> Result<()> {
     git.check_repository()?;
 
     let config = ConfigManager::new(git.clone()).load()?;
-    let notes = NotesManager::new(git, config);
+    let notes = NotesManager::new(git.clone(), config.clone());
 
     let adrs = notes.list()?;
 
@@ -51,6 +86,21 @@ pub fn run(args: Args) -> Result<()> {
         println!();
         println!("This repository doesn't have any ADRs yet.");
         println!();
+
+        if !args.no_seed && (args.yes || (!args.non_interactive && confirm_seed()?)) {
+            let adr = create_seed_adr(&git, &config, &notes)?;
+            tracing::info!("{} Created ADR: {}", "✓".green(), adr.id);
+            tracing::info!("  Title: {}", adr.frontmatter.title);
+            println!();
+            println!("  {} View it: git adr show {}", "→".blue(), adr.id.cyan());
+            println!(
+                "  {} Record your next decision: git adr new <title>",
+                "→".blue()
+            );
+            println!();
+            return Ok(());
+        }
+
         println!("Get started by creating your first ADR:");
         println!(
             "  {} git adr new \"Use git-adr for architecture decisions\"",
@@ -106,6 +156,49 @@ pub fn run(args: Args) -> Result<()> {
     Ok(())
 }
 
+/// Ask whether to create the starter ADR (defaults to no).
+fn confirm_seed() -> Result<bool> {
+    print!("Create a first ADR, \"{SEED_TITLE}\"? [y/N] ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    println!();
+
+    Ok(input.eq_ignore_ascii_case("y") || input.eq_ignore_ascii_case("yes"))
+}
+
+/// Create the accepted "Use Architecture Decision Records" ADR using the
+/// configured template.
+fn create_seed_adr(git: &Git, config: &AdrConfig, notes: &NotesManager) -> Result<Adr> {
+    if !config.initialized {
+        return Err(Error::NotInitialized.into());
+    }
+
+    let adr_id = notes.new_id(SEED_TITLE)?;
+    let mut adr = Adr::new(adr_id, SEED_TITLE.to_string());
+    adr.commit = git.head()?;
+    adr.frontmatter.status = AdrStatus::Accepted;
+    adr.frontmatter
+        .deciders
+        .clone_from(&config.default_deciders);
+    if let Ok((name, email)) = git.user_identity() {
+        adr.frontmatter.authors.push(format!("{name} <{email}>"));
+    }
+    adr.frontmatter.date = Some(FlexibleDate(Utc::now()));
+    adr.frontmatter.format = Some(config.format.clone());
+    adr.body = SEED_BODY.to_string();
+
+    let template_engine = TemplateEngine::for_repo(git, config)?;
+    adr.body = template_engine.render_converted(&config.format, &adr)?;
+
+    config.check_body_size(&adr.body)?;
+    notes.create(&adr)?;
+
+    Ok(adr)
+}
+
 /// Print summary statistics.
 fn print_summary(adrs: &[crate::core::Adr]) {
     let mut status_counts: HashMap<&AdrStatus, usize> = HashMap::new();
@@ -144,6 +237,7 @@ fn show_overview(adrs: &[&crate::core::Adr], args: &Args) -> Result<()> {
             AdrStatus::Deprecated => "deprecated".dimmed(),
             AdrStatus::Superseded => "superseded".dimmed(),
             AdrStatus::Rejected => "rejected".red(),
+            AdrStatus::Archived => "archived".dimmed(),
         };
 
         println!(
@@ -199,7 +293,7 @@ fn show_by_tag(adrs: &[&crate::core::Adr], _args: &Args) -> Result<()> {
 
     // Sort tags by count
     let mut tags: Vec<_> = by_tag.iter().collect();
-    tags.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+    tags.sort_by_key(|(_, adrs)| std::cmp::Reverse(adrs.len()));
 
     println!("{}", "Decisions by Category".bold());
     println!();
@@ -0,0 +1,71 @@
+//! Pager support for long command output.
+//!
+//! Mirrors git's behavior: output is sent through a pager only when stdout
+//! is a terminal, so piping or redirecting always gets plain output.
+
+use anyhow::Result;
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// Pager used when nothing else is configured.
+const DEFAULT_PAGER: &str = "less -FRX";
+
+/// Resolve the pager command.
+///
+/// Checks `$GIT_PAGER`, then the `adr.pager` config value, then `$PAGER`,
+/// falling back to `less -FRX`. Returns `None` when paging is disabled by an
+/// empty value or `cat`.
+#[must_use]
+pub fn pager_command(configured: Option<&str>) -> Option<String> {
+    let pager = std::env::var("GIT_PAGER")
+        .ok()
+        .or_else(|| configured.map(str::to_string))
+        .or_else(|| std::env::var("PAGER").ok())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+
+    let trimmed = pager.trim();
+    if trimmed.is_empty() || trimmed == "cat" {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Write `content` to stdout, through a pager when stdout is a terminal.
+///
+/// `configured` is the `adr.pager` value; pass `enabled = false` for
+/// `--no-pager`. If the pager cannot be started the content is printed
+/// directly.
+///
+/// # Errors
+///
+/// Returns an error if writing to stdout fails.
+pub fn page(content: &str, configured: Option<&str>, enabled: bool) -> Result<()> {
+    let pager = if enabled && std::io::stdout().is_terminal() {
+        pager_command(configured)
+    } else {
+        None
+    };
+
+    if let Some(pager) = pager {
+        let child = Command::new("sh")
+            .arg("-c")
+            .arg(&pager)
+            .stdin(Stdio::piped())
+            .spawn();
+
+        if let Ok(mut child) = child {
+            if let Some(mut stdin) = child.stdin.take() {
+                // The user quitting the pager early closes the pipe; not an error
+                let _ = stdin.write_all(content.as_bytes());
+            }
+            child.wait()?;
+            return Ok(());
+        }
+    }
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(content.as_bytes())?;
+    stdout.flush()?;
+    Ok(())
+}
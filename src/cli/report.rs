@@ -9,7 +9,7 @@ use std::fmt::Write as _;
 use std::fs;
 use std::path::Path;
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use crate::core::{Adr, AdrStatus, ConfigManager, DateWindow, Git, NotesManager};
 
 /// Arguments for the report command.
 #[derive(ClapArgs, Debug)]
@@ -29,6 +29,14 @@ pub struct Args {
     /// Include timeline analysis.
     #[arg(long)]
     pub timeline: bool,
+
+    /// Only report ADRs dated on or after this date.
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only report ADRs dated on or before this date.
+    #[arg(long)]
+    pub until: Option<String>,
 }
 
 /// Run the report command.
@@ -43,10 +51,13 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git, config);
 
-    let adrs = notes.list()?;
+    let mut adrs = notes.list()?;
+
+    let window = DateWindow::parse(args.since.as_deref(), args.until.as_deref())?;
+    window.retain(&mut adrs);
 
     if adrs.is_empty() {
-        eprintln!(
+        tracing::info!(
             "{} No ADRs found. Create your first ADR with: git adr new <title>",
             "→".yellow()
         );
@@ -76,13 +87,31 @@ pub fn run(args: Args) -> Result<()> {
         }
     }
 
+    // Proposals older than adr.staleDays, oldest first
+    let now = Utc::now();
+    let stale_days = i64::from(notes.config().stale_days);
+    let mut stale: Vec<(&Adr, i64)> = adrs
+        .iter()
+        .filter_map(|adr| Some((adr, adr.proposal_age_days(now)?)))
+        .filter(|(_, days)| *days > stale_days)
+        .collect();
+    stale.sort_by_key(|(_, days)| std::cmp::Reverse(*days));
+
     let report = match args.format.as_str() {
-        "json" => generate_json_report(&adrs, &status_counts, &tag_counts, &monthly_counts)?,
+        "json" => generate_json_report(
+            &adrs,
+            &status_counts,
+            &tag_counts,
+            &monthly_counts,
+            &stale,
+            stale_days,
+        )?,
         "html" => generate_html_report(
             &adrs,
             &status_counts,
             &tag_counts,
             &monthly_counts,
+            &stale,
             args.detailed,
             args.timeline,
         ),
@@ -91,6 +120,7 @@ pub fn run(args: Args) -> Result<()> {
             &status_counts,
             &tag_counts,
             &monthly_counts,
+            &stale,
             args.detailed,
             args.timeline,
         ),
@@ -104,7 +134,7 @@ pub fn run(args: Args) -> Result<()> {
             }
         }
         fs::write(&output_path, &report)?;
-        eprintln!("{} Report saved to: {}", "✓".green(), output_path.cyan());
+        tracing::info!("{} Report saved to: {}", "✓".green(), output_path.cyan());
     } else {
         println!("{report}");
     }
@@ -118,6 +148,8 @@ fn generate_json_report(
     status_counts: &HashMap<AdrStatus, usize>,
     tag_counts: &HashMap<String, usize>,
     monthly_counts: &HashMap<String, usize>,
+    stale: &[(&Adr, i64)],
+    stale_days: i64,
 ) -> Result<String> {
     let mut status_map: HashMap<String, usize> = HashMap::new();
     for (status, count) in status_counts {
@@ -131,6 +163,10 @@ fn generate_json_report(
         "tag_breakdown": tag_counts,
         "monthly_breakdown": monthly_counts,
         "acceptance_rate": calculate_acceptance_rate(status_counts),
+        "stale_proposals": {
+            "threshold_days": stale_days,
+            "ids": stale.iter().map(|(adr, _)| &adr.id).collect::<Vec<_>>(),
+        },
     });
 
     Ok(serde_json::to_string_pretty(&report)?)
@@ -143,6 +179,7 @@ fn generate_markdown_report(
     status_counts: &HashMap<AdrStatus, usize>,
     tag_counts: &HashMap<String, usize>,
     monthly_counts: &HashMap<String, usize>,
+    stale: &[(&Adr, i64)],
     detailed: bool,
     timeline: bool,
 ) -> String {
@@ -188,6 +225,19 @@ fn generate_markdown_report(
     }
     report.push('\n');
 
+    // Stale proposals
+    if !stale.is_empty() {
+        report.push_str("## Stale Proposals\n\n");
+        for (adr, days) in stale {
+            let _ = writeln!(
+                report,
+                "- **{}** {} ({} days)",
+                adr.id, adr.frontmatter.title, days
+            );
+        }
+        report.push('\n');
+    }
+
     // Top tags
     if !tag_counts.is_empty() {
         report.push_str("## Top Tags\n\n");
@@ -244,6 +294,7 @@ fn generate_html_report(
     status_counts: &HashMap<AdrStatus, usize>,
     tag_counts: &HashMap<String, usize>,
     monthly_counts: &HashMap<String, usize>,
+    stale: &[(&Adr, i64)],
     detailed: bool,
     timeline: bool,
 ) -> String {
@@ -322,6 +373,19 @@ fn generate_html_report(
     }
     html.push_str("</table>\n\n");
 
+    // Stale proposals
+    if !stale.is_empty() {
+        html.push_str("<h2>Stale Proposals</h2>\n<table>\n<tr><th>ID</th><th>Title</th><th>Days Proposed</th></tr>\n");
+        for (adr, days) in stale {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                adr.id, adr.frontmatter.title, days
+            );
+        }
+        html.push_str("</table>\n\n");
+    }
+
     // Tags
     if !tag_counts.is_empty() {
         html.push_str("<h2>Top Tags</h2>\n<p>");
@@ -0,0 +1,40 @@
+//! Restore an archived ADR.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+
+use crate::core::{ConfigManager, Git, NotesManager};
+
+/// Arguments for the restore command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// ADR ID to restore.
+    pub adr_id: String,
+}
+
+/// Run the restore command.
+///
+/// # Errors
+///
+/// Returns an error if the ADR is not found, not archived, or cannot be restored.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git, config);
+
+    let id = notes
+        .resolve_archived_id(&args.adr_id)
+        .or_else(|_| notes.resolve_id(&args.adr_id))?;
+    let restored = notes.restore(&id)?;
+    tracing::info!(
+        "{} ADR restored: {} (status: {})",
+        "✓".green(),
+        restored.id,
+        restored.frontmatter.status
+    );
+
+    Ok(())
+}
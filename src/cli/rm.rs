@@ -5,7 +5,8 @@ use clap::Args as ClapArgs;
 use colored::Colorize;
 use std::io::{self, Write};
 
-use crate::core::{ConfigManager, Git, NotesManager};
+use crate::core::{Adr, ConfigManager, Git, NotesManager};
+use crate::Error;
 
 /// Arguments for the rm command.
 #[derive(ClapArgs, Debug)]
@@ -16,6 +17,14 @@ pub struct Args {
     /// Skip confirmation prompt.
     #[arg(long, short)]
     pub force: bool,
+
+    /// Archive the ADR instead of deleting it (recover with `git adr restore`).
+    #[arg(long)]
+    pub archive: bool,
+
+    /// Show what would be removed, including links left dangling, without changing anything.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Run the rm command.
@@ -31,14 +40,35 @@ pub fn run(args: Args) -> Result<()> {
     let notes = NotesManager::new(git, config);
 
     // Find the ADR to confirm it exists
+    let id = notes.resolve_id(&args.adr_id)?;
     let adrs = notes.list()?;
     let adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+        .iter()
+        .find(|a| a.id == id)
+        .ok_or_else(|| Error::adr_not_found(&id))?;
 
-    eprintln!("{} ADR: {} - {}", "→".blue(), adr.id, adr.frontmatter.title);
-    eprintln!("  Status: {}", adr.frontmatter.status);
+    tracing::info!("{} ADR: {} - {}", "→".blue(), adr.id, adr.frontmatter.title);
+    tracing::info!("  Status: {}", adr.frontmatter.status);
+
+    if args.dry_run {
+        let action = if args.archive { "archive" } else { "remove" };
+        eprintln!("{} Dry run: would {} {}", "!".yellow(), action, adr.id);
+        // Archived ADRs still exist, so only deletion leaves links dangling
+        if !args.archive {
+            for (source, field) in backlinks(adrs.as_slice(), &adr.id) {
+                tracing::warn!("{} ({}) would point at a missing ADR", source, field);
+            }
+        }
+        return Ok(());
+    }
+
+    // Archiving is recoverable, so it needs no confirmation
+    if args.archive {
+        notes.archive(&adr.id)?;
+        tracing::info!("{} ADR archived: {}", "✓".green(), adr.id);
+        tracing::info!("  Restore it with: git adr restore {}", adr.id);
+        return Ok(());
+    }
 
     // Confirm deletion unless --force
     if !args.force {
@@ -49,7 +79,7 @@ pub fn run(args: Args) -> Result<()> {
         io::stdin().read_line(&mut input)?;
 
         if !input.trim().eq_ignore_ascii_case("y") {
-            eprintln!("{} Aborted", "!".yellow());
+            tracing::warn!("Aborted");
             return Ok(());
         }
     }
@@ -57,7 +87,24 @@ pub fn run(args: Args) -> Result<()> {
     // Delete the ADR
     notes.delete(&adr.id)?;
 
-    eprintln!("{} ADR removed: {}", "✓".green(), adr.id);
+    tracing::info!("{} ADR removed: {}", "✓".green(), adr.id);
 
     Ok(())
 }
+
+/// Find ADRs that refer to `id`, as `(source id, frontmatter field)` pairs.
+fn backlinks(adrs: &[Adr], id: &str) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    for other in adrs.iter().filter(|a| a.id != id) {
+        for link in other.frontmatter.links.iter().filter(|l| l.target == id) {
+            found.push((other.id.clone(), format!("links: {}", link.rel)));
+        }
+        if other.frontmatter.supersedes.as_deref() == Some(id) {
+            found.push((other.id.clone(), "supersedes".to_string()));
+        }
+        if other.frontmatter.superseded_by.as_deref() == Some(id) {
+            found.push((other.id.clone(), "superseded_by".to_string()));
+        }
+    }
+    found
+}
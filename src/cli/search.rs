@@ -4,8 +4,14 @@ use anyhow::Result;
 use clap::Args as ClapArgs;
 use colored::Colorize;
 use regex::Regex;
+use std::io::IsTerminal;
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use crate::core::{
+    Adr, AdrStatus, ConfigManager, Git, IndexManager, NotesManager, SearchOptions, Snippet,
+};
+
+/// Characters of ADR body shown on each side of a match.
+const SNIPPET_RADIUS: usize = 60;
 
 /// Arguments for the search command.
 #[derive(ClapArgs, Debug)]
@@ -29,6 +35,10 @@ pub struct Args {
     #[arg(long, short = 'E')]
     pub regex: bool,
 
+    /// Only match whole words.
+    #[arg(long, short)]
+    pub word: bool,
+
     /// Context lines to show.
     #[arg(long, short = 'C', default_value = "2")]
     pub context: usize,
@@ -36,6 +46,18 @@ pub struct Args {
     /// Maximum results.
     #[arg(long)]
     pub limit: Option<usize>,
+
+    /// Print results as JSON, best match first.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Rank ADRs by TF-IDF similarity to the query instead of matching text.
+    #[arg(long, conflicts_with_all = ["case_sensitive", "regex", "word"])]
+    pub semantic: bool,
+
+    /// Fetch ADR notes from the default remote first.
+    #[arg(long)]
+    pub fetch: bool,
 }
 
 /// A search match result.
@@ -51,18 +73,23 @@ struct SearchMatch {
 /// # Errors
 ///
 /// Returns an error if search fails.
+#[allow(clippy::too_many_lines)]
 pub fn run(args: Args) -> Result<()> {
     let git = Git::new();
     git.check_repository()?;
 
     let config = ConfigManager::new(git.clone()).load()?;
-    let notes = NotesManager::new(git, config);
+    let notes = NotesManager::new(git.clone(), config);
+
+    if args.fetch || notes.config().auto_fetch {
+        notes.fetch_for_read();
+    }
 
     let mut adrs = notes.list()?;
 
     // Filter by status
     if let Some(status_str) = &args.status {
-        let status: AdrStatus = status_str.parse().map_err(|e| anyhow::anyhow!("{}", e))?;
+        let status: AdrStatus = status_str.parse()?;
         adrs.retain(|a| a.frontmatter.status == status);
     }
 
@@ -71,21 +98,17 @@ pub fn run(args: Args) -> Result<()> {
         adrs.retain(|a| a.frontmatter.tags.iter().any(|t| t.contains(tag)));
     }
 
+    if args.semantic {
+        return run_semantic(&args, &git, &notes, &adrs);
+    }
+
     // Build search pattern
-    let pattern = if args.regex {
-        if args.case_sensitive {
-            Regex::new(&args.query)?
-        } else {
-            Regex::new(&format!("(?i){}", &args.query))?
-        }
-    } else {
-        let escaped = regex::escape(&args.query);
-        if args.case_sensitive {
-            Regex::new(&escaped)?
-        } else {
-            Regex::new(&format!("(?i){}", escaped))?
-        }
-    };
+    let pattern = SearchOptions {
+        case_sensitive: args.case_sensitive,
+        whole_word: args.word,
+        regex: args.regex,
+    }
+    .pattern(&args.query)?;
 
     let mut total_matches = 0;
     let mut results = Vec::new();
@@ -124,20 +147,55 @@ pub fn run(args: Args) -> Result<()> {
             results.push((adr.clone(), matches));
         }
 
-        // Check limit
+        // Check limit (JSON results are ranked first, so they need every match)
         if let Some(limit) = args.limit {
-            if results.len() >= limit {
+            if !args.json && results.len() >= limit {
                 break;
             }
         }
     }
 
+    if args.json {
+        let mut hits: Vec<(usize, &Adr, &[SearchMatch])> = results
+            .iter()
+            .map(|(adr, matches)| (score(adr, &pattern), adr, matches.as_slice()))
+            .collect();
+        hits.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.id.cmp(&b.1.id)));
+        hits.truncate(args.limit.unwrap_or(hits.len()));
+
+        let json: Vec<_> = hits
+            .into_iter()
+            .map(|(score, adr, matches)| {
+                // Fall back to the matching line when only the frontmatter matched
+                let snippet = Snippet::find(&adr.body, &pattern, SNIPPET_RADIUS).map_or_else(
+                    || {
+                        matches
+                            .first()
+                            .map(|m| m.line.trim().to_string())
+                            .unwrap_or_default()
+                    },
+                    |snippet| snippet.text,
+                );
+                serde_json::json!({
+                    "id": adr.id,
+                    "title": adr.frontmatter.title,
+                    "status": adr.frontmatter.status.to_string(),
+                    "score": score,
+                    "snippet": snippet,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        return Ok(());
+    }
+
     if results.is_empty() {
-        eprintln!("{} No matches found for: {}", "→".yellow(), args.query);
+        tracing::info!("{} No matches found for: {}", "→".yellow(), args.query);
         return Ok(());
     }
 
-    // Display results
+    // Display results, highlighting matches only on a terminal
+    let highlight = std::io::stdout().is_terminal();
     for (adr, matches) in &results {
         println!(
             "{} {} - {}",
@@ -145,6 +203,10 @@ pub fn run(args: Args) -> Result<()> {
             format!("[{}]", adr.frontmatter.status).dimmed(),
             adr.frontmatter.title
         );
+        if let Some(snippet) = Snippet::find(&adr.body, &pattern, SNIPPET_RADIUS) {
+            println!("  {}", render_snippet(&snippet, highlight));
+            println!();
+        }
 
         for m in matches {
             // Print context before
@@ -153,9 +215,13 @@ pub fn run(args: Args) -> Result<()> {
             }
 
             // Print matching line with highlighting
-            let highlighted = pattern.replace_all(&m.line, |caps: &regex::Captures| {
-                format!("{}", caps[0].red().bold())
-            });
+            let highlighted = if highlight {
+                pattern.replace_all(&m.line, |caps: &regex::Captures| {
+                    format!("{}", caps[0].red().bold())
+                })
+            } else {
+                m.line.as_str().into()
+            };
             println!(
                 "  {} {}",
                 format!("{}:", m.line_number).yellow(),
@@ -171,7 +237,7 @@ pub fn run(args: Args) -> Result<()> {
         }
     }
 
-    eprintln!(
+    tracing::info!(
         "{} {} match(es) in {} ADR(s)",
         "→".blue(),
         total_matches,
@@ -180,3 +246,71 @@ pub fn run(args: Args) -> Result<()> {
 
     Ok(())
 }
+
+/// Rank the ADRs in `adrs` by TF-IDF similarity to the query, using the
+/// search index (rebuilt first if it is out of date).
+fn run_semantic(args: &Args, git: &Git, notes: &NotesManager, adrs: &[Adr]) -> Result<()> {
+    let manager = IndexManager::with_config(git.clone(), notes.config());
+    let mut ranked = manager.semantic_search(notes, &args.query)?;
+
+    // Keep the status and tag filters
+    ranked.retain(|(entry, _)| adrs.iter().any(|adr| adr.id == entry.id));
+    ranked.truncate(args.limit.unwrap_or(ranked.len()));
+
+    if args.json {
+        let json: Vec<_> = ranked
+            .iter()
+            .map(|(entry, score)| {
+                serde_json::json!({
+                    "id": entry.id,
+                    "title": entry.title,
+                    "status": entry.status,
+                    "score": score,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        return Ok(());
+    }
+
+    if ranked.is_empty() {
+        tracing::info!("{} No similar ADRs found for: {}", "→".yellow(), args.query);
+        return Ok(());
+    }
+
+    for (entry, score) in &ranked {
+        println!(
+            "{} {} - {} {}",
+            entry.id.cyan().bold(),
+            format!("[{}]", entry.status).dimmed(),
+            entry.title,
+            format!("({score:.2})").dimmed()
+        );
+    }
+    tracing::info!("{} {} similar ADR(s)", "→".blue(), ranked.len());
+
+    Ok(())
+}
+
+/// Relevance of an ADR: one point per match in the body, three per match in
+/// the title.
+fn score(adr: &Adr, pattern: &Regex) -> usize {
+    pattern.find_iter(&adr.body).count() + 3 * pattern.find_iter(&adr.frontmatter.title).count()
+}
+
+/// A snippet with its matches in bold red, or as plain text.
+fn render_snippet(snippet: &Snippet, highlight: bool) -> String {
+    if !highlight {
+        return snippet.text.clone();
+    }
+
+    let mut out = String::new();
+    let mut pos = 0;
+    for range in &snippet.matches {
+        out.push_str(&snippet.text[pos..range.start]);
+        out.push_str(&snippet.text[range.clone()].red().bold().to_string());
+        pos = range.end;
+    }
+    out.push_str(&snippet.text[pos..]);
+    out
+}
@@ -1,16 +1,27 @@
-//! Show an ADR.
+//! Show one or more ADRs.
 
 use anyhow::Result;
 use clap::Args as ClapArgs;
 use colored::Colorize;
 
-use crate::core::{ConfigManager, Git, NotesManager};
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+
+use crate::cli::{diff, pager};
+use crate::core::{Adr, ConfigManager, Git, NotesManager};
+use crate::Error;
 
 /// Arguments for the show command.
 #[derive(ClapArgs, Debug)]
 pub struct Args {
-    /// ADR ID to show.
-    pub adr_id: String,
+    /// IDs of the ADRs to show.
+    #[arg(required_unless_present = "all")]
+    pub adr_ids: Vec<String>,
+
+    /// Show every ADR.
+    #[arg(long, conflicts_with_all = ["adr_ids", "history", "field"])]
+    pub all: bool,
 
     /// Output format (markdown, yaml, json).
     #[arg(long, short, default_value = "markdown")]
@@ -19,6 +30,31 @@ pub struct Args {
     /// Show only metadata.
     #[arg(long)]
     pub metadata_only: bool,
+
+    /// Print raw markdown instead of rendering it for the terminal.
+    #[arg(long)]
+    pub raw: bool,
+
+    /// Don't pipe output through a pager.
+    #[arg(long)]
+    pub no_pager: bool,
+
+    /// Fetch ADR notes from the default remote first.
+    #[arg(long)]
+    pub fetch: bool,
+
+    /// Show every revision of the ADR with a diff against the one before it.
+    #[arg(long, conflicts_with_all = ["metadata_only", "raw"])]
+    pub history: bool,
+
+    /// Check the signature on the notes commit that last wrote the ADR and
+    /// report the signer before showing it.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Print only this field's value (exit code 2 if the ADR doesn't have it).
+    #[arg(long, conflicts_with_all = ["history", "metadata_only", "raw"])]
+    pub field: Option<String>,
 }
 
 /// Run the show command.
@@ -31,67 +67,476 @@ pub fn run(args: Args) -> Result<()> {
     git.check_repository()?;
 
     let config = ConfigManager::new(git.clone()).load()?;
-    let notes = NotesManager::new(git, config);
-
-    // Try to find ADR by ID (exact match or partial)
-    let adrs = notes.list()?;
-    let adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
-
-    match args.format.as_str() {
-        "json" => {
-            let output = if args.metadata_only {
-                serde_json::json!({
-                    "id": adr.id,
-                    "title": adr.frontmatter.title,
-                    "status": adr.frontmatter.status.to_string(),
-                    "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
-                    "tags": adr.frontmatter.tags,
-                    "authors": adr.frontmatter.authors,
-                    "deciders": adr.frontmatter.deciders,
-                    "commit": adr.commit,
-                })
+    let notes = NotesManager::new(git, config.clone());
+
+    if args.fetch || notes.config().auto_fetch {
+        notes.fetch_for_read();
+    }
+
+    let mut adrs = if args.all {
+        notes.list()?
+    } else {
+        args.adr_ids
+            .iter()
+            .map(|id| notes.resolve(id))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    if adrs.len() > 1 && (args.history || args.field.is_some()) {
+        anyhow::bail!("--history and --field take a single ADR");
+    }
+    // Only ADRs named for a full display show when they were last modified
+    if !args.all && !args.history && args.field.is_none() {
+        notes.load_history(&mut adrs)?;
+    }
+
+    // Reported on stderr so the ADR itself stays the only stdout
+    if args.verify {
+        for adr in &adrs {
+            let signer = notes.verify_commit(&adr.id)?;
+            if adrs.len() == 1 {
+                eprintln!("{} Good signature from {}", "✓".green(), signer);
             } else {
-                serde_json::json!({
-                    "id": adr.id,
-                    "title": adr.frontmatter.title,
-                    "status": adr.frontmatter.status.to_string(),
-                    "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
-                    "tags": adr.frontmatter.tags,
-                    "authors": adr.frontmatter.authors,
-                    "deciders": adr.frontmatter.deciders,
-                    "commit": adr.commit,
-                    "body": adr.body,
-                })
-            };
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        },
+                eprintln!(
+                    "{} Good signature on {} from {}",
+                    "✓".green(),
+                    adr.id.cyan(),
+                    signer
+                );
+            }
+        }
+    }
+
+    if let [adr] = adrs.as_slice() {
+        if let Some(name) = &args.field {
+            let value = field_value(adr, name)?.ok_or_else(|| Error::FieldNotFound {
+                id: adr.id.clone(),
+                field: name.clone(),
+            })?;
+            println!("{value}");
+            return Ok(());
+        }
+
+        if args.history {
+            let output = format_history(&notes, adr, args.format == "json")?;
+            return pager::page(&output, config.pager.as_deref(), !args.no_pager);
+        }
+    }
+
+    // A single ADR named on the command line prints as before; several (or
+    // --all) print as a JSON array or separated by a rule
+    let output = if args.format == "json" {
+        let values: Vec<serde_json::Value> = adrs
+            .iter()
+            .map(|adr| json_value(adr, args.metadata_only))
+            .collect();
+        let json = if args.all || values.len() != 1 {
+            serde_json::to_string_pretty(&values)?
+        } else {
+            serde_json::to_string_pretty(&values[0])?
+        };
+        format!("{json}\n")
+    } else {
+        let rule = format!("\n{}\n\n", "─".repeat(72).dimmed());
+        adrs.iter()
+            .map(|adr| format_adr(adr, &args))
+            .collect::<Result<Vec<_>>>()?
+            .join(&rule)
+    };
+
+    pager::page(&output, config.pager.as_deref(), !args.no_pager)
+}
+
+/// An ADR as JSON, without its body if `metadata_only`.
+fn json_value(adr: &Adr, metadata_only: bool) -> serde_json::Value {
+    let mut value = serde_json::json!({
+        "id": adr.id,
+        "title": adr.frontmatter.title,
+        "status": adr.frontmatter.status.to_string(),
+        "date": adr.frontmatter.date.as_ref().map(|d| d.datetime().to_rfc3339()),
+        "tags": adr.frontmatter.tags,
+        "authors": adr.frontmatter.authors,
+        "deciders": adr.frontmatter.deciders,
+        "commit": adr.commit,
+        "last_modified": adr.last_modified.map(|d| d.to_rfc3339()),
+        "note_author": adr.note_author,
+        "word_count": adr.word_count(),
+        "reading_minutes": adr.reading_minutes(),
+    });
+    if !metadata_only {
+        value["body"] = serde_json::Value::String(adr.body.clone());
+    }
+    value
+}
+
+/// An ADR in the markdown or YAML format.
+fn format_adr(adr: &Adr, args: &Args) -> Result<String> {
+    Ok(match args.format.as_str() {
         "yaml" => {
             if args.metadata_only {
-                println!("{}", serde_yaml::to_string(&adr.frontmatter)?);
+                format!("{}\n", serde_yaml::to_string(&adr.frontmatter)?)
             } else {
-                println!("{}", adr.to_markdown()?);
+                format!("{}\n", adr.to_markdown()?)
             }
         },
         _ => {
             if args.metadata_only {
-                println!("{} {}", "ID:".bold(), adr.id.cyan());
-                println!("{} {}", "Title:".bold(), adr.frontmatter.title);
-                println!("{} {}", "Status:".bold(), adr.frontmatter.status);
-                if let Some(date) = &adr.frontmatter.date {
-                    println!("{} {}", "Date:".bold(), date.datetime().format("%Y-%m-%d"));
-                }
-                if !adr.frontmatter.tags.is_empty() {
-                    println!("{} {}", "Tags:".bold(), adr.frontmatter.tags.join(", "));
-                }
-                println!("{} {}", "Commit:".bold(), &adr.commit[..8]);
+                let mut out = format_metadata(adr);
+                let _ = writeln!(
+                    out,
+                    "{} {} (~{} min read)",
+                    "Words:".bold(),
+                    adr.word_count(),
+                    adr.reading_minutes()
+                );
+                out
+            } else if args.raw || !std::io::stdout().is_terminal() {
+                format!("{}\n", adr.to_markdown()?)
             } else {
-                println!("{}", adr.to_markdown()?);
+                format!("{}\n{}", format_metadata(adr), render_markdown(&adr.body))
             }
         },
+    })
+}
+
+/// The value of a frontmatter field (or `id`/`commit`) as plain text.
+///
+/// Lists are joined with commas. Returns `None` if the ADR doesn't have the
+/// field.
+fn field_value(adr: &Adr, name: &str) -> Result<Option<String>> {
+    match name {
+        "id" => return Ok(Some(adr.id.clone())),
+        "commit" => return Ok(Some(adr.commit.clone())),
+        _ => {},
     }
 
-    Ok(())
+    let frontmatter = serde_yaml::to_value(&adr.frontmatter)?;
+    let Some(value) = frontmatter.get(name) else {
+        return Ok(None);
+    };
+    Ok(match value {
+        serde_yaml::Value::Null => None,
+        serde_yaml::Value::Sequence(items) => Some(
+            items
+                .iter()
+                .map(scalar_text)
+                .collect::<Result<Vec<_>>>()?
+                .join(","),
+        ),
+        value => Some(scalar_text(value)?),
+    })
+}
+
+/// A YAML value as text: scalars as written, anything nested as JSON.
+fn scalar_text(value: &serde_yaml::Value) -> Result<String> {
+    Ok(match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Null => String::new(),
+        value => serde_json::to_string(value)?,
+    })
+}
+
+/// Format the ADR's note revisions, newest first, each diffed against the
+/// revision before it.
+fn format_history(notes: &NotesManager, adr: &Adr, json: bool) -> Result<String> {
+    let git = notes.git();
+    let revisions = notes.history(adr)?;
+
+    let mut entries = Vec::with_capacity(revisions.len());
+    for (i, revision) in revisions.iter().enumerate() {
+        let previous = revisions.get(i + 1).and_then(|r| r.blob.as_deref());
+        let diff = git.diff_blobs(previous, revision.blob.as_deref())?;
+        entries.push((revision, diff));
+    }
+
+    if json {
+        let output: Vec<_> = entries
+            .iter()
+            .map(|(revision, diff)| {
+                serde_json::json!({
+                    "notes_commit": revision.notes_commit,
+                    "author": revision.author,
+                    "date": revision.date.to_rfc3339(),
+                    "removed": revision.blob.is_none(),
+                    "diff": diff,
+                })
+            })
+            .collect();
+        return Ok(format!("{}\n", serde_json::to_string_pretty(&output)?));
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "{} {} revision(s) of ADR {}",
+        "→".blue(),
+        entries.len(),
+        adr.id.cyan()
+    );
+    for (revision, diff) in &entries {
+        out.push('\n');
+        let _ = writeln!(
+            out,
+            "{} {}  {}  {}{}",
+            "revision".yellow(),
+            revision.notes_commit[..8].yellow(),
+            revision.date.format("%Y-%m-%d %H:%M"),
+            revision.author,
+            if revision.blob.is_none() {
+                "  (note removed)"
+            } else {
+                ""
+            }
+        );
+        out.push_str(&diff::colorize(diff));
+    }
+    Ok(out)
+}
+
+/// Format the ADR metadata as labelled lines.
+fn format_metadata(adr: &Adr) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{} {}", "ID:".bold(), adr.id.cyan());
+    let _ = writeln!(out, "{} {}", "Title:".bold(), adr.frontmatter.title);
+    let _ = writeln!(out, "{} {}", "Status:".bold(), adr.frontmatter.status);
+    if let Some(date) = &adr.frontmatter.date {
+        let _ = writeln!(
+            out,
+            "{} {}",
+            "Date:".bold(),
+            date.datetime().format("%Y-%m-%d")
+        );
+    }
+    if !adr.frontmatter.tags.is_empty() {
+        let _ = writeln!(
+            out,
+            "{} {}",
+            "Tags:".bold(),
+            adr.frontmatter.tags.join(", ")
+        );
+    }
+    let _ = writeln!(out, "{} {}", "Commit:".bold(), &adr.commit[..8]);
+    if let Some(modified) = &adr.last_modified {
+        let _ = write!(
+            out,
+            "{} {}",
+            "Modified:".bold(),
+            modified.format("%Y-%m-%d %H:%M")
+        );
+        if let Some(author) = &adr.note_author {
+            let _ = write!(out, " by {author}");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render markdown with ANSI styling for terminal display.
+fn render_markdown(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut renderer = TerminalRenderer::default();
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event);
+    }
+
+    let mut output = renderer.output.trim_end().to_string();
+    output.push('\n');
+    output
+}
+
+/// Event-driven markdown renderer that writes ANSI-styled text.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct TerminalRenderer {
+    output: String,
+    /// Open lists; `Some(n)` is an ordered list at item `n`.
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    heading: Option<HeadingLevel>,
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    code_block: bool,
+    link: Option<String>,
+    table_row: Vec<String>,
+    table_cell: Option<String>,
+}
+
+impl TerminalRenderer {
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if self.code_block {
+                    for line in text.lines() {
+                        self.write(&format!("    {}\n", line.green()));
+                    }
+                } else {
+                    let styled = self.style(&text);
+                    self.write(&styled);
+                }
+            },
+            Event::Code(code) => self.write(&code.yellow().to_string()),
+            Event::Html(raw) | Event::InlineHtml(raw) => self.write(&raw),
+            Event::SoftBreak => self.write(" "),
+            Event::HardBreak => self.newline(),
+            Event::Rule => {
+                self.ensure_newline();
+                self.write(&"─".repeat(40).dimmed().to_string());
+                self.output.push_str("\n\n");
+            },
+            Event::TaskListMarker(checked) => self.write(if checked { "[x] " } else { "[ ] " }),
+            _ => {},
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.ensure_newline();
+                self.heading = Some(level);
+            },
+            Tag::BlockQuote(_) => {
+                self.ensure_newline();
+                self.quote_depth += 1;
+            },
+            Tag::CodeBlock(_) => {
+                self.ensure_newline();
+                self.code_block = true;
+            },
+            Tag::List(start) => {
+                self.ensure_newline();
+                self.lists.push(start);
+            },
+            Tag::Item => {
+                self.ensure_newline();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        let marker = format!("{n}.");
+                        *n += 1;
+                        marker
+                    },
+                    _ => "•".to_string(),
+                };
+                self.write(&format!("{}{} ", "  ".repeat(depth), marker.cyan()));
+            },
+            Tag::Emphasis => self.italic = true,
+            Tag::Strong => self.bold = true,
+            Tag::Strikethrough => self.strikethrough = true,
+            Tag::Link { dest_url, .. } => self.link = Some(dest_url.to_string()),
+            Tag::TableHead | Tag::TableRow => self.table_row.clear(),
+            Tag::TableCell => self.table_cell = Some(String::new()),
+            _ => {},
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.newline();
+                if self.lists.is_empty() {
+                    self.newline();
+                }
+            },
+            TagEnd::Heading(_) => {
+                self.heading = None;
+                self.output.push_str("\n\n");
+            },
+            TagEnd::BlockQuote(_) => {
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+            },
+            TagEnd::CodeBlock => {
+                self.code_block = false;
+                self.newline();
+            },
+            TagEnd::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.ensure_newline();
+                    self.newline();
+                }
+            },
+            TagEnd::Item => self.ensure_newline(),
+            TagEnd::Emphasis => self.italic = false,
+            TagEnd::Strong => self.bold = false,
+            TagEnd::Strikethrough => self.strikethrough = false,
+            TagEnd::Link => {
+                if let Some(url) = self.link.take() {
+                    self.write(&format!(" ({})", url.dimmed()));
+                }
+            },
+            TagEnd::TableCell => {
+                if let Some(cell) = self.table_cell.take() {
+                    self.table_row.push(cell);
+                }
+            },
+            TagEnd::TableHead => {
+                let row = self.table_row.join(" │ ");
+                self.write(&row.bold().to_string());
+                self.newline();
+            },
+            TagEnd::TableRow => {
+                let row = self.table_row.join(" │ ");
+                self.write(&row);
+                self.newline();
+            },
+            TagEnd::Table => self.newline(),
+            _ => {},
+        }
+    }
+
+    /// Apply the active inline styles to a piece of text.
+    fn style(&self, text: &str) -> String {
+        let mut styled = text.normal();
+        match self.heading {
+            Some(HeadingLevel::H1) => styled = styled.bold().underline().cyan(),
+            Some(HeadingLevel::H2) => styled = styled.bold().cyan(),
+            Some(_) => styled = styled.bold(),
+            None => {},
+        }
+        if self.bold {
+            styled = styled.bold();
+        }
+        if self.italic {
+            styled = styled.italic();
+        }
+        if self.strikethrough {
+            styled = styled.strikethrough();
+        }
+        if self.link.is_some() {
+            styled = styled.underline().blue();
+        }
+        styled.to_string()
+    }
+
+    /// Write text, prefixing block quote markers at the start of a line.
+    fn write(&mut self, text: &str) {
+        if let Some(cell) = &mut self.table_cell {
+            cell.push_str(text);
+            return;
+        }
+        if self.quote_depth > 0 && self.at_line_start() {
+            self.output
+                .push_str(&"│ ".repeat(self.quote_depth).dimmed().to_string());
+        }
+        self.output.push_str(text);
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+    }
+
+    fn ensure_newline(&mut self) {
+        if !self.at_line_start() {
+            self.newline();
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.output.is_empty() || self.output.ends_with('\n')
+    }
 }
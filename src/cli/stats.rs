@@ -1,11 +1,12 @@
 //! Show ADR statistics.
 
 use anyhow::Result;
+use chrono::Utc;
 use clap::Args as ClapArgs;
 use colored::Colorize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use crate::core::{AdrStatus, ConfigManager, Git, NotesManager};
+use crate::core::{Adr, AdrStatus, ConfigManager, DateWindow, Git, NotesManager, WORDS_PER_MINUTE};
 
 /// Arguments for the stats command.
 #[derive(ClapArgs, Debug)]
@@ -13,6 +14,26 @@ pub struct Args {
     /// Output format (text, json).
     #[arg(long, short, default_value = "text")]
     pub format: String,
+
+    /// Fetch ADR notes from the default remote first.
+    #[arg(long)]
+    pub fetch: bool,
+
+    /// Days after which a proposed ADR counts as stale (default: adr.staleDays).
+    #[arg(long)]
+    pub stale_days: Option<i64>,
+
+    /// Count ADRs per author and decider.
+    #[arg(long)]
+    pub by_author: bool,
+
+    /// Only count ADRs dated on or after this date.
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only count ADRs dated on or before this date.
+    #[arg(long)]
+    pub until: Option<String>,
 }
 
 /// Run the stats command.
@@ -20,6 +41,7 @@ pub struct Args {
 /// # Errors
 ///
 /// Returns an error if stats fails.
+#[allow(clippy::too_many_lines)]
 pub fn run(args: Args) -> Result<()> {
     let git = Git::new();
     git.check_repository()?;
@@ -27,7 +49,14 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git, config);
 
-    let adrs = notes.list()?;
+    if args.fetch || notes.config().auto_fetch {
+        notes.fetch_for_read();
+    }
+
+    let mut adrs = notes.list()?;
+
+    let window = DateWindow::parse(args.since.as_deref(), args.until.as_deref())?;
+    window.retain(&mut adrs);
 
     // Calculate statistics
     let total = adrs.len();
@@ -46,6 +75,24 @@ pub fn run(args: Args) -> Result<()> {
         }
     }
 
+    // Count by author and decider, ordered by name
+    let mut by_author: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    if args.by_author {
+        for adr in &adrs {
+            for author in &adr.frontmatter.authors {
+                by_author.entry(author.as_str()).or_default().0 += 1;
+            }
+            for decider in &adr.frontmatter.deciders {
+                by_author.entry(decider.as_str()).or_default().1 += 1;
+            }
+        }
+    }
+
+    // Body length, with the average rounded to the nearest word
+    let total_words: usize = adrs.iter().map(Adr::word_count).sum();
+    let average_words = (total_words + total / 2).checked_div(total).unwrap_or(0);
+    let reading_minutes = total_words.div_ceil(WORDS_PER_MINUTE);
+
     // Find date range
     let dates: Vec<_> = adrs
         .iter()
@@ -54,8 +101,25 @@ pub fn run(args: Args) -> Result<()> {
     let oldest = dates.iter().min_by_key(|d| d.datetime());
     let newest = dates.iter().max_by_key(|d| d.datetime());
 
+    // Age of each dated proposal; undated ones can't be aged and are skipped
+    let now = Utc::now();
+    let stale_days = args
+        .stale_days
+        .unwrap_or_else(|| i64::from(notes.config().stale_days));
+    let proposal_ages: Vec<_> = adrs
+        .iter()
+        .filter_map(|a| Some((a, a.proposal_age_days(now)?)))
+        .collect();
+    let oldest_proposal = proposal_ages.iter().max_by_key(|(_, days)| *days);
+    let stale_ids: Vec<&str> = proposal_ages
+        .iter()
+        .filter(|(_, days)| *days > stale_days)
+        .map(|(adr, _)| adr.id.as_str())
+        .collect();
+    let stale_proposals = stale_ids.len();
+
     if args.format.as_str() == "json" {
-        let stats = serde_json::json!({
+        let mut stats = serde_json::json!({
             "total": total,
             "by_status": by_status.iter()
                 .map(|(k, v)| (k.to_string(), *v))
@@ -64,8 +128,35 @@ pub fn run(args: Args) -> Result<()> {
             "date_range": {
                 "oldest": oldest.map(|d| d.datetime().to_rfc3339()),
                 "newest": newest.map(|d| d.datetime().to_rfc3339()),
-            }
+            },
+            "stale_days": stale_days,
+            "stale_proposals": stale_proposals,
+            "stale_proposal_ids": stale_ids,
+            "oldest_proposal_days": oldest_proposal.map(|(_, days)| days),
+            "words": {
+                "total": total_words,
+                "average": average_words,
+                "reading_minutes": reading_minutes,
+            },
         });
+        if args.since.is_some() || args.until.is_some() {
+            stats["window"] = serde_json::json!({
+                "since": window.since.map(|d| d.to_rfc3339()),
+                "until": window.until.map(|d| d.to_rfc3339()),
+            });
+        }
+        if args.by_author {
+            stats["by_author"] = by_author
+                .iter()
+                .map(|(name, (authored, decided))| {
+                    (
+                        (*name).to_string(),
+                        serde_json::json!({ "authored": authored, "decided": decided }),
+                    )
+                })
+                .collect::<serde_json::Map<_, _>>()
+                .into();
+        }
         println!("{}", serde_json::to_string_pretty(&stats)?);
     } else {
         println!("{}", "ADR Statistics".bold().underline());
@@ -98,13 +189,47 @@ pub fn run(args: Args) -> Result<()> {
         if !by_tag.is_empty() {
             println!("{}", "Top Tags:".bold());
             let mut tags: Vec<_> = by_tag.into_iter().collect();
-            tags.sort_by(|a, b| b.1.cmp(&a.1));
+            tags.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
             for (tag, count) in tags.iter().take(10) {
                 println!("  {} {}", tag.cyan(), count);
             }
             println!();
         }
 
+        // Author breakdown, most active first
+        if args.by_author && !by_author.is_empty() {
+            println!("{}", "By Author:".bold());
+            let mut people: Vec<_> = by_author.iter().collect();
+            people.sort_by_key(|(_, (authored, decided))| std::cmp::Reverse(authored + decided));
+            for (name, (authored, decided)) in people {
+                println!(
+                    "  {} {} authored, {} decided",
+                    name.cyan(),
+                    authored,
+                    decided
+                );
+            }
+            println!();
+        }
+
+        // Length
+        if total > 0 {
+            println!("{}", "Length:".bold());
+            println!("  {:12} {}", "Total words", total_words.to_string().cyan());
+            println!(
+                "  {:12} {} per ADR",
+                "Average",
+                average_words.to_string().cyan()
+            );
+            println!(
+                "  {:12} ~{} min at {} words/min",
+                "Reading",
+                reading_minutes.to_string().cyan(),
+                WORDS_PER_MINUTE
+            );
+            println!();
+        }
+
         // Date range
         if let (Some(old), Some(new)) = (oldest, newest) {
             println!("{}", "Date Range:".bold());
@@ -115,6 +240,32 @@ pub fn run(args: Args) -> Result<()> {
                 new.datetime().format("%Y-%m-%d")
             );
         }
+
+        // Proposal aging
+        if let Some((adr, days)) = oldest_proposal {
+            println!();
+            println!("{}", "Proposal Aging:".bold());
+            println!(
+                "  {:12} {} days ({})",
+                "Oldest",
+                days.to_string().cyan(),
+                adr.id
+            );
+            let stale = stale_proposals.to_string();
+            println!(
+                "  {:12} {} over {} days",
+                "Stale",
+                if stale_proposals > 0 {
+                    stale.yellow()
+                } else {
+                    stale.cyan()
+                },
+                stale_days
+            );
+            if !stale_ids.is_empty() {
+                println!("  {:12} {}", "", stale_ids.join(", ").yellow());
+            }
+        }
     }
 
     Ok(())
@@ -17,8 +17,16 @@ pub struct Args {
     pub title: String,
 
     /// Template format for new ADR.
-    #[arg(long)]
+    #[arg(long, conflicts_with = "copy_body")]
     pub template: Option<String>,
+
+    /// Start the new ADR from the superseded ADR's body instead of a template.
+    #[arg(long)]
+    pub copy_body: bool,
+
+    /// Show the ADR that would be created and the changes to the old one, without writing.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Run the supersede command.
@@ -33,14 +41,9 @@ pub fn run(args: Args) -> Result<()> {
     let config = ConfigManager::new(git.clone()).load()?;
     let notes = NotesManager::new(git.clone(), config.clone());
 
-    // Find the ADR to supersede
-    let adrs = notes.list()?;
-    let mut old_adr = adrs
-        .into_iter()
-        .find(|a| a.id == args.adr_id || a.id.contains(&args.adr_id))
-        .ok_or_else(|| anyhow::anyhow!("ADR not found: {}", args.adr_id))?;
+    let old_adr = notes.resolve(&args.adr_id)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Superseding ADR {} with: {}",
         "→".blue(),
         old_adr.id.cyan(),
@@ -48,8 +51,7 @@ pub fn run(args: Args) -> Result<()> {
     );
 
     // Generate new ADR ID
-    let next_num = notes.next_number()?;
-    let new_adr_id = notes.format_id(next_num);
+    let new_adr_id = notes.new_id(&args.title)?;
 
     // Determine template format
     let format = args.template.as_deref().unwrap_or(&config.format);
@@ -67,27 +69,53 @@ pub fn run(args: Args) -> Result<()> {
         .tags
         .clone_from(&old_adr.frontmatter.tags);
 
-    // Add supersedes link
-    new_adr.frontmatter.supersedes = Some(old_adr.id.clone());
-
-    // Render template for body
-    let template_engine = TemplateEngine::new();
-    let mut context = std::collections::HashMap::new();
-    context.insert("title".to_string(), new_adr.frontmatter.title.clone());
-    context.insert("status".to_string(), new_adr.frontmatter.status.to_string());
-    let body = template_engine.render(format, &context)?;
-    new_adr.body = body;
-
-    // Save new ADR
-    notes.create(&new_adr)?;
-
-    // Update old ADR status to superseded
-    old_adr.frontmatter.status = AdrStatus::Superseded;
-    old_adr.frontmatter.superseded_by = Some(new_adr_id.clone());
-    notes.update(&old_adr)?;
-
-    eprintln!("{} Created new ADR: {}", "✓".green(), new_adr_id.cyan());
-    eprintln!(
+    // Seed the body from the old ADR, or render the template
+    new_adr.body = if args.copy_body {
+        format!(
+            "> Supersedes {}: {}\n\n{}",
+            old_adr.id,
+            old_adr.frontmatter.title,
+            old_adr.body.trim()
+        )
+    } else {
+        let template_engine = TemplateEngine::for_repo(&git, &config)?;
+        template_engine.render_with_adr(format, &new_adr)?
+    };
+
+    if args.dry_run {
+        eprintln!("{} Dry run: no changes written", "!".yellow());
+        eprintln!(
+            "  Would create {}: {} ({}) on commit {}",
+            new_adr_id.cyan(),
+            new_adr.frontmatter.title,
+            new_adr.frontmatter.status,
+            git.short_hash(&new_adr.commit)?
+        );
+        eprintln!(
+            "  Would change {} status: {} → {}",
+            old_adr.id.cyan(),
+            old_adr.frontmatter.status,
+            AdrStatus::Superseded
+        );
+        eprintln!(
+            "  Would link {} supersedes {}",
+            new_adr_id.cyan(),
+            old_adr.id.cyan()
+        );
+        if new_adr.commit == old_adr.commit {
+            tracing::warn!(
+                "{} is already on this commit; create a new commit first",
+                old_adr.id
+            );
+        }
+        return Ok(());
+    }
+
+    // Save new ADR and mark the old one as superseded
+    let (old_adr, _) = notes.supersede(&old_adr.id, &new_adr)?;
+
+    tracing::info!("{} Created new ADR: {}", "✓".green(), new_adr_id.cyan());
+    tracing::info!(
         "{} Updated {} status to superseded",
         "✓".green(),
         old_adr.id.cyan()
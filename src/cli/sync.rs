@@ -42,27 +42,28 @@ pub fn run(args: Args) -> Result<()> {
     let do_push = args.push || !args.pull;
     let do_fetch = args.pull || !args.push;
 
-    eprintln!("{} Syncing with remote: {}", "→".blue(), args.remote.cyan());
+    tracing::info!("{} Syncing with remote: {}", "→".blue(), args.remote.cyan());
 
     if do_fetch {
-        eprintln!("  Fetching notes...");
+        tracing::info!("  Fetching notes...");
         match notes.sync(&args.remote, false, true) {
-            Ok(()) => eprintln!("    {} Fetched ADR notes", "✓".green()),
+            Ok(()) => tracing::info!("    {} Fetched and merged ADR notes", "✓".green()),
             Err(e) => {
-                // Fetch failures are often non-fatal (remote might not have notes yet)
+                // Missing remote notes are skipped, so this is a merge failure or conflict
                 eprintln!(
-                    "    {} Could not fetch notes: {}",
-                    "!".yellow(),
+                    "    {} Could not merge notes: {}",
+                    "✗".red(),
                     e.to_string().lines().next().unwrap_or("unknown error")
                 );
+                return Err(e.into());
             },
         }
     }
 
     if do_push {
-        eprintln!("  Pushing notes...");
+        tracing::info!("  Pushing notes...");
         match notes.sync(&args.remote, true, false) {
-            Ok(()) => eprintln!("    {} Pushed ADR notes", "✓".green()),
+            Ok(()) => tracing::info!("    {} Pushed ADR notes", "✓".green()),
             Err(e) => {
                 // Push failures are more serious
                 eprintln!(
@@ -75,7 +76,7 @@ pub fn run(args: Args) -> Result<()> {
         }
     }
 
-    eprintln!("{} Sync complete", "✓".green());
+    tracing::info!("{} Sync complete", "✓".green());
 
     Ok(())
 }
@@ -0,0 +1,107 @@
+//! List, rename and merge tags across ADRs.
+
+use anyhow::Result;
+use clap::{Args as ClapArgs, Subcommand};
+use colored::Colorize;
+
+use crate::core::{ConfigManager, Git, IndexManager, NotesManager};
+
+/// Arguments for the tag command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// Tag subcommand.
+    #[command(subcommand)]
+    pub command: TagCommand,
+}
+
+/// Tag subcommands.
+#[derive(Subcommand, Debug)]
+pub enum TagCommand {
+    /// List every tag with how many ADRs use it, most used first.
+    List {
+        /// Output format (text, json).
+        #[arg(long, short, default_value = "text", value_parser = ["text", "json"])]
+        format: String,
+    },
+    /// Rename a tag on every ADR that has it.
+    Rename {
+        /// Tag to rename.
+        old: String,
+        /// New tag name.
+        new: String,
+    },
+    /// Replace several tags with one on every ADR that has them.
+    Merge {
+        /// Tags to merge.
+        #[arg(required = true)]
+        tags: Vec<String>,
+        /// Tag to merge them into.
+        #[arg(long)]
+        into: String,
+    },
+}
+
+/// Run the tag command.
+///
+/// # Errors
+///
+/// Returns an error if the tags cannot be rewritten.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git.clone(), config);
+
+    let (tags, into) = match args.command {
+        TagCommand::List { format } => return run_list(&notes, &format),
+        TagCommand::Rename { old, new } => (vec![old], new),
+        TagCommand::Merge { tags, into } => (tags, into),
+    };
+    let sources: Vec<&str> = tags.iter().map(String::as_str).collect();
+
+    let changed = notes.merge_tags(&sources, &into)?;
+    if changed == 0 {
+        tracing::warn!("No ADRs tagged {}", tags.join(", "));
+        return Ok(());
+    }
+
+    // Keep search results in step with the new tags
+    IndexManager::with_config(git, notes.config()).rebuild(&notes)?;
+
+    tracing::info!(
+        "{} Retagged {} ADR(s): {} → {}",
+        "✓".green(),
+        changed,
+        tags.join(", ").cyan(),
+        into.cyan()
+    );
+
+    Ok(())
+}
+
+/// Print each tag and its ADR count, most used first.
+fn run_list(notes: &NotesManager, format: &str) -> Result<()> {
+    let mut counts: Vec<_> = notes.tag_counts()?.into_iter().collect();
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    if format == "json" {
+        let output: Vec<_> = counts
+            .iter()
+            .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    if counts.is_empty() {
+        tracing::warn!("No tags found");
+        return Ok(());
+    }
+    let width = counts.iter().map(|(tag, _)| tag.len()).max().unwrap_or(0);
+    for (tag, count) in &counts {
+        println!("{}  {}", format!("{tag:width$}").cyan(), count);
+    }
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use colored::Colorize;
 use std::fs;
 use std::path::Path;
 
-use crate::core::Git;
+use crate::core::{ConfigManager, Git, TemplateEngine, BUILTIN_TEMPLATES};
 
 /// Arguments for the templates command.
 #[derive(ClapArgs, Debug)]
@@ -19,6 +19,13 @@ pub struct Args {
 /// Templates subcommands.
 #[derive(Subcommand, Debug)]
 pub enum TemplatesCommand {
+    /// List ADR templates available to `new --template`.
+    #[command(visible_alias = "adr-formats")]
+    List,
+
+    /// List the variables an ADR template uses.
+    Vars(VarsArgs),
+
     /// Generate a pull request template with ADR section.
     Pr(PrArgs),
 
@@ -32,6 +39,13 @@ pub enum TemplatesCommand {
     All(AllArgs),
 }
 
+/// Arguments for listing template variables.
+#[derive(ClapArgs, Debug)]
+pub struct VarsArgs {
+    /// ADR template name.
+    pub name: String,
+}
+
 /// Arguments for PR template generation.
 #[derive(ClapArgs, Debug)]
 pub struct PrArgs {
@@ -90,6 +104,8 @@ pub fn run(args: Args) -> Result<()> {
     git.check_repository()?;
 
     match args.command {
+        TemplatesCommand::List => run_list(&git),
+        TemplatesCommand::Vars(vars_args) => run_vars(&git, &vars_args),
         TemplatesCommand::Pr(pr_args) => run_pr(pr_args),
         TemplatesCommand::Issue(issue_args) => run_issue(issue_args),
         TemplatesCommand::Codeowners(codeowners_args) => run_codeowners(codeowners_args),
@@ -97,6 +113,68 @@ pub fn run(args: Args) -> Result<()> {
     }
 }
 
+/// List built-in and custom ADR templates.
+fn run_list(git: &Git) -> Result<()> {
+    let config = ConfigManager::new(git.clone()).load()?;
+    let engine = TemplateEngine::for_repo(git, &config)?;
+
+    let names: Vec<&str> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _, _)| *name)
+        .filter(|name| engine.is_builtin(name))
+        .chain(engine.custom_templates())
+        .collect();
+    let width = names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+    println!("{}", "ADR templates:".bold());
+    for name in names {
+        let source = if engine.is_builtin(name) {
+            "built-in".dimmed()
+        } else {
+            "custom  ".green()
+        };
+        let marker = if name == config.format { "*" } else { " " };
+        println!(
+            "{marker} {}  {}  {}",
+            format!("{name:<width$}").cyan(),
+            source,
+            engine.description(name).unwrap_or_default()
+        );
+    }
+
+    if let Some(dir) = &config.templates_dir {
+        tracing::info!("");
+        tracing::info!("Custom templates from: {}", dir.cyan());
+    }
+
+    Ok(())
+}
+
+/// List the variables an ADR template uses.
+fn run_vars(git: &Git, args: &VarsArgs) -> Result<()> {
+    let config = ConfigManager::new(git.clone()).load()?;
+    let engine = TemplateEngine::for_repo(git, &config)?;
+
+    if !engine.has_template(&args.name) {
+        anyhow::bail!(
+            "Unknown template: {}. Run 'git adr templates list' to see available templates.",
+            args.name
+        );
+    }
+
+    let variables = engine.template_variables(&args.name);
+    if variables.is_empty() {
+        tracing::warn!("Template {} uses no variables", args.name.cyan());
+        return Ok(());
+    }
+
+    for variable in variables {
+        println!("{variable}");
+    }
+
+    Ok(())
+}
+
 /// Generate PR template.
 fn run_pr(args: PrArgs) -> Result<()> {
     let output_path = Path::new(&args.output);
@@ -117,7 +195,7 @@ fn run_pr(args: PrArgs) -> Result<()> {
 
     fs::write(output_path, PR_TEMPLATE)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Generated PR template: {}",
         "✓".green(),
         output_path.display().to_string().cyan()
@@ -140,22 +218,18 @@ fn run_issue(args: IssueArgs) -> Result<()> {
         ("config.yml", ISSUE_CONFIG),
     ];
 
-    eprintln!("{} Generating issue templates...", "→".blue());
+    tracing::info!("{} Generating issue templates...", "→".blue());
 
     for (filename, content) in &templates {
         let file_path = output_dir.join(filename);
 
         if file_path.exists() && !args.force {
-            eprintln!(
-                "  {} {} already exists (use --force to overwrite)",
-                "!".yellow(),
-                filename
-            );
+            tracing::warn!("{} already exists (use --force to overwrite)", filename);
             continue;
         }
 
         fs::write(&file_path, *content)?;
-        eprintln!("  {} Generated {}", "✓".green(), filename.cyan());
+        tracing::info!("  {} Generated {}", "✓".green(), filename.cyan());
     }
 
     Ok(())
@@ -182,20 +256,20 @@ fn run_codeowners(args: CodeownersArgs) -> Result<()> {
     let content = CODEOWNERS_TEMPLATE.replace("{owner}", &args.owner);
     fs::write(output_path, content)?;
 
-    eprintln!(
+    tracing::info!(
         "{} Generated CODEOWNERS: {}",
         "✓".green(),
         output_path.display().to_string().cyan()
     );
-    eprintln!("  ADR owner set to: {}", args.owner.cyan());
+    tracing::info!("  ADR owner set to: {}", args.owner.cyan());
 
     Ok(())
 }
 
 /// Generate all templates.
 fn run_all(args: AllArgs) -> Result<()> {
-    eprintln!("{} Generating all ADR-related templates...", "→".blue());
-    eprintln!();
+    tracing::info!("{} Generating all ADR-related templates...", "→".blue());
+    tracing::info!("");
 
     // Generate PR template
     run_pr(PrArgs {
@@ -216,8 +290,8 @@ fn run_all(args: AllArgs) -> Result<()> {
         force: args.force,
     })?;
 
-    eprintln!();
-    eprintln!("{} All templates generated!", "✓".green());
+    tracing::info!("");
+    tracing::info!("{} All templates generated!", "✓".green());
 
     Ok(())
 }
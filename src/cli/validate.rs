@@ -0,0 +1,194 @@
+//! Validate ADRs.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+
+use crate::core::{ConfigManager, Git, NotesManager};
+
+/// Arguments for the validate command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// Output format (text, json).
+    #[arg(long, short, default_value = "text")]
+    pub format: String,
+
+    /// Also report ADRs that share a title (ignoring case and whitespace).
+    #[arg(long)]
+    pub duplicates: bool,
+
+    /// Also report numbers missing from the ADR ID sequence.
+    #[arg(long)]
+    pub gaps: bool,
+
+    /// Also report required sections (per the ADR's format) that are
+    /// missing or still hold the template's placeholder text.
+    #[arg(long)]
+    pub sections: bool,
+
+    /// Also report ADRs with an empty title or no date, notes that can't be
+    /// parsed (e.g. an unknown status), superseded ADRs without
+    /// `superseded_by`, and IDs used more than once.
+    #[arg(long)]
+    pub metadata: bool,
+
+    /// Run every check (duplicates, gaps, sections and metadata).
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// Run the validate command.
+///
+/// # Errors
+///
+/// Returns an error if validation cannot run or finds problems.
+#[allow(clippy::too_many_lines)]
+pub fn run(mut args: Args) -> Result<()> {
+    if args.all {
+        args.duplicates = true;
+        args.gaps = true;
+        args.sections = true;
+        args.metadata = true;
+    }
+
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git, config);
+
+    let issues = notes.validate_links()?;
+    let duplicates = if args.duplicates {
+        notes.find_duplicate_titles()?
+    } else {
+        Vec::new()
+    };
+    let gaps: Vec<String> = if args.gaps {
+        notes
+            .id_gaps()?
+            .into_iter()
+            .map(|n| notes.format_id(n))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let sections = if args.sections {
+        notes.validate_sections()?
+    } else {
+        Vec::new()
+    };
+    let metadata = if args.metadata {
+        notes.validate_metadata()?
+    } else {
+        Vec::new()
+    };
+    let valid = issues.is_empty()
+        && duplicates.is_empty()
+        && gaps.is_empty()
+        && sections.is_empty()
+        && metadata.is_empty();
+
+    if args.format == "json" {
+        let mut report = serde_json::json!({
+            "valid": valid,
+            "issues": issues,
+        });
+        if args.duplicates {
+            report["duplicates"] = duplicates
+                .iter()
+                .map(|(title, ids)| serde_json::json!({ "title": title, "ids": ids }))
+                .collect();
+        }
+        if args.gaps {
+            report["gaps"] = serde_json::json!(gaps);
+        }
+        if args.sections {
+            report["sections"] = serde_json::json!(sections);
+        }
+        if args.metadata {
+            report["metadata"] = serde_json::json!(metadata);
+        }
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for issue in &issues {
+            println!(
+                "{} {} [{}] {}",
+                "✗".red(),
+                issue.adr_id.cyan(),
+                issue.kind,
+                issue.message
+            );
+        }
+        for (title, ids) in &duplicates {
+            println!(
+                "{} {} [duplicate] share the title \"{}\"",
+                "✗".red(),
+                ids.join(", ").cyan(),
+                title
+            );
+        }
+        for id in &gaps {
+            println!(
+                "{} {} [gap] is missing from the ID sequence",
+                "✗".red(),
+                id.cyan()
+            );
+        }
+        for issue in &sections {
+            println!(
+                "{} {} [{}] {}",
+                "✗".red(),
+                issue.adr_id.cyan(),
+                issue.kind,
+                issue.message
+            );
+        }
+        for issue in &metadata {
+            println!(
+                "{} {} [{}] {}",
+                "✗".red(),
+                issue.adr_id.cyan(),
+                issue.kind,
+                issue.message
+            );
+        }
+    }
+
+    // The result is the command's output, so --quiet doesn't hide it
+    if valid {
+        if args.format != "json" {
+            println!("{} All ADR links are valid", "✓".green());
+            if args.duplicates {
+                println!("{} No duplicate titles", "✓".green());
+            }
+            if args.gaps {
+                println!("{} No gaps in ADR numbering", "✓".green());
+            }
+            if args.sections {
+                println!("{} All required sections are filled in", "✓".green());
+            }
+            if args.metadata {
+                println!("{} All ADR metadata is complete", "✓".green());
+            }
+        }
+        return Ok(());
+    }
+
+    let mut found = Vec::new();
+    if !issues.is_empty() {
+        found.push(format!("{} link issue(s)", issues.len()));
+    }
+    if !duplicates.is_empty() {
+        found.push(format!("{} duplicate title(s)", duplicates.len()));
+    }
+    if !gaps.is_empty() {
+        found.push(format!("{} numbering gap(s)", gaps.len()));
+    }
+    if !sections.is_empty() {
+        found.push(format!("{} incomplete section(s)", sections.len()));
+    }
+    if !metadata.is_empty() {
+        found.push(format!("{} metadata issue(s)", metadata.len()));
+    }
+    anyhow::bail!("{} found", found.join(", "))
+}
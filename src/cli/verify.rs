@@ -0,0 +1,38 @@
+//! Verify the GPG signature of an ADR.
+
+use anyhow::Result;
+use clap::Args as ClapArgs;
+use colored::Colorize;
+
+use crate::core::{ConfigManager, Git, NotesManager};
+
+/// Arguments for the verify command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    /// ADR ID to verify.
+    pub adr_id: String,
+}
+
+/// Run the verify command.
+///
+/// # Errors
+///
+/// Returns an error if the ADR is not found, unsigned, or its signature is bad.
+pub fn run(args: Args) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let notes = NotesManager::new(git, config);
+
+    let id = notes.resolve_id(&args.adr_id)?;
+    let signer = notes.verify(&id)?;
+    println!(
+        "{} Good signature on {} from {}",
+        "✓".green(),
+        id.cyan(),
+        signer
+    );
+
+    Ok(())
+}
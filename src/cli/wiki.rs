@@ -1,7 +1,13 @@
 //! Wiki synchronization CLI commands.
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Args as ClapArgs;
+use colored::Colorize;
+
+use crate::core::{AdrConfig, ConfigManager, Git, NotesManager};
+use crate::wiki::{
+    detect_platform, SyncMode, SyncOptions, SyncState, WikiConfig, WikiPlatform, WikiService,
+};
 
 /// Wiki synchronization commands.
 #[derive(ClapArgs, Debug)]
@@ -30,7 +36,7 @@ pub enum WikiCommands {
 /// Arguments for wiki push.
 #[derive(ClapArgs, Debug)]
 pub struct PushArgs {
-    /// Wiki provider (github, gitlab).
+    /// Wiki provider (github, gitlab, confluence).
     #[arg(long, short)]
     pub provider: Option<String>,
 
@@ -42,7 +48,7 @@ pub struct PushArgs {
     #[arg(long)]
     pub adr: Option<String>,
 
-    /// Force overwrite existing wiki pages.
+    /// Overwrite wiki pages even if they were edited since the last sync.
     #[arg(long, short)]
     pub force: bool,
 }
@@ -50,7 +56,7 @@ pub struct PushArgs {
 /// Arguments for wiki pull.
 #[derive(ClapArgs, Debug)]
 pub struct PullArgs {
-    /// Wiki provider (github, gitlab).
+    /// Wiki provider (github, gitlab, confluence).
     #[arg(long, short)]
     pub provider: Option<String>,
 
@@ -61,20 +67,20 @@ pub struct PullArgs {
     /// Only pull specific ADR.
     #[arg(long)]
     pub adr: Option<String>,
+
+    /// Overwrite ADRs even if they were edited since the last sync.
+    #[arg(long, short)]
+    pub force: bool,
 }
 
 /// Arguments for wiki status.
 #[derive(ClapArgs, Debug)]
-pub struct StatusArgs {
-    /// Show verbose status information.
-    #[arg(long, short)]
-    pub verbose: bool,
-}
+pub struct StatusArgs {}
 
 /// Arguments for wiki configuration.
 #[derive(ClapArgs, Debug)]
 pub struct ConfigArgs {
-    /// Wiki provider (github, gitlab).
+    /// Wiki provider (github, gitlab, confluence).
     #[arg(long, short)]
     pub provider: Option<String>,
 
@@ -102,48 +108,150 @@ pub fn run(args: Args) -> Result<()> {
 }
 
 fn run_push(args: PushArgs) -> Result<()> {
-    println!("Pushing ADRs to wiki");
-    if let Some(provider) = &args.provider {
-        println!("Provider: {}", provider);
-    }
-    if let Some(repo) = &args.repo {
-        println!("Repository: {}", repo);
-    }
-    if let Some(adr) = &args.adr {
-        println!("ADR: {}", adr);
-    }
-    if args.force {
-        println!("Force mode enabled");
-    }
-
-    // TODO: Implement wiki push
-    // let service = WikiService::from_config()?;
-    // service.push(args.adr.as_deref(), args.force)?;
-
-    println!("\n[Wiki push not yet implemented in Rust version]");
-    Ok(())
+    run_sync(
+        SyncMode::Push,
+        args.provider.as_deref(),
+        args.repo.as_deref(),
+        args.adr.as_deref(),
+        args.force,
+    )
 }
 
 fn run_pull(args: PullArgs) -> Result<()> {
-    println!("Pulling ADRs from wiki");
-    if let Some(provider) = &args.provider {
-        println!("Provider: {}", provider);
+    run_sync(
+        SyncMode::Pull,
+        args.provider.as_deref(),
+        args.repo.as_deref(),
+        args.adr.as_deref(),
+        args.force,
+    )
+}
+
+/// Sync one ADR, or all of them, with the wiki.
+fn run_sync(
+    mode: SyncMode,
+    provider: Option<&str>,
+    repo: Option<&str>,
+    adr: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let git = Git::new();
+    git.check_repository()?;
+
+    let config = ConfigManager::new(git.clone()).load()?;
+    let wiki_config = wiki_config(&git, &config, provider, repo)?;
+    let notes = NotesManager::new(git.clone(), config);
+
+    let adrs = match adr {
+        Some(id) => vec![notes.resolve(id)?],
+        None => notes.list()?,
+    };
+
+    let verb = if mode == SyncMode::Push {
+        "Pushing"
+    } else {
+        "Pulling"
+    };
+    let target = match wiki_config.platform {
+        WikiPlatform::Confluence => wiki_config.space_key.clone().unwrap_or_default(),
+        _ => wiki_config.repository.clone(),
+    };
+    tracing::info!(
+        "{} {verb} {} ADR(s) with the {} wiki ({})",
+        "→".blue(),
+        adrs.len(),
+        wiki_config.platform,
+        target.cyan()
+    );
+
+    let state = SyncState::new(git, notes.config());
+    let result =
+        WikiService::new(wiki_config).sync_with(&state, &adrs, SyncOptions { mode, force })?;
+    for updated in &result.updated {
+        notes.update(updated)?;
     }
-    if let Some(repo) = &args.repo {
-        println!("Repository: {}", repo);
+
+    tracing::info!(
+        "{} {} pushed, {} pulled, {} unchanged",
+        "✓".green(),
+        result.pushed,
+        result.pulled,
+        result.unchanged
+    );
+    for conflict in &result.conflicts {
+        tracing::warn!("{conflict}");
     }
-    if let Some(adr) = &args.adr {
-        println!("ADR: {}", adr);
+    for error in &result.errors {
+        tracing::error!("{error}");
     }
 
-    // TODO: Implement wiki pull
-    // let service = WikiService::from_config()?;
-    // service.pull(args.adr.as_deref())?;
-
-    println!("\n[Wiki pull not yet implemented in Rust version]");
+    if !result.conflicts.is_empty() {
+        bail!(
+            "{} ADR(s) skipped because of conflicts; use --force to overwrite",
+            result.conflicts.len()
+        );
+    }
+    if !result.errors.is_empty() {
+        bail!("{} ADR(s) failed to sync", result.errors.len());
+    }
     Ok(())
 }
 
+/// Wiki configuration from the command line and `adr.wiki.*` settings.
+///
+/// The platform and repository fall back to those of the wiki URL or the
+/// default remote.
+fn wiki_config(
+    git: &Git,
+    config: &AdrConfig,
+    provider: Option<&str>,
+    repo: Option<&str>,
+) -> Result<WikiConfig> {
+    let remote_url = match git.default_remote()? {
+        Some(remote) => git.config_get(&format!("remote.{remote}.url"))?,
+        None => None,
+    };
+    let remote_url = remote_url.as_deref();
+
+    let provider = provider.or_else(|| {
+        config
+            .wiki_platform
+            .as_deref()
+            .filter(|platform| *platform != "auto")
+    });
+    let platform = match (provider, config.wiki_url.as_deref().or(remote_url)) {
+        (Some(provider), _) => provider.parse::<WikiPlatform>()?,
+        (None, Some(url)) => detect_platform(url)?,
+        (None, None) => bail!("No git remote to detect the wiki from; pass --provider and --repo"),
+    };
+    let repository = match (
+        repo.or(config.wiki_repository.as_deref()),
+        remote_url.and_then(repository_path),
+    ) {
+        (Some(repo), _) => repo.to_string(),
+        (None, Some(path)) => path,
+        (None, None) if platform == WikiPlatform::Confluence => String::new(),
+        (None, None) => bail!("Could not determine the wiki repository; pass --repo"),
+    };
+
+    let mut wiki_config = WikiConfig::new(platform, repository);
+    wiki_config.base_url.clone_from(&config.wiki_url);
+    wiki_config.space_key.clone_from(&config.wiki_space_key);
+    wiki_config.username.clone_from(&config.wiki_username);
+    Ok(wiki_config)
+}
+
+/// The `owner/repo` path of a remote URL, in either URL or scp-like form.
+fn repository_path(url: &str) -> Option<String> {
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?.1,
+        None => url.split_once(':')?.1,
+    };
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    path.contains('/').then(|| path.to_string())
+}
+
 fn run_status(_args: StatusArgs) -> Result<()> {
     println!("Wiki synchronization status");
 
@@ -3,10 +3,13 @@
 //! This module defines the core ADR structure that represents an
 //! Architecture Decision Record with its metadata and content.
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Days, Months, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 
+/// Reading speed used for reading-time estimates, in words per minute.
+pub const WORDS_PER_MINUTE: usize = 200;
+
 /// Flexible date type that accepts both full datetime and date-only formats.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FlexibleDate(pub DateTime<Utc>);
@@ -61,8 +64,97 @@ impl<'de> Deserialize<'de> for FlexibleDate {
     }
 }
 
+/// Parse a `--since`/`--until` style date filter.
+///
+/// Accepts RFC3339, `YYYY-MM-DD`, `today`, `yesterday`, or an age such as
+/// `7d`, `2w`, `3m` or `1y` counted back from `now`. Calendar dates and the
+/// keywords resolve to midnight UTC.
+///
+/// # Errors
+///
+/// Returns an error if the input is none of these.
+pub fn parse_date_filter(s: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, crate::Error> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+
+    let today = now.date_naive().and_time(NaiveTime::MIN).and_utc();
+    let resolved = match s.to_ascii_lowercase().as_str() {
+        "today" => Some(today),
+        "yesterday" => today.checked_sub_days(Days::new(1)),
+        age => date_ago(age, now),
+    };
+    resolved.ok_or_else(|| crate::Error::ParseError {
+        message: format!(
+            "Invalid date format: {s}. Use YYYY-MM-DD, RFC3339, today, yesterday \
+             or an age like 7d, 2w, 3m or 1y."
+        ),
+    })
+}
+
+/// Inclusive date bounds from `--since`/`--until` filters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateWindow {
+    /// Earliest date kept.
+    pub since: Option<DateTime<Utc>>,
+    /// Latest date kept.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl DateWindow {
+    /// Parse the bounds, each in any form [`parse_date_filter`] accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either bound is not a valid date filter.
+    pub fn parse(since: Option<&str>, until: Option<&str>) -> Result<Self, crate::Error> {
+        let now = Utc::now();
+        Ok(Self {
+            since: since.map(|s| parse_date_filter(s, now)).transpose()?,
+            until: until.map(|s| parse_date_filter(s, now)).transpose()?,
+        })
+    }
+
+    /// Whether an ADR is dated within the window. Undated ADRs always are.
+    #[must_use]
+    pub fn contains(&self, adr: &Adr) -> bool {
+        adr.frontmatter.date.as_ref().is_none_or(|d| {
+            self.since.is_none_or(|since| d.datetime() >= since)
+                && self.until.is_none_or(|until| d.datetime() <= until)
+        })
+    }
+
+    /// Keep only the ADRs dated within the window.
+    pub fn retain(&self, adrs: &mut Vec<Adr>) {
+        adrs.retain(|adr| self.contains(adr));
+    }
+}
+
+/// The time `age` (a count followed by `d`, `w`, `m` or `y`) before `now`.
+fn date_ago(age: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let unit = age.chars().last()?;
+    let digits = &age[..age.len() - unit.len_utf8()];
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let count: u32 = digits.parse().ok()?;
+    match unit {
+        'd' => now.checked_sub_days(Days::new(count.into())),
+        'w' => now.checked_sub_days(Days::new(u64::from(count) * 7)),
+        'm' => now.checked_sub_months(Months::new(count)),
+        'y' => now.checked_sub_months(Months::new(count.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
 /// Status of an ADR.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+///
+/// Statuses order by lifecycle stage, the same order as [`AdrStatus::ALL`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum AdrStatus {
     /// ADR is proposed but not yet accepted.
@@ -76,6 +168,50 @@ pub enum AdrStatus {
     Superseded,
     /// ADR has been rejected.
     Rejected,
+    /// ADR has been archived (soft-deleted) and is hidden by default.
+    Archived,
+}
+
+impl AdrStatus {
+    /// All statuses, in lifecycle order.
+    pub const ALL: [Self; 6] = [
+        Self::Proposed,
+        Self::Accepted,
+        Self::Deprecated,
+        Self::Superseded,
+        Self::Rejected,
+        Self::Archived,
+    ];
+
+    /// Check whether moving from this status to `next` is an allowed transition.
+    ///
+    /// The lifecycle is: a proposal is accepted or rejected (or replaced
+    /// outright), an accepted decision is later deprecated or superseded,
+    /// and a rejected proposal may be reopened. Staying in the same status
+    /// is always allowed. Archiving and restoring are not transitions; they
+    /// go through `rm --archive` and `restore`.
+    #[must_use]
+    pub fn can_transition_to(&self, next: &Self) -> bool {
+        self == next
+            || matches!(
+                (self, next),
+                (
+                    Self::Proposed,
+                    Self::Accepted | Self::Rejected | Self::Superseded
+                ) | (Self::Accepted | Self::Deprecated, Self::Superseded)
+                    | (Self::Accepted, Self::Deprecated)
+                    | (Self::Rejected, Self::Proposed)
+            )
+    }
+
+    /// Statuses this status may move to, excluding itself.
+    #[must_use]
+    pub fn allowed_transitions(&self) -> Vec<Self> {
+        Self::ALL
+            .into_iter()
+            .filter(|next| next != self && self.can_transition_to(next))
+            .collect()
+    }
 }
 
 impl std::fmt::Display for AdrStatus {
@@ -86,6 +222,7 @@ impl std::fmt::Display for AdrStatus {
             Self::Deprecated => write!(f, "deprecated"),
             Self::Superseded => write!(f, "superseded"),
             Self::Rejected => write!(f, "rejected"),
+            Self::Archived => write!(f, "archived"),
         }
     }
 }
@@ -100,6 +237,7 @@ impl std::str::FromStr for AdrStatus {
             "deprecated" => Ok(Self::Deprecated),
             "superseded" => Ok(Self::Superseded),
             "rejected" => Ok(Self::Rejected),
+            "archived" => Ok(Self::Archived),
             _ => Err(crate::Error::InvalidStatus {
                 status: s.to_string(),
                 valid: vec![
@@ -108,6 +246,7 @@ impl std::str::FromStr for AdrStatus {
                     "deprecated".to_string(),
                     "superseded".to_string(),
                     "rejected".to_string(),
+                    "archived".to_string(),
                 ],
             }),
         }
@@ -193,6 +332,13 @@ pub struct Adr {
     pub frontmatter: AdrFrontmatter,
     /// Markdown body content.
     pub body: String,
+    /// When the note was last written, from the notes ref history.
+    ///
+    /// Only populated by [`NotesManager::load_history`](crate::core::NotesManager::load_history)
+    /// and [`NotesManager::list_with_history`](crate::core::NotesManager::list_with_history).
+    pub last_modified: Option<DateTime<Utc>>,
+    /// Who last wrote the note, as `Name <email>`.
+    pub note_author: Option<String>,
 }
 
 impl Adr {
@@ -208,6 +354,8 @@ impl Adr {
                 ..Default::default()
             },
             body: String::new(),
+            last_modified: None,
+            note_author: None,
         }
     }
 
@@ -223,11 +371,15 @@ impl Adr {
             commit,
             frontmatter,
             body,
+            last_modified: None,
+            note_author: None,
         })
     }
 
     /// Parse YAML frontmatter from markdown content.
     fn parse_frontmatter(content: &str) -> Result<(AdrFrontmatter, String), crate::Error> {
+        // Normalize Windows line endings so the closing marker is found
+        let content = content.replace("\r\n", "\n");
         let content = content.trim();
 
         if !content.starts_with("---") {
@@ -277,6 +429,19 @@ impl Adr {
         &self.frontmatter.title
     }
 
+    /// The title as lowercase words, ignoring punctuation and spacing, for
+    /// spotting ADRs that record the same decision.
+    #[must_use]
+    pub fn normalized_title(&self) -> String {
+        self.frontmatter
+            .title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Get the ADR status.
     #[must_use]
     pub const fn status(&self) -> &AdrStatus {
@@ -291,6 +456,96 @@ impl Adr {
             .iter()
             .any(|t| t.eq_ignore_ascii_case(tag))
     }
+
+    /// Whole days this ADR has been proposed as of `now`.
+    ///
+    /// `None` unless the ADR is proposed and dated.
+    #[must_use]
+    pub fn proposal_age_days(&self, now: DateTime<Utc>) -> Option<i64> {
+        if self.frontmatter.status != AdrStatus::Proposed {
+            return None;
+        }
+        let date = self.frontmatter.date.as_ref()?;
+        Some((now - date.datetime()).num_days())
+    }
+
+    /// Number of words in the body.
+    ///
+    /// Markdown markup such as heading markers, list bullets and table rules
+    /// isn't counted, nor are the numbers of an ordered list.
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        let is_ordered_marker = |word: &str| {
+            word.strip_suffix(['.', ')'])
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        };
+        self.body
+            .lines()
+            .map(|line| {
+                let mut words = line.split_whitespace().peekable();
+                words.next_if(|word| is_ordered_marker(word));
+                words
+                    .filter(|word| word.chars().any(char::is_alphanumeric))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Estimated minutes to read the body at [`WORDS_PER_MINUTE`], rounded up.
+    #[must_use]
+    pub fn reading_minutes(&self) -> usize {
+        self.word_count().div_ceil(WORDS_PER_MINUTE)
+    }
+}
+
+/// JSON representation of an ADR, as written by `export --format json`.
+///
+/// Frontmatter fields, custom ones included, sit next to `commit` and
+/// `body` at the top level, so `import` reads the ADR back unchanged.
+#[derive(Debug, Serialize)]
+pub struct AdrJson<'a> {
+    id: &'a str,
+    commit: &'a str,
+    title: &'a str,
+    status: String,
+    date: Option<String>,
+    tags: &'a [String],
+    authors: &'a [String],
+    deciders: &'a [String],
+    links: &'a [AdrLink],
+    format: Option<&'a str>,
+    supersedes: Option<&'a str>,
+    superseded_by: Option<&'a str>,
+    body: &'a str,
+    #[serde(flatten)]
+    custom: &'a HashMap<String, serde_yaml::Value>,
+}
+
+impl<'a> AdrJson<'a> {
+    /// Borrow an ADR's fields for serialization.
+    #[must_use]
+    pub fn from_adr(adr: &'a Adr) -> Self {
+        Self {
+            id: &adr.id,
+            commit: &adr.commit,
+            title: &adr.frontmatter.title,
+            status: adr.frontmatter.status.to_string(),
+            date: adr
+                .frontmatter
+                .date
+                .as_ref()
+                .map(|d| d.datetime().to_rfc3339()),
+            tags: &adr.frontmatter.tags,
+            authors: &adr.frontmatter.authors,
+            deciders: &adr.frontmatter.deciders,
+            links: &adr.frontmatter.links,
+            format: adr.frontmatter.format.as_deref(),
+            supersedes: adr.frontmatter.supersedes.as_deref(),
+            superseded_by: adr.frontmatter.superseded_by.as_deref(),
+            body: &adr.body,
+            custom: &adr.frontmatter.custom,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -298,6 +553,66 @@ mod tests {
     use super::*;
     use chrono::Datelike;
 
+    #[test]
+    fn test_parse_date_filter() {
+        let now = DateTime::parse_from_rfc3339("2025-03-31T15:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let parse = |s: &str| parse_date_filter(s, now).map(|dt| dt.to_rfc3339());
+
+        assert_eq!(parse("2025-01-15").unwrap(), "2025-01-15T00:00:00+00:00");
+        assert_eq!(
+            parse("2025-01-15T08:00:00Z").unwrap(),
+            "2025-01-15T08:00:00+00:00"
+        );
+        assert_eq!(parse("today").unwrap(), "2025-03-31T00:00:00+00:00");
+        assert_eq!(parse("Yesterday").unwrap(), "2025-03-30T00:00:00+00:00");
+        assert_eq!(parse("7d").unwrap(), "2025-03-24T15:30:00+00:00");
+        assert_eq!(parse("2w").unwrap(), "2025-03-17T15:30:00+00:00");
+        // Month arithmetic clamps to the end of shorter months
+        assert_eq!(parse("1m").unwrap(), "2025-02-28T15:30:00+00:00");
+        assert_eq!(parse("1y").unwrap(), "2024-03-31T15:30:00+00:00");
+
+        for invalid in ["", "d", "7", "7x", "+7d", "-1d", "last week", "2025/01/15"] {
+            let err = parse(invalid).unwrap_err();
+            assert!(err.to_string().contains("Invalid date format"), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_date_window() {
+        let dated = |id: &str, date: &str| {
+            let mut adr = Adr::new(id.to_string(), id.to_string());
+            adr.frontmatter.date = Some(FlexibleDate(
+                DateTime::parse_from_rfc3339(date)
+                    .unwrap()
+                    .with_timezone(&Utc),
+            ));
+            adr
+        };
+        let mut undated = Adr::new("undated".to_string(), "Undated".to_string());
+        undated.frontmatter.date = None;
+        let all = vec![
+            dated("early", "2024-01-01T00:00:00Z"),
+            dated("middle", "2024-06-15T00:00:00Z"),
+            dated("late", "2024-12-31T00:00:00Z"),
+            undated,
+        ];
+        let ids = |adrs: &[Adr]| adrs.iter().map(|adr| adr.id.clone()).collect::<Vec<_>>();
+
+        let mut adrs = all.clone();
+        DateWindow::parse(Some("2024-06-15"), Some("2024-12-01"))
+            .unwrap()
+            .retain(&mut adrs);
+        assert_eq!(ids(&adrs), ["middle", "undated"]);
+
+        let mut adrs = all;
+        DateWindow::default().retain(&mut adrs);
+        assert_eq!(adrs.len(), 4);
+
+        assert!(DateWindow::parse(Some("soon"), None).is_err());
+    }
+
     #[test]
     fn test_status_display() {
         assert_eq!(AdrStatus::Proposed.to_string(), "proposed");
@@ -305,6 +620,7 @@ mod tests {
         assert_eq!(AdrStatus::Deprecated.to_string(), "deprecated");
         assert_eq!(AdrStatus::Superseded.to_string(), "superseded");
         assert_eq!(AdrStatus::Rejected.to_string(), "rejected");
+        assert_eq!(AdrStatus::Archived.to_string(), "archived");
     }
 
     #[test]
@@ -329,6 +645,10 @@ mod tests {
             "rejected".parse::<AdrStatus>().unwrap(),
             AdrStatus::Rejected
         );
+        assert_eq!(
+            "Archived".parse::<AdrStatus>().unwrap(),
+            AdrStatus::Archived
+        );
     }
 
     #[test]
@@ -443,6 +763,15 @@ No closing marker
         assert!(adr.has_tag("Architecture"));
     }
 
+    #[test]
+    fn test_adr_normalized_title() {
+        let adr = Adr::new(
+            "ADR-0001".to_string(),
+            "  Use  PostgreSQL, not MySQL! ".to_string(),
+        );
+        assert_eq!(adr.normalized_title(), "use postgresql not mysql");
+    }
+
     #[test]
     fn test_flexible_date_from_datetime() {
         let now = Utc::now();
@@ -555,6 +884,17 @@ Body content
         assert!(adr.frontmatter.custom.contains_key("custom_field"));
     }
 
+    #[test]
+    fn test_from_markdown_crlf() {
+        let content = "---\r\nid: ADR-0001\r\ntitle: Windows ADR\r\nstatus: accepted\r\ntags:\r\n  - windows\r\n---\r\n\r\n# Windows ADR\r\n\r\nSaved with CRLF.\r\n";
+        let adr = Adr::from_markdown("ADR-0001".to_string(), "abc123".to_string(), content)
+            .expect("Should parse CRLF content");
+        assert_eq!(adr.frontmatter.title, "Windows ADR");
+        assert_eq!(adr.frontmatter.status, AdrStatus::Accepted);
+        assert_eq!(adr.frontmatter.tags, vec!["windows"]);
+        assert_eq!(adr.body, "# Windows ADR\n\nSaved with CRLF.");
+    }
+
     #[test]
     fn test_status_hash() {
         use std::collections::HashSet;
@@ -565,4 +905,108 @@ Body content
         set.insert(AdrStatus::Proposed);
         assert_eq!(set.len(), 2); // Same status, no increase
     }
+
+    #[test]
+    fn test_status_allowed_transitions() {
+        assert_eq!(
+            AdrStatus::Proposed.allowed_transitions(),
+            vec![
+                AdrStatus::Accepted,
+                AdrStatus::Superseded,
+                AdrStatus::Rejected
+            ]
+        );
+        assert_eq!(
+            AdrStatus::Rejected.allowed_transitions(),
+            vec![AdrStatus::Proposed]
+        );
+        assert!(AdrStatus::Superseded.allowed_transitions().is_empty());
+    }
+
+    #[test]
+    fn test_proposal_age_days() {
+        let now = Utc::now();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Test".to_string());
+        adr.frontmatter.date = Some(FlexibleDate(now - chrono::Duration::days(100)));
+        assert_eq!(adr.proposal_age_days(now), Some(100));
+
+        adr.frontmatter.status = AdrStatus::Accepted;
+        assert_eq!(adr.proposal_age_days(now), None);
+
+        adr.frontmatter.status = AdrStatus::Proposed;
+        adr.frontmatter.date = None;
+        assert_eq!(adr.proposal_age_days(now), None);
+    }
+
+    #[test]
+    fn test_word_count() {
+        let mut adr = Adr::new("ADR-0001".to_string(), "Test".to_string());
+        assert_eq!(adr.word_count(), 0);
+        assert_eq!(adr.reading_minutes(), 0);
+
+        adr.body = "## Context\n\n\
+            We need a *fast* database.\n\n\
+            - Use PostgreSQL\n\
+            * not MySQL\n\
+            1. Migrate in Q3\n\
+            > Quoted - text\n\n\
+            | a | b |\n\
+            |---|---|\n\
+            ---\n"
+            .to_string();
+        // Context, We need a fast database, Use PostgreSQL, not MySQL,
+        // Migrate in Q3, Quoted text, a b
+        assert_eq!(adr.word_count(), 17);
+        assert_eq!(adr.reading_minutes(), 1);
+
+        adr.body = "word ".repeat(401);
+        assert_eq!(adr.reading_minutes(), 3);
+    }
+
+    #[test]
+    fn test_status_transition_matrix() {
+        use AdrStatus::{Accepted, Archived, Deprecated, Proposed, Rejected, Superseded};
+
+        // (from, to, allowed)
+        let cases = [
+            (Proposed, Proposed, true),
+            (Proposed, Accepted, true),
+            (Proposed, Deprecated, false),
+            (Proposed, Superseded, true),
+            (Proposed, Rejected, true),
+            (Accepted, Proposed, false),
+            (Accepted, Accepted, true),
+            (Accepted, Deprecated, true),
+            (Accepted, Superseded, true),
+            (Accepted, Rejected, false),
+            (Deprecated, Proposed, false),
+            (Deprecated, Accepted, false),
+            (Deprecated, Deprecated, true),
+            (Deprecated, Superseded, true),
+            (Deprecated, Rejected, false),
+            (Superseded, Proposed, false),
+            (Superseded, Accepted, false),
+            (Superseded, Deprecated, false),
+            (Superseded, Superseded, true),
+            (Superseded, Rejected, false),
+            (Rejected, Proposed, true),
+            (Rejected, Accepted, false),
+            (Rejected, Deprecated, false),
+            (Rejected, Superseded, false),
+            (Rejected, Rejected, true),
+            (Accepted, Archived, false),
+            (Archived, Proposed, false),
+            (Archived, Accepted, false),
+            (Archived, Archived, true),
+        ];
+
+        for (from, to, allowed) in cases {
+            assert_eq!(
+                from.can_transition_to(&to),
+                allowed,
+                "{from} -> {to} should be {}",
+                if allowed { "allowed" } else { "rejected" }
+            );
+        }
+    }
 }
@@ -0,0 +1,228 @@
+//! Artifacts attached to ADRs.
+//!
+//! An ADR's artifacts are stored as one JSON note on its commit in the
+//! artifacts notes ref: a list of records, each with the file content
+//! base64-encoded alongside a SHA-256 checksum used to detect corruption.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+use crate::Error;
+
+/// A file attached to an ADR.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Artifact {
+    /// File name the artifact was attached as.
+    pub filename: String,
+    /// Size of the decoded content in bytes.
+    pub size: u64,
+    /// ID of the ADR the artifact belongs to.
+    pub adr_id: String,
+    /// Description/alt text.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Hex-encoded SHA-256 of the decoded content.
+    ///
+    /// `None` for artifacts attached before checksums were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Base64-encoded content.
+    pub content: String,
+}
+
+impl Artifact {
+    /// Create an artifact from raw file content, recording its checksum.
+    #[must_use]
+    pub fn new(filename: String, adr_id: String, description: Option<String>, data: &[u8]) -> Self {
+        Self {
+            filename,
+            size: data.len() as u64,
+            adr_id,
+            description,
+            sha256: Some(sha256_hex(data)),
+            content: BASE64.encode(data),
+        }
+    }
+
+    /// Parse an artifact from its JSON note.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the note is not a valid artifact record.
+    pub fn from_json(content: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    /// Serialize the artifact as a JSON note.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse the artifacts stored in an artifact note.
+    ///
+    /// Notes written before an ADR could carry several artifacts hold a
+    /// single record rather than a list; they parse as a list of one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the note is neither an artifact record nor a
+    /// list of them.
+    pub fn list_from_json(content: &str) -> Result<Vec<Self>, Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Stored {
+            List(Vec<Artifact>),
+            Single(Artifact),
+        }
+
+        Ok(match serde_json::from_str(content)? {
+            Stored::List(artifacts) => artifacts,
+            Stored::Single(artifact) => vec![artifact],
+        })
+    }
+
+    /// Serialize `artifacts` as an artifact note.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn list_to_json(artifacts: &[Self]) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(artifacts)?)
+    }
+
+    /// Decode the artifact content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid base64.
+    pub fn decode(&self) -> Result<Vec<u8>, Error> {
+        BASE64.decode(&self.content).map_err(|e| Error::ParseError {
+            message: format!("invalid artifact content for {}: {e}", self.filename),
+        })
+    }
+
+    /// Recompute the checksum and size of the stored content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content cannot be decoded.
+    pub fn verify(&self) -> Result<ArtifactCheck, Error> {
+        let data = self.decode()?;
+        let actual = sha256_hex(&data);
+
+        Ok(ArtifactCheck {
+            checksum_ok: self.sha256.as_ref().map(|expected| *expected == actual),
+            size_ok: data.len() as u64 == self.size,
+            actual_sha256: actual,
+            actual_size: data.len() as u64,
+        })
+    }
+}
+
+/// Result of re-hashing an artifact's content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactCheck {
+    /// Whether the checksum matches, or `None` if none was recorded.
+    pub checksum_ok: Option<bool>,
+    /// Whether the decoded size matches the recorded size.
+    pub size_ok: bool,
+    /// Checksum of the stored content.
+    pub actual_sha256: String,
+    /// Size of the stored content in bytes.
+    pub actual_size: u64,
+}
+
+impl ArtifactCheck {
+    /// Whether the artifact is intact (a missing checksum is not a failure).
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.size_ok && self.checksum_ok != Some(false)
+    }
+}
+
+/// Hex-encoded SHA-256 of `data`.
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_records_checksum() {
+        let artifact = Artifact::new(
+            "hello.txt".to_string(),
+            "ADR-0001".to_string(),
+            None,
+            b"hello",
+        );
+        assert_eq!(artifact.size, 5);
+        assert_eq!(
+            artifact.sha256.as_deref(),
+            Some("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")
+        );
+        assert_eq!(artifact.decode().expect("Should decode"), b"hello");
+    }
+
+    #[test]
+    fn test_verify_intact() {
+        let artifact = Artifact::new("a.bin".to_string(), "ADR-0001".to_string(), None, b"data");
+        let check = artifact.verify().expect("Should verify");
+        assert!(check.is_ok());
+        assert_eq!(check.checksum_ok, Some(true));
+    }
+
+    #[test]
+    fn test_verify_corrupted() {
+        let mut artifact =
+            Artifact::new("a.bin".to_string(), "ADR-0001".to_string(), None, b"data");
+        artifact.content = BASE64.encode(b"dat4");
+        let check = artifact.verify().expect("Should verify");
+        assert!(!check.is_ok());
+        assert_eq!(check.checksum_ok, Some(false));
+        assert!(check.size_ok);
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        let artifacts = vec![
+            Artifact::new("a.txt".to_string(), "ADR-0001".to_string(), None, b"a"),
+            Artifact::new("b.txt".to_string(), "ADR-0001".to_string(), None, b"b"),
+        ];
+        let json = Artifact::list_to_json(&artifacts).expect("Should serialize");
+        let parsed = Artifact::list_from_json(&json).expect("Should parse");
+        let names: Vec<&str> = parsed.iter().map(|a| a.filename.as_str()).collect();
+        assert_eq!(names, ["a.txt", "b.txt"]);
+    }
+
+    #[test]
+    fn test_list_from_single_artifact_note() {
+        let single = Artifact::new("a.txt".to_string(), "ADR-0001".to_string(), None, b"a");
+        let json = single.to_json().expect("Should serialize");
+        let parsed = Artifact::list_from_json(&json).expect("Should parse");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].filename, "a.txt");
+    }
+
+    #[test]
+    fn test_legacy_artifact_without_checksum() {
+        let json = r#"{"filename":"old.txt","size":3,"adr_id":"ADR-0001","description":null,"content":"b2xk"}"#;
+        let artifact = Artifact::from_json(json).expect("Should parse");
+        assert!(artifact.sha256.is_none());
+        let check = artifact.verify().expect("Should verify");
+        assert_eq!(check.checksum_ok, None);
+        assert!(check.is_ok());
+    }
+}
@@ -1,9 +1,65 @@
 //! Configuration management for git-adr.
 //!
-//! This module handles loading and saving configuration from git config.
+//! This module handles loading and saving configuration from git config
+//! and the project's `.adr.toml`.
 
-use crate::core::Git;
+use crate::core::{Git, TemplateEngine};
 use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Default `adr.maxArtifactSize`: 5 MiB.
+const DEFAULT_MAX_ARTIFACT_SIZE: usize = 5 * 1024 * 1024;
+
+/// Default `adr.maxBodySize`: 10 MiB.
+const DEFAULT_MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+
+/// Default `adr.staleDays`.
+const DEFAULT_STALE_DAYS: u32 = 90;
+
+/// Project config file at the repository root, shared through version control.
+pub const PROJECT_CONFIG_FILE: &str = ".adr.toml";
+
+/// Comment written at the top of a generated `.adr.toml`.
+const PROJECT_CONFIG_HEADER: &str =
+    "# Shared git-adr settings. Local git config (git adr config set) overrides them.";
+
+/// How IDs are generated for new ADRs (`adr.idScheme`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdScheme {
+    /// Prefix plus a zero-padded number, e.g. `ADR-0001`.
+    #[default]
+    Numeric,
+    /// `adr-` plus the kebab-cased title, e.g. `adr-use-postgresql`.
+    Slug,
+    /// Creation date plus the kebab-cased title, e.g. `20251215-use-postgresql`.
+    DateSlug,
+}
+
+impl std::fmt::Display for IdScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Numeric => write!(f, "numeric"),
+            Self::Slug => write!(f, "slug"),
+            Self::DateSlug => write!(f, "date-slug"),
+        }
+    }
+}
+
+impl std::str::FromStr for IdScheme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "slug" => Ok(Self::Slug),
+            "date-slug" => Ok(Self::DateSlug),
+            _ => Err(Error::validation(format!(
+                "idScheme must be numeric, slug or date-slug, got '{s}'"
+            ))),
+        }
+    }
+}
 
 /// Configuration for git-adr.
 #[derive(Debug, Clone)]
@@ -18,6 +74,43 @@ pub struct AdrConfig {
     pub format: String,
     /// Whether the repository is initialized for ADRs.
     pub initialized: bool,
+    /// Pager command for long output (overrides `$PAGER`).
+    pub pager: Option<String>,
+    /// Git notes namespace; ADRs live in `refs/notes/<namespace>`.
+    pub namespace: String,
+    /// Whether to GPG-sign ADR notes when writing them.
+    pub sign: bool,
+    /// Directory of custom ADR templates, relative to the repository root.
+    pub templates_dir: Option<String>,
+    /// Whether read commands fetch notes from the default remote first.
+    pub auto_fetch: bool,
+    /// Editor for ADR content (after `$GIT_EDITOR`, before `core.editor`).
+    pub editor: Option<String>,
+    /// Deciders recorded on new ADRs when none are given.
+    pub default_deciders: Vec<String>,
+    /// Globs of architecture paths; commits touching them must reference
+    /// an ADR when the `adr-guard` hook is installed.
+    pub architecture_paths: Vec<String>,
+    /// Largest artifact `attach` accepts, in bytes.
+    pub max_artifact_size: usize,
+    /// Largest ADR body `new` and `edit` accept, in bytes.
+    pub max_body_size: usize,
+    /// How IDs are generated for new ADRs.
+    pub id_scheme: IdScheme,
+    /// Days after which a proposed ADR counts as stale.
+    pub stale_days: u32,
+    /// Wiki platform (`github`, `gitlab` or `confluence`); detected from the
+    /// default remote when unset.
+    pub wiki_platform: Option<String>,
+    /// Wiki repository (`owner/repo` or GitLab project path); taken from the
+    /// default remote when unset.
+    pub wiki_repository: Option<String>,
+    /// Wiki base URL: a self-hosted GitHub or GitLab, or the Confluence site.
+    pub wiki_url: Option<String>,
+    /// Confluence space key.
+    pub wiki_space_key: Option<String>,
+    /// Confluence username.
+    pub wiki_username: Option<String>,
 }
 
 impl Default for AdrConfig {
@@ -28,10 +121,157 @@ impl Default for AdrConfig {
             template: "default".to_string(),
             format: "nygard".to_string(),
             initialized: false,
+            pager: None,
+            namespace: "adr".to_string(),
+            sign: false,
+            templates_dir: None,
+            auto_fetch: false,
+            editor: None,
+            default_deciders: Vec::new(),
+            architecture_paths: Vec::new(),
+            max_artifact_size: DEFAULT_MAX_ARTIFACT_SIZE,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            id_scheme: IdScheme::Numeric,
+            stale_days: DEFAULT_STALE_DAYS,
+            wiki_platform: None,
+            wiki_repository: None,
+            wiki_url: None,
+            wiki_space_key: None,
+            wiki_username: None,
         }
     }
 }
 
+impl AdrConfig {
+    /// Notes ref holding ADR content.
+    #[must_use]
+    pub fn notes_ref(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Notes ref holding the search index.
+    #[must_use]
+    pub fn index_ref(&self) -> String {
+        format!("{}-index", self.namespace)
+    }
+
+    /// Notes ref holding detached signatures of ADR notes.
+    #[must_use]
+    pub fn signatures_ref(&self) -> String {
+        format!("{}-sig", self.namespace)
+    }
+
+    /// Notes ref holding attached artifacts.
+    #[must_use]
+    pub fn artifacts_ref(&self) -> String {
+        format!("{}-artifacts", self.namespace)
+    }
+
+    /// Notes ref holding the content hash of each ADR's last wiki sync.
+    #[must_use]
+    pub fn wiki_ref(&self) -> String {
+        format!("{}-wiki", self.namespace)
+    }
+
+    /// Notes ref holding archived ADRs.
+    #[must_use]
+    pub fn archive_ref(&self) -> String {
+        format!("{}-archive", self.namespace)
+    }
+
+    /// Check an artifact's size against `adr.maxArtifactSize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ContentTooLarge`] if `size` exceeds the limit.
+    pub const fn check_artifact_size(&self, size: usize) -> Result<(), Error> {
+        check_size(size, self.max_artifact_size)
+    }
+
+    /// Check an ADR body's size against `adr.maxBodySize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ContentTooLarge`] if `body` exceeds the limit.
+    pub const fn check_body_size(&self, body: &str) -> Result<(), Error> {
+        check_size(body.len(), self.max_body_size)
+    }
+}
+
+/// Settings shared through the project's `.adr.toml`.
+///
+/// Fields left out of the file fall through to git config and defaults.
+/// Keys use the same names as their `adr.*` git config counterparts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Prefix for ADR IDs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Number of digits in ADR IDs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digits: Option<u8>,
+    /// Default template name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Default ADR format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Git notes namespace for ADRs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Directory of custom ADR templates, relative to the repository root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_dir: Option<String>,
+}
+
+impl ProjectConfig {
+    /// Config keys the file can set.
+    const KEYS: &[&str] = &[
+        "prefix",
+        "digits",
+        "template",
+        "format",
+        "namespace",
+        "templatesDir",
+    ];
+
+    /// The shared settings of `config`.
+    #[must_use]
+    pub fn from_config(config: &AdrConfig) -> Self {
+        Self {
+            prefix: Some(config.prefix.clone()),
+            digits: Some(config.digits),
+            template: Some(config.template.clone()),
+            format: Some(config.format.clone()),
+            namespace: Some(config.namespace.clone()),
+            templates_dir: config.templates_dir.clone(),
+        }
+    }
+
+    /// The value the file sets for config `key`, formatted as in git config.
+    fn value(&self, key: &str) -> Option<String> {
+        match key {
+            "prefix" => self.prefix.clone(),
+            "digits" => self.digits.map(|digits| digits.to_string()),
+            "template" => self.template.clone(),
+            "format" => self.format.clone(),
+            "namespace" => self.namespace.clone(),
+            "templatesDir" => self.templates_dir.clone(),
+            _ => None,
+        }
+    }
+}
+
+/// Config keys that hold a list, stored as a multi-valued git config key.
+///
+/// Setting one of these splits the value on commas, so
+/// `default-deciders "Alice,Bob"` stores two values.
+const LIST_KEYS: &[&str] = &["default-deciders", "architecturePaths"];
+
+/// Largest accepted `adr.digits`.
+const MAX_DIGITS: u8 = 10;
+
 /// Manager for ADR configuration.
 #[derive(Debug)]
 pub struct ConfigManager {
@@ -52,6 +292,7 @@ impl ConfigManager {
     /// Returns an error if configuration cannot be loaded.
     pub fn load(&self) -> Result<AdrConfig, Error> {
         let mut config = AdrConfig::default();
+        let project = self.project_config()?;
 
         // Check if initialized
         if let Some(val) = self.git.config_get("adr.initialized")? {
@@ -59,47 +300,289 @@ impl ConfigManager {
         }
 
         // Load prefix
-        if let Some(val) = self.git.config_get("adr.prefix")? {
+        if let Some(val) = self.shared_value("prefix", &project)? {
             config.prefix = val;
         }
 
         // Load digits
-        if let Some(val) = self.git.config_get("adr.digits")? {
+        if let Some(val) = self.shared_value("digits", &project)? {
             if let Ok(digits) = val.parse::<u8>() {
                 config.digits = digits;
             }
         }
 
         // Load template
-        if let Some(val) = self.git.config_get("adr.template")? {
+        if let Some(val) = self.shared_value("template", &project)? {
             config.template = val;
         }
 
         // Load format
-        if let Some(val) = self.git.config_get("adr.format")? {
+        if let Some(val) = self.shared_value("format", &project)? {
             config.format = val;
         }
 
+        // Load pager
+        config.pager = self.git.config_get("adr.pager")?;
+
+        // Load signing
+        if let Some(val) = self.git.config_get("adr.sign")? {
+            config.sign = val == "true";
+        }
+
+        // Load editor
+        config.editor = self
+            .git
+            .config_get("adr.editor")?
+            .filter(|val| !val.is_empty());
+
+        // Load default deciders
+        config.default_deciders = self
+            .git
+            .config_get_all("adr.default-deciders")?
+            .iter()
+            .flat_map(|val| split_list(val))
+            .collect();
+
+        // Load architecture paths
+        config.architecture_paths = self
+            .git
+            .config_get_all("adr.architecturePaths")?
+            .iter()
+            .flat_map(|val| split_list(val))
+            .collect();
+
+        // Load auto-fetch
+        if let Some(val) = self.git.config_get("adr.autoFetch")? {
+            config.auto_fetch = val == "true";
+        }
+
+        // Load custom templates directory
+        config.templates_dir = self
+            .shared_value("templatesDir", &project)?
+            .filter(|val| !val.is_empty());
+
+        // Load size limits
+        if let Some(val) = self.git.config_get("adr.maxArtifactSize")? {
+            if let Ok(size) = val.parse() {
+                config.max_artifact_size = size;
+            }
+        }
+        if let Some(val) = self.git.config_get("adr.maxBodySize")? {
+            if let Ok(size) = val.parse() {
+                config.max_body_size = size;
+            }
+        }
+
+        if let Some(val) = self.git.config_get("adr.staleDays")? {
+            if let Ok(days) = val.parse() {
+                config.stale_days = days;
+            }
+        }
+
+        // Load ID scheme
+        if let Some(val) = self.git.config_get("adr.idScheme")? {
+            if let Ok(scheme) = val.parse() {
+                config.id_scheme = scheme;
+            }
+        }
+
+        // Load wiki settings
+        config.wiki_platform = self.git_value("adr.wiki.platform")?;
+        config.wiki_repository = self.git_value("adr.wiki.repository")?;
+        config.wiki_url = self.git_value("adr.wiki.url")?;
+        config.wiki_space_key = self.git_value("adr.wiki.spaceKey")?;
+        config.wiki_username = self.git_value("adr.wiki.username")?;
+
+        // Load namespace
+        if let Some(val) = self.shared_value("namespace", &project)? {
+            if !val.is_empty() {
+                config.namespace = val;
+            }
+        }
+
+        self.check_project_config(&project, &config)?;
+
         Ok(config)
     }
 
+    /// Check the values `.adr.toml` sets as `set` would check them.
+    fn check_project_config(
+        &self,
+        project: &ProjectConfig,
+        config: &AdrConfig,
+    ) -> Result<(), Error> {
+        for key in ProjectConfig::KEYS {
+            let Some(value) = project.value(key) else {
+                continue;
+            };
+            let checked = if *key == "format" {
+                self.check_format(config, &value)
+            } else {
+                self.validate(key, &value)
+            };
+            checked.map_err(|e| Error::Config {
+                message: format!(
+                    "invalid {PROJECT_CONFIG_FILE}: {}",
+                    match e {
+                        Error::Validation { message } => message,
+                        other => other.to_string(),
+                    }
+                ),
+            })?;
+        }
+        Ok(())
+    }
+
     /// Save configuration to git config.
     ///
     /// # Errors
     ///
     /// Returns an error if configuration cannot be saved.
+    ///
+    /// Settings the project's `.adr.toml` already provides are not copied
+    /// into git config, so later changes to the file take effect.
     pub fn save(&self, config: &AdrConfig) -> Result<(), Error> {
+        let project = self.project_config()?;
         self.git
             .config_set("adr.initialized", &config.initialized.to_string())?;
-        self.git.config_set("adr.prefix", &config.prefix)?;
-        self.git
-            .config_set("adr.digits", &config.digits.to_string())?;
-        self.git.config_set("adr.template", &config.template)?;
-        self.git.config_set("adr.format", &config.format)?;
+        self.save_shared("prefix", &config.prefix, &project)?;
+        self.save_shared("digits", &config.digits.to_string(), &project)?;
+        self.save_shared("template", &config.template, &project)?;
+        self.save_shared("format", &config.format, &project)?;
+        self.save_shared("namespace", &config.namespace, &project)?;
+        if config.sign {
+            self.git.config_set("adr.sign", "true")?;
+        }
+        if config.auto_fetch {
+            self.git.config_set("adr.autoFetch", "true")?;
+        }
+        if let Some(editor) = &config.editor {
+            self.git.config_set("adr.editor", editor)?;
+        }
+        if !config.default_deciders.is_empty() {
+            self.set("default-deciders", &config.default_deciders.join(","))?;
+        }
+        if !config.architecture_paths.is_empty() {
+            self.set("architecturePaths", &config.architecture_paths.join(","))?;
+        }
+        if let Some(pager) = &config.pager {
+            self.git.config_set("adr.pager", pager)?;
+        }
+        if let Some(dir) = &config.templates_dir {
+            self.save_shared("templatesDir", dir, &project)?;
+        }
+        if config.max_artifact_size != DEFAULT_MAX_ARTIFACT_SIZE {
+            self.git
+                .config_set("adr.maxArtifactSize", &config.max_artifact_size.to_string())?;
+        }
+        if config.max_body_size != DEFAULT_MAX_BODY_SIZE {
+            self.git
+                .config_set("adr.maxBodySize", &config.max_body_size.to_string())?;
+        }
+        if config.id_scheme != IdScheme::Numeric {
+            self.git
+                .config_set("adr.idScheme", &config.id_scheme.to_string())?;
+        }
+        if config.stale_days != DEFAULT_STALE_DAYS {
+            self.git
+                .config_set("adr.staleDays", &config.stale_days.to_string())?;
+        }
+        for (key, value) in [
+            ("adr.wiki.platform", &config.wiki_platform),
+            ("adr.wiki.repository", &config.wiki_repository),
+            ("adr.wiki.url", &config.wiki_url),
+            ("adr.wiki.spaceKey", &config.wiki_space_key),
+            ("adr.wiki.username", &config.wiki_username),
+        ] {
+            if let Some(value) = value {
+                self.git.config_set(key, value)?;
+            }
+        }
 
         Ok(())
     }
 
+    /// Get a git config value, treating an empty one as unset.
+    fn git_value(&self, key: &str) -> Result<Option<String>, Error> {
+        Ok(self.git.config_get(key)?.filter(|val| !val.is_empty()))
+    }
+
+    /// Save a setting `.adr.toml` can share, unless the file already sets it.
+    fn save_shared(&self, key: &str, value: &str, project: &ProjectConfig) -> Result<(), Error> {
+        let full_key = format!("adr.{key}");
+        if project.value(key).as_deref() == Some(value) {
+            // A local copy would shadow later changes to the project file
+            self.git.config_unset(&full_key, true)
+        } else {
+            self.git.config_set(&full_key, value)
+        }
+    }
+
+    /// Get a setting `.adr.toml` can share.
+    ///
+    /// Repository-local git config wins over the project file, which in
+    /// turn wins over global and system git config.
+    fn shared_value(&self, key: &str, project: &ProjectConfig) -> Result<Option<String>, Error> {
+        let full_key = format!("adr.{key}");
+        match project.value(key) {
+            Some(value) if self.project_overrides(&full_key)? => Ok(Some(value)),
+            _ => self.git.config_get(&full_key),
+        }
+    }
+
+    /// Whether a value in `.adr.toml` takes precedence over git config `key`.
+    fn project_overrides(&self, full_key: &str) -> Result<bool, Error> {
+        Ok(matches!(
+            self.git.config_scope(full_key)?.as_deref(),
+            None | Some("global" | "system")
+        ))
+    }
+
+    /// Path of the project's `.adr.toml`, or `None` outside a work tree.
+    #[must_use]
+    pub fn project_config_path(&self) -> Option<PathBuf> {
+        self.git
+            .repo_root()
+            .ok()
+            .map(|root| root.join(PROJECT_CONFIG_FILE))
+    }
+
+    /// Read the project's `.adr.toml`; empty if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the file is not valid, or an error if it
+    /// cannot be read.
+    pub fn project_config(&self) -> Result<ProjectConfig, Error> {
+        let Some(path) = self.project_config_path() else {
+            return Ok(ProjectConfig::default());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content).map_err(|e| Error::Config {
+                message: format!("invalid {}: {e}", path.display()),
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ProjectConfig::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the shared settings of `config` to the project's `.adr.toml`.
+    ///
+    /// Returns the path written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not in a work tree or the file cannot be written.
+    pub fn write_project_config(&self, config: &AdrConfig) -> Result<PathBuf, Error> {
+        let path = self.git.repo_root()?.join(PROJECT_CONFIG_FILE);
+        let body =
+            toml::to_string(&ProjectConfig::from_config(config)).map_err(|e| Error::Config {
+                message: format!("failed to serialize {PROJECT_CONFIG_FILE}: {e}"),
+            })?;
+        std::fs::write(&path, format!("{PROJECT_CONFIG_HEADER}\n{body}"))?;
+        Ok(path)
+    }
+
     /// Initialize ADR in the repository.
     ///
     /// # Errors
@@ -114,38 +597,233 @@ impl ConfigManager {
         init_config.initialized = true;
         self.save(&init_config)?;
 
+        // Create the notes ref so the repository reads as initialized
+        self.git.notes_init(init_config.notes_ref())?;
+
         Ok(())
     }
 
     /// Check if ADR is initialized in this repository.
     ///
+    /// Both `adr.initialized` and the ADR notes ref must be present; config
+    /// without the ref (e.g. after the ref was deleted) needs repair.
+    ///
     /// # Errors
     ///
     /// Returns an error if the check fails.
     pub fn is_initialized(&self) -> Result<bool, Error> {
         let config = self.load()?;
-        Ok(config.initialized)
+        Ok(config.initialized
+            && self
+                .git
+                .ref_exists(&format!("refs/notes/{}", config.notes_ref()))?)
+    }
+
+    /// Every configuration value in effect, with where it comes from.
+    ///
+    /// Returns `(key, value, source)` for each [`AdrConfig`] field, where
+    /// `source` is `.adr.toml` for values taken from the project file,
+    /// `default` for values nothing sets, and otherwise the git config
+    /// scope (`local`, `global`, `system`, ...). Unset optional values are
+    /// empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration cannot be loaded.
+    pub fn effective(&self) -> Result<Vec<(&'static str, String, String)>, Error> {
+        let config = self.load()?;
+        let project = self.project_config()?;
+        let values = [
+            ("initialized", config.initialized.to_string()),
+            ("prefix", config.prefix.clone()),
+            ("digits", config.digits.to_string()),
+            ("template", config.template.clone()),
+            ("format", config.format.clone()),
+            ("namespace", config.namespace.clone()),
+            ("idScheme", config.id_scheme.to_string()),
+            ("sign", config.sign.to_string()),
+            ("autoFetch", config.auto_fetch.to_string()),
+            (
+                "templatesDir",
+                config.templates_dir.clone().unwrap_or_default(),
+            ),
+            ("editor", config.editor.clone().unwrap_or_default()),
+            ("pager", config.pager.clone().unwrap_or_default()),
+            ("default-deciders", config.default_deciders.join(",")),
+            ("architecturePaths", config.architecture_paths.join(",")),
+            ("maxArtifactSize", config.max_artifact_size.to_string()),
+            ("maxBodySize", config.max_body_size.to_string()),
+            ("staleDays", config.stale_days.to_string()),
+            (
+                "wiki.platform",
+                config.wiki_platform.clone().unwrap_or_default(),
+            ),
+            (
+                "wiki.repository",
+                config.wiki_repository.clone().unwrap_or_default(),
+            ),
+            ("wiki.url", config.wiki_url.clone().unwrap_or_default()),
+            (
+                "wiki.spaceKey",
+                config.wiki_space_key.clone().unwrap_or_default(),
+            ),
+            (
+                "wiki.username",
+                config.wiki_username.clone().unwrap_or_default(),
+            ),
+        ];
+
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                let full_key = format!("adr.{key}");
+                let from_project =
+                    project.value(key).is_some() && self.project_overrides(&full_key)?;
+                let source = if from_project {
+                    PROJECT_CONFIG_FILE.to_string()
+                } else {
+                    self.git
+                        .config_scope(&full_key)?
+                        .unwrap_or_else(|| "default".to_string())
+                };
+                Ok((key, value, source))
+            })
+            .collect()
     }
 
     /// Get a specific config value.
     ///
+    /// List keys return all their values joined with commas.
+    ///
     /// # Errors
     ///
     /// Returns an error if the value cannot be retrieved.
     pub fn get(&self, key: &str) -> Result<Option<String>, Error> {
-        self.git.config_get(&format!("adr.{key}"))
+        let full_key = format!("adr.{key}");
+        if LIST_KEYS.contains(&key) {
+            let values = self.git.config_get_all(&full_key)?;
+            return Ok((!values.is_empty()).then(|| values.join(",")));
+        }
+        self.git.config_get(&full_key)
     }
 
-    /// Set a specific config value.
+    /// Set a specific config value after validating it.
+    ///
+    /// List keys are split on commas and replace any existing values.
     ///
     /// # Errors
     ///
-    /// Returns an error if the value cannot be set.
+    /// Returns [`Error::Validation`] if the key is unknown or the value is
+    /// invalid for it, or an error if the value cannot be set.
     pub fn set(&self, key: &str, value: &str) -> Result<(), Error> {
-        self.git.config_set(&format!("adr.{key}"), value)
+        self.validate(key, value)?;
+        self.set_raw(key, value)
+    }
+
+    /// Set a config value without validating the key or value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be set.
+    pub fn set_raw(&self, key: &str, value: &str) -> Result<(), Error> {
+        let full_key = format!("adr.{key}");
+        if LIST_KEYS.contains(&key) {
+            self.git.config_unset(&full_key, true)?;
+            for item in split_list(value) {
+                self.git.config_add(&full_key, &item)?;
+            }
+            return Ok(());
+        }
+        self.git.config_set(&full_key, value)
+    }
+
+    /// Check that `value` is acceptable for the known config `key`.
+    fn validate(&self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "digits" => match value.parse::<u8>() {
+                Ok(1..=MAX_DIGITS) => Ok(()),
+                _ => Err(Error::validation(format!(
+                    "digits must be a number from 1 to {MAX_DIGITS}, got '{value}'"
+                ))),
+            },
+            "prefix" if value.trim().is_empty() => {
+                Err(Error::validation("prefix must not be empty"))
+            },
+            "format" => self.check_format(&self.load()?, value),
+            "maxArtifactSize" | "maxBodySize" => match value.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(()),
+                _ => Err(Error::validation(format!(
+                    "{key} must be a positive number of bytes, got '{value}'"
+                ))),
+            },
+            "staleDays" => match value.parse::<u32>() {
+                Ok(days) if days > 0 => Ok(()),
+                _ => Err(Error::validation(format!(
+                    "staleDays must be a positive number of days, got '{value}'"
+                ))),
+            },
+            "idScheme" => value.parse::<IdScheme>().map(|_| ()),
+            "wiki.platform" => match value {
+                "github" | "gitlab" | "confluence" | "auto" => Ok(()),
+                _ => Err(Error::validation(format!(
+                    "wiki.platform must be github, gitlab, confluence or auto, got '{value}'"
+                ))),
+            },
+            "initialized" | "sign" | "autoFetch" => match value {
+                "true" | "false" => Ok(()),
+                _ => Err(Error::validation(format!(
+                    "{key} must be 'true' or 'false', got '{value}'"
+                ))),
+            },
+            "prefix" | "template" | "namespace" | "pager" | "templatesDir" | "editor"
+            | "default-deciders" | "architecturePaths" | "wiki.repository" | "wiki.url"
+            | "wiki.spaceKey" | "wiki.username" => Ok(()),
+            _ => Err(Error::validation(format!(
+                "unknown config key '{key}' (use --raw to set it anyway)"
+            ))),
+        }
+    }
+
+    /// Check that `format` names a template available with `config`.
+    fn check_format(&self, config: &AdrConfig, format: &str) -> Result<(), Error> {
+        // Built-in formats don't need templatesDir to exist
+        if TemplateEngine::new().has_template(format) {
+            return Ok(());
+        }
+        let engine = TemplateEngine::for_repo(&self.git, config)?;
+        if engine.has_template(format) {
+            Ok(())
+        } else {
+            let mut names = engine.list_templates();
+            names.sort_unstable();
+            Err(Error::validation(format!(
+                "unknown format '{format}' (available: {})",
+                names.join(", ")
+            )))
+        }
+    }
+}
+
+/// Error if `size` exceeds `max`.
+const fn check_size(size: usize, max: usize) -> Result<(), Error> {
+    if size > max {
+        Err(Error::ContentTooLarge { size, max })
+    } else {
+        Ok(())
     }
 }
 
+/// Split a comma-separated list, trimming items and dropping empty ones.
+#[must_use]
+pub fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,6 +877,23 @@ mod tests {
         assert!(!config.initialized);
     }
 
+    #[test]
+    fn test_namespace_refs() {
+        let config = AdrConfig::default();
+        assert_eq!(config.notes_ref(), "adr");
+        assert_eq!(config.index_ref(), "adr-index");
+        assert_eq!(config.artifacts_ref(), "adr-artifacts");
+        assert_eq!(config.signatures_ref(), "adr-sig");
+
+        let config = AdrConfig {
+            namespace: "decisions".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.notes_ref(), "decisions");
+        assert_eq!(config.index_ref(), "decisions-index");
+        assert_eq!(config.artifacts_ref(), "decisions-artifacts");
+    }
+
     #[test]
     fn test_custom_config() {
         let config = AdrConfig {
@@ -207,6 +902,7 @@ mod tests {
             template: "madr".to_string(),
             format: "madr".to_string(),
             initialized: true,
+            ..Default::default()
         };
         assert_eq!(config.prefix, "DECISION-");
         assert_eq!(config.digits, 3);
@@ -241,6 +937,7 @@ mod tests {
             template: "madr".to_string(),
             format: "madr".to_string(),
             initialized: false,
+            ..Default::default()
         };
 
         let result = manager.initialize(&config);
@@ -265,6 +962,7 @@ mod tests {
             template: "nygard".to_string(),
             format: "nygard".to_string(),
             initialized: true,
+            ..Default::default()
         };
 
         manager.save(&config).expect("Should save config");
@@ -292,6 +990,30 @@ mod tests {
         assert!(manager.is_initialized().expect("Should check"));
     }
 
+    #[test]
+    fn test_is_initialized_requires_notes_ref() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git.clone());
+
+        manager
+            .initialize(&AdrConfig::default())
+            .expect("Should initialize");
+        assert!(git.ref_exists("refs/notes/adr").expect("Should check ref"));
+
+        // Config left behind without the notes ref
+        git.run_silent(&["update-ref", "-d", "refs/notes/adr"])
+            .expect("Should delete ref");
+        assert!(manager.load().expect("Should load").initialized);
+        assert!(!manager.is_initialized().expect("Should check"));
+
+        // Initializing again repairs the ref without touching existing notes
+        manager
+            .initialize(&AdrConfig::default())
+            .expect("Should initialize");
+        assert!(manager.is_initialized().expect("Should check"));
+    }
+
     #[test]
     fn test_config_get_set() {
         let temp_dir = setup_git_repo();
@@ -299,7 +1021,7 @@ mod tests {
         let manager = ConfigManager::new(git);
 
         // Set a custom value
-        manager.set("custom", "value").expect("Should set");
+        manager.set_raw("custom", "value").expect("Should set");
 
         // Get the value
         let result = manager.get("custom").expect("Should get");
@@ -309,4 +1031,334 @@ mod tests {
         let result = manager.get("nonexistent").expect("Should get");
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_editor_and_default_deciders_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git.clone());
+
+        manager
+            .set("default-deciders", "Alice, Bob")
+            .expect("Should set deciders");
+        manager.set("editor", "vim -n").expect("Should set editor");
+
+        // Stored as a multi-valued key
+        assert_eq!(
+            git.config_get_all("adr.default-deciders")
+                .expect("Should get all"),
+            vec!["Alice", "Bob"]
+        );
+        assert_eq!(
+            manager.get("default-deciders").expect("Should get"),
+            Some("Alice,Bob".to_string())
+        );
+
+        let loaded = manager.load().expect("Should load config");
+        assert_eq!(loaded.default_deciders, vec!["Alice", "Bob"]);
+        assert_eq!(loaded.editor.as_deref(), Some("vim -n"));
+
+        // Saving replaces rather than appends
+        let config = AdrConfig {
+            default_deciders: vec!["Carol".to_string()],
+            ..loaded
+        };
+        manager.save(&config).expect("Should save config");
+        let loaded = manager.load().expect("Should load config");
+        assert_eq!(loaded.default_deciders, vec!["Carol"]);
+    }
+
+    #[test]
+    fn test_set_validates_known_keys() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        for (key, value) in [
+            ("digits", "abc"),
+            ("digits", "0"),
+            ("digits", "300"),
+            ("prefix", ""),
+            ("prefix", "  "),
+            ("format", "no-such-format"),
+            ("initialized", "yes"),
+            ("sign", "1"),
+            ("unknownkey", "value"),
+        ] {
+            let result = manager.set(key, value);
+            assert!(
+                matches!(result, Err(Error::Validation { .. })),
+                "{key}={value:?} should be rejected"
+            );
+            assert_eq!(manager.get(key).expect("Should get"), None);
+        }
+
+        manager.set("digits", "3").expect("Should set digits");
+        manager.set("prefix", "DEC-").expect("Should set prefix");
+        manager.set("format", "madr").expect("Should set format");
+        manager
+            .set("initialized", "true")
+            .expect("Should set initialized");
+
+        let config = manager.load().expect("Should load config");
+        assert_eq!(config.digits, 3);
+        assert_eq!(config.prefix, "DEC-");
+        assert_eq!(config.format, "madr");
+        assert!(config.initialized);
+    }
+
+    #[test]
+    fn test_effective_reports_sources() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        manager.set("prefix", "DEC-").expect("Should set prefix");
+        manager
+            .set("default-deciders", "Alice,Bob")
+            .expect("Should set deciders");
+
+        let effective = manager.effective().expect("Should list config");
+        let find = |key: &str| {
+            effective
+                .iter()
+                .find(|(k, _, _)| *k == key)
+                .map(|(_, value, source)| (value.as_str(), source.as_str()))
+        };
+        assert_eq!(find("prefix"), Some(("DEC-", "local")));
+        assert_eq!(find("default-deciders"), Some(("Alice,Bob", "local")));
+        assert_eq!(find("digits"), Some(("4", "default")));
+        assert_eq!(find("editor"), Some(("", "default")));
+        assert_eq!(effective.len(), 22);
+    }
+
+    #[test]
+    fn test_project_config_precedence() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        std::fs::write(
+            temp_dir.path().join(PROJECT_CONFIG_FILE),
+            "prefix = \"DEC-\"\ndigits = 3\nformat = \"madr\"\ntemplatesDir = \"adr-templates\"\n",
+        )
+        .expect("Failed to write project config");
+
+        let config = manager.load().expect("Should load config");
+        assert_eq!(config.prefix, "DEC-");
+        assert_eq!(config.digits, 3);
+        assert_eq!(config.format, "madr");
+        assert_eq!(config.templates_dir.as_deref(), Some("adr-templates"));
+        assert_eq!(config.template, "default");
+
+        // Local git config wins over the project file
+        manager.set("prefix", "LOC-").expect("Should set prefix");
+        let config = manager.load().expect("Should load config");
+        assert_eq!(config.prefix, "LOC-");
+        assert_eq!(config.digits, 3);
+
+        let effective = manager.effective().expect("Should list config");
+        let source = |key: &str| {
+            effective
+                .iter()
+                .find(|(k, _, _)| *k == key)
+                .map(|(_, _, source)| source.clone())
+        };
+        assert_eq!(source("prefix").as_deref(), Some("local"));
+        assert_eq!(source("digits").as_deref(), Some(PROJECT_CONFIG_FILE));
+        assert_eq!(source("template").as_deref(), Some("default"));
+    }
+
+    #[test]
+    fn test_save_leaves_project_settings_to_file() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        let config = AdrConfig {
+            prefix: "DEC-".to_string(),
+            ..AdrConfig::default()
+        };
+        let path = manager
+            .write_project_config(&config)
+            .expect("Should write project config");
+        assert_eq!(
+            manager
+                .project_config()
+                .expect("Should read project config"),
+            ProjectConfig::from_config(&config)
+        );
+        assert!(std::fs::read_to_string(path)
+            .expect("Should read file")
+            .contains("prefix = \"DEC-\""));
+
+        manager.set("prefix", "OLD-").expect("Should set prefix");
+        manager.save(&config).expect("Should save config");
+        assert_eq!(manager.get("prefix").expect("Should get"), None);
+        assert_eq!(manager.get("digits").expect("Should get"), None);
+        assert_eq!(manager.load().expect("Should load").prefix, "DEC-");
+
+        // Settings that differ from the file are still saved locally
+        let local = AdrConfig {
+            digits: 5,
+            ..config
+        };
+        manager.save(&local).expect("Should save config");
+        assert_eq!(
+            manager.get("digits").expect("Should get"),
+            Some("5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_project_config() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        std::fs::write(
+            temp_dir.path().join(PROJECT_CONFIG_FILE),
+            "prefx = \"DEC-\"\n",
+        )
+        .expect("Failed to write project config");
+        assert!(matches!(manager.load(), Err(Error::Config { .. })));
+
+        // Values get the same checks as `set`
+        for content in ["digits = 0\n", "prefix = \" \"\n", "format = \"rfc\"\n"] {
+            std::fs::write(temp_dir.path().join(PROJECT_CONFIG_FILE), content)
+                .expect("Failed to write project config");
+            assert!(
+                matches!(manager.load(), Err(Error::Config { .. })),
+                "{content} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_raw_skips_validation() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        manager
+            .set_raw("unknownkey", "value")
+            .expect("Should set unknown key");
+        assert_eq!(
+            manager.get("unknownkey").expect("Should get"),
+            Some("value".to_string())
+        );
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let config = AdrConfig {
+            max_artifact_size: 10,
+            max_body_size: 4,
+            ..AdrConfig::default()
+        };
+
+        assert!(config.check_artifact_size(10).is_ok());
+        assert!(matches!(
+            config.check_artifact_size(11),
+            Err(Error::ContentTooLarge { size: 11, max: 10 })
+        ));
+        assert!(config.check_body_size("body").is_ok());
+        assert!(matches!(
+            config.check_body_size("bodies"),
+            Err(Error::ContentTooLarge { size: 6, max: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_size_limits_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        manager
+            .set("maxArtifactSize", "1024")
+            .expect("Should set artifact limit");
+        assert!(matches!(
+            manager.set("maxBodySize", "0"),
+            Err(Error::Validation { .. })
+        ));
+
+        let config = manager.load().expect("Should load config");
+        assert_eq!(config.max_artifact_size, 1024);
+        assert_eq!(config.max_body_size, DEFAULT_MAX_BODY_SIZE);
+    }
+
+    #[test]
+    fn test_stale_days_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        assert_eq!(manager.load().unwrap().stale_days, DEFAULT_STALE_DAYS);
+
+        manager
+            .set("staleDays", "14")
+            .expect("Should set stale days");
+        assert_eq!(manager.load().unwrap().stale_days, 14);
+
+        assert!(matches!(
+            manager.set("staleDays", "-1"),
+            Err(Error::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_id_scheme_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        assert_eq!(manager.load().unwrap().id_scheme, IdScheme::Numeric);
+
+        manager
+            .set("idScheme", "date-slug")
+            .expect("Should set scheme");
+        assert_eq!(manager.load().unwrap().id_scheme, IdScheme::DateSlug);
+
+        assert!(matches!(
+            manager.set("idScheme", "uuid"),
+            Err(Error::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wiki_settings_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = ConfigManager::new(git);
+
+        assert_eq!(manager.load().unwrap().wiki_space_key, None);
+
+        manager
+            .set("wiki.platform", "confluence")
+            .expect("Should set platform");
+        manager
+            .set("wiki.url", "https://example.atlassian.net/wiki")
+            .expect("Should set URL");
+        manager
+            .set("wiki.spaceKey", "ARCH")
+            .expect("Should set space key");
+        manager
+            .set("wiki.username", "alice@example.com")
+            .expect("Should set username");
+
+        let config = manager.load().unwrap();
+        assert_eq!(config.wiki_platform.as_deref(), Some("confluence"));
+        assert_eq!(
+            config.wiki_url.as_deref(),
+            Some("https://example.atlassian.net/wiki")
+        );
+        assert_eq!(config.wiki_space_key.as_deref(), Some("ARCH"));
+        assert_eq!(config.wiki_username.as_deref(), Some("alice@example.com"));
+
+        assert!(matches!(
+            manager.set("wiki.platform", "mediawiki"),
+            Err(Error::Validation { .. })
+        ));
+    }
 }
@@ -0,0 +1,100 @@
+//! External editor support.
+//!
+//! This module opens ADR content in the user's editor, the same way git
+//! does for commit messages.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::core::{AdrConfig, Git};
+use crate::Error;
+
+/// Name of the scratch file written inside the `.git` directory.
+const EDIT_FILE: &str = "ADR_EDITMSG.md";
+
+/// Open `content` in the user's editor and return the saved result.
+///
+/// `$GIT_EDITOR` wins, then `adr.editor`, then whatever git would use
+/// (`core.editor`, `$VISUAL`, `$EDITOR`).
+///
+/// # Errors
+///
+/// Returns an error if the editor cannot be started, exits with a
+/// non-zero status, or the saved file is empty.
+pub fn open_in_editor(git: &Git, config: &AdrConfig, content: &str) -> Result<String, Error> {
+    let editor = match (std::env::var("GIT_EDITOR"), &config.editor) {
+        (Ok(editor), _) if !editor.is_empty() => editor,
+        (_, Some(editor)) => editor.clone(),
+        _ => git.editor()?,
+    };
+    let path = git.git_dir()?.join(EDIT_FILE);
+    run_editor(&editor, &path, git.work_dir(), content)
+}
+
+/// Write `content` to `path`, run `editor` on it and read the result back.
+fn run_editor(editor: &str, path: &Path, work_dir: &Path, content: &str) -> Result<String, Error> {
+    std::fs::write(path, content)?;
+
+    // Run through the shell so editors with arguments ("code --wait") work
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$@\""))
+        .arg(editor)
+        .arg(path)
+        .current_dir(work_dir)
+        .status();
+
+    let result = match status {
+        Ok(status) if status.success() => std::fs::read_to_string(path).map_err(Error::from),
+        Ok(status) => Err(Error::Other(format!(
+            "editor '{editor}' exited with {status}, aborting"
+        ))),
+        Err(e) => Err(Error::Other(format!(
+            "failed to launch editor '{editor}': {e}"
+        ))),
+    };
+    let _ = std::fs::remove_file(path);
+
+    let edited = result?;
+    if edited.trim().is_empty() {
+        return Err(Error::validation("aborting due to empty content"));
+    }
+
+    Ok(edited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_editor_returns_saved_content() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join(EDIT_FILE);
+
+        let result =
+            run_editor("echo 'edited' >", &path, temp_dir.path(), "original").expect("Should edit");
+        assert_eq!(result.trim(), "edited");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_run_editor_failure() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join(EDIT_FILE);
+
+        let result = run_editor("false", &path, temp_dir.path(), "original");
+        assert!(matches!(result, Err(Error::Other(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_run_editor_empty_content() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path().join(EDIT_FILE);
+
+        let result = run_editor(": >", &path, temp_dir.path(), "original");
+        assert!(matches!(result, Err(Error::Validation { .. })));
+    }
+}
@@ -3,18 +3,45 @@
 //! This module provides a wrapper around git subprocess calls,
 //! handling command execution, error parsing, and output processing.
 
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use wait_timeout::ChildExt;
 
 use crate::Error;
 
+/// Default limit on how long a single git command may run.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Environment variable overriding the timeout, in seconds (`0` disables it).
+pub const TIMEOUT_ENV: &str = "GIT_ADR_TIMEOUT";
+
+/// One revision of a note in the history of its notes ref.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRevision {
+    /// Notes ref commit that wrote this revision.
+    pub notes_commit: String,
+    /// Author of the notes commit, as `Name <email>`.
+    pub author: String,
+    /// When the revision was written.
+    pub date: DateTime<Utc>,
+    /// Blob holding the note, or `None` if the note was removed.
+    pub blob: Option<String>,
+}
+
 /// Git subprocess wrapper.
 #[derive(Debug, Clone)]
 pub struct Git {
     /// Working directory for git commands.
     work_dir: PathBuf,
     /// Path to git executable.
-    git_path: PathBuf,
+    program: PathBuf,
+    /// Time after which a git command is killed; `None` waits forever.
+    timeout: Option<Duration>,
 }
 
 impl Default for Git {
@@ -29,7 +56,8 @@ impl Git {
     pub fn new() -> Self {
         Self {
             work_dir: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
-            git_path: PathBuf::from("git"),
+            program: PathBuf::from("git"),
+            timeout: default_timeout(),
         }
     }
 
@@ -38,10 +66,21 @@ impl Git {
     pub fn with_work_dir<P: AsRef<Path>>(path: P) -> Self {
         Self {
             work_dir: path.as_ref().to_path_buf(),
-            git_path: PathBuf::from("git"),
+            program: PathBuf::from("git"),
+            timeout: default_timeout(),
         }
     }
 
+    /// Set how long a git command may run before it is killed.
+    ///
+    /// `None` disables the timeout. The default is 30 seconds, or the
+    /// value of `$GIT_ADR_TIMEOUT` in seconds.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Get the working directory.
     #[must_use]
     pub fn work_dir(&self) -> &Path {
@@ -74,28 +113,81 @@ impl Git {
         Ok(PathBuf::from(output.trim()))
     }
 
+    /// Get the path to the `.git` directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not in a git repository.
+    pub fn git_dir(&self) -> Result<PathBuf, Error> {
+        let output = self.run_output(&["rev-parse", "--absolute-git-dir"])?;
+        Ok(PathBuf::from(output.trim()))
+    }
+
+    /// Get the editor git would use (`$GIT_EDITOR`, `core.editor`,
+    /// `$VISUAL`, `$EDITOR`, then the built-in default).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no editor can be determined.
+    pub fn editor(&self) -> Result<String, Error> {
+        let output = self.run_output(&["var", "GIT_EDITOR"])?;
+        Ok(output.trim().to_string())
+    }
+
     /// Run a git command and return the raw output.
     ///
     /// # Errors
     ///
-    /// Returns an error if the command fails to execute.
+    /// Returns an error if the command fails to execute or runs past the
+    /// timeout, in which case it is killed.
     pub fn run(&self, args: &[&str]) -> Result<Output, Error> {
-        Command::new(&self.git_path)
+        tracing::debug!("git {}", args.join(" "));
+
+        let io_error = |e: std::io::Error| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                Error::GitNotFound
+            } else {
+                Error::git(e.to_string(), command_vec(args), -1, "")
+            }
+        };
+
+        let mut child = Command::new(&self.program)
             .current_dir(&self.work_dir)
             .args(args)
-            .output()
-            .map_err(|e| {
-                if e.kind() == std::io::ErrorKind::NotFound {
-                    Error::GitNotFound
-                } else {
-                    Error::Git {
-                        message: e.to_string(),
-                        command: args.iter().map(|s| (*s).to_string()).collect(),
-                        exit_code: -1,
-                        stderr: String::new(),
-                    }
-                }
-            })
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(io_error)?;
+
+        let Some(timeout) = self.timeout else {
+            return child.wait_with_output().map_err(io_error);
+        };
+
+        // Drain the pipes while waiting so a chatty command can't block on a full pipe
+        let stdout = child.stdout.take().map(drain);
+        let stderr = child.stderr.take().map(drain);
+
+        if let Some(status) = child.wait_timeout(timeout).map_err(io_error)? {
+            return Ok(Output {
+                status,
+                stdout: collect(stdout),
+                stderr: collect(stderr),
+            });
+        }
+
+        let _ = child.kill();
+        let _ = child.wait();
+        Err(Error::git(
+            format!(
+                "git command timed out after {}s: git {}",
+                timeout.as_secs_f64(),
+                args.join(" ")
+            ),
+            command_vec(args),
+            -1,
+            "",
+        ))
     }
 
     /// Run a git command and return stdout as a string.
@@ -150,6 +242,35 @@ impl Git {
         Ok(output.trim().to_string())
     }
 
+    /// Create an empty commit on top of `HEAD` and move the current branch
+    /// to it.
+    ///
+    /// The index and working tree are left alone and no hooks run, so
+    /// staged changes stay staged. Returns the new commit's hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no `HEAD` commit or the branch cannot be
+    /// updated.
+    pub fn commit_empty(&self, message: &str) -> Result<String, Error> {
+        let head = self.head()?;
+        let tree = self.run_output(&["rev-parse", &format!("{head}^{{tree}}")])?;
+        let commit = self.run_output(&["commit-tree", tree.trim(), "-p", &head, "-m", message])?;
+        let commit = commit.trim().to_string();
+        self.run_silent(&["update-ref", "-m", message, "HEAD", &commit, &head])?;
+        Ok(commit)
+    }
+
+    /// Check whether a fully qualified ref (e.g. `refs/notes/adr`) exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if git cannot be run.
+    pub fn ref_exists(&self, reference: &str) -> Result<bool, Error> {
+        let output = self.run(&["show-ref", "--verify", "--quiet", reference])?;
+        Ok(output.status.success())
+    }
+
     /// Get a short commit hash.
     ///
     /// # Errors
@@ -177,6 +298,63 @@ impl Git {
         }
     }
 
+    /// Get every value of a multi-valued git config key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if git cannot be run.
+    pub fn config_get_all(&self, key: &str) -> Result<Vec<String>, Error> {
+        let output = self.run(&["config", "--get-all", key])?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.trim().to_string())
+                .collect())
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Get the scope (`local`, `global`, `system`, ...) a git config key is
+    /// set in, or `None` if it isn't set.
+    ///
+    /// For a multi-valued key this is the scope of the last value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if git cannot be run.
+    pub fn config_scope(&self, key: &str) -> Result<Option<String>, Error> {
+        let output = self.run(&["config", "--show-scope", "--get-all", key])?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .last()
+                .and_then(|line| line.split('\t').next())
+                .map(str::to_string))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the configured user identity as `(name, email)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user.name` or `user.email` is not set.
+    pub fn user_identity(&self) -> Result<(String, String), Error> {
+        let name = self
+            .config_get("user.name")?
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| Error::config("user.name is not set"))?;
+        let email = self
+            .config_get("user.email")?
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| Error::config("user.email is not set"))?;
+        Ok((name, email))
+    }
+
     /// Set a git config value.
     ///
     /// # Errors
@@ -186,6 +364,15 @@ impl Git {
         self.run_silent(&["config", key, value])
     }
 
+    /// Add a value to a multi-valued git config key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be set.
+    pub fn config_add(&self, key: &str, value: &str) -> Result<(), Error> {
+        self.run_silent(&["config", "--add", key, value])
+    }
+
     /// Unset a git config value.
     ///
     /// If `all` is true, removes all values for multi-valued keys.
@@ -243,6 +430,106 @@ impl Git {
         ])
     }
 
+    /// Add or update notes for a commit and sign the resulting notes commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the notes commit cannot be signed; the
+    /// note itself has been written by then.
+    pub fn notes_add_signed(
+        &self,
+        notes_ref: &str,
+        commit: &str,
+        content: &str,
+    ) -> Result<(), Error> {
+        self.notes_add(notes_ref, commit, content)?;
+        self.sign_notes_commit(notes_ref)
+    }
+
+    /// Replace the tip of `refs/notes/<notes_ref>` with a signed copy.
+    ///
+    /// `git notes` has no option to sign the commits it writes, so the tip is
+    /// rebuilt with `commit-tree -S`, which follows `user.signingkey`,
+    /// `gpg.format` and `gpg.program` like any signed commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the commit cannot be signed.
+    pub fn sign_notes_commit(&self, notes_ref: &str) -> Result<(), Error> {
+        let full_ref = format!("refs/notes/{notes_ref}");
+        let tip = self.run_output(&["rev-parse", "--verify", &full_ref])?;
+        let tip = tip.trim();
+        let tree = self.run_output(&["rev-parse", &format!("{tip}^{{tree}}")])?;
+        let parents = self.run_output(&["rev-list", "--parents", "-n", "1", tip])?;
+        let message = self.run_output(&["log", "-1", "--format=%B", tip])?;
+
+        let mut args = vec!["commit-tree", "-S", tree.trim(), "-m", message.trim_end()];
+        for parent in parents.split_whitespace().skip(1) {
+            args.extend(["-p", parent]);
+        }
+        let output = self.run(&args)?;
+        if !output.status.success() {
+            return Err(Error::signing(format!(
+                "failed to sign notes commit: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let signed = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        self.run_silent(&["update-ref", &full_ref, &signed, tip])
+    }
+
+    /// Check the signature on a notes commit with `git log --show-signature`.
+    ///
+    /// Returns the signer, or `None` if the commit is not signed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the signature is bad or can't be
+    /// checked (e.g. the key is unknown).
+    pub fn verify_notes_commit(&self, commit: &str) -> Result<Option<String>, Error> {
+        let output = self.run_output(&[
+            "log",
+            "-1",
+            "--show-signature",
+            "--format=%x00%G?%x00%GS",
+            commit,
+        ])?;
+
+        // The signature check's own output comes before the format fields
+        let mut fields = output.splitn(3, '\0');
+        let details = fields.next().unwrap_or_default().trim();
+        let status = fields.next().unwrap_or_default();
+        let signer = fields.next().unwrap_or_default().trim();
+
+        match status {
+            "G" | "U" => Ok(Some(signer.to_string())),
+            "N" => Ok(None),
+            _ => Err(Error::signing(format!(
+                "bad or unverifiable signature on notes commit {commit}: {details}"
+            ))),
+        }
+    }
+
+    /// Create `refs/notes/<notes_ref>` pointing at an empty notes commit,
+    /// unless the ref already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit or ref cannot be created.
+    pub fn notes_init(&self, notes_ref: &str) -> Result<(), Error> {
+        let full_ref = format!("refs/notes/{notes_ref}");
+        if self.ref_exists(&full_ref)? {
+            return Ok(());
+        }
+
+        // `mktree` with no input writes the empty tree
+        let tree = self.run_output(&["mktree"])?;
+        let commit =
+            self.run_output(&["commit-tree", tree.trim(), "-m", "Initialize git-adr notes"])?;
+        self.run_silent(&["update-ref", &full_ref, commit.trim(), ""])
+    }
+
     /// Remove notes for a commit.
     ///
     /// # Errors
@@ -278,6 +565,286 @@ impl Git {
         Ok(results)
     }
 
+    /// List the revisions of a commit's note, newest first.
+    ///
+    /// Walks the history of `refs/notes/<notes_ref>`, so each entry reflects
+    /// who wrote that version of the note rather than who made the commit.
+    /// Rewrites with `notes add -f` appear as separate revisions; notes
+    /// commits that only move the note between fan-out directories are
+    /// collapsed. Returns an empty list if the ref does not exist or the note
+    /// was never written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit cannot be resolved or git output is malformed.
+    pub fn notes_log(&self, notes_ref: &str, commit: &str) -> Result<Vec<NoteRevision>, Error> {
+        let commit = if commit.len() == 40 {
+            commit.to_string()
+        } else {
+            self.run_output(&["rev-parse", "--verify", &format!("{commit}^{{commit}}")])?
+                .trim()
+                .to_string()
+        };
+
+        // Notes trees may fan out into `ab/cdef...` subdirectories
+        let paths = [
+            commit.clone(),
+            format!("{}/{}", &commit[..2], &commit[2..]),
+            format!("{}/{}/{}", &commit[..2], &commit[2..4], &commit[4..]),
+        ];
+        let mut history = self.notes_history_of(notes_ref, &paths)?;
+        Ok(history.remove(&commit).unwrap_or_default())
+    }
+
+    /// List the revisions of every note in `refs/notes/<notes_ref>`, newest
+    /// first, keyed by the annotated commit.
+    ///
+    /// Revisions are as described for [`notes_log`](Self::notes_log), but
+    /// all notes are read in a single walk of the notes history.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if git output is malformed.
+    pub fn notes_history(
+        &self,
+        notes_ref: &str,
+    ) -> Result<HashMap<String, Vec<NoteRevision>>, Error> {
+        self.notes_history_of(notes_ref, &[])
+    }
+
+    /// Walk the notes history once, limited to `paths` if any are given,
+    /// and bucket the note revisions by annotated commit.
+    fn notes_history_of(
+        &self,
+        notes_ref: &str,
+        paths: &[String],
+    ) -> Result<HashMap<String, Vec<NoteRevision>>, Error> {
+        let notes_ref = format!("refs/notes/{notes_ref}");
+        let mut args = vec![
+            "log",
+            "--raw",
+            "--no-abbrev",
+            "--no-renames",
+            "--format=%x01%H%x00%an <%ae>%x00%aI",
+            &notes_ref,
+            "--",
+        ];
+        args.extend(paths.iter().map(String::as_str));
+
+        let output = self.run(&args)?;
+        let mut history: HashMap<String, Vec<NoteRevision>> = HashMap::new();
+        if !output.status.success() {
+            return Ok(history);
+        }
+
+        // Each notes commit is a header line followed by one raw diff line
+        // per note it touched; walk them oldest first
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for entry in stdout.split('\x01').filter(|e| !e.trim().is_empty()).rev() {
+            let mut lines = entry.lines();
+            let mut fields = lines.next().unwrap_or_default().splitn(3, '\0');
+            let (Some(notes_commit), Some(author), Some(date)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let date = DateTime::parse_from_rfc3339(date).map_err(|e| Error::ParseError {
+                message: format!("Invalid notes log date '{date}': {e}"),
+            })?;
+
+            // `:<mode> <mode> <old blob> <new blob> <status>\t<path>`; a note
+            // moved between fan-out directories is removed and added in the
+            // same commit, and the addition wins
+            let mut changes: HashMap<String, Option<String>> = HashMap::new();
+            for line in lines {
+                let Some((meta, path)) = line.strip_prefix(':').and_then(|l| l.split_once('\t'))
+                else {
+                    continue;
+                };
+                let blob = meta
+                    .split_whitespace()
+                    .nth(3)
+                    .filter(|blob| blob.bytes().any(|b| b != b'0'))
+                    .map(str::to_string);
+                let annotated = path.replace('/', "");
+                let change = changes.entry(annotated).or_default();
+                if blob.is_some() {
+                    *change = blob;
+                }
+            }
+
+            for (annotated, blob) in changes {
+                let revisions = history.entry(annotated).or_default();
+                // A fan-out rewrite touches the path without changing the note
+                if revisions.last().is_some_and(|prev| prev.blob == blob) {
+                    continue;
+                }
+                revisions.push(NoteRevision {
+                    notes_commit: notes_commit.to_string(),
+                    author: author.to_string(),
+                    date: date.with_timezone(&Utc),
+                    blob,
+                });
+            }
+        }
+        for revisions in history.values_mut() {
+            revisions.reverse();
+        }
+
+        Ok(history)
+    }
+
+    /// Read a blob's content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob does not exist.
+    pub fn blob_content(&self, blob: &str) -> Result<String, Error> {
+        self.run_output(&["cat-file", "blob", blob])
+    }
+
+    /// Unified diff between two blobs, without the file headers.
+    ///
+    /// `None` stands for empty content, so a missing side shows every line
+    /// as added or removed. The result starts at the first `@@` hunk header
+    /// and is empty when the blobs are identical.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either blob does not exist.
+    pub fn diff_blobs(&self, from: Option<&str>, to: Option<&str>) -> Result<String, Error> {
+        // Write the empty blob so `git diff` can resolve it in any repository
+        let empty = self.run_output(&["hash-object", "-w", "-t", "blob", "--stdin"])?;
+        let empty = empty.trim();
+        let from = from.unwrap_or(empty);
+        let to = to.unwrap_or(empty);
+
+        let diff = self.run_output(&["diff", "--no-color", "--no-ext-diff", from, to])?;
+        Ok(diff
+            .find("@@")
+            .map_or_else(String::new, |start| diff[start..].to_string()))
+    }
+
+    /// Create an ASCII-armored detached GPG signature for `content`.
+    ///
+    /// Uses `gpg.program` (default `gpg`) and `user.signingkey` when set,
+    /// otherwise GPG's default key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if GPG cannot be run or fails to sign.
+    pub fn sign(&self, content: &str) -> Result<String, Error> {
+        let program = self.gpg_program()?;
+        let mut args = vec!["--batch", "--detach-sign", "--armor"];
+        let key = self.config_get("user.signingkey")?;
+        if let Some(key) = &key {
+            args.extend(["--local-user", key]);
+        }
+
+        let output = self.run_gpg(&program, &args, content)?;
+        if !output.status.success() {
+            return Err(Error::signing(format!(
+                "failed to sign with {program}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    /// Verify a detached GPG signature over `content`.
+    ///
+    /// Returns the signer's user ID on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if GPG cannot be run or the signature is
+    /// not a good signature over `content`.
+    pub fn verify_signature(&self, content: &str, signature: &str) -> Result<String, Error> {
+        let program = self.gpg_program()?;
+        let sig_path = self.git_dir()?.join("ADR_SIGNATURE.asc");
+        std::fs::write(&sig_path, signature)?;
+
+        let sig_arg = sig_path.to_string_lossy().to_string();
+        let output = self.run_gpg(
+            &program,
+            &["--batch", "--status-fd=1", "--verify", &sig_arg, "-"],
+            content,
+        );
+        let _ = std::fs::remove_file(&sig_path);
+        let output = output?;
+
+        let status = String::from_utf8_lossy(&output.stdout);
+        let signer = status
+            .lines()
+            .find_map(|line| line.strip_prefix("[GNUPG:] GOODSIG "))
+            .map(|rest| rest.split_once(' ').map_or(rest, |(_, uid)| uid).to_string());
+
+        match signer {
+            Some(signer) if output.status.success() => Ok(signer),
+            _ => Err(Error::signing(format!(
+                "bad or unverifiable signature: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+        }
+    }
+
+    /// Get the configured GPG program.
+    fn gpg_program(&self) -> Result<String, Error> {
+        Ok(self
+            .config_get("gpg.program")?
+            .unwrap_or_else(|| "gpg".to_string()))
+    }
+
+    /// Run GPG with `input` on stdin.
+    fn run_gpg(&self, program: &str, args: &[&str], input: &str) -> Result<Output, Error> {
+        let mut child = Command::new(program)
+            .current_dir(&self.work_dir)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| Error::signing(format!("failed to run {program}: {e}")))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|e| Error::signing(format!("failed to write to {program}: {e}")))?;
+        }
+
+        child
+            .wait_with_output()
+            .map_err(|e| Error::signing(format!("failed to run {program}: {e}")))
+    }
+
+    /// The remote to fetch from by default.
+    ///
+    /// Uses the current branch's upstream remote, then `origin`, then the
+    /// first configured remote. Returns `None` if there are no remotes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if git cannot be run.
+    pub fn default_remote(&self) -> Result<Option<String>, Error> {
+        let output = self.run(&["symbolic-ref", "--quiet", "--short", "HEAD"])?;
+        if output.status.success() {
+            let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if let Some(remote) = self.config_get(&format!("branch.{branch}.remote"))? {
+                if !remote.is_empty() && remote != "." {
+                    return Ok(Some(remote));
+                }
+            }
+        }
+
+        let remotes = self.run_output(&["remote"])?;
+        let remotes: Vec<&str> = remotes.lines().map(str::trim).collect();
+        if remotes.contains(&"origin") {
+            return Ok(Some("origin".to_string()));
+        }
+        Ok(remotes.first().map(|r| (*r).to_string()))
+    }
+
     /// Push notes to a remote.
     ///
     /// # Errors
@@ -303,6 +870,129 @@ impl Git {
             &format!("refs/notes/{notes_ref}:refs/notes/{notes_ref}"),
         ])
     }
+
+    /// Fetch a remote's notes into `refs/notes/remotes/<remote>/<notes_ref>`.
+    ///
+    /// Unlike [`notes_fetch`](Self::notes_fetch) this never touches the local
+    /// notes ref, so the result can be merged with [`notes_merge`](Self::notes_merge).
+    /// Returns the tracking ref.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetch fails.
+    pub fn notes_fetch_tracking(&self, remote: &str, notes_ref: &str) -> Result<String, Error> {
+        let tracking = format!("refs/notes/remotes/{remote}/{notes_ref}");
+        // An empty --refmap stops a configured `refs/notes/*:refs/notes/*`
+        // fetch refspec from also overwriting the local ref
+        self.run_silent(&[
+            "fetch",
+            "--refmap=",
+            remote,
+            &format!("+refs/notes/{notes_ref}:{tracking}"),
+        ])?;
+        Ok(tracking)
+    }
+
+    /// Abort an in-progress notes merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no merge to abort.
+    pub fn notes_merge_abort(&self, notes_ref: &str) -> Result<(), Error> {
+        self.run_silent(&["notes", "--ref", notes_ref, "merge", "--abort"])
+    }
+
+    /// Merge notes from `other_ref` into `notes_ref` using `git notes merge`.
+    ///
+    /// `strategy` is one of git's notes merge strategies (`manual`, `ours`,
+    /// `theirs`, `union`, `cat_sort_uniq`). With `manual`, conflicting notes
+    /// are left in `.git/NOTES_MERGE_WORKTREE` for the user to resolve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Git`] naming the conflicting commits if the merge
+    /// conflicts, or if it fails for any other reason.
+    pub fn notes_merge(
+        &self,
+        notes_ref: &str,
+        other_ref: &str,
+        strategy: &str,
+    ) -> Result<(), Error> {
+        let args = [
+            "notes",
+            "--ref",
+            notes_ref,
+            "merge",
+            "--strategy",
+            strategy,
+            other_ref,
+        ];
+        let output = self.run(&args)?;
+
+        if output.status.success() {
+            return Ok(());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let conflicts: Vec<&str> = stdout
+            .lines()
+            .chain(stderr.lines())
+            .filter_map(|line| line.strip_prefix("CONFLICT"))
+            .filter_map(|line| line.split_whitespace().last())
+            .collect();
+
+        let message = if conflicts.is_empty() {
+            format!("git command failed: git {}", args.join(" "))
+        } else {
+            format!(
+                "notes merge conflict in refs/notes/{notes_ref} on commit(s) {}; resolve in \
+                 .git/NOTES_MERGE_WORKTREE and run 'git notes --ref {notes_ref} merge --commit', \
+                 or abort with 'git notes --ref {notes_ref} merge --abort'",
+                conflicts.join(", ")
+            )
+        };
+
+        Err(Error::git(
+            message,
+            command_vec(&args),
+            output.status.code().unwrap_or(-1),
+            stderr,
+        ))
+    }
+}
+
+/// Read the timeout from `$GIT_ADR_TIMEOUT`, falling back to the default.
+fn default_timeout() -> Option<Duration> {
+    match std::env::var(TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+    {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None => Some(DEFAULT_TIMEOUT),
+    }
+}
+
+/// Copy command arguments for error reporting.
+fn command_vec(args: &[&str]) -> Vec<String> {
+    args.iter().map(|s| (*s).to_string()).collect()
+}
+
+/// Read a child's pipe to the end on a background thread.
+fn drain<R: Read + Send + 'static>(mut pipe: R) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+/// Collect the output of a [`drain`] thread.
+fn collect(handle: Option<JoinHandle<Vec<u8>>>) -> Vec<u8> {
+    handle
+        .and_then(|h| h.join().ok())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -536,4 +1226,144 @@ mod tests {
         let short = git.short_hash(&head).unwrap();
         assert!(short.len() < head.len());
     }
+
+    #[test]
+    fn test_user_identity() {
+        let temp_dir = TempDir::new().unwrap();
+        Command::new("git")
+            .current_dir(temp_dir.path())
+            .args(["init"])
+            .output()
+            .unwrap();
+        let git = Git::with_work_dir(temp_dir.path());
+        git.config_set("user.name", "Jane Doe").unwrap();
+        git.config_set("user.email", "jane@example.com").unwrap();
+
+        let (name, email) = git.user_identity().unwrap();
+        assert_eq!(name, "Jane Doe");
+        assert_eq!(email, "jane@example.com");
+    }
+
+    #[test]
+    fn test_notes_log() {
+        let temp_dir = TempDir::new().unwrap();
+        Command::new("git")
+            .current_dir(temp_dir.path())
+            .args(["init"])
+            .output()
+            .unwrap();
+        let git = Git::with_work_dir(temp_dir.path());
+        git.config_set("user.name", "Jane Doe").unwrap();
+        git.config_set("user.email", "jane@example.com").unwrap();
+        git.run_silent(&["commit", "--allow-empty", "-m", "Initial"])
+            .unwrap();
+        let head = git.head().unwrap();
+
+        assert!(git.notes_log("adr", &head).unwrap().is_empty());
+
+        git.notes_add("adr", &head, "note").unwrap();
+        let revisions = git.notes_log("adr", "HEAD").unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].author, "Jane Doe <jane@example.com>");
+        assert!(
+            Utc::now()
+                .signed_duration_since(revisions[0].date)
+                .num_minutes()
+                < 5
+        );
+
+        // `notes add -f` rewrites the note as a new revision
+        git.notes_add("adr", &head, "note\nedited").unwrap();
+        let revisions = git.notes_log("adr", &head).unwrap();
+        assert_eq!(revisions.len(), 2);
+        let newest = revisions[0].blob.as_deref().unwrap();
+        let oldest = revisions[1].blob.as_deref().unwrap();
+        assert_eq!(git.blob_content(newest).unwrap(), "note\nedited\n");
+
+        let diff = git.diff_blobs(Some(oldest), Some(newest)).unwrap();
+        assert!(diff.starts_with("@@"));
+        assert!(diff.contains("+edited"));
+        let diff = git.diff_blobs(None, Some(oldest)).unwrap();
+        assert!(diff.contains("+note"));
+    }
+
+    #[test]
+    fn test_notes_history() {
+        let temp_dir = TempDir::new().unwrap();
+        Command::new("git")
+            .current_dir(temp_dir.path())
+            .args(["init"])
+            .output()
+            .unwrap();
+        let git = Git::with_work_dir(temp_dir.path());
+        git.config_set("user.name", "Jane Doe").unwrap();
+        git.config_set("user.email", "jane@example.com").unwrap();
+        git.run_silent(&["commit", "--allow-empty", "-m", "First"])
+            .unwrap();
+        let first = git.head().unwrap();
+        git.run_silent(&["commit", "--allow-empty", "-m", "Second"])
+            .unwrap();
+        let second = git.head().unwrap();
+
+        assert!(git.notes_history("adr").unwrap().is_empty());
+
+        git.notes_add("adr", &first, "one").unwrap();
+        git.notes_add("adr", &second, "two").unwrap();
+        git.notes_add("adr", &first, "one\nedited").unwrap();
+        git.notes_remove("adr", &second).unwrap();
+
+        let history = git.notes_history("adr").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[&first], git.notes_log("adr", &first).unwrap());
+        assert_eq!(history[&first].len(), 2);
+        let latest = history[&first][0].blob.as_deref().unwrap();
+        assert_eq!(git.blob_content(latest).unwrap(), "one\nedited\n");
+
+        // The removal is the newest revision of the second note
+        assert_eq!(history[&second].len(), 2);
+        assert!(history[&second][0].blob.is_none());
+        assert!(history[&second][1].blob.is_some());
+    }
+
+    #[test]
+    fn test_run_timeout_kills_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let marker = temp_dir.path().join("finished");
+        let git = Git {
+            program: PathBuf::from("sh"),
+            ..Git::with_work_dir(temp_dir.path())
+        }
+        .with_timeout(Some(Duration::from_millis(200)));
+
+        let start = std::time::Instant::now();
+        let result = git.run(&["-c", "sleep 2 && touch finished"]);
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        let Err(Error::Git {
+            message, command, ..
+        }) = result
+        else {
+            unreachable!("expected timeout error, got {result:?}");
+        };
+        assert!(message.contains("timed out"));
+        assert_eq!(command[0], "-c");
+
+        // The command was killed rather than left running
+        std::thread::sleep(Duration::from_millis(2500));
+        assert!(!marker.exists());
+    }
+
+    #[test]
+    fn test_run_without_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let git = Git {
+            program: PathBuf::from("sh"),
+            ..Git::with_work_dir(temp_dir.path())
+        }
+        .with_timeout(None);
+
+        let output = git.run(&["-c", "echo done"]).unwrap();
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "done");
+    }
 }
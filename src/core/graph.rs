@@ -0,0 +1,178 @@
+//! Relationship graph between ADRs.
+//!
+//! Nodes are ADRs and edges are their typed relationships: supersession
+//! (`supersedes`/`superseded_by`) and the entries of `links`. The graph
+//! can be rendered as Graphviz DOT or as a Mermaid flowchart.
+
+use std::fmt::Write;
+
+use crate::core::{Adr, AdrStatus};
+
+/// Collect the relationship edges between ADRs as `(from, to, label)`.
+///
+/// A supersession recorded on both sides (`supersedes` on the new ADR and
+/// `superseded_by` on the old one) yields a single edge.
+#[must_use]
+pub fn graph_edges(adrs: &[Adr]) -> Vec<(String, String, String)> {
+    let mut edges: Vec<(String, String, String)> = Vec::new();
+    let mut push = |edge: (String, String, String)| {
+        if !edges.contains(&edge) {
+            edges.push(edge);
+        }
+    };
+
+    for adr in adrs {
+        if let Some(old) = &adr.frontmatter.supersedes {
+            push((adr.id.clone(), old.clone(), "supersedes".to_string()));
+        }
+        if let Some(new) = &adr.frontmatter.superseded_by {
+            push((new.clone(), adr.id.clone(), "supersedes".to_string()));
+        }
+        for link in &adr.frontmatter.links {
+            push((adr.id.clone(), link.target.clone(), link.rel.clone()));
+        }
+    }
+
+    edges
+}
+
+/// Fill color used for an ADR node in graph exports.
+const fn status_color(status: &AdrStatus) -> &'static str {
+    match status {
+        AdrStatus::Proposed => "#f39c12",
+        AdrStatus::Accepted => "#27ae60",
+        AdrStatus::Deprecated => "#95a5a6",
+        AdrStatus::Superseded => "#9b59b6",
+        AdrStatus::Rejected => "#e74c3c",
+        AdrStatus::Archived => "#7f8c8d",
+    }
+}
+
+/// Render the ADR relationship graph in Graphviz DOT format.
+#[must_use]
+pub fn dot_graph(adrs: &[Adr]) -> String {
+    let mut content =
+        String::from("digraph adrs {\n    rankdir=LR;\n    node [shape=box, style=filled];\n\n");
+    for adr in adrs {
+        let _ = writeln!(
+            content,
+            "    \"{}\" [label=\"{}\\n{}\", fillcolor=\"{}\"];",
+            adr.id,
+            adr.id,
+            adr.frontmatter.title.replace('"', "\\\""),
+            status_color(&adr.frontmatter.status)
+        );
+    }
+    content.push('\n');
+    for (from, to, label) in graph_edges(adrs) {
+        let _ = writeln!(content, "    \"{from}\" -> \"{to}\" [label=\"{label}\"];");
+    }
+    content.push_str("}\n");
+    content
+}
+
+/// Render the ADR relationship graph as a Mermaid flowchart.
+///
+/// The output is a bare `flowchart` definition; wrap it in a ```` ```mermaid ````
+/// fence to embed it in Markdown.
+#[must_use]
+pub fn mermaid_graph(adrs: &[Adr]) -> String {
+    /// Mermaid node IDs can't contain dashes.
+    fn node_id(id: &str) -> String {
+        id.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    let mut content = String::from("flowchart LR\n");
+    for adr in adrs {
+        let _ = writeln!(
+            content,
+            "    {}[\"{}: {}\"]:::{}",
+            node_id(&adr.id),
+            adr.id,
+            adr.frontmatter.title.replace('"', "#quot;"),
+            adr.frontmatter.status
+        );
+    }
+    for (from, to, label) in graph_edges(adrs) {
+        let _ = writeln!(
+            content,
+            "    {} -->|{}| {}",
+            node_id(&from),
+            label,
+            node_id(&to)
+        );
+    }
+    for status in AdrStatus::ALL {
+        let _ = writeln!(
+            content,
+            "    classDef {} fill:{},color:#fff",
+            status,
+            status_color(&status)
+        );
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AdrLink;
+
+    fn adr(id: &str, title: &str, status: AdrStatus) -> Adr {
+        let mut adr = Adr::new(id.to_string(), title.to_string());
+        adr.frontmatter.status = status;
+        adr
+    }
+
+    fn sample() -> Vec<Adr> {
+        let mut old = adr("ADR-0001", "Use MySQL", AdrStatus::Superseded);
+        old.frontmatter.superseded_by = Some("ADR-0002".to_string());
+        let mut new = adr("ADR-0002", "Use \"Postgres\"", AdrStatus::Accepted);
+        new.frontmatter.supersedes = Some("ADR-0001".to_string());
+        new.frontmatter.links.push(AdrLink {
+            rel: "depends_on".to_string(),
+            target: "ADR-0003".to_string(),
+        });
+        vec![old, new, adr("ADR-0003", "Use Docker", AdrStatus::Proposed)]
+    }
+
+    #[test]
+    fn test_graph_edges_dedupes_supersession() {
+        let edges = graph_edges(&sample());
+        assert_eq!(
+            edges,
+            vec![
+                (
+                    "ADR-0002".to_string(),
+                    "ADR-0001".to_string(),
+                    "supersedes".to_string()
+                ),
+                (
+                    "ADR-0002".to_string(),
+                    "ADR-0003".to_string(),
+                    "depends_on".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dot_graph() {
+        let dot = dot_graph(&sample());
+        assert!(dot.starts_with("digraph adrs {"));
+        assert!(dot.contains(r##""ADR-0002" [label="ADR-0002\nUse \"Postgres\"", fillcolor="#27ae60"];"##));
+        assert!(dot.contains(r#""ADR-0002" -> "ADR-0003" [label="depends_on"];"#));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_mermaid_graph() {
+        let mermaid = mermaid_graph(&sample());
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains(r#"ADR_0002["ADR-0002: Use #quot;Postgres#quot;"]:::accepted"#));
+        assert!(mermaid.contains("ADR_0002 -->|supersedes| ADR_0001"));
+        assert!(mermaid.contains("classDef proposed fill:#f39c12,color:#fff"));
+    }
+}
@@ -1,16 +1,30 @@
 //! Search index for ADRs.
 //!
 //! This module provides full-text search capabilities for ADRs
-//! using an index stored in git notes.
+//! using an index stored in git notes. The index also keeps stemmed term
+//! counts so ADRs can be ranked by TF-IDF similarity without any network
+//! access.
 
-use crate::core::{Adr, Git, NotesManager};
+use crate::core::similarity::{rank_by_terms, term_counts};
+use crate::core::{Adr, AdrConfig, Git, NotesManager};
 use crate::Error;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 
-/// Notes reference for the search index.
+/// Default notes reference for the search index.
+///
+/// The ref actually used comes from [`AdrConfig::index_ref`].
 pub const INDEX_NOTES_REF: &str = "adr-index";
 
+/// Version of the index format written by this release.
+///
+/// Version 2 added the original-case `content` of each entry, version 3 the
+/// stemmed `terms` used by semantic search, and version 4 dropped stop
+/// words from them. Indexes with an older version are rebuilt when loaded.
+pub const CURRENT_INDEX_VERSION: u32 = 4;
+
 /// A search index entry for an ADR.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexEntry {
@@ -24,27 +38,38 @@ pub struct IndexEntry {
     pub status: String,
     /// Tags.
     pub tags: Vec<String>,
-    /// Full-text content for searching.
+    /// Full-text content for searching, lowercased.
     pub text: String,
+    /// Full-text content in its original case.
+    ///
+    /// Empty in indexes built before case-sensitive search existed; `text`
+    /// is searched instead.
+    #[serde(default)]
+    pub content: String,
+    /// Stemmed term counts of the content, for semantic search.
+    #[serde(default)]
+    pub terms: HashMap<String, u32>,
 }
 
 impl IndexEntry {
     /// Create an index entry from an ADR.
     #[must_use]
     pub fn from_adr(adr: &Adr) -> Self {
+        let content = format!(
+            "{} {} {}",
+            adr.frontmatter.title,
+            adr.frontmatter.tags.join(" "),
+            adr.body
+        );
         Self {
             id: adr.id.clone(),
             commit: adr.commit.clone(),
             title: adr.frontmatter.title.clone(),
             status: adr.frontmatter.status.to_string(),
             tags: adr.frontmatter.tags.clone(),
-            text: format!(
-                "{} {} {}",
-                adr.frontmatter.title,
-                adr.frontmatter.tags.join(" "),
-                adr.body
-            )
-            .to_lowercase(),
+            text: content.to_lowercase(),
+            terms: term_counts(&content),
+            content,
         }
     }
 
@@ -56,6 +81,121 @@ impl IndexEntry {
             || self.id.to_lowercase().contains(&query_lower)
             || self.title.to_lowercase().contains(&query_lower)
     }
+
+    /// Check if this entry matches a compiled search pattern.
+    #[must_use]
+    pub fn is_match(&self, pattern: &Regex) -> bool {
+        pattern.is_match(self.searchable())
+            || pattern.is_match(&self.id)
+            || pattern.is_match(&self.title)
+    }
+
+    /// Text around the first match of `query`, keeping up to `radius`
+    /// characters on each side.
+    #[must_use]
+    pub fn snippet(&self, query: &str, radius: usize) -> Option<Snippet> {
+        let pattern = SearchOptions::default().pattern(query).ok()?;
+        Snippet::find(self.searchable(), &pattern, radius)
+    }
+
+    /// The original-case content, or the lowercased text for old indexes.
+    fn searchable(&self) -> &str {
+        if self.content.is_empty() {
+            &self.text
+        } else {
+            &self.content
+        }
+    }
+}
+
+/// How a search query is matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Match case exactly instead of ignoring it.
+    pub case_sensitive: bool,
+    /// Only match whole words.
+    pub whole_word: bool,
+    /// Treat the query as a regular expression instead of literal text.
+    pub regex: bool,
+}
+
+impl SearchOptions {
+    /// Compile `query` into a pattern honouring these options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `query` is not a valid regular expression.
+    pub fn pattern(&self, query: &str) -> Result<Regex, Error> {
+        let mut source = if self.regex {
+            format!("(?:{query})")
+        } else {
+            regex::escape(query)
+        };
+
+        if self.whole_word {
+            // A literal only gets a boundary on ends that are word characters,
+            // so queries like `C++` still match
+            let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if self.regex || is_word(query.chars().next()) {
+                source.insert_str(0, r"\b");
+            }
+            if self.regex || is_word(query.chars().next_back()) {
+                source.push_str(r"\b");
+            }
+        }
+        if !self.case_sensitive {
+            source.insert_str(0, "(?i)");
+        }
+
+        Regex::new(&source).map_err(|e| Error::validation(format!("invalid search pattern: {e}")))
+    }
+}
+
+/// A window of text around a search match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    /// The text, with whitespace collapsed and `…` marking cut-off ends.
+    pub text: String,
+    /// Byte ranges of every match within `text`.
+    pub matches: Vec<Range<usize>>,
+}
+
+impl Snippet {
+    /// Find the first match of `pattern` in `text` and cut a window of up to
+    /// `radius` characters on each side of it.
+    #[must_use]
+    pub fn find(text: &str, pattern: &Regex, radius: usize) -> Option<Self> {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let first = pattern.find(&text)?;
+
+        let start = text[..first.start()]
+            .char_indices()
+            .rev()
+            .take(radius)
+            .last()
+            .map_or_else(|| first.start(), |(i, _)| i);
+        let end = text[first.end()..]
+            .char_indices()
+            .nth(radius)
+            .map_or(text.len(), |(i, _)| first.end() + i);
+
+        let prefix = if start > 0 { "…" } else { "" };
+        let window = &text[start..end];
+        let matches = pattern
+            .find_iter(window)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.start() + prefix.len()..m.end() + prefix.len())
+            .collect();
+
+        let mut snippet = format!("{prefix}{window}");
+        if end < text.len() {
+            snippet.push('…');
+        }
+        Some(Self {
+            text: snippet,
+            matches,
+        })
+    }
 }
 
 /// The search index.
@@ -73,10 +213,20 @@ impl SearchIndex {
     pub fn new() -> Self {
         Self {
             entries: HashMap::new(),
-            version: 1,
+            version: CURRENT_INDEX_VERSION,
         }
     }
 
+    /// Build an index of `adrs`.
+    #[must_use]
+    pub fn from_adrs(adrs: &[Adr]) -> Self {
+        let mut index = Self::new();
+        for adr in adrs {
+            index.upsert(IndexEntry::from_adr(adr));
+        }
+        index
+    }
+
     /// Add or update an entry.
     pub fn upsert(&mut self, entry: IndexEntry) {
         self.entries.insert(entry.id.clone(), entry);
@@ -96,6 +246,38 @@ impl SearchIndex {
             .collect()
     }
 
+    /// Rank entries by TF-IDF cosine similarity to `query`.
+    ///
+    /// Words are compared by stem, so "cached databases" finds an ADR about
+    /// caching a database. Entries sharing no stem with the query are left
+    /// out; the rest come most similar first.
+    #[must_use]
+    pub fn semantic_search(&self, query: &str) -> Vec<(&IndexEntry, f64)> {
+        let documents: Vec<(&str, &HashMap<String, u32>)> = self
+            .entries
+            .values()
+            .map(|entry| (entry.id.as_str(), &entry.terms))
+            .collect();
+        rank_by_terms(&term_counts(query), &documents)
+            .into_iter()
+            .filter_map(|(id, score)| Some((self.entries.get(id)?, score)))
+            .collect()
+    }
+
+    /// Whether the index holds exactly `adrs`, as they are now.
+    #[must_use]
+    pub fn is_current(&self, adrs: &[Adr]) -> bool {
+        self.entries.len() == adrs.len()
+            && adrs.iter().all(|adr| {
+                self.entries.get(&adr.id).is_some_and(|entry| {
+                    let fresh = IndexEntry::from_adr(adr);
+                    entry.commit == fresh.commit
+                        && entry.status == fresh.status
+                        && entry.content == fresh.content
+                })
+            })
+    }
+
     /// Get all entries.
     #[must_use]
     pub fn all(&self) -> Vec<&IndexEntry> {
@@ -103,35 +285,70 @@ impl SearchIndex {
     }
 }
 
+/// Just enough of a stored index to tell which format it is in.
+#[derive(Deserialize)]
+struct StoredVersion {
+    #[serde(default)]
+    version: u32,
+}
+
 /// Manager for the search index.
 #[derive(Debug)]
 pub struct IndexManager {
     git: Git,
+    notes_ref: String,
 }
 
 impl IndexManager {
-    /// Create a new `IndexManager`.
+    /// Create a new `IndexManager` using the default index ref.
     #[must_use]
-    pub const fn new(git: Git) -> Self {
-        Self { git }
+    pub fn new(git: Git) -> Self {
+        Self {
+            git,
+            notes_ref: INDEX_NOTES_REF.to_string(),
+        }
+    }
+
+    /// Create a new `IndexManager` using the index ref for the configured
+    /// namespace.
+    #[must_use]
+    pub fn with_config(git: Git, config: &AdrConfig) -> Self {
+        Self {
+            git,
+            notes_ref: config.index_ref(),
+        }
     }
 
     /// Load the index from git notes.
     ///
+    /// An index stored in an older format is rebuilt from `notes` (and
+    /// saved) instead of being read.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the index cannot be loaded.
-    pub fn load(&self) -> Result<SearchIndex, Error> {
+    /// Returns an error if the index cannot be loaded or rebuilt.
+    pub fn load(&self, notes: &NotesManager) -> Result<SearchIndex, Error> {
         // We store the index in a note attached to a special "index" ref
         // For simplicity, we use the repo's initial commit or a fixed hash
         let commit = self.get_index_commit()?;
 
-        match self.git.notes_show(INDEX_NOTES_REF, &commit)? {
-            Some(content) => serde_yaml::from_str(&content).map_err(|e| Error::ParseError {
-                message: format!("Failed to parse index: {e}"),
-            }),
-            None => Ok(SearchIndex::new()),
+        let Some(content) = self.git.notes_show(&self.notes_ref, &commit)? else {
+            return Ok(SearchIndex::new());
+        };
+        let parse_error = |e: serde_yaml::Error| Error::ParseError {
+            message: format!("Failed to parse index: {e}"),
+        };
+
+        let StoredVersion { version } = serde_yaml::from_str(&content).map_err(parse_error)?;
+        if version < CURRENT_INDEX_VERSION {
+            tracing::info!(
+                "Rebuilding search index: format v{version} is older than v{}",
+                CURRENT_INDEX_VERSION
+            );
+            return self.rebuild(notes);
         }
+
+        serde_yaml::from_str(&content).map_err(parse_error)
     }
 
     /// Save the index to git notes.
@@ -145,7 +362,7 @@ impl IndexManager {
             message: format!("Failed to serialize index: {e}"),
         })?;
 
-        self.git.notes_add(INDEX_NOTES_REF, &commit, &content)?;
+        self.git.notes_add(&self.notes_ref, &commit, &content)?;
 
         Ok(())
     }
@@ -156,13 +373,7 @@ impl IndexManager {
     ///
     /// Returns an error if the index cannot be rebuilt.
     pub fn rebuild(&self, notes: &NotesManager) -> Result<SearchIndex, Error> {
-        let adrs = notes.list()?;
-        let mut index = SearchIndex::new();
-
-        for adr in &adrs {
-            index.upsert(IndexEntry::from_adr(adr));
-        }
-
+        let index = SearchIndex::from_adrs(&notes.list()?);
         self.save(&index)?;
 
         Ok(index)
@@ -173,11 +384,48 @@ impl IndexManager {
     /// # Errors
     ///
     /// Returns an error if the search fails.
-    pub fn search(&self, query: &str) -> Result<Vec<IndexEntry>, Error> {
-        let index = self.load()?;
+    pub fn search(&self, notes: &NotesManager, query: &str) -> Result<Vec<IndexEntry>, Error> {
+        let index = self.load(notes)?;
         Ok(index.search(query).into_iter().cloned().collect())
     }
 
+    /// Load the index, or build a fresh one in memory if it doesn't match
+    /// the ADRs in `notes`.
+    ///
+    /// The stored index is left alone, so read-only commands never write
+    /// notes; `git adr sync` and the commands that change ADRs keep it
+    /// up to date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be loaded or rebuilt.
+    pub fn load_current(&self, notes: &NotesManager) -> Result<SearchIndex, Error> {
+        let index = self.load(notes)?;
+        let adrs = notes.list()?;
+        if index.is_current(&adrs) {
+            return Ok(index);
+        }
+        Ok(SearchIndex::from_adrs(&adrs))
+    }
+
+    /// Rank ADRs by TF-IDF similarity to a query, most similar first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index cannot be loaded or rebuilt.
+    pub fn semantic_search(
+        &self,
+        notes: &NotesManager,
+        query: &str,
+    ) -> Result<Vec<(IndexEntry, f64)>, Error> {
+        let index = self.load_current(notes)?;
+        Ok(index
+            .semantic_search(query)
+            .into_iter()
+            .map(|(entry, score)| (entry.clone(), score))
+            .collect())
+    }
+
     /// Get the commit hash used to store the index.
     fn get_index_commit(&self) -> Result<String, Error> {
         // Try to get the first commit in the repository
@@ -219,6 +467,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec!["architecture".to_string()],
             text: "use rust for cli architecture".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         };
 
         assert!(entry.matches("rust"));
@@ -254,6 +504,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "use rust".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
         index.upsert(IndexEntry {
             id: "ADR-0002".to_string(),
@@ -262,6 +514,8 @@ mod tests {
             status: "accepted".to_string(),
             tags: vec![],
             text: "use python".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         assert_eq!(index.search("rust").len(), 1);
@@ -279,6 +533,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "use rust".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         assert_eq!(index.entries.len(), 1);
@@ -296,6 +552,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "first".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
         index.upsert(IndexEntry {
             id: "ADR-0002".to_string(),
@@ -304,6 +562,8 @@ mod tests {
             status: "accepted".to_string(),
             tags: vec![],
             text: "second".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         let all = index.all();
@@ -320,6 +580,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "original".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         index.upsert(IndexEntry {
@@ -329,6 +591,8 @@ mod tests {
             status: "accepted".to_string(),
             tags: vec![],
             text: "updated".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         assert_eq!(index.entries.len(), 1);
@@ -338,7 +602,7 @@ mod tests {
     #[test]
     fn test_search_index_new() {
         let index = SearchIndex::new();
-        assert_eq!(index.version, 1);
+        assert_eq!(index.version, CURRENT_INDEX_VERSION);
         assert!(index.entries.is_empty());
     }
 
@@ -358,6 +622,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "something else".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         };
         // Should match by ID
         assert!(entry.matches("ADR-0001"));
@@ -373,12 +639,167 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec![],
             text: "some text".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         };
         // Should match by title
         assert!(entry.matches("PostgreSQL"));
         assert!(entry.matches("POSTGRESQL"));
     }
 
+    #[test]
+    fn test_snippet_window() {
+        let pattern = Regex::new("(?i)redis").unwrap();
+        let snippet = Snippet::find(
+            "We chose\n\nRedis over Memcached for redis streams",
+            &pattern,
+            5,
+        )
+        .expect("Should find a match");
+        assert_eq!(snippet.text, "…hose Redis over…");
+        assert_eq!(snippet.matches, vec![8..13]);
+        assert_eq!(&snippet.text[8..13], "Redis");
+
+        let snippet = Snippet::find("Redis", &pattern, 10).expect("Should find a match");
+        assert_eq!(snippet.text, "Redis");
+        assert_eq!(snippet.matches, vec![0..5]);
+
+        assert!(Snippet::find("Postgres", &pattern, 10).is_none());
+    }
+
+    #[test]
+    fn test_index_entry_snippet() {
+        let entry = IndexEntry {
+            id: "ADR-0001".to_string(),
+            commit: "abc123".to_string(),
+            title: "Use Rust".to_string(),
+            status: "proposed".to_string(),
+            tags: vec![],
+            text: "use rust for the cli and rust for the server".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
+        };
+        let snippet = entry.snippet("RUST", 4).expect("Should find a match");
+        assert_eq!(snippet.text, "use rust for…");
+        assert_eq!(snippet.matches, vec![4..8]);
+        assert!(entry.snippet("python", 4).is_none());
+    }
+
+    #[test]
+    fn test_search_options_case_and_word() {
+        let mut adr = Adr::new("ADR-0001".to_string(), "Public API".to_string());
+        adr.body = "Steady growth of the REST API.".to_string();
+        let entry = IndexEntry::from_adr(&adr);
+
+        let search = |query: &str, options: SearchOptions| {
+            entry.is_match(&options.pattern(query).expect("Should compile"))
+        };
+        let exact = SearchOptions {
+            case_sensitive: true,
+            ..SearchOptions::default()
+        };
+        let word = SearchOptions {
+            whole_word: true,
+            ..SearchOptions::default()
+        };
+
+        assert!(search("api", SearchOptions::default()));
+        assert!(!search("api", exact));
+        assert!(search("API", exact));
+        assert!(!search("row", word));
+        assert!(search("growth", word));
+        assert!(search("Steady", exact));
+    }
+
+    #[test]
+    fn test_search_options_pattern() {
+        let word = SearchOptions {
+            whole_word: true,
+            ..SearchOptions::default()
+        };
+        assert!(word
+            .pattern("C++")
+            .unwrap()
+            .is_match("written in c++ mostly"));
+        assert!(!word.pattern("api").unwrap().is_match("capillary"));
+
+        let regex = SearchOptions {
+            regex: true,
+            whole_word: true,
+            ..SearchOptions::default()
+        };
+        assert!(regex.pattern("red|blue").unwrap().is_match("a blue box"));
+        assert!(!regex.pattern("red|blue").unwrap().is_match("bluest"));
+        assert!(regex.pattern("(").is_err());
+    }
+
+    #[test]
+    fn test_index_entry_from_old_index_without_content() {
+        let yaml = "id: ADR-0001\ncommit: abc123\ntitle: Use Rust\n\
+                    status: proposed\ntags: []\ntext: use rust\n";
+        let entry: IndexEntry = serde_yaml::from_str(yaml).expect("Should deserialize");
+        assert!(entry.content.is_empty());
+        assert!(entry.is_match(&SearchOptions::default().pattern("RUST").unwrap()));
+    }
+
+    fn adr(id: &str, title: &str, body: &str) -> Adr {
+        let mut adr = Adr::new(id.to_string(), title.to_string());
+        adr.body = body.to_string();
+        adr
+    }
+
+    #[test]
+    fn test_semantic_search_ranks_without_exact_terms() {
+        let mut index = SearchIndex::new();
+        for adr in [
+            adr(
+                "ADR-0001",
+                "Caching layer",
+                "We cache database queries in Redis to cut latency.",
+            ),
+            adr(
+                "ADR-0002",
+                "Frontend styling",
+                "Components are styled with Tailwind; the database is untouched.",
+            ),
+            adr("ADR-0003", "Logging", "Structured logs go to stdout."),
+        ] {
+            index.upsert(IndexEntry::from_adr(&adr));
+        }
+
+        // No entry contains the query text itself
+        let query = "cached databases";
+        assert!(index.search(query).is_empty());
+
+        let ranked = index.semantic_search(query);
+        let ids: Vec<&str> = ranked.iter().map(|(entry, _)| entry.id.as_str()).collect();
+        assert_eq!(ids, ["ADR-0001", "ADR-0002"]);
+        assert!(ranked[0].1 > ranked[1].1);
+    }
+
+    #[test]
+    fn test_semantic_search_no_overlap() {
+        let mut index = SearchIndex::new();
+        index.upsert(IndexEntry::from_adr(&adr("ADR-0001", "Logging", "Logs.")));
+        assert!(index.semantic_search("kubernetes").is_empty());
+        assert!(SearchIndex::new().semantic_search("anything").is_empty());
+    }
+
+    #[test]
+    fn test_search_index_is_current() {
+        let mut first = adr("ADR-0001", "Logging", "Structured logs.");
+        let mut index = SearchIndex::new();
+        index.upsert(IndexEntry::from_adr(&first));
+        assert!(index.is_current(std::slice::from_ref(&first)));
+
+        first.body = "Plain text logs.".to_string();
+        assert!(!index.is_current(std::slice::from_ref(&first)));
+
+        let second = adr("ADR-0002", "Metrics", "Prometheus.");
+        index.upsert(IndexEntry::from_adr(&first));
+        assert!(!index.is_current(&[first, second]));
+    }
+
     #[test]
     fn test_index_manager_new() {
         let git = Git::new();
@@ -396,6 +817,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec!["test".to_string()],
             text: "test".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
         let cloned = index.clone();
         assert_eq!(cloned.entries.len(), 1);
@@ -411,6 +834,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec!["test".to_string()],
             text: "test content".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         };
         let cloned = entry.clone();
         assert_eq!(cloned.id, entry.id);
@@ -429,6 +854,8 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec!["tag1".to_string()],
             text: "test".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         let yaml = serde_yaml::to_string(&index).expect("Should serialize");
@@ -482,10 +909,11 @@ mod tests {
     fn test_index_manager_load_empty() {
         let temp_dir = setup_git_repo();
         let git = Git::with_work_dir(temp_dir.path());
+        let notes = NotesManager::new(git.clone(), AdrConfig::default());
         let manager = IndexManager::new(git);
 
         // Load should return empty index when none exists
-        let index = manager.load().expect("Should load");
+        let index = manager.load(&notes).expect("Should load");
         assert!(index.entries.is_empty());
     }
 
@@ -493,6 +921,7 @@ mod tests {
     fn test_index_manager_save_and_load() {
         let temp_dir = setup_git_repo();
         let git = Git::with_work_dir(temp_dir.path());
+        let notes = NotesManager::new(git.clone(), AdrConfig::default());
         let manager = IndexManager::new(git);
 
         // Create and save an index
@@ -504,12 +933,14 @@ mod tests {
             status: "proposed".to_string(),
             tags: vec!["tag1".to_string()],
             text: "test".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
         });
 
         manager.save(&index).expect("Should save");
 
         // Load it back
-        let loaded = manager.load().expect("Should load");
+        let loaded = manager.load(&notes).expect("Should load");
         assert_eq!(loaded.entries.len(), 1);
         assert!(loaded.entries.contains_key("ADR-0001"));
     }
@@ -564,12 +995,12 @@ mod tests {
         index_manager.rebuild(&notes).expect("Should rebuild");
 
         // Search
-        let results = index_manager.search("Rust").expect("Should search");
+        let results = index_manager.search(&notes, "Rust").expect("Should search");
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].id, "ADR-0001");
 
         // Search for both
-        let results = index_manager.search("Use").expect("Should search");
+        let results = index_manager.search(&notes, "Use").expect("Should search");
         assert_eq!(results.len(), 2);
     }
 
@@ -577,12 +1008,72 @@ mod tests {
     fn test_index_manager_get_index_commit() {
         let temp_dir = setup_git_repo();
         let git = Git::with_work_dir(temp_dir.path());
+        let notes = NotesManager::new(git.clone(), AdrConfig::default());
         let manager = IndexManager::new(git);
 
         // get_index_commit is private, but we can test it indirectly via save/load
         let mut index = SearchIndex::new();
         manager.save(&index).expect("Should save");
-        index = manager.load().expect("Should load");
-        assert_eq!(index.version, 1);
+        index = manager.load(&notes).expect("Should load");
+        assert_eq!(index.version, CURRENT_INDEX_VERSION);
+    }
+
+    #[test]
+    fn test_index_manager_load_migrates_old_version() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let notes = NotesManager::new(git.clone(), AdrConfig::default());
+        let manager = IndexManager::new(git);
+
+        let adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+        notes.create(&adr).expect("Should create ADR");
+
+        // A v0 index with a stale entry and no `content` field
+        let mut stale = SearchIndex::default();
+        stale.upsert(IndexEntry {
+            id: "ADR-0099".to_string(),
+            commit: "abc123".to_string(),
+            title: "Gone".to_string(),
+            status: "proposed".to_string(),
+            tags: vec![],
+            text: "gone".to_string(),
+            content: String::new(),
+            terms: HashMap::new(),
+        });
+        manager.save(&stale).expect("Should save");
+
+        let index = manager.load(&notes).expect("Should load");
+        assert_eq!(index.version, CURRENT_INDEX_VERSION);
+        assert_eq!(index.entries.len(), 1);
+        assert!(index.entries["ADR-0001"].content.starts_with("Use Rust"));
+
+        // The upgraded index was saved, so it loads as-is next time
+        let reloaded = manager.load(&notes).expect("Should load");
+        assert_eq!(reloaded.version, CURRENT_INDEX_VERSION);
+        assert!(reloaded.entries.contains_key("ADR-0001"));
+    }
+
+    #[test]
+    fn test_index_manager_semantic_search_rebuilds_stale_index() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let notes = NotesManager::new(git.clone(), AdrConfig::default());
+        let manager = IndexManager::new(git);
+
+        // An empty index is saved before any ADR exists
+        manager.rebuild(&notes).expect("Should rebuild");
+        let mut adr = Adr::new("ADR-0001".to_string(), "Cache queries".to_string());
+        adr.body = "Cache database queries.".to_string();
+        notes.create(&adr).expect("Should create ADR");
+
+        let ranked = manager
+            .semantic_search(&notes, "caching")
+            .expect("Should search");
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].0.id, "ADR-0001");
+
+        // Searching doesn't write the fresh index back
+        let stored = manager.load(&notes).expect("Should load");
+        assert!(stored.entries.is_empty());
     }
 }
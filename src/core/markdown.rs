@@ -0,0 +1,22 @@
+//! Markdown rendering shared by the HTML outputs.
+
+use pulldown_cmark::{Event, Options, Parser};
+
+/// Render an ADR body as HTML.
+///
+/// Uses a CommonMark parser with GFM tables, strikethrough and task lists.
+/// Raw HTML embedded in the body is escaped, not passed through, and an
+/// unclosed code fence runs to the end of the document and is closed.
+#[must_use]
+pub fn markdown_to_html(content: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(content, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        other => other,
+    });
+
+    let mut output = String::new();
+    pulldown_cmark::html::push_html(&mut output, parser);
+    output
+}
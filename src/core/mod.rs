@@ -7,17 +7,40 @@
 //! - [`IndexManager`] - Search index operations
 //! - [`ConfigManager`] - Configuration management
 //! - [`TemplateEngine`] - Template rendering
+//! - [`open_in_editor`] - Interactive editing in the user's editor
+//! - [`Artifact`] - Files attached to ADRs
+//! - [`graph_edges`] - Relationship graph between ADRs (DOT and Mermaid rendering)
+//! - [`markdown_to_html`] - HTML rendering of ADR bodies
+//! - [`rank_by_terms`] - TF-IDF similarity for semantic search and link suggestions
 
 mod adr;
+mod artifact;
 mod config;
+mod editor;
 mod git;
+mod graph;
 mod index;
+mod markdown;
 mod notes;
+mod similarity;
 mod templates;
 
-pub use adr::{Adr, AdrStatus, FlexibleDate};
-pub use config::{AdrConfig, ConfigManager};
-pub use git::Git;
-pub use index::IndexManager;
-pub use notes::{NotesManager, ADR_NOTES_REF, ARTIFACTS_NOTES_REF};
-pub use templates::TemplateEngine;
+pub use adr::{
+    parse_date_filter, Adr, AdrFrontmatter, AdrJson, AdrLink, AdrStatus, DateWindow, FlexibleDate,
+    WORDS_PER_MINUTE,
+};
+pub use artifact::{Artifact, ArtifactCheck};
+pub use config::{
+    split_list, AdrConfig, ConfigManager, IdScheme, ProjectConfig, PROJECT_CONFIG_FILE,
+};
+pub use editor::open_in_editor;
+pub use git::{Git, NoteRevision};
+pub use graph::{dot_graph, graph_edges, mermaid_graph};
+pub use index::{IndexManager, SearchOptions, Snippet};
+pub use markdown::markdown_to_html;
+pub use notes::{
+    decision_lead_time, LinkIssue, LinkIssueKind, MetadataIssue, MetadataIssueKind, NotesManager,
+    SectionIssue, SectionIssueKind, ADR_NOTES_REF, ARTIFACTS_NOTES_REF,
+};
+pub use similarity::{rank_by_terms, term_counts};
+pub use templates::{TemplateEngine, BUILTIN_TEMPLATES};
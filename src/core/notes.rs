@@ -3,14 +3,144 @@
 //! This module provides the `NotesManager` which handles CRUD operations
 //! for ADRs stored in git notes.
 
-use crate::core::{Adr, AdrConfig, Git};
+use crate::core::{
+    Adr, AdrConfig, AdrStatus, Artifact, Git, IdScheme, NoteRevision, TemplateEngine,
+};
 use crate::Error;
-
-/// Notes reference for ADR content.
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Default notes reference for ADR content.
+///
+/// The ref actually used comes from [`AdrConfig::notes_ref`].
 pub const ADR_NOTES_REF: &str = "adr";
-/// Notes reference for artifacts.
+/// Default notes reference for artifacts.
+///
+/// The ref actually used comes from [`AdrConfig::artifacts_ref`].
 pub const ARTIFACTS_NOTES_REF: &str = "adr-artifacts";
 
+/// Custom frontmatter field recording the status an archived ADR had.
+const ARCHIVED_FROM_FIELD: &str = "archived_from";
+
+/// Cached mapping from ADR ID to the commit carrying its note.
+///
+/// Stored in the git directory and tied to the notes ref tip it was built
+/// from, so any write to the notes ref invalidates it.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IdMap {
+    /// Notes ref commit the map was built from.
+    tip: String,
+    /// ADR ID to annotated commit.
+    ids: HashMap<String, String>,
+}
+
+/// Kind of problem found in an ADR link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkIssueKind {
+    /// The link points at an ADR that does not exist.
+    Dangling,
+    /// The target ADR does not point back at the source.
+    Asymmetric,
+}
+
+impl std::fmt::Display for LinkIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dangling => write!(f, "dangling"),
+            Self::Asymmetric => write!(f, "asymmetric"),
+        }
+    }
+}
+
+/// A problem found while validating links between ADRs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkIssue {
+    /// ADR containing the link.
+    pub adr_id: String,
+    /// Frontmatter field holding the link (`links`, `supersedes`, `superseded_by`).
+    pub field: String,
+    /// ADR ID the link points at.
+    pub target: String,
+    /// Kind of problem.
+    pub kind: LinkIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kind of problem found in a required ADR section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionIssueKind {
+    /// The section is absent or empty.
+    Missing,
+    /// The section still holds the template's placeholder text.
+    Placeholder,
+}
+
+impl std::fmt::Display for SectionIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing"),
+            Self::Placeholder => write!(f, "placeholder"),
+        }
+    }
+}
+
+/// A required section of an ADR that hasn't been filled in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SectionIssue {
+    /// ADR with the incomplete section.
+    pub adr_id: String,
+    /// Section heading, as the ADR's format names it.
+    pub section: String,
+    /// Kind of problem.
+    pub kind: SectionIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kind of problem found in an ADR's metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetadataIssueKind {
+    /// The note can't be parsed, e.g. because of an unknown status.
+    Invalid,
+    /// The title is empty.
+    MissingTitle,
+    /// The ADR has no date.
+    MissingDate,
+    /// The status is superseded but `superseded_by` is not set.
+    MissingSupersededBy,
+    /// Another ADR has the same ID.
+    DuplicateId,
+}
+
+impl std::fmt::Display for MetadataIssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid => write!(f, "invalid"),
+            Self::MissingTitle => write!(f, "missing-title"),
+            Self::MissingDate => write!(f, "missing-date"),
+            Self::MissingSupersededBy => write!(f, "missing-superseded-by"),
+            Self::DuplicateId => write!(f, "duplicate-id"),
+        }
+    }
+}
+
+/// A problem found in an ADR's frontmatter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MetadataIssue {
+    /// ADR with the problem.
+    pub adr_id: String,
+    /// Kind of problem.
+    pub kind: MetadataIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
 /// Manager for ADR operations in git notes.
 #[derive(Debug)]
 pub struct NotesManager {
@@ -43,11 +173,43 @@ impl NotesManager {
     ///
     /// Returns an error if ADRs cannot be listed.
     pub fn list(&self) -> Result<Vec<Adr>, Error> {
-        let notes = self.git.notes_list(ADR_NOTES_REF)?;
+        let adrs = self.read_ref(self.config.notes_ref())?;
+
+        // Refresh the ID map; it is only a cache, so failures are ignored
+        if let Some(tip) = self.notes_tip() {
+            let mut map = IdMap {
+                tip,
+                ids: HashMap::new(),
+            };
+            for adr in &adrs {
+                map.ids
+                    .entry(adr.id.clone())
+                    .or_insert_with(|| adr.commit.clone());
+            }
+            if let (Ok(path), Ok(json)) = (self.id_map_path(), serde_json::to_string(&map)) {
+                let _ = std::fs::write(path, json);
+            }
+        }
+
+        Ok(adrs)
+    }
+
+    /// List ADRs moved to the archive ref by [`archive`](Self::archive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs cannot be listed.
+    pub fn list_archived(&self) -> Result<Vec<Adr>, Error> {
+        self.read_ref(&self.config.archive_ref())
+    }
+
+    /// Read every ADR note in `notes_ref`, sorted by ID.
+    fn read_ref(&self, notes_ref: &str) -> Result<Vec<Adr>, Error> {
+        let notes = self.git.notes_list(notes_ref)?;
         let mut adrs = Vec::new();
 
         for (note_hash, commit) in notes {
-            if let Some(content) = self.git.notes_show(ADR_NOTES_REF, &commit)? {
+            if let Some(content) = self.git.notes_show(notes_ref, &commit)? {
                 // Extract ADR ID from the content or generate from commit
                 let id = self.extract_id(&content, &commit)?;
                 if let Ok(adr) = Adr::from_markdown(id, commit.clone(), &content) {
@@ -59,81 +221,996 @@ impl NotesManager {
 
         // Sort by ID
         adrs.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(adrs)
+    }
 
+    /// Current commit of the notes ref, or `None` if it does not exist.
+    fn notes_tip(&self) -> Option<String> {
+        let notes_ref = format!("refs/notes/{}", self.config.notes_ref());
+        self.git
+            .run_output(&["rev-parse", "--verify", "--quiet", &notes_ref])
+            .ok()
+            .map(|tip| tip.trim().to_string())
+    }
+
+    /// Location of the ID map cache for this namespace.
+    fn id_map_path(&self) -> Result<PathBuf, Error> {
+        Ok(self
+            .git
+            .git_dir()?
+            .join(format!("{}-ids.json", self.config.notes_ref())))
+    }
+
+    /// Look up an ADR through the ID map without scanning every note.
+    ///
+    /// Returns `None` when the map is missing, stale, or does not lead to
+    /// the requested ADR, so the caller can fall back to a full scan.
+    fn get_cached(&self, id: &str) -> Option<Adr> {
+        let json = std::fs::read_to_string(self.id_map_path().ok()?).ok()?;
+        let map: IdMap = serde_json::from_str(&json).ok()?;
+        if Some(&map.tip) != self.notes_tip().as_ref() {
+            return None;
+        }
+
+        let commit = map.ids.get(id)?;
+        let content = self
+            .git
+            .notes_show(self.config.notes_ref(), commit)
+            .ok()??;
+        let adr_id = self.extract_id(&content, commit).ok()?;
+        if adr_id != id {
+            return None;
+        }
+        Adr::from_markdown(adr_id, commit.clone(), &content).ok()
+    }
+
+    /// List all ADRs with note history metadata.
+    ///
+    /// Like [`list`](Self::list), but also fills in `last_modified` and
+    /// `note_author` on each ADR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs or their history cannot be read.
+    pub fn list_with_history(&self) -> Result<Vec<Adr>, Error> {
+        let mut adrs = self.list()?;
+        self.load_history(&mut adrs)?;
         Ok(adrs)
     }
 
+    /// Fill in the `last_modified` and `note_author` of ADRs from the history
+    /// of the notes ref, read in a single walk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes history cannot be read.
+    pub fn load_history(&self, adrs: &mut [Adr]) -> Result<(), Error> {
+        let history = self.git.notes_history(self.config.notes_ref())?;
+        for adr in adrs {
+            if let Some(latest) = history
+                .get(&adr.commit)
+                .and_then(|revisions| revisions.first())
+            {
+                adr.note_author = Some(latest.author.clone());
+                adr.last_modified = Some(latest.date);
+            }
+        }
+        Ok(())
+    }
+
+    /// Revisions of an ADR's note, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes history cannot be read.
+    pub fn history(&self, adr: &Adr) -> Result<Vec<NoteRevision>, Error> {
+        self.git.notes_log(self.config.notes_ref(), &adr.commit)
+    }
+
+    /// Status of an ADR at each revision of its note, oldest first.
+    ///
+    /// Revisions that removed the note or can't be parsed are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes history cannot be read.
+    pub fn status_history(&self, adr: &Adr) -> Result<Vec<(DateTime<Utc>, AdrStatus)>, Error> {
+        let mut statuses = Vec::new();
+        for revision in self.history(adr)?.into_iter().rev() {
+            let Some(blob) = revision.blob else {
+                continue;
+            };
+            let content = self.git.blob_content(&blob)?;
+            if let Ok(parsed) = Adr::from_markdown(adr.id.clone(), adr.commit.clone(), &content) {
+                statuses.push((revision.date, parsed.frontmatter.status));
+            }
+        }
+        Ok(statuses)
+    }
+
+    /// Find one revision of an ADR's note.
+    ///
+    /// `rev` is either `~N`, counting back from the latest revision (`~0`),
+    /// or a prefix of the notes commit that wrote the revision.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history cannot be read or `rev` matches no
+    /// revision, or more than one.
+    pub fn revision(&self, adr: &Adr, rev: &str) -> Result<NoteRevision, Error> {
+        let mut revisions = self.history(adr)?;
+
+        if let Some(back) = rev.strip_prefix('~') {
+            let back: usize = back
+                .parse()
+                .map_err(|_| Error::validation(format!("invalid revision '{rev}'")))?;
+            if back >= revisions.len() {
+                return Err(Error::validation(format!(
+                    "{} has only {} revision(s)",
+                    adr.id,
+                    revisions.len()
+                )));
+            }
+            return Ok(revisions.swap_remove(back));
+        }
+
+        let mut matches = revisions
+            .into_iter()
+            .filter(|r| r.notes_commit.starts_with(rev));
+        match (matches.next(), matches.next()) {
+            (Some(revision), None) if !rev.is_empty() => Ok(revision),
+            (Some(_), Some(_)) => Err(Error::validation(format!(
+                "revision '{rev}' is ambiguous for {}",
+                adr.id
+            ))),
+            _ => Err(Error::validation(format!(
+                "no revision '{rev}' in the history of {}",
+                adr.id
+            ))),
+        }
+    }
+
     /// Get an ADR by ID.
     ///
+    /// Resolves the ID through a cached ID-to-commit map when it is current,
+    /// reading a single note; otherwise scans all notes and rebuilds the map.
+    ///
     /// # Errors
     ///
     /// Returns an error if the ADR is not found or cannot be read.
     pub fn get(&self, id: &str) -> Result<Adr, Error> {
+        if let Some(adr) = self.get_cached(id) {
+            return Ok(adr);
+        }
+
         let adrs = self.list()?;
         adrs.into_iter()
             .find(|adr| adr.id == id)
             .ok_or_else(|| Error::AdrNotFound { id: id.to_string() })
     }
 
+    /// Resolve user input to exactly one ADR.
+    ///
+    /// Accepts the same forms as [`resolve_id`](Self::resolve_id); this is
+    /// what commands taking an ADR ID should use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AdrNotFound`] if nothing matches, or a validation
+    /// error listing the candidates if the input matches more than one ADR.
+    pub fn resolve(&self, input: &str) -> Result<Adr, Error> {
+        let input = input.trim();
+        if let Some(adr) = self.get_cached(input) {
+            return Ok(adr);
+        }
+
+        let adrs = self.list()?;
+        let ids: Vec<String> = adrs.iter().map(|adr| adr.id.clone()).collect();
+        let id = self.match_id(input, &ids)?;
+        adrs.into_iter()
+            .find(|adr| adr.id == id)
+            .ok_or(Error::AdrNotFound { id })
+    }
+
+    /// Resolve user input to a canonical ADR ID.
+    ///
+    /// Accepts the full ID (`ADR-0001`), the zero-padded number (`0001`),
+    /// the bare number (`1`), or a unique substring of an ID such as part of
+    /// a slug. Prefixes and substrings are matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AdrNotFound`] if nothing matches, or a validation
+    /// error listing the candidates if the input matches more than one ADR.
+    pub fn resolve_id(&self, input: &str) -> Result<String, Error> {
+        let input = input.trim();
+        if self.get_cached(input).is_some() {
+            return Ok(input.to_string());
+        }
+
+        let ids: Vec<String> = self.list()?.into_iter().map(|adr| adr.id).collect();
+        self.match_id(input, &ids)
+    }
+
+    /// Resolve user input to the ID of an archived ADR.
+    ///
+    /// Accepts the same forms as [`resolve_id`](Self::resolve_id).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AdrNotFound`] if no archived ADR matches, or a
+    /// validation error if the input matches more than one.
+    pub fn resolve_archived_id(&self, input: &str) -> Result<String, Error> {
+        let ids: Vec<String> = self
+            .list_archived()?
+            .into_iter()
+            .map(|adr| adr.id)
+            .collect();
+        self.match_id(input.trim(), &ids)
+    }
+
+    /// Match user input against a set of ADR IDs.
+    fn match_id(&self, input: &str, ids: &[String]) -> Result<String, Error> {
+        if ids.iter().any(|id| id == input) {
+            return Ok(input.to_string());
+        }
+        if let Some(id) = ids.iter().find(|id| id.eq_ignore_ascii_case(input)) {
+            return Ok(id.clone());
+        }
+
+        let number = |s: &str| -> Option<u32> {
+            let prefix = &self.config.prefix;
+            let digits = match s.get(..prefix.len()) {
+                Some(head) if head.eq_ignore_ascii_case(prefix) => &s[prefix.len()..],
+                _ => s,
+            };
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok()
+        };
+
+        let mut matches: Vec<&String> = match number(input) {
+            Some(n) => ids.iter().filter(|id| number(id) == Some(n)).collect(),
+            None => Vec::new(),
+        };
+        if matches.is_empty() {
+            let needle = input.to_lowercase();
+            matches = ids
+                .iter()
+                .filter(|id| id.to_lowercase().contains(&needle))
+                .collect();
+        }
+
+        match matches.as_slice() {
+            [] => Err(Error::AdrNotFound {
+                id: input.to_string(),
+            }),
+            [id] => Ok((*id).clone()),
+            candidates => Err(Error::validation(format!(
+                "ambiguous ADR ID '{}': matches {}",
+                input,
+                candidates
+                    .iter()
+                    .map(|id| id.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
     /// Get an ADR by commit hash.
     ///
     /// # Errors
     ///
     /// Returns an error if the ADR is not found.
     pub fn get_by_commit(&self, commit: &str) -> Result<Adr, Error> {
-        let content =
-            self.git
-                .notes_show(ADR_NOTES_REF, commit)?
-                .ok_or_else(|| Error::AdrNotFound {
-                    id: commit.to_string(),
-                })?;
+        let content = self
+            .git
+            .notes_show(self.config.notes_ref(), commit)?
+            .ok_or_else(|| Error::AdrNotFound {
+                id: commit.to_string(),
+            })?;
+
+        let id = self.extract_id(&content, commit)?;
+        Adr::from_markdown(id, commit.to_string(), &content)
+    }
+
+    /// Create a new ADR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR cannot be created.
+    pub fn create(&self, adr: &Adr) -> Result<(), Error> {
+        let commit = if adr.commit.is_empty() {
+            self.git.head()?
+        } else {
+            adr.commit.clone()
+        };
+
+        let content = adr.to_markdown()?;
+        self.write_note(&commit, &content)
+    }
+
+    /// Point a new ADR at a commit no other ADR is attached to.
+    ///
+    /// `adr.commit` defaults to `HEAD`. Notes hold one ADR per commit, so if
+    /// that commit already carries one, the ADR moves to a fresh empty commit
+    /// (see [`Git::commit_empty`]) instead of replacing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes cannot be read or the commit cannot be
+    /// created.
+    pub fn claim_commit(&self, adr: &mut Adr) -> Result<(), Error> {
+        if adr.commit.is_empty() {
+            adr.commit = self.git.head()?;
+        }
+        if self
+            .git
+            .notes_show(self.config.notes_ref(), &adr.commit)?
+            .is_some()
+        {
+            let message = format!("{}: {}", adr.id, adr.frontmatter.title);
+            adr.commit = self.git.commit_empty(&message)?;
+        }
+        Ok(())
+    }
+
+    /// Update an existing ADR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR cannot be updated.
+    pub fn update(&self, adr: &Adr) -> Result<(), Error> {
+        // Verify ADR exists
+        let _ = self.get(&adr.id)?;
+
+        let content = adr.to_markdown()?;
+        self.write_note(&adr.commit, &content)
+    }
+
+    /// Update an ADR that [`archive`](Self::archive) moved to the archive ref.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AdrNotFound`] if the ADR is not in the archive ref.
+    pub fn update_archived(&self, adr: &Adr) -> Result<(), Error> {
+        let archive_ref = self.config.archive_ref();
+        if self.git.notes_show(&archive_ref, &adr.commit)?.is_none() {
+            return Err(Error::adr_not_found(&adr.id));
+        }
+
+        self.git
+            .notes_add(&archive_ref, &adr.commit, &adr.to_markdown()?)
+    }
+
+    /// Write an ADR note, signing it when `adr.sign` is enabled.
+    ///
+    /// The notes commit that writes the note is signed, and a detached
+    /// signature over the note exactly as git stored it is kept in the
+    /// signatures ref on the same commit. If signing fails, the previous
+    /// note is restored.
+    fn write_note(&self, commit: &str, content: &str) -> Result<(), Error> {
+        let notes_ref = self.config.notes_ref();
+        if !self.config.sign {
+            return self.git.notes_add(notes_ref, commit, content);
+        }
+
+        let previous = self.git.notes_show(notes_ref, commit)?;
+        let signed = self
+            .git
+            .notes_add_signed(notes_ref, commit, content)
+            .and_then(|()| {
+                let stored = self.git.notes_show(notes_ref, commit)?.unwrap_or_default();
+                self.git.sign(&stored)
+            });
+
+        match signed {
+            Ok(signature) => {
+                self.git
+                    .notes_add(&self.config.signatures_ref(), commit, &signature)
+            },
+            Err(e) => {
+                let _ = match previous {
+                    Some(previous) => self.git.notes_add(notes_ref, commit, &previous),
+                    None => self.git.notes_remove(notes_ref, commit),
+                };
+                Err(e)
+            },
+        }
+    }
+
+    /// Verify the GPG signature of an ADR note.
+    ///
+    /// Returns the signer's user ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the ADR has no signature or the
+    /// signature does not match its content.
+    pub fn verify(&self, id: &str) -> Result<String, Error> {
+        let adr = self.get(id)?;
+        let content = self
+            .git
+            .notes_show(self.config.notes_ref(), &adr.commit)?
+            .ok_or_else(|| Error::adr_not_found(id))?;
+        let signature = self
+            .git
+            .notes_show(&self.config.signatures_ref(), &adr.commit)?
+            .ok_or_else(|| Error::signing(format!("{} is not signed", adr.id)))?;
+
+        self.git.verify_signature(&content, &signature)
+    }
+
+    /// Verify the signature on the notes commit that last wrote an ADR.
+    ///
+    /// Returns the signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if that commit is unsigned or its
+    /// signature is bad or can't be checked.
+    pub fn verify_commit(&self, id: &str) -> Result<String, Error> {
+        let adr = self.get(id)?;
+        let latest = self
+            .history(&adr)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::adr_not_found(id))?;
+
+        self.git
+            .verify_notes_commit(&latest.notes_commit)?
+            .ok_or_else(|| Error::signing(format!("{} is not signed", adr.id)))
+    }
+
+    /// Get the artifacts attached to an ADR, in the order they were attached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifact note cannot be read or parsed.
+    pub fn artifacts(&self, adr: &Adr) -> Result<Vec<Artifact>, Error> {
+        self.git
+            .notes_show(&self.config.artifacts_ref(), &adr.commit)?
+            .map_or_else(
+                || Ok(Vec::new()),
+                |content| Artifact::list_from_json(&content),
+            )
+    }
+
+    /// Attach `artifact` to an ADR, replacing an artifact with the same
+    /// filename and keeping the others.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifact note cannot be read or written.
+    pub fn add_artifact(&self, adr: &Adr, artifact: Artifact) -> Result<(), Error> {
+        let mut artifacts = self.artifacts(adr)?;
+        match artifacts
+            .iter_mut()
+            .find(|existing| existing.filename == artifact.filename)
+        {
+            Some(existing) => *existing = artifact,
+            None => artifacts.push(artifact),
+        }
+        self.git.notes_add(
+            &self.config.artifacts_ref(),
+            &adr.commit,
+            &Artifact::list_to_json(&artifacts)?,
+        )
+    }
+
+    /// Remove the artifact named `name` from an ADR and return it.
+    ///
+    /// The artifact note itself is removed along with the last artifact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if the ADR has no artifact by that name,
+    /// or an error if the artifact note cannot be read or written.
+    pub fn remove_artifact(&self, adr: &Adr, name: &str) -> Result<Artifact, Error> {
+        let mut artifacts = self.artifacts(adr)?;
+        let Some(index) = artifacts.iter().position(|a| a.filename == name) else {
+            return Err(Error::validation(format!(
+                "no artifact named {name} on ADR {}",
+                adr.id
+            )));
+        };
+        let removed = artifacts.remove(index);
+
+        let artifacts_ref = self.config.artifacts_ref();
+        if artifacts.is_empty() {
+            self.git.notes_remove(&artifacts_ref, &adr.commit)?;
+        } else {
+            self.git.notes_add(
+                &artifacts_ref,
+                &adr.commit,
+                &Artifact::list_to_json(&artifacts)?,
+            )?;
+        }
+        Ok(removed)
+    }
+
+    /// Supersede an existing ADR with a new one.
+    ///
+    /// Writes `new_adr` with `supersedes` pointing at the old ADR, then marks
+    /// the old ADR as superseded with `superseded_by` pointing back. If the
+    /// old ADR cannot be updated, the new note is rolled back so neither
+    /// side is left half-linked.
+    ///
+    /// Returns the updated `(old, new)` pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the old ADR is not found, both ADRs would share
+    /// a commit, or either note cannot be written.
+    pub fn supersede(&self, old_id: &str, new_adr: &Adr) -> Result<(Adr, Adr), Error> {
+        let mut old_adr = self.get(old_id)?;
+
+        let mut new_adr = new_adr.clone();
+        if new_adr.commit.is_empty() {
+            new_adr.commit = self.git.head()?;
+        }
+        if new_adr.commit == old_adr.commit {
+            return Err(Error::validation(format!(
+                "{} and {} cannot be attached to the same commit ({})",
+                old_adr.id, new_adr.id, new_adr.commit
+            )));
+        }
+
+        new_adr.frontmatter.supersedes = Some(old_adr.id.clone());
+        old_adr.frontmatter.status = AdrStatus::Superseded;
+        old_adr.frontmatter.superseded_by = Some(new_adr.id.clone());
+
+        // Remember what was on the new commit so it can be restored
+        let previous = self
+            .git
+            .notes_show(self.config.notes_ref(), &new_adr.commit)?;
+
+        self.create(&new_adr)?;
+
+        let old_content = old_adr.to_markdown()?;
+        if let Err(e) = self.write_note(&old_adr.commit, &old_content) {
+            let rollback = match &previous {
+                Some(content) => self.write_note(&new_adr.commit, content),
+                None => self
+                    .git
+                    .notes_remove(self.config.notes_ref(), &new_adr.commit),
+            };
+            return Err(match rollback {
+                Ok(()) => e,
+                Err(rollback_err) => Error::Other(format!(
+                    "failed to update {}: {e}; rollback of {} also failed: {rollback_err}",
+                    old_adr.id, new_adr.id
+                )),
+            });
+        }
+
+        Ok((old_adr, new_adr))
+    }
+
+    /// Delete an ADR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR cannot be deleted.
+    pub fn delete(&self, id: &str) -> Result<(), Error> {
+        let adr = self.get(id)?;
+        self.git
+            .notes_remove(self.config.notes_ref(), &adr.commit)?;
+        // Not every ADR is signed
+        let _ = self
+            .git
+            .notes_remove(&self.config.signatures_ref(), &adr.commit);
+        Ok(())
+    }
+
+    /// Archive an ADR instead of deleting it.
+    ///
+    /// The note moves to the archive ref (`<namespace>-archive`), which hides
+    /// it from [`list`](Self::list) while keeping it recoverable with
+    /// [`restore`](Self::restore). The archived copy has status `archived`
+    /// and remembers its previous status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR is not found, already archived, another
+    /// archived ADR is on the same commit, or the notes cannot be written.
+    pub fn archive(&self, id: &str) -> Result<Adr, Error> {
+        let mut adr = self.get(id)?;
+        if adr.frontmatter.status == AdrStatus::Archived {
+            return Err(Error::validation(format!("{id} is already archived")));
+        }
+
+        let archive_ref = self.config.archive_ref();
+        if let Some(content) = self.git.notes_show(&archive_ref, &adr.commit)? {
+            let other = self.extract_id(&content, &adr.commit)?;
+            return Err(Error::validation(format!(
+                "cannot archive {id}: archived {other} is on the same commit; restore it first"
+            )));
+        }
+
+        adr.frontmatter.custom.insert(
+            ARCHIVED_FROM_FIELD.to_string(),
+            serde_yaml::Value::String(adr.frontmatter.status.to_string()),
+        );
+        adr.frontmatter.status = AdrStatus::Archived;
+
+        // Copy before removing so a failure never loses the ADR
+        self.git
+            .notes_add(&archive_ref, &adr.commit, &adr.to_markdown()?)?;
+        self.git
+            .notes_remove(self.config.notes_ref(), &adr.commit)?;
+        // Not every ADR is signed
+        let _ = self
+            .git
+            .notes_remove(&self.config.signatures_ref(), &adr.commit);
+
+        Ok(adr)
+    }
+
+    /// Restore an archived ADR to the status it had before archiving.
+    ///
+    /// The note moves from the archive ref back to the ADR ref. ADRs
+    /// archived without a recorded status come back as `proposed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ADR is not found, not archived, its commit
+    /// now carries another ADR, or the notes cannot be written.
+    pub fn restore(&self, id: &str) -> Result<Adr, Error> {
+        let archived = self.list_archived()?.into_iter().find(|adr| adr.id == id);
+        let Some(mut adr) = archived else {
+            // Report a missing ADR as such rather than as not archived
+            self.get(id)?;
+            return Err(Error::validation(format!("{id} is not archived")));
+        };
+
+        if let Some(content) = self.git.notes_show(self.config.notes_ref(), &adr.commit)? {
+            let other = self.extract_id(&content, &adr.commit)?;
+            return Err(Error::validation(format!(
+                "cannot restore {id}: its commit now carries {other}"
+            )));
+        }
+
+        Self::unarchive(&mut adr);
+        self.write_note(&adr.commit, &adr.to_markdown()?)?;
+        self.git
+            .notes_remove(&self.config.archive_ref(), &adr.commit)?;
+
+        Ok(adr)
+    }
+
+    /// Put an archived ADR back in the status recorded when it was archived.
+    fn unarchive(adr: &mut Adr) {
+        adr.frontmatter.status = adr
+            .frontmatter
+            .custom
+            .remove(ARCHIVED_FROM_FIELD)
+            .and_then(|v| v.as_str().and_then(|s| s.parse().ok()))
+            .unwrap_or_default();
+    }
+
+    /// How many ADRs use each tag.
+    ///
+    /// Tags that differ only in case are counted together, under the first
+    /// spelling seen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs cannot be listed.
+    pub fn tag_counts(&self) -> Result<BTreeMap<String, usize>, Error> {
+        // Lowercased tag -> (first spelling seen, ADR count)
+        let mut groups: HashMap<String, (String, usize)> = HashMap::new();
+        for adr in self.list()? {
+            let mut seen = HashSet::new();
+            for tag in adr.frontmatter.tags {
+                let key = tag.to_lowercase();
+                if seen.insert(key.clone()) {
+                    groups.entry(key).or_insert((tag, 0)).1 += 1;
+                }
+            }
+        }
+        Ok(groups.into_values().collect())
+    }
+
+    /// Rename a tag on every ADR that has it.
+    ///
+    /// Returns how many ADRs changed. See [`merge_tags`](Self::merge_tags).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `new` is empty or a note cannot be written.
+    pub fn rename_tag(&self, old: &str, new: &str) -> Result<usize, Error> {
+        self.merge_tags(&[old], new)
+    }
+
+    /// Replace each of `tags` with `into` on every ADR that has them.
+    ///
+    /// Tags match case-insensitively. An ADR that ends up with `into` more
+    /// than once keeps only the first. Returns how many ADRs changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `into` is empty or a note cannot be written.
+    pub fn merge_tags(&self, tags: &[&str], into: &str) -> Result<usize, Error> {
+        let into = into.trim();
+        if into.is_empty() {
+            return Err(Error::validation("tag cannot be empty"));
+        }
+
+        let mut changed = 0;
+        for mut adr in self.list()? {
+            let mut merged: Vec<String> = Vec::with_capacity(adr.frontmatter.tags.len());
+            for tag in &adr.frontmatter.tags {
+                let tag = if tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                    into
+                } else {
+                    tag.as_str()
+                };
+                let duplicate = tag.eq_ignore_ascii_case(into)
+                    && merged.iter().any(|t| t.eq_ignore_ascii_case(into));
+                if !duplicate {
+                    merged.push(tag.to_string());
+                }
+            }
+            if merged == adr.frontmatter.tags {
+                continue;
+            }
+
+            adr.frontmatter.tags = merged;
+            self.write_note(&adr.commit, &adr.to_markdown()?)?;
+            changed += 1;
+        }
+        Ok(changed)
+    }
+
+    /// Check every ADR link against the set of existing ADRs.
+    ///
+    /// Reports `links` targets, `supersedes` and `superseded_by` values that
+    /// point at missing ADRs, and supersession links whose target does not
+    /// point back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs cannot be listed.
+    pub fn validate_links(&self) -> Result<Vec<LinkIssue>, Error> {
+        let adrs = self.list()?;
+        // Archived ADRs still exist, so links to them are not dangling
+        let archived = self.list_archived()?;
+        let by_id: HashMap<&str, &Adr> = adrs
+            .iter()
+            .chain(&archived)
+            .map(|a| (a.id.as_str(), a))
+            .collect();
+        let mut issues = Vec::new();
+
+        let dangling = |adr: &Adr, field: &str, target: &str| LinkIssue {
+            adr_id: adr.id.clone(),
+            field: field.to_string(),
+            target: target.to_string(),
+            kind: LinkIssueKind::Dangling,
+            message: format!("{} {} {}, which does not exist", adr.id, field, target),
+        };
+
+        for adr in &adrs {
+            for link in &adr.frontmatter.links {
+                if !by_id.contains_key(link.target.as_str()) {
+                    issues.push(dangling(adr, "links", &link.target));
+                }
+            }
+
+            if let Some(target) = &adr.frontmatter.supersedes {
+                match by_id.get(target.as_str()) {
+                    None => issues.push(dangling(adr, "supersedes", target)),
+                    Some(other) if other.frontmatter.superseded_by.as_ref() != Some(&adr.id) => {
+                        issues.push(LinkIssue {
+                            adr_id: adr.id.clone(),
+                            field: "supersedes".to_string(),
+                            target: target.clone(),
+                            kind: LinkIssueKind::Asymmetric,
+                            message: format!(
+                                "{} supersedes {}, but {} is not marked superseded_by {}",
+                                adr.id, target, target, adr.id
+                            ),
+                        });
+                    },
+                    Some(_) => {},
+                }
+            }
+
+            if let Some(target) = &adr.frontmatter.superseded_by {
+                match by_id.get(target.as_str()) {
+                    None => issues.push(dangling(adr, "superseded_by", target)),
+                    Some(other) if other.frontmatter.supersedes.as_ref() != Some(&adr.id) => {
+                        issues.push(LinkIssue {
+                            adr_id: adr.id.clone(),
+                            field: "superseded_by".to_string(),
+                            target: target.clone(),
+                            kind: LinkIssueKind::Asymmetric,
+                            message: format!(
+                                "{} is superseded_by {}, but {} does not supersede {}",
+                                adr.id, target, target, adr.id
+                            ),
+                        });
+                    },
+                    Some(_) => {},
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Check every ADR's required sections (such as nygard's Context,
+    /// Decision and Consequences) are present and no longer hold the
+    /// template's placeholder text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs or custom templates cannot be loaded.
+    pub fn validate_sections(&self) -> Result<Vec<SectionIssue>, Error> {
+        let engine = TemplateEngine::for_repo(&self.git, &self.config)?;
+        let mut issues = Vec::new();
+        for adr in self.list()? {
+            for (section, kind) in engine.incomplete_sections(&adr, &self.config.format) {
+                let message = match kind {
+                    SectionIssueKind::Missing => {
+                        format!("{} is missing a \"{section}\" section", adr.id)
+                    },
+                    SectionIssueKind::Placeholder => format!(
+                        "{}'s \"{section}\" section still has the template's placeholder text",
+                        adr.id
+                    ),
+                };
+                issues.push(SectionIssue {
+                    adr_id: adr.id.clone(),
+                    section: section.to_string(),
+                    kind,
+                    message,
+                });
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Check every ADR's frontmatter.
+    ///
+    /// Reports notes that can't be parsed (such as an unknown status), empty
+    /// titles, missing dates, superseded ADRs without `superseded_by`, and
+    /// IDs used by more than one ADR. Issues are ordered by ADR ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes cannot be read.
+    pub fn validate_metadata(&self) -> Result<Vec<MetadataIssue>, Error> {
+        let mut issues: Vec<MetadataIssue> = self
+            .invalid_notes()?
+            .into_iter()
+            .map(|(adr_id, reason)| MetadataIssue {
+                message: format!("{adr_id} cannot be parsed: {reason}"),
+                adr_id,
+                kind: MetadataIssueKind::Invalid,
+            })
+            .collect();
+
+        let adrs = self.list()?;
+        let issue = |adr: &Adr, kind, message: String| MetadataIssue {
+            adr_id: adr.id.clone(),
+            kind,
+            message,
+        };
+        let mut by_id: BTreeMap<&str, Vec<&Adr>> = BTreeMap::new();
+        for adr in &adrs {
+            by_id.entry(&adr.id).or_default().push(adr);
+            if adr.frontmatter.title.trim().is_empty() {
+                let message = format!("{} has no title", adr.id);
+                issues.push(issue(adr, MetadataIssueKind::MissingTitle, message));
+            }
+            if adr.frontmatter.date.is_none() {
+                let message = format!("{} has no date", adr.id);
+                issues.push(issue(adr, MetadataIssueKind::MissingDate, message));
+            }
+            if adr.frontmatter.status == AdrStatus::Superseded
+                && adr.frontmatter.superseded_by.is_none()
+            {
+                let message = format!("{} is superseded but has no superseded_by", adr.id);
+                issues.push(issue(adr, MetadataIssueKind::MissingSupersededBy, message));
+            }
+        }
+
+        for (id, group) in by_id {
+            if group.len() < 2 {
+                continue;
+            }
+            let commits = group
+                .iter()
+                .map(|adr| self.git.short_hash(&adr.commit))
+                .collect::<Result<Vec<_>, _>>()?;
+            let message = format!("{id} is used by the notes on {}", commits.join(", "));
+            issues.push(issue(group[0], MetadataIssueKind::DuplicateId, message));
+        }
 
-        let id = self.extract_id(&content, commit)?;
-        Adr::from_markdown(id, commit.to_string(), &content)
+        issues.sort_by(|a, b| a.adr_id.cmp(&b.adr_id));
+        Ok(issues)
     }
 
-    /// Create a new ADR.
+    /// Group ADRs whose titles match once normalized (see
+    /// [`Adr::normalized_title`]).
+    ///
+    /// Returns `(normalized title, IDs)` pairs for every title shared by more
+    /// than one ADR, ordered by title.
     ///
     /// # Errors
     ///
-    /// Returns an error if the ADR cannot be created.
-    pub fn create(&self, adr: &Adr) -> Result<(), Error> {
-        let commit = if adr.commit.is_empty() {
-            self.git.head()?
-        } else {
-            adr.commit.clone()
-        };
-
-        let content = adr.to_markdown()?;
-        self.git.notes_add(ADR_NOTES_REF, &commit, &content)?;
+    /// Returns an error if ADRs cannot be listed.
+    pub fn find_duplicate_titles(&self) -> Result<Vec<(String, Vec<String>)>, Error> {
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for adr in self.list()? {
+            groups
+                .entry(adr.normalized_title())
+                .or_default()
+                .push(adr.id);
+        }
 
-        Ok(())
+        Ok(groups
+            .into_iter()
+            .filter(|(_, ids)| ids.len() > 1)
+            .collect())
     }
 
-    /// Update an existing ADR.
+    /// Numbers missing between 1 and the highest numeric ADR ID.
+    ///
+    /// Archived ADRs keep their numbers, so they don't count as gaps. Always
+    /// empty unless `adr.idScheme` is numeric.
     ///
     /// # Errors
     ///
-    /// Returns an error if the ADR cannot be updated.
-    pub fn update(&self, adr: &Adr) -> Result<(), Error> {
-        // Verify ADR exists
-        let _ = self.get(&adr.id)?;
+    /// Returns an error if ADRs cannot be listed.
+    pub fn id_gaps(&self) -> Result<Vec<u32>, Error> {
+        if self.config.id_scheme != IdScheme::Numeric {
+            return Ok(Vec::new());
+        }
 
-        let content = adr.to_markdown()?;
-        self.git.notes_add(ADR_NOTES_REF, &adr.commit, &content)?;
+        let adrs = self.list()?;
+        let archived = self.list_archived()?;
+        let used: HashSet<u32> = adrs
+            .iter()
+            .chain(&archived)
+            .filter_map(|adr| {
+                adr.id
+                    .strip_prefix(&self.config.prefix)
+                    .and_then(|s| s.parse::<u32>().ok())
+            })
+            .collect();
+        let max = used.iter().copied().max().unwrap_or(0);
 
-        Ok(())
+        Ok((1..max).filter(|n| !used.contains(n)).collect())
     }
 
-    /// Delete an ADR.
+    /// ADR notes that can't be parsed, such as ones with an unknown status,
+    /// as `(ID, reason)` pairs.
+    ///
+    /// [`list`](Self::list) skips these notes, so they are otherwise
+    /// invisible.
     ///
     /// # Errors
     ///
-    /// Returns an error if the ADR cannot be deleted.
-    pub fn delete(&self, id: &str) -> Result<(), Error> {
-        let adr = self.get(id)?;
-        self.git.notes_remove(ADR_NOTES_REF, &adr.commit)?;
-        Ok(())
+    /// Returns an error if the notes cannot be read.
+    pub fn invalid_notes(&self) -> Result<Vec<(String, String)>, Error> {
+        let notes_ref = self.config.notes_ref();
+        let mut invalid = Vec::new();
+        for (_, commit) in self.git.notes_list(notes_ref)? {
+            let Some(content) = self.git.notes_show(notes_ref, &commit)? else {
+                continue;
+            };
+            let id = self.extract_id(&content, &commit)?;
+            if let Err(e) = Adr::from_markdown(id.clone(), commit, &content) {
+                invalid.push((id, e.to_string()));
+            }
+        }
+        invalid.sort();
+        Ok(invalid)
     }
 
     /// Get the next available ADR number.
@@ -142,9 +1219,12 @@ impl NotesManager {
     ///
     /// Returns an error if ADRs cannot be listed.
     pub fn next_number(&self) -> Result<u32, Error> {
+        // Archived ADRs keep their numbers
         let adrs = self.list()?;
+        let archived = self.list_archived()?;
         let max_num = adrs
             .iter()
+            .chain(&archived)
             .filter_map(|adr| {
                 adr.id
                     .strip_prefix(&self.config.prefix)
@@ -167,6 +1247,36 @@ impl NotesManager {
         )
     }
 
+    /// Generate the ID for a new ADR titled `title`, following `adr.idScheme`.
+    ///
+    /// Slug IDs that are already taken get a `-2`, `-3`, ... suffix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if ADRs cannot be listed.
+    pub fn new_id(&self, title: &str) -> Result<String, Error> {
+        let base = match self.config.id_scheme {
+            IdScheme::Numeric => return Ok(self.format_id(self.next_number()?)),
+            IdScheme::Slug => format!("adr-{}", slugify(title)),
+            IdScheme::DateSlug => format!("{}-{}", Utc::now().format("%Y%m%d"), slugify(title)),
+        };
+
+        // Archived ADRs keep their IDs
+        let taken: Vec<String> = self
+            .list()?
+            .into_iter()
+            .chain(self.list_archived()?)
+            .map(|adr| adr.id)
+            .collect();
+        let mut id = base.clone();
+        let mut suffix = 1;
+        while taken.contains(&id) {
+            suffix += 1;
+            id = format!("{base}-{suffix}");
+        }
+        Ok(id)
+    }
+
     /// Extract ADR ID from content or generate from commit.
     fn extract_id(&self, content: &str, commit: &str) -> Result<String, Error> {
         // Try to parse frontmatter and get ID field
@@ -201,28 +1311,108 @@ impl NotesManager {
             .and_then(|fm| fm.id)
     }
 
+    /// Best-effort fetch of ADR notes from the default remote before a read.
+    ///
+    /// Remote notes are merged into the local ref, so local changes are never
+    /// overwritten. A missing remote or offline network is ignored, and a
+    /// conflicting merge is aborted and left for `sync`; either way the read
+    /// proceeds with what is already local.
+    pub fn fetch_for_read(&self) {
+        let Ok(Some(remote)) = self.git.default_remote() else {
+            return;
+        };
+        let notes_ref = self.config.notes_ref();
+        if let Ok(tracking) = self.git.notes_fetch_tracking(&remote, notes_ref) {
+            let merged = self.git.notes_merge(notes_ref, &tracking, "manual");
+            if merged.is_err() {
+                let _ = self.git.notes_merge_abort(notes_ref);
+            }
+        }
+    }
+
     /// Sync notes with remote.
     ///
+    /// Fetching merges the remote's notes into the local refs with
+    /// `git notes merge` rather than overwriting them, so ADRs edited on
+    /// different machines are combined. Remote refs that don't exist yet are
+    /// skipped.
+    ///
     /// # Errors
     ///
-    /// Returns an error if sync fails.
+    /// Returns an error if sync fails, including an [`Error::Git`] naming the
+    /// conflicting commits when the same ADR was changed on both sides.
     pub fn sync(&self, remote: &str, push: bool, fetch: bool) -> Result<(), Error> {
         if fetch {
-            // Fetch notes (ignore errors if ref doesn't exist on remote)
-            let _ = self.git.notes_fetch(remote, ADR_NOTES_REF);
-            let _ = self.git.notes_fetch(remote, ARTIFACTS_NOTES_REF);
+            // ADR conflicts must be resolved by hand; the merge is left in progress
+            if let Ok(tracking) = self
+                .git
+                .notes_fetch_tracking(remote, self.config.notes_ref())
+            {
+                self.git
+                    .notes_merge(self.config.notes_ref(), &tracking, "manual")?;
+            }
+
+            // Artifacts, signatures and archived ADRs keep the local note on conflict
+            for notes_ref in [
+                self.config.artifacts_ref(),
+                self.config.signatures_ref(),
+                self.config.archive_ref(),
+            ] {
+                if let Ok(tracking) = self.git.notes_fetch_tracking(remote, &notes_ref) {
+                    self.git.notes_merge(&notes_ref, &tracking, "ours")?;
+                }
+            }
         }
 
         if push {
-            self.git.notes_push(remote, ADR_NOTES_REF)?;
-            // Only push artifacts if they exist
-            let _ = self.git.notes_push(remote, ARTIFACTS_NOTES_REF);
+            self.git.notes_push(remote, self.config.notes_ref())?;
+            // Only push artifacts and archived ADRs if they exist
+            let _ = self.git.notes_push(remote, &self.config.artifacts_ref());
+            let _ = self.git.notes_push(remote, &self.config.archive_ref());
+            if self.config.sign {
+                self.git
+                    .notes_push(remote, &self.config.signatures_ref())?;
+            }
         }
 
         Ok(())
     }
 }
 
+/// Kebab-case a title for use in an ID (`Use PostgreSQL 16!` becomes
+/// `use-postgresql-16`).
+fn slugify(title: &str) -> String {
+    let slug = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "untitled".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Time from an ADR's first proposed revision to the revision that accepted
+/// or rejected it.
+///
+/// `history` is the ADR's status per revision, oldest first, as returned by
+/// [`NotesManager::status_history`]. Returns `None` for ADRs that were never
+/// proposed or haven't been decided yet.
+#[must_use]
+pub fn decision_lead_time(history: &[(DateTime<Utc>, AdrStatus)]) -> Option<Duration> {
+    let proposed = history
+        .iter()
+        .position(|(_, status)| *status == AdrStatus::Proposed)?;
+    let (proposed_at, _) = history[proposed];
+    history[proposed..]
+        .iter()
+        .find(|(_, status)| matches!(status, AdrStatus::Accepted | AdrStatus::Rejected))
+        .map(|(decided_at, _)| *decided_at - proposed_at)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -358,6 +1548,28 @@ invalid: yaml: content:
         assert_eq!(manager.config().prefix, "TEST-");
     }
 
+    #[test]
+    fn test_claim_commit() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git.clone(), AdrConfig::default());
+        let head = git.head().expect("Should get HEAD");
+
+        let mut first = Adr::new("ADR-0001".to_string(), "First".to_string());
+        manager.claim_commit(&mut first).expect("Should claim");
+        assert_eq!(first.commit, head);
+        manager.create(&first).expect("Should create ADR");
+
+        // HEAD is taken, so the second ADR gets a new commit on top of it
+        let mut second = Adr::new("ADR-0002".to_string(), "Second".to_string());
+        manager.claim_commit(&mut second).expect("Should claim");
+        assert_ne!(second.commit, head);
+        assert_eq!(git.head().expect("Should get HEAD"), second.commit);
+        manager.create(&second).expect("Should create ADR");
+
+        assert_eq!(manager.list().expect("Should list ADRs").len(), 2);
+    }
+
     #[test]
     fn test_create_with_empty_commit() {
         let temp_dir = setup_git_repo();
@@ -414,4 +1626,696 @@ invalid: yaml: content:
         let result = manager.get_by_commit(&head);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_supersede_links_both_adrs() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let mut old = Adr::new("ADR-0001".to_string(), "Old Decision".to_string());
+        old.frontmatter.status = AdrStatus::Accepted;
+        manager.create(&old).expect("Should create ADR");
+
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", "Second commit"])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to create commit");
+
+        let new = Adr::new("ADR-0002".to_string(), "New Decision".to_string());
+        let (old, new) = manager
+            .supersede("ADR-0001", &new)
+            .expect("Should supersede");
+        assert_eq!(old.frontmatter.superseded_by.as_deref(), Some("ADR-0002"));
+        assert_eq!(new.frontmatter.supersedes.as_deref(), Some("ADR-0001"));
+
+        let old = manager.get("ADR-0001").expect("Should get old ADR");
+        let new = manager.get("ADR-0002").expect("Should get new ADR");
+        assert_eq!(old.frontmatter.status, AdrStatus::Superseded);
+        assert_eq!(old.frontmatter.superseded_by.as_deref(), Some("ADR-0002"));
+        assert_eq!(new.frontmatter.supersedes.as_deref(), Some("ADR-0001"));
+    }
+
+    #[test]
+    fn test_merge_tags() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let tagged = [
+            vec!["db", "api"],
+            vec!["database", "DB"],
+            vec!["databases"],
+            vec!["ui"],
+        ];
+        for (i, tags) in tagged.iter().enumerate() {
+            let mut adr = Adr::new(format!("ADR-000{}", i + 1), format!("Decision {i}"));
+            adr.frontmatter.tags = tags.iter().map(ToString::to_string).collect();
+            manager.create(&adr).expect("Should create ADR");
+            StdCommand::new("git")
+                .args(["commit", "--allow-empty", "-m", "Next commit"])
+                .current_dir(temp_dir.path())
+                .output()
+                .expect("Failed to create commit");
+        }
+
+        assert_eq!(manager.rename_tag("DB", "database").unwrap(), 2);
+        let tags = |id: &str| manager.get(id).unwrap().frontmatter.tags;
+        assert_eq!(tags("ADR-0001"), ["database", "api"]);
+        assert_eq!(tags("ADR-0002"), ["database"]);
+
+        assert_eq!(
+            manager
+                .merge_tags(&["database", "databases"], "storage")
+                .unwrap(),
+            3
+        );
+        assert_eq!(tags("ADR-0001"), ["storage", "api"]);
+        assert_eq!(tags("ADR-0003"), ["storage"]);
+        assert_eq!(tags("ADR-0004"), ["ui"]);
+
+        assert_eq!(manager.rename_tag("missing", "other").unwrap(), 0);
+        assert!(manager.rename_tag("ui", " ").is_err());
+    }
+
+    #[test]
+    fn test_tag_counts() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let tagged = [vec!["db", "api"], vec!["DB", "Db"], vec!["API"], vec!["ui"]];
+        for (i, tags) in tagged.iter().enumerate() {
+            let mut adr = Adr::new(format!("ADR-000{}", i + 1), format!("Decision {i}"));
+            adr.frontmatter.tags = tags.iter().map(ToString::to_string).collect();
+            manager.create(&adr).expect("Should create ADR");
+            StdCommand::new("git")
+                .args(["commit", "--allow-empty", "-m", "Next commit"])
+                .current_dir(temp_dir.path())
+                .output()
+                .expect("Failed to create commit");
+        }
+
+        let counts = manager.tag_counts().unwrap();
+        assert_eq!(counts.get("db"), Some(&2));
+        assert_eq!(counts.get("api"), Some(&2));
+        assert_eq!(counts.get("ui"), Some(&1));
+        assert!(!counts.contains_key("DB"));
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn test_supersede_same_commit_rejected() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let old = Adr::new("ADR-0001".to_string(), "Old Decision".to_string());
+        manager.create(&old).expect("Should create ADR");
+
+        let new = Adr::new("ADR-0002".to_string(), "New Decision".to_string());
+        let result = manager.supersede("ADR-0001", &new);
+        assert!(matches!(result, Err(Error::Validation { .. })));
+
+        // The old ADR must be untouched
+        let old = manager.get("ADR-0001").expect("Should get old ADR");
+        assert_eq!(old.frontmatter.status, AdrStatus::Proposed);
+        assert!(old.frontmatter.superseded_by.is_none());
+    }
+
+    #[test]
+    fn test_supersede_missing_old_adr() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let new = Adr::new("ADR-0002".to_string(), "New Decision".to_string());
+        let result = manager.supersede("ADR-0001", &new);
+        assert!(matches!(result, Err(Error::AdrNotFound { .. })));
+        assert!(manager.list().expect("Should list").is_empty());
+    }
+
+    /// Create an ADR on a fresh empty commit.
+    fn create_on_new_commit(temp_dir: &TempDir, manager: &NotesManager, adr: &Adr) {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", &adr.id])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to create commit");
+        manager.create(adr).expect("Should create ADR");
+    }
+
+    #[test]
+    fn test_validate_metadata() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git.clone(), AdrConfig::default());
+
+        let dated = |id: &str, title: &str| {
+            let mut adr = Adr::new(id.to_string(), title.to_string());
+            adr.frontmatter.date = Some(Utc::now().into());
+            adr
+        };
+        create_on_new_commit(&temp_dir, &manager, &dated("ADR-0001", "Clean"));
+        create_on_new_commit(&temp_dir, &manager, &dated("ADR-0002", " "));
+        let mut superseded = dated("ADR-0003", "Superseded");
+        superseded.frontmatter.status = AdrStatus::Superseded;
+        create_on_new_commit(&temp_dir, &manager, &superseded);
+        create_on_new_commit(&temp_dir, &manager, &dated("ADR-0003", "Again"));
+        let mut undated = Adr::new("ADR-0004".to_string(), "Undated".to_string());
+        undated.frontmatter.date = None;
+        create_on_new_commit(&temp_dir, &manager, &undated);
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", "ADR-0005"])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to create commit");
+        git.notes_add(
+            "adr",
+            "HEAD",
+            "---\nid: ADR-0005\ntitle: Draft\nstatus: wip\n---\n",
+        )
+        .unwrap();
+
+        let found: Vec<(String, MetadataIssueKind)> = manager
+            .validate_metadata()
+            .unwrap()
+            .into_iter()
+            .map(|issue| (issue.adr_id, issue.kind))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("ADR-0002".to_string(), MetadataIssueKind::MissingTitle),
+                (
+                    "ADR-0003".to_string(),
+                    MetadataIssueKind::MissingSupersededBy
+                ),
+                ("ADR-0003".to_string(), MetadataIssueKind::DuplicateId),
+                ("ADR-0004".to_string(), MetadataIssueKind::MissingDate),
+                ("ADR-0005".to_string(), MetadataIssueKind::Invalid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_artifact_binary_round_trip() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git.clone(), AdrConfig::default());
+
+        let mut adr = Adr::new("ADR-0001".to_string(), "Diagram".to_string());
+        adr.commit = git.head().expect("Should get HEAD");
+        manager.create(&adr).expect("Should create ADR");
+        assert!(manager.artifacts(&adr).expect("Should read").is_empty());
+
+        // PNG signature plus bytes that aren't valid UTF-8
+        let data: Vec<u8> = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]
+            .into_iter()
+            .chain((0..=255).rev())
+            .collect();
+        let artifact = Artifact::new("diagram.png".to_string(), adr.id.clone(), None, &data);
+        manager.add_artifact(&adr, artifact).expect("Should attach");
+
+        let stored = manager.artifacts(&adr).expect("Should read");
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].decode().expect("Should decode"), data);
+    }
+
+    #[test]
+    fn test_add_and_remove_artifacts() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git.clone(), AdrConfig::default());
+
+        let mut adr = Adr::new("ADR-0001".to_string(), "Diagrams".to_string());
+        adr.commit = git.head().expect("Should get HEAD");
+        manager.create(&adr).expect("Should create ADR");
+
+        let attach = |name: &str, data: &[u8]| {
+            let artifact = Artifact::new(name.to_string(), adr.id.clone(), None, data);
+            manager.add_artifact(&adr, artifact).expect("Should attach");
+        };
+        let names = || -> Vec<String> {
+            manager
+                .artifacts(&adr)
+                .expect("Should read")
+                .into_iter()
+                .map(|a| a.filename)
+                .collect()
+        };
+
+        attach("a.png", b"first");
+        attach("b.png", b"second");
+        assert_eq!(names(), ["a.png", "b.png"]);
+
+        // Re-attaching a name replaces that artifact only
+        attach("a.png", b"again");
+        assert_eq!(names(), ["a.png", "b.png"]);
+        assert_eq!(manager.artifacts(&adr).expect("Should read")[0].size, 5);
+
+        let removed = manager
+            .remove_artifact(&adr, "a.png")
+            .expect("Should remove");
+        assert_eq!(removed.filename, "a.png");
+        assert_eq!(names(), ["b.png"]);
+        assert!(matches!(
+            manager.remove_artifact(&adr, "a.png"),
+            Err(Error::Validation { .. })
+        ));
+
+        manager
+            .remove_artifact(&adr, "b.png")
+            .expect("Should remove");
+        assert!(git
+            .notes_show(&manager.config().artifacts_ref(), &adr.commit)
+            .expect("Should read")
+            .is_none());
+    }
+
+    #[test]
+    fn test_validate_links_clean_graph() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let mut old = Adr::new("ADR-0001".to_string(), "Old".to_string());
+        old.frontmatter.superseded_by = Some("ADR-0002".to_string());
+        let mut new = Adr::new("ADR-0002".to_string(), "New".to_string());
+        new.frontmatter.supersedes = Some("ADR-0001".to_string());
+        new.frontmatter.links.push(crate::core::adr::AdrLink {
+            rel: "relates-to".to_string(),
+            target: "ADR-0001".to_string(),
+        });
+        create_on_new_commit(&temp_dir, &manager, &old);
+        create_on_new_commit(&temp_dir, &manager, &new);
+
+        let issues = manager.validate_links().expect("Should validate");
+        assert!(issues.is_empty(), "unexpected issues: {issues:?}");
+    }
+
+    #[test]
+    fn test_validate_links_dangling() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let mut adr = Adr::new("ADR-0001".to_string(), "Broken".to_string());
+        adr.frontmatter.supersedes = Some("ADR-0042".to_string());
+        adr.frontmatter.superseded_by = Some("ADR-0043".to_string());
+        adr.frontmatter.links.push(crate::core::adr::AdrLink {
+            rel: "relates-to".to_string(),
+            target: "ADR-0044".to_string(),
+        });
+        create_on_new_commit(&temp_dir, &manager, &adr);
+
+        let issues = manager.validate_links().expect("Should validate");
+        assert_eq!(issues.len(), 3);
+        assert!(issues.iter().all(|i| i.kind == LinkIssueKind::Dangling));
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, ["links", "supersedes", "superseded_by"]);
+        assert_eq!(issues[0].target, "ADR-0044");
+    }
+
+    #[test]
+    fn test_find_duplicate_titles() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        for (id, title) in [
+            ("ADR-0001", "Use Postgres"),
+            ("ADR-0002", "Use MySQL"),
+            ("ADR-0003", "use  postgres"),
+        ] {
+            let adr = Adr::new(id.to_string(), title.to_string());
+            create_on_new_commit(&temp_dir, &manager, &adr);
+        }
+
+        let duplicates = manager.find_duplicate_titles().expect("Should check");
+        assert_eq!(
+            duplicates,
+            [(
+                "use postgres".to_string(),
+                vec!["ADR-0001".to_string(), "ADR-0003".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_id_gaps() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git.clone(), AdrConfig::default());
+        assert!(manager.id_gaps().unwrap().is_empty());
+
+        for id in ["ADR-0001", "ADR-0002", "ADR-0004"] {
+            let adr = Adr::new(id.to_string(), format!("Decision {id}"));
+            create_on_new_commit(&temp_dir, &manager, &adr);
+        }
+        assert_eq!(manager.id_gaps().unwrap(), [3]);
+
+        // Slug schemes have no sequence to check
+        let slug = NotesManager::new(
+            git,
+            AdrConfig {
+                id_scheme: IdScheme::DateSlug,
+                ..AdrConfig::default()
+            },
+        );
+        assert!(slug.id_gaps().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_links_asymmetric() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        // ADR-0002 supersedes ADR-0001, which doesn't point back;
+        // ADR-0003 claims to be superseded by ADR-0001, which doesn't agree.
+        let old = Adr::new("ADR-0001".to_string(), "Old".to_string());
+        let mut new = Adr::new("ADR-0002".to_string(), "New".to_string());
+        new.frontmatter.supersedes = Some("ADR-0001".to_string());
+        let mut other = Adr::new("ADR-0003".to_string(), "Other".to_string());
+        other.frontmatter.superseded_by = Some("ADR-0001".to_string());
+        create_on_new_commit(&temp_dir, &manager, &old);
+        create_on_new_commit(&temp_dir, &manager, &new);
+        create_on_new_commit(&temp_dir, &manager, &other);
+
+        let issues = manager.validate_links().expect("Should validate");
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|i| i.kind == LinkIssueKind::Asymmetric));
+        assert_eq!(issues[0].adr_id, "ADR-0002");
+        assert_eq!(issues[0].field, "supersedes");
+        assert_eq!(issues[1].adr_id, "ADR-0003");
+        assert_eq!(issues[1].field, "superseded_by");
+    }
+
+    #[test]
+    fn test_list_with_history() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let adr = Adr::new("ADR-0001".to_string(), "Decision".to_string());
+        manager.create(&adr).expect("Should create ADR");
+
+        let plain = manager.list().expect("Should list ADRs");
+        assert!(plain[0].note_author.is_none());
+        assert!(plain[0].last_modified.is_none());
+
+        let adrs = manager.list_with_history().expect("Should list ADRs");
+        assert_eq!(
+            adrs[0].note_author.as_deref(),
+            Some("Test User <test@example.com>")
+        );
+        assert!(adrs[0].last_modified.is_some());
+    }
+
+    #[test]
+    fn test_revision() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let mut adr = Adr::new("ADR-0001".to_string(), "Decision".to_string());
+        manager.create(&adr).expect("Should create ADR");
+        adr = manager.get("ADR-0001").expect("Should get ADR");
+        adr.frontmatter.title = "Revised decision".to_string();
+        manager.update(&adr).expect("Should update ADR");
+
+        let history = manager.history(&adr).expect("Should read history");
+        assert_eq!(history.len(), 2);
+
+        let latest = manager.revision(&adr, "~0").expect("Should resolve ~0");
+        assert_eq!(latest, history[0]);
+        let previous = manager.revision(&adr, "~1").expect("Should resolve ~1");
+        assert_eq!(previous, history[1]);
+        let by_hash = manager
+            .revision(&adr, &history[1].notes_commit[..10])
+            .expect("Should resolve hash prefix");
+        assert_eq!(by_hash, history[1]);
+
+        assert!(manager.revision(&adr, "~2").is_err());
+        assert!(manager.revision(&adr, "nothex").is_err());
+        assert!(manager.revision(&adr, "").is_err());
+    }
+
+    fn day(n: i64) -> DateTime<Utc> {
+        DateTime::UNIX_EPOCH + Duration::days(n)
+    }
+
+    #[test]
+    fn test_decision_lead_time() {
+        // Proposed, edited while proposed, then accepted and later superseded
+        let history = [
+            (day(0), AdrStatus::Proposed),
+            (day(3), AdrStatus::Proposed),
+            (day(10), AdrStatus::Accepted),
+            (day(40), AdrStatus::Superseded),
+        ];
+        assert_eq!(decision_lead_time(&history), Some(Duration::days(10)));
+
+        let rejected = [(day(5), AdrStatus::Proposed), (day(7), AdrStatus::Rejected)];
+        assert_eq!(decision_lead_time(&rejected), Some(Duration::days(2)));
+
+        // Still open
+        let open = [(day(0), AdrStatus::Proposed), (day(1), AdrStatus::Proposed)];
+        assert_eq!(decision_lead_time(&open), None);
+
+        // Created already accepted: there was no proposal to time
+        let direct = [(day(0), AdrStatus::Accepted)];
+        assert_eq!(decision_lead_time(&direct), None);
+
+        assert_eq!(decision_lead_time(&[]), None);
+    }
+
+    #[test]
+    fn test_status_history() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let adr = Adr::new("ADR-0001".to_string(), "Decision".to_string());
+        manager.create(&adr).expect("Should create ADR");
+        let mut adr = manager.get("ADR-0001").expect("Should get ADR");
+        adr.frontmatter.status = AdrStatus::Accepted;
+        manager.update(&adr).expect("Should update ADR");
+
+        let statuses: Vec<_> = manager
+            .status_history(&adr)
+            .expect("Should read status history")
+            .into_iter()
+            .map(|(_, status)| status)
+            .collect();
+        assert_eq!(statuses, [AdrStatus::Proposed, AdrStatus::Accepted]);
+    }
+
+    #[test]
+    fn test_new_id_schemes() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+
+        let numeric = NotesManager::new(git.clone(), AdrConfig::default());
+        assert_eq!(numeric.new_id("Anything").unwrap(), "ADR-0001");
+
+        let slug = NotesManager::new(
+            git.clone(),
+            AdrConfig {
+                id_scheme: IdScheme::Slug,
+                ..AdrConfig::default()
+            },
+        );
+        assert_eq!(
+            slug.new_id("Coverage target: 95 percent!").unwrap(),
+            "adr-coverage-target-95-percent"
+        );
+
+        let date_slug = NotesManager::new(
+            git,
+            AdrConfig {
+                id_scheme: IdScheme::DateSlug,
+                ..AdrConfig::default()
+            },
+        );
+        let id = date_slug.new_id("Coverage target 95 percent").unwrap();
+        assert_eq!(
+            id,
+            format!("{}-coverage-target-95-percent", Utc::now().format("%Y%m%d"))
+        );
+    }
+
+    #[test]
+    fn test_slug_ids_resolve_and_stay_unique() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let config = AdrConfig {
+            id_scheme: IdScheme::Slug,
+            ..AdrConfig::default()
+        };
+        let manager = NotesManager::new(git.clone(), config);
+
+        let id = manager.new_id("Use PostgreSQL").unwrap();
+        manager
+            .create(&Adr::new(id.clone(), "Use PostgreSQL".to_string()))
+            .expect("Should create ADR");
+
+        // A second ADR with the same title gets a suffix
+        git.run_silent(&["commit", "--allow-empty", "-m", "Second"])
+            .unwrap();
+        assert_eq!(
+            manager.new_id("Use PostgreSQL").unwrap(),
+            "adr-use-postgresql-2"
+        );
+
+        assert_eq!(manager.resolve_id("adr-use-postgresql").unwrap(), id);
+        assert_eq!(manager.resolve_id("postgresql").unwrap(), id);
+        assert_eq!(manager.resolve_id("PostgreSQL").unwrap(), id);
+        assert_eq!(
+            manager.resolve("USE-POSTGRES").unwrap().frontmatter.title,
+            "Use PostgreSQL"
+        );
+    }
+
+    #[test]
+    fn test_custom_namespace() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let config = AdrConfig {
+            namespace: "decisions".to_string(),
+            ..Default::default()
+        };
+        let manager = NotesManager::new(git.clone(), config);
+
+        let adr = Adr::new("ADR-0001".to_string(), "Decision".to_string());
+        manager.create(&adr).expect("Should create ADR");
+
+        assert_eq!(git.notes_list("decisions").unwrap().len(), 1);
+        assert!(git.notes_list(ADR_NOTES_REF).unwrap().is_empty());
+        assert_eq!(manager.list().expect("Should list ADRs").len(), 1);
+
+        let default = NotesManager::new(git, AdrConfig::default());
+        assert!(default.list().expect("Should list ADRs").is_empty());
+    }
+
+    #[test]
+    fn test_archive_and_restore() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        let mut adr = Adr::new("ADR-0001".to_string(), "Decision".to_string());
+        adr.frontmatter.status = AdrStatus::Accepted;
+        manager.create(&adr).expect("Should create ADR");
+
+        let archived = manager.archive("ADR-0001").expect("Should archive");
+        assert_eq!(archived.frontmatter.status, AdrStatus::Archived);
+
+        // Moved out of the ADR ref into the archive ref
+        assert!(manager.list().expect("Should list").is_empty());
+        assert!(matches!(
+            manager.get("ADR-0001"),
+            Err(Error::AdrNotFound { .. })
+        ));
+        let listed = manager.list_archived().expect("Should list archived");
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, "ADR-0001");
+        assert_eq!(
+            manager.resolve_archived_id("1").expect("Should resolve"),
+            "ADR-0001"
+        );
+        // Archived numbers are not reused
+        assert_eq!(manager.next_number().expect("Should number"), 2);
+
+        let restored = manager.restore("ADR-0001").expect("Should restore");
+        assert_eq!(restored.frontmatter.status, AdrStatus::Accepted);
+        let stored = manager.get("ADR-0001").expect("Should get ADR");
+        assert_eq!(stored.frontmatter.status, AdrStatus::Accepted);
+        assert!(!stored.frontmatter.custom.contains_key(ARCHIVED_FROM_FIELD));
+        assert!(manager
+            .list_archived()
+            .expect("Should list archived")
+            .is_empty());
+
+        assert!(matches!(
+            manager.restore("ADR-0001"),
+            Err(Error::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_get_uses_id_map() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        for i in 1..=100 {
+            StdCommand::new("git")
+                .args(["commit", "--allow-empty", "-m", &format!("Commit {i}")])
+                .current_dir(temp_dir.path())
+                .output()
+                .expect("Failed to create commit");
+            let adr = Adr::new(manager.format_id(i), format!("Decision {i}"));
+            manager.create(&adr).expect("Should create ADR");
+        }
+
+        // A full scan builds the map
+        let start = std::time::Instant::now();
+        assert_eq!(manager.list().expect("Should list ADRs").len(), 100);
+        let list_time = start.elapsed();
+        assert!(manager.id_map_path().unwrap().exists());
+
+        let start = std::time::Instant::now();
+        let adr = manager.get("ADR-0042").expect("Should get ADR");
+        let get_time = start.elapsed();
+        assert_eq!(adr.frontmatter.title, "Decision 42");
+        assert!(
+            get_time < list_time,
+            "get took {get_time:?}, list took {list_time:?}"
+        );
+
+        // Writes move the notes ref, so a stale map falls back to scanning
+        let mut updated = adr;
+        updated.frontmatter.title = "Renamed".to_string();
+        manager.update(&updated).expect("Should update ADR");
+        assert!(manager.get_cached("ADR-0042").is_none());
+        let adr = manager.get("ADR-0042").expect("Should get ADR");
+        assert_eq!(adr.frontmatter.title, "Renamed");
+        assert!(manager.get_cached("ADR-0042").is_some());
+        assert!(manager.get("ADR-9999").is_err());
+    }
+
+    #[test]
+    fn test_resolve_id() {
+        let temp_dir = setup_git_repo();
+        let git = Git::with_work_dir(temp_dir.path());
+        let manager = NotesManager::new(git, AdrConfig::default());
+
+        for id in ["ADR-0001", "ADR-0002", "ADR-0012"] {
+            let adr = Adr::new(id.to_string(), format!("Decision {id}"));
+            create_on_new_commit(&temp_dir, &manager, &adr);
+        }
+
+        assert_eq!(manager.resolve_id("ADR-0001").unwrap(), "ADR-0001");
+        assert_eq!(manager.resolve_id("0002").unwrap(), "ADR-0002");
+        assert_eq!(manager.resolve_id("12").unwrap(), "ADR-0012");
+        assert_eq!(manager.resolve_id("adr-2").unwrap(), "ADR-0002");
+
+        assert!(matches!(
+            manager.resolve_id("99"),
+            Err(Error::AdrNotFound { .. })
+        ));
+        let err = manager.resolve_id("ADR-00").unwrap_err();
+        assert!(matches!(err, Error::Validation { .. }));
+        assert!(err.to_string().contains("ADR-0001, ADR-0002, ADR-0012"));
+        let adr = manager.resolve("adr-0012").expect("Should resolve");
+        assert_eq!(adr.id, "ADR-0012");
+        assert_eq!(adr.frontmatter.title, "Decision ADR-0012");
+        assert!(matches!(
+            manager.resolve("ADR-00"),
+            Err(Error::Validation { .. })
+        ));
+    }
 }
@@ -0,0 +1,160 @@
+//! TF-IDF text similarity.
+//!
+//! Semantic search over the index and AI link suggestions both rank ADRs
+//! with these functions, so the two agree without any network access.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+/// Words too common in ADRs to say anything about similarity.
+const STOP_WORDS: &str = "\
+    the and for are but not you all can our was will with this that from have has use using \
+    into than then they them their there which when what would should could also been being \
+    more most such each other some any these those its about decision context consequences \
+    status adr";
+
+/// Count the stemmed words in `text`.
+///
+/// Words shorter than three characters and [stop words](STOP_WORDS) are
+/// left out.
+#[must_use]
+pub fn term_counts(text: &str) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.split_whitespace().any(|stop| stop == word))
+    {
+        *counts.entry(stem(&word)).or_default() += 1;
+    }
+    counts
+}
+
+/// Rank documents by TF-IDF cosine similarity to `query`.
+///
+/// Documents are `(key, terms)` pairs with terms from [`term_counts`].
+/// Documents sharing no term with the query are left out; the rest come
+/// most similar first, then by key.
+#[must_use]
+pub fn rank_by_terms<K: Copy + Ord, S: BuildHasher>(
+    query: &HashMap<String, u32, S>,
+    documents: &[(K, &HashMap<String, u32, S>)],
+) -> Vec<(K, f64)> {
+    let mut document_frequency: HashMap<&str, u32> = HashMap::new();
+    for (_, terms) in documents {
+        for term in terms.keys() {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+    #[allow(clippy::cast_precision_loss)] // ADR counts are small
+    let total = documents.len() as f64;
+    let weights = |terms: &HashMap<String, u32, S>| -> HashMap<String, f64> {
+        terms
+            .iter()
+            .filter_map(|(term, &count)| {
+                let df = *document_frequency.get(term.as_str())?;
+                let idf = (total / f64::from(df)).ln_1p();
+                Some((term.clone(), f64::from(count) * idf))
+            })
+            .collect()
+    };
+
+    let query = weights(query);
+    let mut ranked: Vec<(K, f64)> = documents
+        .iter()
+        .map(|(key, terms)| (*key, cosine(&query, &weights(terms))))
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+}
+
+/// Reduce an English word to a rough stem, so "caching", "cached" and
+/// "cache" all become "cach".
+fn stem(word: &str) -> String {
+    const SUFFIXES: &[&str] = &["ations", "ation", "ings", "ing", "ed", "es", "ly", "s", "e"];
+
+    let stemmed = SUFFIXES
+        .iter()
+        .find_map(|suffix| {
+            word.strip_suffix(suffix)
+                .filter(|stem| stem.chars().count() >= 3)
+        })
+        .unwrap_or(word);
+    // "policy" and "policies" meet at "polici"
+    match stemmed.strip_suffix('y') {
+        Some(stem) if stem.chars().count() >= 3 => format!("{stem}i"),
+        _ => stemmed.to_string(),
+    }
+}
+
+/// Cosine similarity of two sparse vectors.
+fn cosine(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+        .sum();
+    let norm = |v: &HashMap<String, f64>| v.values().map(|w| w * w).sum::<f64>().sqrt();
+    let denominator = norm(a) * norm(b);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        for (word, expected) in [
+            ("caching", "cach"),
+            ("cached", "cach"),
+            ("cache", "cach"),
+            ("databases", "databas"),
+            ("database", "databas"),
+            ("policy", "polici"),
+            ("policies", "polici"),
+            ("uses", "use"),
+            ("api", "api"),
+        ] {
+            assert_eq!(stem(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_term_counts_skips_stop_words() {
+        let terms = term_counts("The cache and the caching layer");
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms["cach"], 2);
+        assert_eq!(terms["layer"], 1);
+    }
+
+    #[test]
+    fn test_rank_by_terms() {
+        let documents = [
+            (
+                "a",
+                term_counts("postgres database replication and backups"),
+            ),
+            ("b", term_counts("frontend styling with tailwind")),
+            ("c", term_counts("database connection pooling")),
+        ];
+        let documents: Vec<(&str, &HashMap<String, u32>)> =
+            documents.iter().map(|(key, terms)| (*key, terms)).collect();
+        let ranked = rank_by_terms(
+            &term_counts("choose a database for replication"),
+            &documents,
+        );
+
+        let keys: Vec<&str> = ranked.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, ["a", "c"]);
+        assert!(ranked[0].1 > ranked[1].1);
+        assert!(ranked
+            .iter()
+            .all(|(_, score)| *score > 0.0 && *score <= 1.0 + f64::EPSILON));
+    }
+}
@@ -3,8 +3,11 @@
 //! This module provides template rendering using Tera,
 //! with built-in templates for common ADR formats.
 
+use crate::core::{Adr, AdrConfig, Git, SectionIssueKind};
 use crate::Error;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
 use tera::{Context, Tera};
 
 /// Built-in ADR template: Nygard format.
@@ -218,10 +221,73 @@ Description of alternative approach
 {% endfor %}
 "#;
 
+/// Built-in templates as `(name, content, description)`.
+pub const BUILTIN_TEMPLATES: &[(&str, &str, &str)] = &[
+    (
+        "nygard",
+        TEMPLATE_NYGARD,
+        "Classic Michael Nygard format: context, decision, consequences",
+    ),
+    (
+        "madr",
+        TEMPLATE_MADR,
+        "MADR: drivers, considered options and their pros and cons",
+    ),
+    (
+        "y-statement",
+        TEMPLATE_Y_STATEMENT,
+        "Y-statement: a single sentence capturing the decision and trade-off",
+    ),
+    (
+        "alexandrian",
+        TEMPLATE_ALEXANDRIAN,
+        "Alexandrian pattern: forces, solution, related patterns",
+    ),
+    (
+        "business-case",
+        TEMPLATE_BUSINESS_CASE,
+        "Business case: costs, benefits, risks and success metrics",
+    ),
+];
+
+/// Section headings and the template variable each one fills, matched
+/// case-insensitively.
+const SECTION_VARIABLES: &[(&str, &str)] = &[
+    ("context", "context"),
+    ("context and problem statement", "context"),
+    ("decision", "decision"),
+    ("decision outcome", "decision"),
+    ("consequences", "consequences"),
+    ("prologue", "prologue"),
+    ("problem statement", "problem"),
+    ("solution", "solution"),
+    ("proposed solution", "solution"),
+    ("background", "background"),
+    ("executive summary", "executive_summary"),
+    ("implementation plan", "implementation"),
+    ("more information", "more_info"),
+];
+
+/// Variables a section can stand in for when a template has no variable of
+/// its own name, as `(template variable, source variables in preference order)`.
+const SECTION_EQUIVALENTS: &[(&str, &[&str])] = &[
+    ("context", &["problem", "background", "prologue"]),
+    ("problem", &["context", "background"]),
+    ("background", &["context"]),
+    ("decision", &["solution"]),
+    ("solution", &["decision"]),
+    ("chosen_option", &["decision", "solution"]),
+];
+
+/// File extensions loaded from a custom templates directory.
+const TEMPLATE_EXTENSIONS: &[&str] = &["md", "tera"];
+
 /// Template engine for ADR generation.
 #[derive(Debug)]
 pub struct TemplateEngine {
     tera: Tera,
+    /// Custom templates by name, including ones that override a built-in.
+    custom: BTreeMap<String, String>,
 }
 
 impl Default for TemplateEngine {
@@ -237,13 +303,31 @@ impl TemplateEngine {
         let mut tera = Tera::default();
 
         // Add built-in templates
-        let _ = tera.add_raw_template("nygard", TEMPLATE_NYGARD);
-        let _ = tera.add_raw_template("madr", TEMPLATE_MADR);
-        let _ = tera.add_raw_template("y-statement", TEMPLATE_Y_STATEMENT);
-        let _ = tera.add_raw_template("alexandrian", TEMPLATE_ALEXANDRIAN);
-        let _ = tera.add_raw_template("business-case", TEMPLATE_BUSINESS_CASE);
+        for (name, content, _) in BUILTIN_TEMPLATES {
+            let _ = tera.add_raw_template(name, content);
+        }
+
+        Self {
+            tera,
+            custom: BTreeMap::new(),
+        }
+    }
 
-        Self { tera }
+    /// Create a template engine with built-in templates plus any custom
+    /// templates from `adr.templatesDir`.
+    ///
+    /// A relative directory is resolved against the repository root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read or a template is invalid.
+    pub fn for_repo(git: &Git, config: &AdrConfig) -> Result<Self, Error> {
+        let mut engine = Self::new();
+        if let Some(dir) = &config.templates_dir {
+            let dir = git.repo_root()?.join(dir);
+            engine.load_dir(&dir)?;
+        }
+        Ok(engine)
     }
 
     /// Add a custom template.
@@ -256,22 +340,69 @@ impl TemplateEngine {
             .add_raw_template(name, content)
             .map_err(|e| Error::TemplateError {
                 message: format!("Failed to add template '{name}': {e}"),
+            })?;
+        self.custom.insert(name.to_string(), content.to_string());
+        Ok(())
+    }
+
+    /// Load every `*.md` and `*.tera` file in `dir` as a custom template
+    /// named after the file stem.
+    ///
+    /// Returns the number of templates loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read or a template is invalid.
+    pub fn load_dir(&mut self, dir: &Path) -> Result<usize, Error> {
+        let entries = std::fs::read_dir(dir).map_err(|e| Error::IoError {
+            message: format!("Failed to read templates directory {}: {e}", dir.display()),
+        })?;
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| TEMPLATE_EXTENSIONS.contains(&ext))
             })
+            .collect();
+        paths.sort();
+
+        for path in &paths {
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let content = std::fs::read_to_string(path).map_err(|e| Error::IoError {
+                message: format!("Failed to read template {}: {e}", path.display()),
+            })?;
+            self.add_template(name, &content)?;
+        }
+
+        Ok(paths.len())
     }
 
     /// Render a template with the given context.
     ///
+    /// The context must be a JSON object; its values may be strings, lists,
+    /// or nested objects, so templates can loop over fields like `options`.
+    ///
     /// # Errors
     ///
-    /// Returns an error if rendering fails.
-    pub fn render(
-        &self,
-        template: &str,
-        context: &HashMap<String, String>,
-    ) -> Result<String, Error> {
+    /// Returns an error if the context is not an object or rendering fails.
+    pub fn render(&self, template: &str, context: &serde_json::Value) -> Result<String, Error> {
+        let serde_json::Value::Object(map) = context else {
+            return Err(Error::TemplateError {
+                message: format!(
+                    "Failed to render template '{template}': context must be an object"
+                ),
+            });
+        };
+
         let mut tera_context = Context::new();
-        for (key, value) in context {
-            tera_context.insert(key, value);
+        for (key, value) in map {
+            tera_context.insert(key.clone(), value);
         }
 
         self.tera
@@ -281,41 +412,333 @@ impl TemplateEngine {
             })
     }
 
+    /// Render a template with an ADR's frontmatter as the context.
+    ///
+    /// See [`adr_context`](Self::adr_context) for the variables provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter can't be converted or rendering fails.
+    pub fn render_with_adr(&self, template: &str, adr: &Adr) -> Result<String, Error> {
+        self.render(template, &Self::adr_context(adr)?)
+    }
+
+    /// Render a template for an existing ADR, carrying its body over.
+    ///
+    /// The body is split into `## ` sections, each filling the template
+    /// variable it corresponds to (both `## Context` and MADR's `## Context
+    /// and Problem Statement` fill `context`). Template defaults only show
+    /// for sections the body lacks, and sections the template has no place
+    /// for are appended under their original headings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter can't be converted or rendering fails.
+    pub fn render_converted(&self, template: &str, adr: &Adr) -> Result<String, Error> {
+        let variables = self.template_variables(template);
+        let mut context = Self::adr_context(adr)?;
+
+        // The template renders the status itself
+        let sections: Vec<_> = split_sections(&adr.body)
+            .into_iter()
+            .filter(|(heading, _)| {
+                !heading
+                    .as_deref()
+                    .is_some_and(|h| h.eq_ignore_ascii_case("status"))
+            })
+            .map(|(heading, content)| {
+                let variable = heading.as_deref().and_then(section_variable);
+                (heading, content, variable)
+            })
+            .collect();
+
+        // Sections matching a variable by name win over equivalents
+        let mut assignments: Vec<(&str, &str)> =
+            variables.iter().map(|v| (v.as_str(), v.as_str())).collect();
+        for (target, sources) in SECTION_EQUIVALENTS {
+            if variables.iter().any(|v| v == target) {
+                assignments.extend(sources.iter().map(|source| (*target, *source)));
+            }
+        }
+
+        let mut used = vec![false; sections.len()];
+        let mut filled: Vec<&str> = Vec::new();
+        for (target, source) in assignments {
+            if filled.contains(&target) {
+                continue;
+            }
+            let found = sections
+                .iter()
+                .enumerate()
+                .find(|(i, (_, _, variable))| !used[*i] && *variable == Some(source));
+            if let Some((i, (_, content, _))) = found {
+                context[target] = content.clone().into();
+                used[i] = true;
+                filled.push(target);
+            }
+        }
+
+        let mut body = self.render(template, &context)?;
+        let leftover: Vec<_> = sections
+            .iter()
+            .zip(&used)
+            .filter(|((_, content, _), used)| !**used && !content.is_empty())
+            .collect();
+        if !leftover.is_empty() {
+            body.truncate(body.trim_end().len());
+            body.push('\n');
+            for ((heading, content, _), _) in leftover {
+                let _ = match heading {
+                    Some(heading) => write!(body, "\n## {heading}\n\n{content}\n"),
+                    None => write!(body, "\n{content}\n"),
+                };
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Sections the ADR's format requires that are missing, empty, or still
+    /// hold the template's placeholder text.
+    ///
+    /// The format comes from the ADR's `format` field, falling back to
+    /// `default_format`. Placeholders are found by rendering the format's
+    /// template for the ADR with no section content and comparing each
+    /// section, ignoring whitespace.
+    #[must_use]
+    pub fn incomplete_sections(
+        &self,
+        adr: &Adr,
+        default_format: &str,
+    ) -> Vec<(&'static str, SectionIssueKind)> {
+        let format = adr.frontmatter.format.as_deref().unwrap_or(default_format);
+        let Some((_, required)) = REQUIRED_SECTIONS.iter().find(|(name, _)| *name == format) else {
+            return Vec::new();
+        };
+
+        let placeholders = Self::adr_context(adr)
+            .and_then(|context| self.render(format, &context))
+            .map(|pristine| section_contents(&pristine))
+            .unwrap_or_default();
+        let contents = section_contents(&adr.body);
+
+        required
+            .iter()
+            .filter_map(|(variable, heading)| {
+                let content = contents
+                    .iter()
+                    .find(|(v, content)| v == variable && !content.is_empty());
+                let kind = match content {
+                    None => SectionIssueKind::Missing,
+                    Some(section) if placeholders.contains(section) => {
+                        SectionIssueKind::Placeholder
+                    },
+                    Some(_) => return None,
+                };
+                Some((*heading, kind))
+            })
+            .collect()
+    }
+
+    /// Build a template context from an ADR's typed frontmatter.
+    ///
+    /// Every frontmatter field is included under its frontmatter name, so
+    /// `deciders`, `tags` and `links` stay lists, `date` is `YYYY-MM-DD`
+    /// and custom fields appear at the top level. `id` is always set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frontmatter can't be converted to JSON.
+    pub fn adr_context(adr: &Adr) -> Result<serde_json::Value, Error> {
+        let mut context =
+            serde_json::to_value(&adr.frontmatter).map_err(|e| Error::TemplateError {
+                message: format!("Failed to build template context for {}: {e}", adr.id),
+            })?;
+        context["id"] = adr.id.clone().into();
+        Ok(context)
+    }
+
     /// List available templates.
     #[must_use]
     pub fn list_templates(&self) -> Vec<String> {
         self.tera.get_template_names().map(String::from).collect()
     }
 
+    /// Names of the context variables a template reads, in order of first use.
+    ///
+    /// Loop variables, `set` targets and the built-in `loop` object are
+    /// excluded, so only values the caller has to supply are reported.
+    /// Returns an empty list if the template doesn't exist.
+    #[must_use]
+    pub fn template_variables(&self, name: &str) -> Vec<String> {
+        let (Ok(source), Ok(found)) = (
+            self.get_template(name),
+            self.tera.get_template_variables(name),
+        ) else {
+            return Vec::new();
+        };
+
+        let mut variables: Vec<(usize, &str)> = found
+            .into_iter()
+            .filter(|var| *var != "loop")
+            .map(|var| (first_use(&source, var), var))
+            .collect();
+        variables.sort_unstable();
+        variables.into_iter().map(|(_, var)| var.to_string()).collect()
+    }
+
     /// Check if a template exists.
     #[must_use]
     pub fn has_template(&self, name: &str) -> bool {
         self.tera.get_template_names().any(|n| n == name)
     }
 
+    /// Names of custom templates, sorted.
+    pub fn custom_templates(&self) -> impl Iterator<Item = &str> {
+        self.custom.keys().map(String::as_str)
+    }
+
+    /// Check if a template is a built-in that hasn't been overridden.
+    #[must_use]
+    pub fn is_builtin(&self, name: &str) -> bool {
+        !self.custom.contains_key(name) && BUILTIN_TEMPLATES.iter().any(|(n, _, _)| *n == name)
+    }
+
+    /// One-line description of a template.
+    ///
+    /// Custom templates are described by a leading `{# ... #}` comment.
+    #[must_use]
+    pub fn description(&self, name: &str) -> Option<String> {
+        if let Some(content) = self.custom.get(name) {
+            let comment = content.trim_start().strip_prefix("{#")?;
+            let end = comment.find("#}")?;
+            return comment[..end]
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .map(String::from);
+        }
+
+        BUILTIN_TEMPLATES
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, _, description)| (*description).to_string())
+    }
+
     /// Get template content.
     ///
     /// # Errors
     ///
     /// Returns an error if the template doesn't exist.
     pub fn get_template(&self, name: &str) -> Result<String, Error> {
-        // Built-in templates
-        match name {
-            "nygard" => Ok(TEMPLATE_NYGARD.to_string()),
-            "madr" => Ok(TEMPLATE_MADR.to_string()),
-            "y-statement" => Ok(TEMPLATE_Y_STATEMENT.to_string()),
-            "alexandrian" => Ok(TEMPLATE_ALEXANDRIAN.to_string()),
-            "business-case" => Ok(TEMPLATE_BUSINESS_CASE.to_string()),
-            _ => Err(Error::TemplateNotFound {
+        if let Some(content) = self.custom.get(name) {
+            return Ok(content.clone());
+        }
+
+        BUILTIN_TEMPLATES
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, content, _)| (*content).to_string())
+            .ok_or_else(|| Error::TemplateNotFound {
                 name: name.to_string(),
-            }),
+            })
+    }
+}
+
+/// Split a markdown body into `(heading, content)` pairs, one per `## `
+/// section.
+///
+/// Text before the first section, other than the `# ` title, comes first
+/// with no heading. Headings inside fenced code blocks don't split.
+fn split_sections(body: &str) -> Vec<(Option<String>, String)> {
+    let mut sections = vec![(None, String::new())];
+    let mut in_fence = false;
+
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            if let Some(heading) = line.strip_prefix("## ") {
+                sections.push((Some(heading.trim().to_string()), String::new()));
+                continue;
+            }
+            if sections.len() == 1 && line.starts_with("# ") {
+                continue;
+            }
+        }
+
+        if let Some((_, content)) = sections.last_mut() {
+            content.push_str(line);
+            content.push('\n');
         }
     }
+
+    sections
+        .into_iter()
+        .map(|(heading, content)| (heading, content.trim().to_string()))
+        .filter(|(heading, content)| heading.is_some() || !content.is_empty())
+        .collect()
+}
+
+/// `(template variable, content)` for each section of a body that fills a
+/// variable, with whitespace collapsed.
+fn section_contents(body: &str) -> Vec<(&'static str, String)> {
+    split_sections(body)
+        .into_iter()
+        .filter_map(|(heading, content)| {
+            let variable = section_variable(heading.as_deref()?)?;
+            let content = content.split_whitespace().collect::<Vec<_>>().join(" ");
+            Some((variable, content))
+        })
+        .collect()
+}
+
+/// Template variable filled by a section with this heading.
+fn section_variable(heading: &str) -> Option<&'static str> {
+    SECTION_VARIABLES
+        .iter()
+        .find(|(name, _)| heading.eq_ignore_ascii_case(name))
+        .map(|(_, variable)| *variable)
+}
+
+/// Sections each format requires, as `(template variable, heading)` pairs.
+///
+/// Formats not listed have no required sections.
+const REQUIRED_SECTIONS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "nygard",
+        &[
+            ("context", "Context"),
+            ("decision", "Decision"),
+            ("consequences", "Consequences"),
+        ],
+    ),
+    (
+        "madr",
+        &[
+            ("context", "Context and Problem Statement"),
+            ("decision", "Decision Outcome"),
+        ],
+    ),
+];
+
+/// Byte offset of the first whole-word occurrence of `ident` in `source`.
+fn first_use(source: &str, ident: &str) -> usize {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    source
+        .match_indices(ident)
+        .find(|(i, _)| {
+            !source[..*i].ends_with(is_ident)
+                && !source[i + ident.len()..].starts_with(is_ident)
+        })
+        .map_or(usize::MAX, |(i, _)| i)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[test]
     fn test_template_engine_new() {
@@ -334,9 +757,7 @@ mod tests {
     #[test]
     fn test_render_nygard() {
         let engine = TemplateEngine::new();
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), "Test ADR".to_string());
-        context.insert("status".to_string(), "Proposed".to_string());
+        let context = json!({ "title": "Test ADR", "status": "Proposed" });
 
         let result = engine
             .render("nygard", &context)
@@ -349,21 +770,73 @@ mod tests {
     #[test]
     fn test_render_madr() {
         let engine = TemplateEngine::new();
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), "MADR Test".to_string());
-        context.insert("status".to_string(), "accepted".to_string());
+        let context = json!({ "title": "MADR Test", "status": "accepted" });
 
         let result = engine.render("madr", &context).expect("Should render");
         assert!(result.contains("# MADR Test"));
         assert!(result.contains("Context and Problem Statement"));
     }
 
+    #[test]
+    fn test_render_madr_with_lists() {
+        let engine = TemplateEngine::new();
+        let context = json!({
+            "title": "MADR Lists",
+            "status": "proposed",
+            "deciders": ["Alice", "Bob"],
+            "options": ["PostgreSQL", "SQLite"],
+        });
+
+        let result = engine.render("madr", &context).expect("Should render");
+        assert!(result.contains("Deciders: Alice, Bob"));
+        assert!(result.contains("* PostgreSQL"));
+        assert!(result.contains("* SQLite"));
+        assert!(!result.contains("* Option 1"));
+    }
+
+    #[test]
+    fn test_render_with_adr_madr() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+        adr.frontmatter.deciders = vec!["Alice".to_string(), "Bob".to_string()];
+        adr.frontmatter.date = Some(crate::core::FlexibleDate(
+            chrono::DateTime::parse_from_rfc3339("2025-03-04T10:00:00Z")
+                .expect("valid date")
+                .into(),
+        ));
+
+        let result = engine.render_with_adr("madr", &adr).expect("Should render");
+        assert!(result.contains("# Use Rust"));
+        assert!(result.contains("Deciders: Alice, Bob"));
+        assert!(result.contains("Date: 2025-03-04"));
+    }
+
+    #[test]
+    fn test_adr_context_keeps_types() {
+        let mut adr = Adr::new("ADR-0002".to_string(), "Typed".to_string());
+        adr.frontmatter.tags = vec!["api".to_string()];
+        adr.frontmatter
+            .custom
+            .insert("priority".to_string(), serde_yaml::Value::from(2));
+
+        let context = TemplateEngine::adr_context(&adr).expect("Should build context");
+        assert_eq!(context["id"], "ADR-0002");
+        assert_eq!(context["status"], "proposed");
+        assert_eq!(context["tags"], json!(["api"]));
+        assert_eq!(context["priority"], 2);
+    }
+
+    #[test]
+    fn test_render_non_object_context() {
+        let engine = TemplateEngine::new();
+        let result = engine.render("nygard", &json!(["not", "an", "object"]));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_render_y_statement() {
         let engine = TemplateEngine::new();
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), "Y Statement Test".to_string());
-        context.insert("status".to_string(), "proposed".to_string());
+        let context = json!({ "title": "Y Statement Test", "status": "proposed" });
 
         let result = engine
             .render("y-statement", &context)
@@ -375,9 +848,7 @@ mod tests {
     #[test]
     fn test_render_alexandrian() {
         let engine = TemplateEngine::new();
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), "Alexandrian Test".to_string());
-        context.insert("status".to_string(), "proposed".to_string());
+        let context = json!({ "title": "Alexandrian Test", "status": "proposed" });
 
         let result = engine
             .render("alexandrian", &context)
@@ -390,9 +861,7 @@ mod tests {
     #[test]
     fn test_render_business_case() {
         let engine = TemplateEngine::new();
-        let mut context = HashMap::new();
-        context.insert("title".to_string(), "Business Case Test".to_string());
-        context.insert("status".to_string(), "proposed".to_string());
+        let context = json!({ "title": "Business Case Test", "status": "proposed" });
 
         let result = engine
             .render("business-case", &context)
@@ -430,6 +899,199 @@ mod tests {
         assert!(nygard.contains("## Context"));
     }
 
+    #[test]
+    fn test_get_custom_template() {
+        let mut engine = TemplateEngine::new();
+        engine
+            .add_template("custom", "# {{ title }}")
+            .expect("Should add template");
+        assert_eq!(
+            engine.get_template("custom").expect("Should get template"),
+            "# {{ title }}"
+        );
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = tempfile::TempDir::new().expect("Failed to create temp directory");
+        std::fs::write(
+            dir.path().join("lightweight.md"),
+            "{# Lightweight team template #}\n# {{ title }}\n",
+        )
+        .expect("Failed to write template");
+        std::fs::write(dir.path().join("nygard.tera"), "# {{ title }} (ours)\n")
+            .expect("Failed to write template");
+        std::fs::write(dir.path().join("notes.txt"), "ignored").expect("Failed to write file");
+
+        let mut engine = TemplateEngine::new();
+        let loaded = engine.load_dir(dir.path()).expect("Should load templates");
+        assert_eq!(loaded, 2);
+
+        assert_eq!(
+            engine.custom_templates().collect::<Vec<_>>(),
+            vec!["lightweight", "nygard"]
+        );
+        assert!(!engine.is_builtin("lightweight"));
+        assert!(!engine.is_builtin("nygard"));
+        assert!(engine.is_builtin("madr"));
+        assert!(!engine.has_template("notes"));
+
+        assert_eq!(
+            engine.description("lightweight").as_deref(),
+            Some("Lightweight team template")
+        );
+        assert_eq!(engine.description("nygard"), None);
+        assert!(engine.description("madr").is_some());
+
+        let rendered = engine
+            .render("nygard", &json!({ "title": "Overridden" }))
+            .expect("Should render");
+        assert_eq!(rendered, "# Overridden (ours)\n");
+    }
+
+    #[test]
+    fn test_template_variables_nygard() {
+        let engine = TemplateEngine::new();
+        assert_eq!(
+            engine.template_variables("nygard"),
+            vec!["title", "status", "context", "decision", "consequences"]
+        );
+    }
+
+    #[test]
+    fn test_template_variables_excludes_locals() {
+        let engine = TemplateEngine::new();
+        let vars = engine.template_variables("business-case");
+        assert!(vars.contains(&"alternatives".to_string()));
+        assert!(vars.contains(&"metrics".to_string()));
+        assert!(!vars.contains(&"alt".to_string()));
+        assert!(!vars.contains(&"metric".to_string()));
+
+        let madr = engine.template_variables("madr");
+        assert!(madr.contains(&"deciders".to_string()));
+        assert!(madr.contains(&"options".to_string()));
+        assert!(!madr.contains(&"option".to_string()));
+    }
+
+    #[test]
+    fn test_template_variables_unknown() {
+        let engine = TemplateEngine::new();
+        assert!(engine.template_variables("nonexistent").is_empty());
+    }
+
+    #[test]
+    fn test_split_sections() {
+        let body = "# Title\n\nIntro text.\n\n## Context\n\nWhy.\n\n```sh\n## not a heading\n```\n\n## Decision\n\nWhat.\n";
+        let sections = split_sections(body);
+        assert_eq!(
+            sections,
+            vec![
+                (None, "Intro text.".to_string()),
+                (
+                    Some("Context".to_string()),
+                    "Why.\n\n```sh\n## not a heading\n```".to_string()
+                ),
+                (Some("Decision".to_string()), "What.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incomplete_sections() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use PostgreSQL".to_string());
+        adr.body = "## Context\n\nWhy.\n\n## Decision\n\n## Consequences\n\nBackups.\n".to_string();
+        assert_eq!(
+            engine.incomplete_sections(&adr, "nygard"),
+            [("Decision", SectionIssueKind::Missing)]
+        );
+        assert!(engine.incomplete_sections(&adr, "y-statement").is_empty());
+
+        adr.frontmatter.format = Some("madr".to_string());
+        adr.body = "## Context and Problem Statement\n\nWhy.\n".to_string();
+        assert_eq!(
+            engine.incomplete_sections(&adr, "nygard"),
+            [("Decision Outcome", SectionIssueKind::Missing)]
+        );
+    }
+
+    #[test]
+    fn test_incomplete_sections_placeholders() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use PostgreSQL".to_string());
+        adr.body = engine.render_with_adr("nygard", &adr).unwrap();
+        assert_eq!(
+            engine.incomplete_sections(&adr, "nygard"),
+            [
+                ("Context", SectionIssueKind::Placeholder),
+                ("Decision", SectionIssueKind::Placeholder),
+                ("Consequences", SectionIssueKind::Placeholder),
+            ]
+        );
+
+        adr.body = adr.body.replacen(
+            "What is the change that we're proposing and/or doing?",
+            "Use PostgreSQL 16.",
+            1,
+        );
+        assert_eq!(
+            engine.incomplete_sections(&adr, "nygard"),
+            [
+                ("Context", SectionIssueKind::Placeholder),
+                ("Consequences", SectionIssueKind::Placeholder),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_converted_nygard_to_madr() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use PostgreSQL".to_string());
+        adr.body = "# Use PostgreSQL\n\n## Status\n\nproposed\n\n## Context\n\nWe need ACID transactions across services.\n\n## Decision\n\nUse PostgreSQL 16.\n\n## Consequences\n\nOps must run backups.\n".to_string();
+
+        let result = engine
+            .render_converted("madr", &adr)
+            .expect("Should convert");
+        assert!(result.contains(
+            "## Context and Problem Statement\n\nWe need ACID transactions across services."
+        ));
+        assert!(!result.contains("Describe the context and problem statement"));
+        assert!(result.contains("Chosen option: \"Use PostgreSQL 16.\""));
+        // MADR has no free-form consequences section, so it is carried over as-is
+        assert!(result.ends_with("## Consequences\n\nOps must run backups.\n"));
+        // Sections missing from the source keep their placeholders
+        assert!(result.contains("Additional information, links, references..."));
+    }
+
+    #[test]
+    fn test_render_converted_madr_to_nygard() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use PostgreSQL".to_string());
+        adr.body = "# Use PostgreSQL\n\n## Context and Problem Statement\n\nWe need ACID.\n\n## Considered Options\n\n* PostgreSQL\n* SQLite\n\n## Decision Outcome\n\nChosen option: \"PostgreSQL\"\n".to_string();
+
+        let result = engine
+            .render_converted("nygard", &adr)
+            .expect("Should convert");
+        assert!(result.contains("## Context\n\nWe need ACID."));
+        assert!(result.contains("## Decision\n\nChosen option: \"PostgreSQL\""));
+        assert!(result.contains("What becomes easier or more difficult"));
+        assert!(result.contains("## Considered Options\n\n* PostgreSQL\n* SQLite\n"));
+    }
+
+    #[test]
+    fn test_render_converted_to_alexandrian() {
+        let engine = TemplateEngine::new();
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use PostgreSQL".to_string());
+        adr.body = "## Context\n\nWe need ACID.\n\n## Decision\n\nUse PostgreSQL.\n".to_string();
+
+        let result = engine
+            .render_converted("alexandrian", &adr)
+            .expect("Should convert");
+        assert!(result.contains("## Problem Statement\n\nWe need ACID."));
+        assert!(result.contains("## Solution\n\nUse PostgreSQL."));
+        assert!(!result.contains("## Context"));
+    }
+
     #[test]
     fn test_get_template_not_found() {
         let engine = TemplateEngine::new();
@@ -440,7 +1102,7 @@ mod tests {
     #[test]
     fn test_render_nonexistent_template() {
         let engine = TemplateEngine::new();
-        let context = HashMap::new();
+        let context = json!({});
         let result = engine.render("nonexistent", &context);
         assert!(result.is_err());
     }
@@ -0,0 +1,75 @@
+//! Relationship graph export (Graphviz DOT and Mermaid).
+
+use crate::core::{dot_graph, mermaid_graph, Adr};
+use crate::export::{ExportResult, Exporter};
+use crate::Error;
+use std::path::Path;
+
+/// Exports the relationship graph between ADRs.
+///
+/// Exporting a single ADR writes the graph of that ADR and its outgoing
+/// links; exporting a set writes one graph file covering all of them.
+#[derive(Debug, Default)]
+pub struct GraphExporter {
+    /// Emit a Mermaid flowchart instead of Graphviz DOT.
+    pub mermaid: bool,
+}
+
+impl GraphExporter {
+    /// Create a Graphviz DOT exporter.
+    #[must_use]
+    pub fn dot() -> Self {
+        Self::default()
+    }
+
+    /// Create a Mermaid flowchart exporter.
+    #[must_use]
+    pub fn mermaid() -> Self {
+        Self { mermaid: true }
+    }
+
+    /// Render the graph of `adrs`.
+    #[must_use]
+    pub fn render(&self, adrs: &[Adr]) -> String {
+        if self.mermaid {
+            mermaid_graph(adrs)
+        } else {
+            dot_graph(adrs)
+        }
+    }
+
+    /// File name used when exporting a set of ADRs.
+    const fn filename(&self) -> &'static str {
+        if self.mermaid {
+            "graph.mmd"
+        } else {
+            "graph.dot"
+        }
+    }
+}
+
+impl Exporter for GraphExporter {
+    fn export(&self, adr: &Adr, path: &Path) -> Result<(), Error> {
+        let content = self.render(std::slice::from_ref(adr));
+        std::fs::write(path, content).map_err(|e| Error::IoError {
+            message: format!("Failed to write {}: {e}", path.display()),
+        })
+    }
+
+    fn export_all(&self, adrs: &[Adr], dir: &Path) -> Result<ExportResult, Error> {
+        std::fs::create_dir_all(dir).map_err(|e| Error::IoError {
+            message: format!("Failed to create directory {}: {e}", dir.display()),
+        })?;
+
+        let path = dir.join(self.filename());
+        std::fs::write(&path, self.render(adrs)).map_err(|e| Error::IoError {
+            message: format!("Failed to write {}: {e}", path.display()),
+        })?;
+
+        Ok(ExportResult {
+            exported: adrs.len(),
+            files: vec![path.display().to_string()],
+            errors: Vec::new(),
+        })
+    }
+}
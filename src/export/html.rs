@@ -1,6 +1,6 @@
 //! HTML export functionality.
 
-use crate::core::Adr;
+use crate::core::{markdown_to_html, Adr};
 use crate::export::{ExportResult, Exporter};
 use crate::Error;
 use std::path::Path;
@@ -54,27 +54,7 @@ impl HtmlExporter {
 
     /// Generate HTML from markdown content.
     fn markdown_to_html(&self, content: &str) -> String {
-        // Simple markdown to HTML conversion
-        // TODO: Use a proper markdown parser
-        content
-            .lines()
-            .map(|line| {
-                if let Some(rest) = line.strip_prefix("# ") {
-                    format!("<h1>{}</h1>", html_escape(rest))
-                } else if let Some(rest) = line.strip_prefix("## ") {
-                    format!("<h2>{}</h2>", html_escape(rest))
-                } else if let Some(rest) = line.strip_prefix("### ") {
-                    format!("<h3>{}</h3>", html_escape(rest))
-                } else if let Some(rest) = line.strip_prefix("- ") {
-                    format!("<li>{}</li>", html_escape(rest))
-                } else if line.is_empty() {
-                    String::new()
-                } else {
-                    format!("<p>{}</p>", html_escape(line))
-                }
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
+        markdown_to_html(content)
     }
 
     /// Generate the CSS style.
@@ -275,3 +255,48 @@ impl HtmlExporter {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(markdown: &str) -> String {
+        HtmlExporter::new().markdown_to_html(markdown)
+    }
+
+    #[test]
+    fn test_markdown_nested_list() {
+        let html = render("- Option A\n  - Pro: fast\n  - Con: costly\n- Option B\n");
+        assert!(html.contains("<li>Option A\n<ul>\n<li>Pro: fast</li>"));
+        assert_eq!(html.matches("<ul>").count(), 2);
+    }
+
+    #[test]
+    fn test_markdown_inline_link() {
+        let html = render("See [RFC 7231](https://example.com/rfc7231) for details.");
+        assert!(html.contains(r#"<a href="https://example.com/rfc7231">RFC 7231</a>"#));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let html = render("| Option | Cost |\n|--------|------|\n| A | Low |\n");
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<th>Option</th>"));
+        assert!(html.contains("<td>Low</td>"));
+    }
+
+    #[test]
+    fn test_markdown_blockquote() {
+        let html = render("> We chose *consistency* over availability.");
+        assert!(html.contains("<blockquote>"));
+        assert!(html.contains("<em>consistency</em>"));
+    }
+
+    #[test]
+    fn test_markdown_raw_html_escaped() {
+        let html = render("<script>alert('x')</script>\n\nText with <b>inline</b> tag.");
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<b>"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+}
@@ -1,9 +1,8 @@
 //! JSON export functionality.
 
-use crate::core::Adr;
+use crate::core::{Adr, AdrJson};
 use crate::export::{ExportResult, Exporter};
 use crate::Error;
-use serde::Serialize;
 use std::path::Path;
 
 /// JSON exporter.
@@ -28,40 +27,6 @@ impl JsonExporter {
     }
 }
 
-/// JSON representation of an ADR.
-#[derive(Debug, Serialize)]
-struct AdrJson<'a> {
-    id: &'a str,
-    commit: &'a str,
-    title: &'a str,
-    status: String,
-    date: Option<String>,
-    tags: &'a [String],
-    authors: &'a [String],
-    deciders: &'a [String],
-    body: &'a str,
-}
-
-impl<'a> AdrJson<'a> {
-    fn from_adr(adr: &'a Adr) -> Self {
-        Self {
-            id: &adr.id,
-            commit: &adr.commit,
-            title: &adr.frontmatter.title,
-            status: adr.frontmatter.status.to_string(),
-            date: adr
-                .frontmatter
-                .date
-                .as_ref()
-                .map(|d| d.datetime().to_rfc3339()),
-            tags: &adr.frontmatter.tags,
-            authors: &adr.frontmatter.authors,
-            deciders: &adr.frontmatter.deciders,
-            body: &adr.body,
-        }
-    }
-}
-
 impl Exporter for JsonExporter {
     fn export(&self, adr: &Adr, path: &Path) -> Result<(), Error> {
         let json_adr = AdrJson::from_adr(adr);
@@ -2,6 +2,7 @@
 //!
 //! This module provides export capabilities:
 //! - DOCX export
+//! - Graphviz DOT and Mermaid relationship graphs
 //! - HTML export
 //! - JSON export
 
@@ -10,10 +11,12 @@ use crate::Error;
 use std::path::Path;
 
 mod docx;
+mod graph;
 mod html;
 mod json;
 
 pub use self::docx::DocxExporter;
+pub use self::graph::GraphExporter;
 pub use self::html::HtmlExporter;
 pub use self::json::JsonExporter;
 
@@ -28,6 +31,10 @@ pub enum ExportFormat {
     Json,
     /// Markdown format.
     Markdown,
+    /// Graphviz DOT relationship graph.
+    Dot,
+    /// Mermaid flowchart relationship graph.
+    Mermaid,
 }
 
 impl std::fmt::Display for ExportFormat {
@@ -37,6 +44,8 @@ impl std::fmt::Display for ExportFormat {
             Self::Html => write!(f, "html"),
             Self::Json => write!(f, "json"),
             Self::Markdown => write!(f, "markdown"),
+            Self::Dot => write!(f, "dot"),
+            Self::Mermaid => write!(f, "mermaid"),
         }
     }
 }
@@ -50,6 +59,8 @@ impl std::str::FromStr for ExportFormat {
             "html" => Ok(Self::Html),
             "json" => Ok(Self::Json),
             "markdown" | "md" => Ok(Self::Markdown),
+            "dot" | "graphviz" => Ok(Self::Dot),
+            "mermaid" | "mmd" => Ok(Self::Mermaid),
             _ => Err(Error::InvalidFormat {
                 format: s.to_string(),
             }),
@@ -95,6 +106,8 @@ pub fn export_adrs(adrs: &[Adr], dir: &Path, format: ExportFormat) -> Result<Exp
         ExportFormat::Docx => DocxExporter::new().export_all(adrs, dir),
         ExportFormat::Html => HtmlExporter::new().export_all(adrs, dir),
         ExportFormat::Json => JsonExporter::new().export_all(adrs, dir),
+        ExportFormat::Dot => GraphExporter::dot().export_all(adrs, dir),
+        ExportFormat::Mermaid => GraphExporter::mermaid().export_all(adrs, dir),
         ExportFormat::Markdown => {
             // Markdown export is just copying the original content
             let mut result = ExportResult::default();
@@ -172,6 +172,15 @@ pub enum Error {
         message: String,
     },
 
+    /// Frontmatter field missing from an ADR.
+    #[error("ADR {id} has no field {field}")]
+    FieldNotFound {
+        /// ADR ID.
+        id: String,
+        /// Field name that was asked for.
+        field: String,
+    },
+
     /// Template not found.
     #[error("template not found: {name}")]
     TemplateNotFound {
@@ -211,6 +220,13 @@ pub enum Error {
         message: String,
     },
 
+    /// GPG signing or signature verification failed.
+    #[error("signing error: {message} - check your GPG setup (user.signingkey, gpg.program)")]
+    Signing {
+        /// Error message.
+        message: String,
+    },
+
     /// Invalid format.
     #[error("invalid format: {format}")]
     InvalidFormat {
@@ -276,6 +292,88 @@ impl Error {
             message: message.into(),
         }
     }
+
+    /// Create a signing error.
+    #[must_use]
+    pub fn signing(message: impl Into<String>) -> Self {
+        Self::Signing {
+            message: message.into(),
+        }
+    }
+
+    /// Name of the error variant, for machine-readable error output.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Git { .. } => "Git",
+            Self::GitNotFound => "GitNotFound",
+            Self::NotARepository { .. } => "NotARepository",
+            Self::NotInitialized => "NotInitialized",
+            Self::AdrNotFound { .. } => "AdrNotFound",
+            Self::InvalidAdr { .. } => "InvalidAdr",
+            Self::Yaml(_) => "Yaml",
+            Self::Json(_) => "Json",
+            Self::Io(_) => "Io",
+            Self::Template(_) => "Template",
+            Self::Config { .. } => "Config",
+            Self::Validation { .. } => "Validation",
+            Self::ContentTooLarge { .. } => "ContentTooLarge",
+            Self::FeatureNotAvailable { .. } => "FeatureNotAvailable",
+            Self::InvalidStatus { .. } => "InvalidStatus",
+            Self::ParseError { .. } => "ParseError",
+            Self::TemplateError { .. } => "TemplateError",
+            Self::TemplateNotFound { .. } => "TemplateNotFound",
+            Self::FieldNotFound { .. } => "FieldNotFound",
+            #[cfg(feature = "ai")]
+            Self::AiNotConfigured { .. } => "AiNotConfigured",
+            #[cfg(feature = "ai")]
+            Self::InvalidProvider { .. } => "InvalidProvider",
+            #[cfg(feature = "wiki")]
+            Self::WikiError { .. } => "WikiError",
+            #[cfg(feature = "export")]
+            Self::ExportError { .. } => "ExportError",
+            Self::Signing { .. } => "Signing",
+            Self::InvalidFormat { .. } => "InvalidFormat",
+            Self::IoError { .. } => "IoError",
+            Self::Other(_) => "Other",
+        }
+    }
+
+    /// Process exit code for the error.
+    ///
+    /// | Code | Errors |
+    /// |------|--------|
+    /// | 3 | not initialized |
+    /// | 4 | ADR or template not found |
+    /// | 5 | invalid input (validation, status, format, parse errors) |
+    /// | 6 | not a git repository, or git not installed |
+    /// | 7 | git command failed |
+    /// | 8 | configuration (including AI provider setup) |
+    /// | 2 | field missing from `show --field` |
+    /// | 1 | anything else |
+    ///
+    /// Code 2 is otherwise used for command-line usage errors.
+    #[must_use]
+    #[allow(clippy::match_same_arms)] // feature-gated variants need their own arm
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::FieldNotFound { .. } => 2,
+            Self::NotInitialized => 3,
+            Self::AdrNotFound { .. } | Self::TemplateNotFound { .. } => 4,
+            Self::Validation { .. }
+            | Self::InvalidAdr { .. }
+            | Self::InvalidStatus { .. }
+            | Self::InvalidFormat { .. }
+            | Self::ParseError { .. }
+            | Self::ContentTooLarge { .. } => 5,
+            Self::NotARepository { .. } | Self::GitNotFound => 6,
+            Self::Git { .. } => 7,
+            Self::Config { .. } | Self::FeatureNotAvailable { .. } => 8,
+            #[cfg(feature = "ai")]
+            Self::AiNotConfigured { .. } | Self::InvalidProvider { .. } => 8,
+            _ => 1,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -294,6 +392,13 @@ mod tests {
         assert!(format!("{err}").contains("git error"));
     }
 
+    #[test]
+    fn test_error_signing() {
+        let err = Error::signing("gpg failed");
+        assert!(matches!(err, Error::Signing { .. }));
+        assert!(format!("{err}").contains("check your GPG setup"));
+    }
+
     #[test]
     fn test_error_adr_not_found() {
         let err = Error::adr_not_found("ADR-0001");
@@ -388,6 +493,26 @@ mod tests {
         assert!(format!("{err}").contains("template not found"));
     }
 
+    #[test]
+    fn test_error_kind_and_exit_code() {
+        let err = Error::adr_not_found("ADR-0001");
+        assert_eq!(err.kind(), "AdrNotFound");
+        assert_eq!(err.exit_code(), 4);
+
+        assert_eq!(Error::NotInitialized.exit_code(), 3);
+        assert_eq!(Error::validation("bad").exit_code(), 5);
+        let err = Error::NotARepository { path: None };
+        assert_eq!(err.kind(), "NotARepository");
+        assert_eq!(err.exit_code(), 6);
+        let err = Error::FieldNotFound {
+            id: "ADR-0001".to_string(),
+            field: "owner".to_string(),
+        };
+        assert_eq!(err.kind(), "FieldNotFound");
+        assert_eq!(err.exit_code(), 2);
+        assert_eq!(Error::Other("oops".to_string()).exit_code(), 1);
+    }
+
     #[test]
     fn test_error_other() {
         let err = Error::Other("generic error".to_string());
@@ -2,38 +2,63 @@
 //!
 //! This is the main entry point for the `git-adr` command-line tool.
 
-use anyhow::Result;
 use clap::Parser;
+use std::process::ExitCode;
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::EnvFilter;
 
 use git_adr::cli::{Cli, Commands};
 
-fn main() -> Result<()> {
-    // Initialize logging
+fn main() -> ExitCode {
+    // Parse CLI arguments
+    let cli = Cli::parse();
+
+    // Progress messages are logged at info level, so --quiet hides them and
+    // --verbose adds debug output; RUST_LOG overrides both
+    let level = if cli.quiet {
+        "error"
+    } else if cli.verbose {
+        "warn,git_adr=debug"
+    } else {
+        "warn,git_adr=info"
+    };
     tracing_subscriber::fmt()
         .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level)),
         )
         .with_writer(std::io::stderr)
+        .event_format(MessageFormat)
         .init();
 
-    // Parse CLI arguments
-    let cli = Cli::parse();
+    // Honor --no-color and NO_COLOR (https://no-color.org) even when
+    // CLICOLOR_FORCE is set
+    if cli.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        colored::control::set_override(false);
+        dialoguer::console::set_colors_enabled_stderr(false);
+    }
 
     // Execute command
-    match cli.command {
+    let json_errors = cli.error_format == "json";
+    let result = match cli.command {
         Commands::Init(args) => git_adr::cli::init::run(args),
         Commands::New(args) => git_adr::cli::new::run(args),
         Commands::List(args) => git_adr::cli::list::run(args),
         Commands::Show(args) => git_adr::cli::show::run(args),
         Commands::Edit(args) => git_adr::cli::edit::run(args),
         Commands::Rm(args) => git_adr::cli::rm::run(args),
+        Commands::Restore(args) => git_adr::cli::restore::run(args),
         Commands::Search(args) => git_adr::cli::search::run(args),
         Commands::Sync(args) => git_adr::cli::sync::run(args),
         Commands::Config(args) => git_adr::cli::config::run(args),
         Commands::Link(args) => git_adr::cli::link::run(args),
+        Commands::Links(args) => git_adr::cli::links::run(args),
         Commands::Supersede(args) => git_adr::cli::supersede::run(args),
+        Commands::Tag(args) => git_adr::cli::tag::run(args),
         Commands::Log(args) => git_adr::cli::log::run(args),
+        Commands::Diff(args) => git_adr::cli::diff::run(args),
         Commands::Stats(args) => git_adr::cli::stats::run(args),
         Commands::Convert(args) => git_adr::cli::convert::run(args),
         Commands::Attach(args) => git_adr::cli::attach::run(args),
@@ -41,14 +66,66 @@ fn main() -> Result<()> {
         Commands::Export(args) => git_adr::cli::export::run(args),
         Commands::Import(args) => git_adr::cli::import::run(args),
         Commands::Hooks(args) => git_adr::cli::hooks::run(args),
+        Commands::CheckCommit(args) => git_adr::cli::check_commit::run(args),
         Commands::Ci(args) => git_adr::cli::ci::run(args),
         Commands::Templates(args) => git_adr::cli::templates::run(args),
         Commands::Report(args) => git_adr::cli::report::run(args),
         Commands::Metrics(args) => git_adr::cli::metrics::run(args),
         Commands::Onboard(args) => git_adr::cli::onboard::run(args),
+        Commands::Validate(args) => git_adr::cli::validate::run(args),
+        Commands::Verify(args) => git_adr::cli::verify::run(args),
         #[cfg(feature = "ai")]
         Commands::Ai(args) => git_adr::cli::ai::run(args),
         #[cfg(feature = "wiki")]
         Commands::Wiki(args) => git_adr::cli::wiki::run(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => report_error(&e, json_errors),
+    }
+}
+
+/// Log line format: progress messages print as-is, warnings and errors
+/// keep their level as a prefix.
+struct MessageFormat;
+
+impl<S, N> FormatEvent<S, N> for MessageFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> std::fmt::Result {
+        let level = *event.metadata().level();
+        if level <= Level::WARN {
+            write!(writer, "{level}: ")?;
+        }
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+/// Print an error and pick the exit code for its [`git_adr::Error`] kind.
+fn report_error(error: &anyhow::Error, json: bool) -> ExitCode {
+    let adr_error = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<git_adr::Error>());
+    let (kind, code) = adr_error.map_or(("Other", 1), |e| (e.kind(), e.exit_code()));
+
+    if json {
+        let report = serde_json::json!({
+            "error": format!("{error:#}"),
+            "kind": kind,
+        });
+        eprintln!("{report}");
+    } else {
+        eprintln!("Error: {error:?}");
     }
+
+    ExitCode::from(u8::try_from(code).unwrap_or(1))
 }
@@ -0,0 +1,408 @@
+//! Confluence integration.
+//!
+//! Each ADR becomes a page in a Confluence space, written through the REST
+//! API. Requests go through `curl` so the wiki feature keeps working without
+//! an HTTP client dependency.
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+use crate::core::Adr;
+use crate::wiki::{WikiClient, WikiConfig};
+use crate::Error;
+
+/// Confluence client for one space.
+#[derive(Debug)]
+pub struct ConfluenceWiki {
+    /// Site URL, e.g. `https://example.atlassian.net/wiki`.
+    pub base_url: String,
+    /// Key of the space pages are written to.
+    pub space_key: String,
+    /// Account email or username.
+    pub username: String,
+    /// API token.
+    token: String,
+}
+
+/// An existing Confluence page.
+#[derive(Debug)]
+struct Page {
+    id: String,
+    version: u64,
+    body: String,
+}
+
+impl ConfluenceWiki {
+    /// Create a new Confluence client.
+    #[must_use]
+    pub fn new(
+        base_url: impl Into<String>,
+        space_key: impl Into<String>,
+        username: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            space_key: space_key.into(),
+            username: username.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Create a client from the wiki configuration.
+    ///
+    /// The username and token fall back to the `CONFLUENCE_USER` and
+    /// `CONFLUENCE_API_TOKEN` environment variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the site URL, space key or credentials are missing.
+    pub fn from_config(config: &WikiConfig) -> Result<Self, Error> {
+        let missing = |what: &str| Error::WikiError {
+            message: format!("Confluence {what} is not configured"),
+        };
+
+        let base_url = config.base_url.clone().ok_or_else(|| missing("site URL"))?;
+        let space_key = config
+            .space_key
+            .clone()
+            .ok_or_else(|| missing("space key"))?;
+        let username = config
+            .username
+            .clone()
+            .or_else(|| std::env::var("CONFLUENCE_USER").ok())
+            .ok_or_else(|| missing("username"))?;
+        let token = config
+            .token
+            .clone()
+            .or_else(|| std::env::var("CONFLUENCE_API_TOKEN").ok())
+            .ok_or_else(|| missing("API token"))?;
+
+        Ok(Self::new(base_url, space_key, username, token))
+    }
+
+    /// Create or update the page for an ADR.
+    ///
+    /// Pages are matched by title, so pushing the same ADR twice updates the
+    /// existing page instead of creating a duplicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Confluence cannot be reached or rejects the page.
+    pub fn push(&self, adr: &Adr) -> Result<(), Error> {
+        self.write_page(&page_title(adr), &storage_format(adr))
+    }
+
+    /// Create or update the page titled `title`.
+    fn write_page(&self, title: &str, body: &str) -> Result<(), Error> {
+        if let Some(page) = self.find_page(title)? {
+            let payload = page_payload(&self.space_key, title, body, Some(&page));
+            self.request(
+                "PUT",
+                &format!("/rest/api/content/{}", page.id),
+                Some(&payload),
+            )?;
+        } else {
+            let payload = page_payload(&self.space_key, title, body, None);
+            self.request("POST", "/rest/api/content", Some(&payload))?;
+        }
+        Ok(())
+    }
+
+    /// Look up a page in the space by exact title.
+    fn find_page(&self, title: &str) -> Result<Option<Page>, Error> {
+        let path = format!(
+            "/rest/api/content?type=page&spaceKey={}&title={}&expand=version,body.storage",
+            url_encode(&self.space_key),
+            url_encode(title)
+        );
+        let response = self.request("GET", &path, None)?;
+
+        let Some(page) = response["results"].get(0) else {
+            return Ok(None);
+        };
+        let id = page["id"].as_str().ok_or_else(|| Error::WikiError {
+            message: format!("Unexpected Confluence response for page '{title}'"),
+        })?;
+
+        Ok(Some(Page {
+            id: id.to_string(),
+            version: page["version"]["number"].as_u64().unwrap_or(1),
+            body: page["body"]["storage"]["value"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        }))
+    }
+
+    /// Send a request to the REST API and parse the JSON response.
+    ///
+    /// Everything, including the credentials, is passed to `curl` as a
+    /// config file on stdin so none of it shows up in the process list.
+    fn request(&self, method: &str, path: &str, body: Option<&Value>) -> Result<Value, Error> {
+        let wiki_error = |message: String| Error::WikiError { message };
+
+        let mut config = format!(
+            "url = {}\nrequest = {}\nuser = {}\nheader = \"Accept: application/json\"\n",
+            curl_quote(&format!("{}{path}", self.base_url)),
+            curl_quote(method),
+            curl_quote(&format!("{}:{}", self.username, self.token)),
+        );
+        if let Some(body) = body {
+            config.push_str("header = \"Content-Type: application/json\"\n");
+            let _ = writeln!(config, "data-binary = {}", curl_quote(&body.to_string()));
+        }
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| wiki_error(format!("Failed to run curl: {e}")))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(config.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(wiki_error(format!(
+                "Confluence request failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (response, status) = stdout.rsplit_once('\n').unwrap_or(("", &*stdout));
+        if !status.trim().starts_with('2') {
+            return Err(wiki_error(format!(
+                "Confluence returned HTTP {} for {method} {path}: {}",
+                status.trim(),
+                response.chars().take(200).collect::<String>()
+            )));
+        }
+
+        if response.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(response)
+            .map_err(|e| wiki_error(format!("Invalid Confluence response: {e}")))
+    }
+}
+
+impl WikiClient for ConfluenceWiki {
+    fn render(&self, adr: &Adr) -> String {
+        storage_format(adr)
+    }
+
+    fn fetch(&self, adr: &Adr) -> Result<Option<String>, Error> {
+        Ok(self.find_page(&page_title(adr))?.map(|page| page.body))
+    }
+
+    fn write(&self, adr: &Adr, content: &str) -> Result<(), Error> {
+        self.write_page(&page_title(adr), content)
+    }
+
+    fn to_body(&self, _content: &str) -> Result<String, Error> {
+        Err(Error::WikiError {
+            message: "Confluence pages can't be pulled back into ADRs".to_string(),
+        })
+    }
+}
+
+/// Title of an ADR's Confluence page.
+///
+/// Titles are unique within a space, so the ID keeps ADRs with the same
+/// title apart.
+#[must_use]
+pub fn page_title(adr: &Adr) -> String {
+    format!("{}: {}", adr.id, adr.frontmatter.title)
+}
+
+/// Convert an ADR to Confluence storage format (XHTML).
+///
+/// The status is shown above the body, fenced code blocks become code
+/// macros, and raw HTML in the body is escaped rather than passed through.
+#[must_use]
+pub fn storage_format(adr: &Adr) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut events = Vec::new();
+    let mut code: Option<(String, String)> = None;
+    for event in Parser::new_ext(&adr.body, options) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code = Some((language, String::new()));
+            },
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some((language, text)) = code.take() {
+                    events.push(Event::Html(code_macro(&language, &text).into()));
+                }
+            },
+            Event::Text(text) if code.is_some() => {
+                if let Some((_, buffer)) = code.as_mut() {
+                    buffer.push_str(&text);
+                }
+            },
+            Event::Html(raw) | Event::InlineHtml(raw) => events.push(Event::Text(raw)),
+            other => events.push(other),
+        }
+    }
+
+    let mut body = String::new();
+    pulldown_cmark::html::push_html(&mut body, events.into_iter());
+
+    format!(
+        "<p><strong>Status:</strong> {}</p>\n{body}",
+        adr.frontmatter.status
+    )
+}
+
+/// A Confluence code macro holding `text`.
+fn code_macro(language: &str, text: &str) -> String {
+    let mut xml = String::from("<ac:structured-macro ac:name=\"code\">");
+    if !language.is_empty() {
+        let _ = write!(
+            xml,
+            "<ac:parameter ac:name=\"language\">{language}</ac:parameter>"
+        );
+    }
+    // "]]>" would end the CDATA section early, so split it across two
+    let _ = writeln!(
+        xml,
+        "<ac:plain-text-body><![CDATA[{}]]></ac:plain-text-body></ac:structured-macro>",
+        text.replace("]]>", "]]]]><![CDATA[>")
+    );
+    xml
+}
+
+/// Request body creating a page, or updating `existing` to the next version.
+fn page_payload(space_key: &str, title: &str, body: &str, existing: Option<&Page>) -> Value {
+    let mut payload = json!({
+        "type": "page",
+        "title": title,
+        "space": { "key": space_key },
+        "body": { "storage": { "value": body, "representation": "storage" } },
+    });
+    if let Some(page) = existing {
+        payload["id"] = json!(page.id);
+        payload["version"] = json!({ "number": page.version + 1 });
+    }
+    payload
+}
+
+/// Quote a value for a curl config file.
+fn curl_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Percent-encode a query parameter value.
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            },
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AdrStatus;
+    use crate::wiki::WikiPlatform;
+
+    fn sample_adr() -> Adr {
+        let mut adr = Adr::new("ADR-0007".to_string(), "Use <Kafka> & Avro".to_string());
+        adr.frontmatter.status = AdrStatus::Accepted;
+        adr.body = "## Context\n\nWe need *events*.\n\n\
+                    ```rust\nlet x = \"]]>\";\n```\n\n\
+                    <script>alert(1)</script>\n"
+            .to_string();
+        adr
+    }
+
+    #[test]
+    fn test_page_title() {
+        assert_eq!(page_title(&sample_adr()), "ADR-0007: Use <Kafka> & Avro");
+    }
+
+    #[test]
+    fn test_storage_format() {
+        let storage = storage_format(&sample_adr());
+
+        assert!(storage.starts_with("<p><strong>Status:</strong> accepted</p>\n"));
+        assert!(storage.contains("<h2>Context</h2>"));
+        assert!(storage.contains("<p>We need <em>events</em>.</p>"));
+        assert!(storage.contains(concat!(
+            "<ac:structured-macro ac:name=\"code\">",
+            "<ac:parameter ac:name=\"language\">rust</ac:parameter>"
+        )));
+        assert!(storage.contains("<![CDATA[let x = \"]]]]><![CDATA[>\";\n]]>"));
+        assert!(storage.contains("&lt;script&gt;"));
+        assert!(!storage.contains("<script>"));
+        assert!(!storage.contains("<pre>"));
+    }
+
+    #[test]
+    fn test_page_payload_create_and_update() {
+        let create = page_payload("ARCH", "ADR-0007: T", "<p>x</p>", None);
+        assert_eq!(create["space"]["key"], "ARCH");
+        assert_eq!(create["body"]["storage"]["representation"], "storage");
+        assert!(create.get("version").is_none());
+
+        let existing = Page {
+            id: "42".to_string(),
+            version: 3,
+            body: String::new(),
+        };
+        let update = page_payload("ARCH", "ADR-0007: T", "<p>x</p>", Some(&existing));
+        assert_eq!(update["id"], "42");
+        assert_eq!(update["version"]["number"], 4);
+    }
+
+    #[test]
+    fn test_curl_quote_and_url_encode() {
+        assert_eq!(curl_quote("a\"b\\c\nd"), r#""a\"b\\c\nd""#);
+        assert_eq!(
+            url_encode("ADR-0007: Use Kafka"),
+            "ADR-0007%3A%20Use%20Kafka"
+        );
+    }
+
+    #[test]
+    fn test_from_config_requires_space_key() {
+        let config = WikiConfig::new(WikiPlatform::Confluence, "")
+            .with_base_url("https://example.atlassian.net/wiki")
+            .with_token("secret");
+        assert!(ConfluenceWiki::from_config(&config).is_err());
+    }
+}
@@ -1,66 +1,80 @@
 //! GitHub Wiki integration.
+//!
+//! Each ADR is a `<ID>.md` page in the repository's `.wiki.git` repository.
 
-use crate::core::Adr;
+use crate::core::{Adr, Git};
+use crate::wiki::repo::{commit_message, page_file, with_credentials, WikiRepo};
+use crate::wiki::{WikiClient, WikiConfig};
 use crate::Error;
 
 /// GitHub Wiki client.
 #[derive(Debug)]
-#[allow(dead_code)] // Fields used via API methods (stub implementation)
 pub struct GitHubWiki {
     /// Repository owner.
     pub owner: String,
     /// Repository name.
     pub repo: String,
-    /// GitHub API token.
-    pub token: Option<String>,
+    /// Clone of the wiki repository.
+    wiki: WikiRepo,
 }
 
 impl GitHubWiki {
-    /// Create a new GitHub Wiki client.
+    /// Create a new GitHub Wiki client, authenticating with `$GITHUB_TOKEN`
+    /// if it is set.
     #[must_use]
     pub fn new(owner: impl Into<String>, repo: impl Into<String>) -> Self {
-        Self {
-            owner: owner.into(),
-            repo: repo.into(),
-            token: std::env::var("GITHUB_TOKEN").ok(),
-        }
+        Self::build(
+            "https://github.com",
+            owner.into(),
+            repo.into(),
+            std::env::var("GITHUB_TOKEN").ok().as_deref(),
+        )
     }
 
-    /// Push an ADR to the wiki.
+    /// Create a client from the wiki configuration.
+    ///
+    /// The base URL (for GitHub Enterprise) defaults to `https://github.com`
+    /// and the token falls back to `$GITHUB_TOKEN`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the push fails.
-    pub fn push(&self, adr: &Adr) -> Result<(), Error> {
-        // TODO: Implement GitHub Wiki push via git clone/push
-        let _ = adr;
-        Err(Error::WikiError {
-            message: "GitHub Wiki push not yet implemented".to_string(),
-        })
+    /// Returns an error if the repository is not in `owner/repo` form.
+    pub fn from_config(config: &WikiConfig) -> Result<Self, Error> {
+        let Some((owner, repo)) = config.repository.split_once('/') else {
+            return Err(Error::WikiError {
+                message: format!("Invalid GitHub repository format: {}", config.repository),
+            });
+        };
+        let token = config
+            .token
+            .clone()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok());
+        Ok(Self::build(
+            config.base_url.as_deref().unwrap_or("https://github.com"),
+            owner.to_string(),
+            repo.to_string(),
+            token.as_deref(),
+        ))
     }
 
-    /// Pull an ADR from the wiki.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the pull fails.
-    pub fn pull(&self, id: &str) -> Result<Adr, Error> {
-        // TODO: Implement GitHub Wiki pull
-        Err(Error::WikiError {
-            message: format!("GitHub Wiki pull not yet implemented for {id}"),
-        })
+    fn build(base_url: &str, owner: String, repo: String, token: Option<&str>) -> Self {
+        let url = format!("{}/{owner}/{repo}.wiki.git", base_url.trim_end_matches('/'));
+        let url = with_credentials(&url, "x-access-token", token);
+        Self {
+            owner,
+            repo,
+            wiki: WikiRepo::new(url, Git::new()),
+        }
     }
+}
 
-    /// List all ADRs in the wiki.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if listing fails.
-    #[allow(dead_code)] // Will be used when wiki feature is fully implemented
-    pub fn list(&self) -> Result<Vec<String>, Error> {
-        // TODO: Implement GitHub Wiki listing
-        Err(Error::WikiError {
-            message: "GitHub Wiki listing not yet implemented".to_string(),
-        })
+impl WikiClient for GitHubWiki {
+    fn fetch(&self, adr: &Adr) -> Result<Option<String>, Error> {
+        self.wiki.read_page(&page_file(adr))
+    }
+
+    fn write(&self, adr: &Adr, content: &str) -> Result<(), Error> {
+        self.wiki
+            .write_page(&page_file(adr), content, &commit_message(adr))
     }
 }
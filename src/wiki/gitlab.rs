@@ -1,67 +1,76 @@
 //! GitLab Wiki integration.
+//!
+//! Each ADR is a `<ID>.md` page in the project's `.wiki.git` repository.
 
-use crate::core::Adr;
+use crate::core::{Adr, Git};
+use crate::wiki::repo::{commit_message, page_file, with_credentials, WikiRepo};
+use crate::wiki::{WikiClient, WikiConfig};
 use crate::Error;
 
 /// GitLab Wiki client.
 #[derive(Debug)]
-#[allow(dead_code)] // Fields used via API methods (stub implementation)
 pub struct GitLabWiki {
-    /// Project ID or path.
+    /// Project path.
     pub project: String,
-    /// GitLab API token.
-    pub token: Option<String>,
-    /// GitLab API base URL.
+    /// GitLab base URL.
     pub base_url: String,
+    /// Clone of the wiki repository.
+    wiki: WikiRepo,
 }
 
 impl GitLabWiki {
     /// Create a new GitLab Wiki client.
+    ///
+    /// The base URL comes from `$GITLAB_URL`, defaulting to
+    /// `https://gitlab.com`, and `$GITLAB_TOKEN` is used to authenticate if
+    /// it is set.
     #[must_use]
     pub fn new(project: impl Into<String>) -> Self {
-        Self {
-            project: project.into(),
-            token: std::env::var("GITLAB_TOKEN").ok(),
-            base_url: std::env::var("GITLAB_URL")
-                .unwrap_or_else(|_| "https://gitlab.com".to_string()),
-        }
+        let base_url =
+            std::env::var("GITLAB_URL").unwrap_or_else(|_| "https://gitlab.com".to_string());
+        Self::build(
+            project.into(),
+            base_url,
+            std::env::var("GITLAB_TOKEN").ok().as_deref(),
+        )
     }
 
-    /// Push an ADR to the wiki.
-    ///
-    /// # Errors
+    /// Create a client from the wiki configuration.
     ///
-    /// Returns an error if the push fails.
-    pub fn push(&self, adr: &Adr) -> Result<(), Error> {
-        // TODO: Implement GitLab Wiki API push
-        let _ = adr;
-        Err(Error::WikiError {
-            message: "GitLab Wiki push not yet implemented".to_string(),
-        })
+    /// The base URL and token fall back to `$GITLAB_URL` and
+    /// `$GITLAB_TOKEN`.
+    #[must_use]
+    pub fn from_config(config: &WikiConfig) -> Self {
+        let base_url = config
+            .base_url
+            .clone()
+            .or_else(|| std::env::var("GITLAB_URL").ok())
+            .unwrap_or_else(|| "https://gitlab.com".to_string());
+        let token = config
+            .token
+            .clone()
+            .or_else(|| std::env::var("GITLAB_TOKEN").ok());
+        Self::build(config.repository.clone(), base_url, token.as_deref())
     }
 
-    /// Pull an ADR from the wiki.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the pull fails.
-    pub fn pull(&self, id: &str) -> Result<Adr, Error> {
-        // TODO: Implement GitLab Wiki API pull
-        Err(Error::WikiError {
-            message: format!("GitLab Wiki pull not yet implemented for {id}"),
-        })
+    fn build(project: String, base_url: String, token: Option<&str>) -> Self {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        let url = with_credentials(&format!("{base_url}/{project}.wiki.git"), "oauth2", token);
+        Self {
+            project,
+            base_url,
+            wiki: WikiRepo::new(url, Git::new()),
+        }
     }
+}
 
-    /// List all ADRs in the wiki.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if listing fails.
-    #[allow(dead_code)] // Will be used when wiki feature is fully implemented
-    pub fn list(&self) -> Result<Vec<String>, Error> {
-        // TODO: Implement GitLab Wiki API listing
-        Err(Error::WikiError {
-            message: "GitLab Wiki listing not yet implemented".to_string(),
-        })
+impl WikiClient for GitLabWiki {
+    fn fetch(&self, adr: &Adr) -> Result<Option<String>, Error> {
+        self.wiki.read_page(&page_file(adr))
+    }
+
+    fn write(&self, adr: &Adr, content: &str) -> Result<(), Error> {
+        self.wiki
+            .write_page(&page_file(adr), content, &commit_message(adr))
     }
 }
@@ -3,14 +3,22 @@
 //! This module provides synchronization between ADRs and wiki platforms:
 //! - GitHub Wiki
 //! - GitLab Wiki
+//! - Confluence
 
 use crate::Error;
 
+mod confluence;
 mod github;
 mod gitlab;
+mod repo;
 mod service;
+mod sync;
 
-pub use service::{WikiConfig, WikiPlatform, WikiService};
+pub use confluence::ConfluenceWiki;
+pub use github::GitHubWiki;
+pub use gitlab::GitLabWiki;
+pub use service::{SyncResult, WikiConfig, WikiPlatform, WikiService};
+pub use sync::{sync_pages, SyncMode, SyncOptions, SyncState, SyncedHashes, WikiClient};
 
 /// Check if wiki features are available.
 #[must_use]
@@ -24,7 +32,9 @@ pub fn is_available() -> bool {
 ///
 /// Returns an error if detection fails.
 pub fn detect_platform(remote_url: &str) -> Result<WikiPlatform, Error> {
-    if remote_url.contains("github.com") {
+    if remote_url.contains("atlassian.net") {
+        Ok(WikiPlatform::Confluence)
+    } else if remote_url.contains("github.com") {
         Ok(WikiPlatform::GitHub)
     } else if remote_url.contains("gitlab.com") || remote_url.contains("gitlab") {
         Ok(WikiPlatform::GitLab)
@@ -34,3 +44,25 @@ pub fn detect_platform(remote_url: &str) -> Result<WikiPlatform, Error> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_platform() {
+        assert_eq!(
+            detect_platform("git@github.com:org/repo.git").unwrap(),
+            WikiPlatform::GitHub
+        );
+        assert_eq!(
+            detect_platform("https://gitlab.com/org/repo.git").unwrap(),
+            WikiPlatform::GitLab
+        );
+        assert_eq!(
+            detect_platform("https://example.atlassian.net/wiki").unwrap(),
+            WikiPlatform::Confluence
+        );
+        assert!(detect_platform("https://example.com/repo.git").is_err());
+    }
+}
@@ -0,0 +1,270 @@
+//! Wikis stored as git repositories.
+//!
+//! GitHub and GitLab keep a project's wiki in a `<project>.wiki.git`
+//! repository with one markdown file per page. Pages are read from a
+//! temporary clone and written by committing to it and pushing, so the wiki
+//! feature needs nothing beyond git.
+
+use std::cell::OnceCell;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{Adr, Git};
+use crate::Error;
+
+/// A wiki repository, cloned on first use.
+#[derive(Debug)]
+pub struct WikiRepo {
+    /// Clone URL of the wiki repository.
+    url: String,
+    /// The ADR repository, whose user identity wiki commits are made with.
+    git: Git,
+    /// Temporary clone of the wiki.
+    checkout: OnceCell<PathBuf>,
+}
+
+impl WikiRepo {
+    /// Create a wiki repository client for `url`.
+    ///
+    /// Commits are made as the user configured in `git`'s repository.
+    #[must_use]
+    pub fn new(url: impl Into<String>, git: Git) -> Self {
+        Self {
+            url: url.into(),
+            git,
+            checkout: OnceCell::new(),
+        }
+    }
+
+    /// Content of the page stored in `file`, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wiki cannot be cloned or the page read.
+    pub fn read_page(&self, file: &str) -> Result<Option<String>, Error> {
+        let path = self.checkout()?.join(file);
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write `content` to the page stored in `file`, then commit and push it.
+    ///
+    /// A page that already has this content is left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page cannot be committed or the push is
+    /// rejected.
+    pub fn write_page(&self, file: &str, content: &str, message: &str) -> Result<(), Error> {
+        let checkout = self.checkout()?;
+        std::fs::write(checkout.join(file), content)?;
+
+        let wiki = Git::with_work_dir(checkout);
+        wiki.run_silent(&["add", "--", file])?;
+        if wiki.run(&["diff", "--cached", "--quiet"])?.status.success() {
+            return Ok(());
+        }
+        wiki.run_silent(&["commit", "--quiet", "-m", message])?;
+        wiki.run_silent(&["push", "--quiet", "origin", "HEAD"])
+            .map_err(|e| self.error(&format!("failed to push {file}"), &e))
+    }
+
+    /// Path of the clone, cloning the wiki first if needed.
+    fn checkout(&self) -> Result<&Path, Error> {
+        if let Some(path) = self.checkout.get() {
+            return Ok(path);
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let path =
+            std::env::temp_dir().join(format!("git-adr-wiki-{}-{nanos}", std::process::id()));
+        let target = path.to_string_lossy();
+        self.git
+            .run_silent(&["clone", "--quiet", &self.url, &target])
+            .map_err(|e| {
+                self.error(
+                    "failed to clone the wiki (a new wiki needs its first page created online)",
+                    &e,
+                )
+            })?;
+
+        // Commit as the ADR author rather than whoever the clone defaults to
+        if let Ok((name, email)) = self.git.user_identity() {
+            let wiki = Git::with_work_dir(&path);
+            wiki.config_set("user.name", &name)?;
+            wiki.config_set("user.email", &email)?;
+        }
+
+        Ok(self.checkout.get_or_init(|| path))
+    }
+
+    /// A wiki error for a failed git command, without the credentials that
+    /// may be embedded in the URL.
+    fn error(&self, action: &str, e: &Error) -> Error {
+        let detail = match e {
+            Error::Git { stderr, .. } if !stderr.trim().is_empty() => stderr.trim().to_string(),
+            other => other.to_string(),
+        };
+        Error::WikiError {
+            message: format!(
+                "{action}: {}",
+                detail.replace(&self.url, &without_credentials(&self.url))
+            ),
+        }
+    }
+}
+
+impl Drop for WikiRepo {
+    fn drop(&mut self) {
+        if let Some(path) = self.checkout.get() {
+            let _ = std::fs::remove_dir_all(path);
+        }
+    }
+}
+
+/// Name of the file holding an ADR's page.
+pub(crate) fn page_file(adr: &Adr) -> String {
+    format!("{}.md", adr.id)
+}
+
+/// Commit message for writing an ADR's page.
+pub(crate) fn commit_message(adr: &Adr) -> String {
+    format!("{}: {}", adr.id, adr.frontmatter.title)
+}
+
+/// `url` with `user:token` credentials added, for HTTPS clone URLs.
+pub(crate) fn with_credentials(url: &str, user: &str, token: Option<&str>) -> String {
+    match (token, url.split_once("://")) {
+        (Some(token), Some((scheme, rest))) => format!("{scheme}://{user}:{token}@{rest}"),
+        _ => url.to_string(),
+    }
+}
+
+/// `url` with any `user:token@` credentials removed.
+fn without_credentials(url: &str) -> String {
+    match url.split_once("://") {
+        Some((scheme, rest)) => match rest.split_once('@') {
+            Some((_, host)) if !rest[..rest.len() - host.len()].contains('/') => {
+                format!("{scheme}://{host}")
+            },
+            _ => url.to_string(),
+        },
+        None => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command as StdCommand;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = StdCommand::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {args:?} failed");
+    }
+
+    /// A bare wiki repository with a home page, and an ADR repository.
+    fn setup() -> (TempDir, String, Git) {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let root = temp_dir.path();
+        let seed = root.join("seed");
+        let repo = root.join("repo");
+        for dir in [&seed, &repo] {
+            std::fs::create_dir(dir).expect("Failed to create directory");
+            git(dir, &["init", "--quiet"]);
+            git(dir, &["config", "user.email", "test@example.com"]);
+            git(dir, &["config", "user.name", "Test User"]);
+        }
+
+        std::fs::write(seed.join("Home.md"), "Welcome").expect("Failed to write page");
+        git(&seed, &["add", "."]);
+        git(&seed, &["commit", "--quiet", "-m", "Home"]);
+        git(root, &["clone", "--quiet", "--bare", "seed", "wiki.git"]);
+
+        let url = root.join("wiki.git").to_string_lossy().to_string();
+        (temp_dir, url, Git::with_work_dir(repo))
+    }
+
+    #[test]
+    fn test_read_and_write_pages() {
+        let (_temp_dir, url, git) = setup();
+
+        let wiki = WikiRepo::new(&url, git.clone());
+        assert_eq!(
+            wiki.read_page("Home.md").expect("Should read").as_deref(),
+            Some("Welcome")
+        );
+        assert!(wiki
+            .read_page("ADR-0001.md")
+            .expect("Should read")
+            .is_none());
+        wiki.write_page("ADR-0001.md", "Use Rust.", "ADR-0001: Use Rust")
+            .expect("Should write");
+        // Writing the same content again is a no-op
+        wiki.write_page("ADR-0001.md", "Use Rust.", "ADR-0001: Use Rust")
+            .expect("Should write");
+
+        // A fresh clone sees the pushed page
+        let fresh = WikiRepo::new(&url, git);
+        assert_eq!(
+            fresh
+                .read_page("ADR-0001.md")
+                .expect("Should read")
+                .as_deref(),
+            Some("Use Rust.")
+        );
+        let log = Git::with_work_dir(fresh.checkout().expect("Should clone"))
+            .run_output(&["log", "--format=%an %s"])
+            .expect("Should read log");
+        assert_eq!(log.lines().next(), Some("Test User ADR-0001: Use Rust"));
+        assert_eq!(log.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_checkout_is_removed_on_drop() {
+        let (_temp_dir, url, git) = setup();
+        let wiki = WikiRepo::new(url, git);
+        let path = wiki.checkout().expect("Should clone").to_path_buf();
+        assert!(path.exists());
+        drop(wiki);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_with_credentials() {
+        assert_eq!(
+            with_credentials(
+                "https://github.com/o/r.wiki.git",
+                "x-access-token",
+                Some("t")
+            ),
+            "https://x-access-token:t@github.com/o/r.wiki.git"
+        );
+        assert_eq!(
+            with_credentials("https://github.com/o/r.wiki.git", "x-access-token", None),
+            "https://github.com/o/r.wiki.git"
+        );
+        assert_eq!(
+            with_credentials("/srv/wiki.git", "oauth2", Some("t")),
+            "/srv/wiki.git"
+        );
+        assert_eq!(
+            without_credentials("https://x-access-token:t@github.com/o/r.wiki.git"),
+            "https://github.com/o/r.wiki.git"
+        );
+        assert_eq!(
+            without_credentials("https://github.com/o/r@v.wiki.git"),
+            "https://github.com/o/r@v.wiki.git"
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Wiki service abstraction.
 
 use crate::core::Adr;
-use crate::wiki::{github::GitHubWiki, gitlab::GitLabWiki};
+use crate::wiki::sync::{sync_pages, SyncOptions, SyncState, WikiClient};
+use crate::wiki::{confluence::ConfluenceWiki, github::GitHubWiki, gitlab::GitLabWiki};
 use crate::Error;
 
 /// Supported wiki platforms.
@@ -11,6 +12,8 @@ pub enum WikiPlatform {
     GitHub,
     /// GitLab Wiki.
     GitLab,
+    /// Confluence (Atlassian).
+    Confluence,
 }
 
 impl std::fmt::Display for WikiPlatform {
@@ -18,6 +21,24 @@ impl std::fmt::Display for WikiPlatform {
         match self {
             Self::GitHub => write!(f, "github"),
             Self::GitLab => write!(f, "gitlab"),
+            Self::Confluence => write!(f, "confluence"),
+        }
+    }
+}
+
+impl std::str::FromStr for WikiPlatform {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "github" => Ok(Self::GitHub),
+            "gitlab" => Ok(Self::GitLab),
+            "confluence" => Ok(Self::Confluence),
+            _ => Err(Error::WikiError {
+                message: format!(
+                    "Unknown wiki provider: {s} (expected github, gitlab or confluence)"
+                ),
+            }),
         }
     }
 }
@@ -31,8 +52,12 @@ pub struct WikiConfig {
     pub repository: String,
     /// API token for authentication.
     pub token: Option<String>,
-    /// Base URL for self-hosted instances.
+    /// Base URL for self-hosted instances, or the Confluence site URL.
     pub base_url: Option<String>,
+    /// Confluence space key.
+    pub space_key: Option<String>,
+    /// Username for platforms that authenticate with username and token.
+    pub username: Option<String>,
 }
 
 impl WikiConfig {
@@ -44,6 +69,8 @@ impl WikiConfig {
             repository: repository.into(),
             token: None,
             base_url: None,
+            space_key: None,
+            username: None,
         }
     }
 
@@ -60,6 +87,20 @@ impl WikiConfig {
         self.base_url = Some(url.into());
         self
     }
+
+    /// Set the Confluence space key.
+    #[must_use]
+    pub fn with_space_key(mut self, space_key: impl Into<String>) -> Self {
+        self.space_key = Some(space_key.into());
+        self
+    }
+
+    /// Set the username.
+    #[must_use]
+    pub fn with_username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
 }
 
 /// Wiki service for synchronizing ADRs.
@@ -81,52 +122,8 @@ impl WikiService {
     ///
     /// Returns an error if the push fails.
     pub fn push(&self, adr: &Adr) -> Result<(), Error> {
-        match self.config.platform {
-            WikiPlatform::GitHub => {
-                let parts: Vec<&str> = self.config.repository.split('/').collect();
-                if parts.len() != 2 {
-                    return Err(Error::WikiError {
-                        message: format!(
-                            "Invalid GitHub repository format: {}",
-                            self.config.repository
-                        ),
-                    });
-                }
-                let wiki = GitHubWiki::new(parts[0], parts[1]);
-                wiki.push(adr)
-            },
-            WikiPlatform::GitLab => {
-                let wiki = GitLabWiki::new(&self.config.repository);
-                wiki.push(adr)
-            },
-        }
-    }
-
-    /// Pull an ADR from the wiki.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the pull fails.
-    pub fn pull(&self, id: &str) -> Result<Adr, Error> {
-        match self.config.platform {
-            WikiPlatform::GitHub => {
-                let parts: Vec<&str> = self.config.repository.split('/').collect();
-                if parts.len() != 2 {
-                    return Err(Error::WikiError {
-                        message: format!(
-                            "Invalid GitHub repository format: {}",
-                            self.config.repository
-                        ),
-                    });
-                }
-                let wiki = GitHubWiki::new(parts[0], parts[1]);
-                wiki.pull(id)
-            },
-            WikiPlatform::GitLab => {
-                let wiki = GitLabWiki::new(&self.config.repository);
-                wiki.pull(id)
-            },
-        }
+        let client = self.client()?;
+        client.write(adr, &client.render(adr))
     }
 
     /// Sync all ADRs with the wiki.
@@ -148,6 +145,34 @@ impl WikiService {
 
         Ok(result)
     }
+
+    /// Sync ADRs with the wiki, reporting pages changed on both sides as
+    /// conflicts instead of overwriting them.
+    ///
+    /// See [`sync_pages`] for how changes are detected. ADRs pulled from the
+    /// wiki are returned in [`SyncResult::updated`] for the caller to save.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wiki client cannot be configured.
+    pub fn sync_with(
+        &self,
+        state: &SyncState,
+        adrs: &[Adr],
+        options: SyncOptions,
+    ) -> Result<SyncResult, Error> {
+        let client = self.client()?;
+        sync_pages(client.as_ref(), state, adrs, options)
+    }
+
+    /// Client for the configured platform.
+    fn client(&self) -> Result<Box<dyn WikiClient>, Error> {
+        Ok(match self.config.platform {
+            WikiPlatform::GitHub => Box::new(GitHubWiki::from_config(&self.config)?),
+            WikiPlatform::GitLab => Box::new(GitLabWiki::from_config(&self.config)),
+            WikiPlatform::Confluence => Box::new(ConfluenceWiki::from_config(&self.config)?),
+        })
+    }
 }
 
 /// Result of a wiki sync operation.
@@ -157,6 +182,12 @@ pub struct SyncResult {
     pub pushed: usize,
     /// Number of ADRs pulled.
     pub pulled: usize,
+    /// Number of ADRs already in sync.
+    pub unchanged: usize,
+    /// ADRs whose body was replaced by wiki edits, still to be saved.
+    pub updated: Vec<Adr>,
+    /// ADRs skipped because the destination changed since the last sync.
+    pub conflicts: Vec<String>,
     /// Errors encountered.
     pub errors: Vec<String>,
 }
@@ -0,0 +1,411 @@
+//! Two-way wiki sync with conflict detection.
+//!
+//! After each sync, hashes of the page content on both sides are stored as a
+//! note on the ADR's commit in the wiki notes ref. On the next sync,
+//! comparing the ADR and the wiki page against those hashes shows which side
+//! changed, so edits made on the wiki are reported instead of being
+//! overwritten.
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+use crate::core::{Adr, AdrConfig, Git};
+use crate::wiki::SyncResult;
+use crate::Error;
+
+/// A wiki that ADR pages can be read from and written to.
+pub trait WikiClient {
+    /// Page content for an ADR, as it would be written to the wiki.
+    fn render(&self, adr: &Adr) -> String {
+        adr.body.clone()
+    }
+
+    /// Current content of an ADR's page, or `None` if it has no page yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wiki cannot be read.
+    fn fetch(&self, adr: &Adr) -> Result<Option<String>, Error>;
+
+    /// Write an ADR's page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wiki rejects the page.
+    fn write(&self, adr: &Adr, content: &str) -> Result<(), Error>;
+
+    /// ADR body for page content pulled from the wiki.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page can't be turned back into markdown.
+    fn to_body(&self, content: &str) -> Result<String, Error> {
+        Ok(content.to_string())
+    }
+}
+
+/// Direction of a sync.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SyncMode {
+    /// Write ADRs to the wiki.
+    #[default]
+    Push,
+    /// Bring wiki edits back into ADR bodies.
+    Pull,
+}
+
+/// Options for [`sync_pages`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// Direction of the sync.
+    pub mode: SyncMode,
+    /// Overwrite the destination even if it changed since the last sync.
+    pub force: bool,
+}
+
+/// Content hashes of both sides of an ADR's page as of its last sync.
+///
+/// They differ when the wiki normalizes what it is sent, as Confluence does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncedHashes {
+    /// Hash of the page content rendered from the ADR.
+    pub local: String,
+    /// Hash of the page content as stored on the wiki.
+    pub wiki: String,
+}
+
+/// Hashes recorded at each ADR's last sync.
+#[derive(Debug)]
+pub struct SyncState {
+    git: Git,
+    notes_ref: String,
+}
+
+impl SyncState {
+    /// Create sync state stored in the configured wiki notes ref.
+    #[must_use]
+    pub fn new(git: Git, config: &AdrConfig) -> Self {
+        Self {
+            git,
+            notes_ref: config.wiki_ref(),
+        }
+    }
+
+    /// Hashes recorded when an ADR was last synced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notes ref cannot be read.
+    pub fn last_synced(&self, adr: &Adr) -> Result<Option<SyncedHashes>, Error> {
+        let Some(note) = self.git.notes_show(&self.notes_ref, &adr.commit)? else {
+            return Ok(None);
+        };
+        let mut hashes = note.split_whitespace();
+        Ok(hashes.next().map(|local| SyncedHashes {
+            local: local.to_string(),
+            wiki: hashes.next().unwrap_or(local).to_string(),
+        }))
+    }
+
+    /// Record the content of both sides of an ADR's page as of now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the note cannot be written.
+    pub fn record(&self, adr: &Adr, local: &str, wiki: &str) -> Result<(), Error> {
+        let note = format!("{} {}", content_hash(local), content_hash(wiki));
+        self.git.notes_add(&self.notes_ref, &adr.commit, &note)
+    }
+}
+
+/// What syncing a single ADR did.
+enum Outcome {
+    Pushed,
+    Pulled(Box<Adr>),
+    Unchanged,
+    Conflict(String),
+}
+
+/// Sync ADRs with a wiki, reporting conflicts instead of overwriting edits.
+///
+/// A side counts as changed when its content no longer matches the hash
+/// recorded at the last sync. Pushing stops at pages edited on the wiki, and
+/// pulling stops at ADRs edited locally, unless `options.force` is set. An
+/// ADR that was never synced but already has a different page is treated as
+/// changed on both sides.
+///
+/// # Errors
+///
+/// Per-ADR failures are collected in [`SyncResult::errors`] rather than
+/// returned.
+pub fn sync_pages(
+    client: &dyn WikiClient,
+    state: &SyncState,
+    adrs: &[Adr],
+    options: SyncOptions,
+) -> Result<SyncResult, Error> {
+    let mut result = SyncResult::default();
+
+    for adr in adrs {
+        match sync_one(client, state, adr, options) {
+            Ok(Outcome::Pushed) => result.pushed += 1,
+            Ok(Outcome::Pulled(updated)) => {
+                result.pulled += 1;
+                result.updated.push(*updated);
+            },
+            Ok(Outcome::Unchanged) => result.unchanged += 1,
+            Ok(Outcome::Conflict(reason)) => {
+                result.conflicts.push(format!("{}: {reason}", adr.id));
+            },
+            Err(e) => result.errors.push(format!("{}: {e}", adr.id)),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sync one ADR's page.
+fn sync_one(
+    client: &dyn WikiClient,
+    state: &SyncState,
+    adr: &Adr,
+    options: SyncOptions,
+) -> Result<Outcome, Error> {
+    const BOTH_CHANGED: &str = "changed in both the ADR and the wiki since the last sync";
+
+    let local = client.render(adr);
+    let local_hash = content_hash(&local);
+    let remote = client.fetch(adr)?;
+    let remote_hash = remote.as_deref().map(content_hash);
+
+    let Some(base) = state.last_synced(adr)? else {
+        // Never synced: an identical page just needs recording, and any
+        // other page is someone else's
+        return match &remote {
+            Some(page) if remote_hash.as_ref() == Some(&local_hash) => {
+                state.record(adr, &local, page)?;
+                Ok(Outcome::Unchanged)
+            },
+            Some(_) if !options.force => Ok(Outcome::Conflict(BOTH_CHANGED.to_string())),
+            _ if options.mode == SyncMode::Pull => Ok(Outcome::Unchanged),
+            _ => push(client, state, adr, &local),
+        };
+    };
+
+    let local_changed = local_hash != base.local;
+    // A deleted page isn't an edit; pushing recreates it
+    let remote_changed = remote_hash.as_ref().is_some_and(|hash| *hash != base.wiki);
+
+    match options.mode {
+        SyncMode::Push => {
+            if remote_changed && !options.force {
+                return Ok(Outcome::Conflict(if local_changed {
+                    BOTH_CHANGED.to_string()
+                } else {
+                    "wiki page was edited since the last sync (pull it first)".to_string()
+                }));
+            }
+            if !local_changed && !remote_changed && remote.is_some() {
+                return Ok(Outcome::Unchanged);
+            }
+            push(client, state, adr, &local)
+        },
+        SyncMode::Pull => {
+            let Some(remote) = remote.filter(|_| remote_changed) else {
+                return Ok(Outcome::Unchanged);
+            };
+            if local_changed && !options.force {
+                return Ok(Outcome::Conflict(BOTH_CHANGED.to_string()));
+            }
+
+            let mut updated = adr.clone();
+            updated.body = client.to_body(&remote)?;
+            state.record(&updated, &client.render(&updated), &remote)?;
+            Ok(Outcome::Pulled(Box::new(updated)))
+        },
+    }
+}
+
+/// Write an ADR's page and record what the wiki stored.
+fn push(
+    client: &dyn WikiClient,
+    state: &SyncState,
+    adr: &Adr,
+    local: &str,
+) -> Result<Outcome, Error> {
+    client.write(adr, local)?;
+    // Read the page back, since the wiki may store it differently
+    let stored = client.fetch(adr)?.unwrap_or_else(|| local.to_string());
+    state.record(adr, local, &stored)?;
+    Ok(Outcome::Pushed)
+}
+
+/// Hex-encoded SHA-256 of page content.
+fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::process::Command as StdCommand;
+    use tempfile::TempDir;
+
+    /// A wiki holding a single page in memory.
+    #[derive(Default)]
+    struct MockWiki {
+        page: RefCell<Option<String>>,
+        writes: RefCell<usize>,
+        /// Appended to every page written, like a wiki normalizing markup.
+        suffix: &'static str,
+    }
+
+    impl WikiClient for MockWiki {
+        fn fetch(&self, _adr: &Adr) -> Result<Option<String>, Error> {
+            Ok(self.page.borrow().clone())
+        }
+
+        fn write(&self, _adr: &Adr, content: &str) -> Result<(), Error> {
+            *self.page.borrow_mut() = Some(format!("{content}{}", self.suffix));
+            *self.writes.borrow_mut() += 1;
+            Ok(())
+        }
+    }
+
+    fn setup() -> (TempDir, SyncState, Adr) {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let path = temp_dir.path();
+        for args in [
+            &["init"][..],
+            &["config", "user.email", "test@example.com"],
+            &["config", "user.name", "Test User"],
+            &["commit", "--allow-empty", "-m", "Initial commit"],
+        ] {
+            StdCommand::new("git")
+                .args(args)
+                .current_dir(path)
+                .output()
+                .expect("Failed to run git");
+        }
+
+        let git = Git::with_work_dir(path);
+        let mut adr = Adr::new("ADR-0001".to_string(), "Use Rust".to_string());
+        adr.commit = git.head().expect("Should have HEAD");
+        adr.body = "Original body.".to_string();
+
+        let state = SyncState::new(git, &AdrConfig::default());
+        (temp_dir, state, adr)
+    }
+
+    fn sync(wiki: &MockWiki, state: &SyncState, adr: &Adr, options: SyncOptions) -> SyncResult {
+        sync_pages(wiki, state, std::slice::from_ref(adr), options).expect("Should sync")
+    }
+
+    #[test]
+    fn test_push_then_unchanged() {
+        let (_temp_dir, state, adr) = setup();
+        let wiki = MockWiki::default();
+
+        assert_eq!(sync(&wiki, &state, &adr, SyncOptions::default()).pushed, 1);
+        assert_eq!(wiki.page.borrow().as_deref(), Some("Original body."));
+
+        let result = sync(&wiki, &state, &adr, SyncOptions::default());
+        assert_eq!(result.unchanged, 1);
+        assert_eq!(*wiki.writes.borrow(), 1);
+    }
+
+    #[test]
+    fn test_normalized_page_is_not_an_edit() {
+        let (_temp_dir, state, adr) = setup();
+        let wiki = MockWiki {
+            suffix: "\n",
+            ..MockWiki::default()
+        };
+
+        assert_eq!(sync(&wiki, &state, &adr, SyncOptions::default()).pushed, 1);
+        let result = sync(&wiki, &state, &adr, SyncOptions::default());
+        assert_eq!(result.unchanged, 1);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_local_edit_is_pushed() {
+        let (_temp_dir, state, mut adr) = setup();
+        let wiki = MockWiki::default();
+        sync(&wiki, &state, &adr, SyncOptions::default());
+
+        adr.body = "Edited locally.".to_string();
+        assert_eq!(sync(&wiki, &state, &adr, SyncOptions::default()).pushed, 1);
+        assert_eq!(wiki.page.borrow().as_deref(), Some("Edited locally."));
+    }
+
+    #[test]
+    fn test_both_changed_is_a_conflict() {
+        let (_temp_dir, state, mut adr) = setup();
+        let wiki = MockWiki::default();
+        sync(&wiki, &state, &adr, SyncOptions::default());
+
+        adr.body = "Edited locally.".to_string();
+        *wiki.page.borrow_mut() = Some("Edited on the wiki.".to_string());
+
+        let result = sync(&wiki, &state, &adr, SyncOptions::default());
+        assert_eq!(result.pushed, 0);
+        assert_eq!(result.conflicts.len(), 1);
+        assert!(result.conflicts[0].contains("changed in both"));
+        assert_eq!(wiki.page.borrow().as_deref(), Some("Edited on the wiki."));
+
+        let pull = SyncOptions {
+            mode: SyncMode::Pull,
+            force: false,
+        };
+        assert_eq!(sync(&wiki, &state, &adr, pull).conflicts.len(), 1);
+
+        let force = SyncOptions {
+            force: true,
+            ..SyncOptions::default()
+        };
+        assert_eq!(sync(&wiki, &state, &adr, force).pushed, 1);
+        assert_eq!(wiki.page.borrow().as_deref(), Some("Edited locally."));
+    }
+
+    #[test]
+    fn test_wiki_edit_blocks_push_and_can_be_pulled() {
+        let (_temp_dir, state, adr) = setup();
+        let wiki = MockWiki::default();
+        sync(&wiki, &state, &adr, SyncOptions::default());
+
+        *wiki.page.borrow_mut() = Some("Edited on the wiki.".to_string());
+        let result = sync(&wiki, &state, &adr, SyncOptions::default());
+        assert_eq!(result.conflicts.len(), 1);
+        assert!(result.conflicts[0].contains("pull it first"));
+
+        let pull = SyncOptions {
+            mode: SyncMode::Pull,
+            force: false,
+        };
+        let result = sync(&wiki, &state, &adr, pull);
+        assert_eq!(result.pulled, 1);
+        assert_eq!(result.updated[0].body, "Edited on the wiki.");
+
+        // The pulled content is now the last-synced state
+        let result = sync(&wiki, &state, &result.updated[0], SyncOptions::default());
+        assert_eq!(result.unchanged, 1);
+    }
+
+    #[test]
+    fn test_unsynced_adr_with_existing_page_is_a_conflict() {
+        let (_temp_dir, state, adr) = setup();
+        let wiki = MockWiki::default();
+        *wiki.page.borrow_mut() = Some("Someone else's page.".to_string());
+
+        let result = sync(&wiki, &state, &adr, SyncOptions::default());
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(*wiki.writes.borrow(), 0);
+    }
+}
@@ -76,6 +76,41 @@ fn setup_test_repo_with_artifact() -> TempDir {
     temp_dir
 }
 
+/// Rewrite the stored artifact note with `edit` applied to the JSON of its
+/// first artifact.
+fn rewrite_artifact(path: &std::path::Path, edit: impl FnOnce(&mut serde_json::Value)) {
+    let note = StdCommand::new("git")
+        .args(["notes", "--ref", "adr-artifacts", "show", "HEAD"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to read artifact note");
+    let mut artifacts: serde_json::Value =
+        serde_json::from_slice(&note.stdout).expect("Artifact note should be JSON");
+    edit(&mut artifacts[0]);
+    StdCommand::new("git")
+        .args([
+            "notes",
+            "--ref",
+            "adr-artifacts",
+            "add",
+            "-f",
+            "-m",
+            &artifacts.to_string(),
+            "HEAD",
+        ])
+        .current_dir(path)
+        .output()
+        .expect("Failed to write artifact note");
+}
+
+/// Swap the stored artifact content for different bytes of the same length.
+fn corrupt_artifact(path: &std::path::Path) {
+    // base64 of "Attached c0ntent"
+    rewrite_artifact(path, |artifact| {
+        artifact["content"] = "QXR0YWNoZWQgYzBudGVudA==".into();
+    });
+}
+
 #[test]
 fn test_artifacts_list() {
     let temp_dir = setup_test_repo_with_artifact();
@@ -88,6 +123,69 @@ fn test_artifacts_list() {
         .stdout(predicate::str::contains("attachment.txt"));
 }
 
+#[test]
+fn test_artifacts_list_shows_checksum() {
+    let temp_dir = setup_test_repo_with_artifact();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["artifacts", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("SHA-256:"))
+        .stdout(predicate::str::contains("16 bytes"));
+}
+
+#[test]
+fn test_artifacts_verify() {
+    let temp_dir = setup_test_repo_with_artifact();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["artifacts", "verify", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("checksum OK"));
+}
+
+#[test]
+fn test_artifacts_verify_detects_corruption() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    corrupt_artifact(path);
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["artifacts", "verify", "ADR-0001"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("checksum mismatch"))
+        .stderr(predicate::str::contains("failed verification"));
+}
+
+#[test]
+fn test_artifacts_verify_without_checksum_is_unverified() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    // Artifacts attached before checksums were recorded have no sha256 field
+    rewrite_artifact(path, |artifact| {
+        artifact
+            .as_object_mut()
+            .expect("Artifact should be an object")
+            .remove("sha256");
+    });
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["artifacts", "verify", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unverified"))
+        .stdout(predicate::str::contains("mismatch").not());
+}
+
 #[test]
 fn test_artifacts_json_format() {
     let temp_dir = setup_test_repo_with_artifact();
@@ -120,6 +218,196 @@ fn test_artifacts_extract() {
     assert_eq!(content, "Attached content");
 }
 
+#[test]
+fn test_artifacts_extract_subcommand() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "extract", "ADR-0001", "--output", "out"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Extracted"));
+    let content = std::fs::read_to_string(path.join("out/attachment.txt")).expect("Failed to read");
+    assert_eq!(content, "Attached content");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "artifacts",
+            "extract",
+            "ADR-0001",
+            "attachment.txt",
+            "-o",
+            "named",
+        ])
+        .assert()
+        .success();
+    assert!(path.join("named/attachment.txt").exists());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "extract", "ADR-0001", "--all", "-o", "all"])
+        .assert()
+        .success();
+    assert!(path.join("all/attachment.txt").exists());
+}
+
+#[test]
+fn test_artifacts_extract_binary_round_trip() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    // PNG signature and IHDR chunk start, followed by every byte value
+    let png: Vec<u8> = [
+        0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, b'I', b'H', b'D',
+        b'R',
+    ]
+    .into_iter()
+    .chain(0..=255)
+    .collect();
+    std::fs::write(path.join("diagram.png"), &png).expect("Failed to write PNG");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "diagram.png"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "artifacts",
+            "extract",
+            "ADR-0001",
+            "diagram.png",
+            "-o",
+            "out",
+        ])
+        .assert()
+        .success();
+
+    let extracted = std::fs::read(path.join("out/diagram.png")).expect("Failed to read");
+    assert_eq!(extracted, png);
+}
+
+#[test]
+fn test_artifacts_extract_unknown_name() {
+    let temp_dir = setup_test_repo_with_artifact();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["artifacts", "extract", "ADR-0001", "missing.png"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No artifact named missing.png"));
+}
+
+#[test]
+fn test_artifacts_extract_warns_on_checksum_mismatch() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    corrupt_artifact(path);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "extract", "ADR-0001", "-o", "out"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "does not match its recorded checksum",
+        ));
+    let content = std::fs::read_to_string(path.join("out/attachment.txt")).expect("Failed to read");
+    assert_eq!(content, "Attached c0ntent");
+}
+
+#[test]
+fn test_artifacts_rm() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "rm", "ADR-0001", "other.png"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no artifact named other.png"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "rm", "ADR-0001", "attachment.txt"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed artifact attachment.txt"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "ADR-0001"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No artifacts found"));
+
+    // The ADR itself is untouched
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test ADR"));
+}
+
+#[test]
+fn test_artifacts_rm_keeps_other_artifacts() {
+    let temp_dir = setup_test_repo_with_artifact();
+    let path = temp_dir.path();
+
+    std::fs::write(path.join("diagram.svg"), "<svg/>").expect("Failed to write attachment");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "diagram.svg"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "rm", "ADR-0001", "attachment.txt"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed artifact attachment.txt"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("diagram.svg"))
+        .stdout(predicate::str::contains("attachment.txt").not());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "verify", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("diagram.svg: checksum OK"));
+}
+
 #[test]
 fn test_artifacts_no_artifacts() {
     let temp_dir = TempDir::new().expect("Failed to create temp directory");
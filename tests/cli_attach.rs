@@ -99,6 +99,55 @@ fn test_attach_file_with_description() {
         .stderr(predicate::str::contains("Attached"));
 }
 
+#[test]
+fn test_attach_second_file_keeps_the_first() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    std::fs::write(path.join("first.txt"), "first").expect("Failed to write file");
+    std::fs::write(path.join("second.txt"), "second").expect("Failed to write file");
+    for file in ["first.txt", "second.txt"] {
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args(["attach", "ADR-0001", file])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("first.txt"))
+        .stdout(predicate::str::contains("second.txt"));
+
+    // With two artifacts, extract needs a name or --all
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "extract", "ADR-0001", "-o", "out"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("name one or pass --all"));
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "extract", "ADR-0001", "--all", "-o", "out"])
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(path.join("out/first.txt")).expect("Failed to read"),
+        "first"
+    );
+    assert_eq!(
+        std::fs::read_to_string(path.join("out/second.txt")).expect("Failed to read"),
+        "second"
+    );
+}
+
 #[test]
 fn test_attach_file_not_found() {
     let temp_dir = setup_test_repo_with_adr();
@@ -125,3 +174,84 @@ fn test_attach_adr_not_found() {
         .failure()
         .stderr(predicate::str::contains("ADR not found"));
 }
+
+#[test]
+fn test_attach_respects_max_artifact_size() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "maxArtifactSize", "1024"])
+        .assert()
+        .success();
+
+    std::fs::write(path.join("under.bin"), vec![b'a'; 1023]).expect("Failed to write file");
+    std::fs::write(path.join("over.bin"), vec![b'a'; 1025]).expect("Failed to write file");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "under.bin"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Attached"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "over.bin"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "content too large: 1025 bytes (max: 1024 bytes)",
+        ))
+        .stderr(predicate::str::contains("--force"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "over.bin", "--force"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Attached"));
+}
+
+#[test]
+fn test_attach_too_large_exit_code() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "maxArtifactSize", "16"])
+        .assert()
+        .success();
+    std::fs::write(path.join("over.bin"), vec![b'a'; 17]).expect("Failed to write file");
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["attach", "ADR-0001", "over.bin", "--error-format", "json"])
+        .output()
+        .expect("Failed to run attach");
+    assert_eq!(output.status.code(), Some(5));
+
+    // The --force hint precedes the JSON report on stderr
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let report = stderr.lines().last().expect("Error should be reported");
+    let error: serde_json::Value = serde_json::from_str(report).expect("Error should be JSON");
+    assert_eq!(error["kind"], "ContentTooLarge");
+    assert!(error["error"].as_str().unwrap().contains("17 bytes"));
+
+    // Nothing was attached
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["artifacts", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("over.bin").not());
+}
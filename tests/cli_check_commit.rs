@@ -0,0 +1,187 @@
+//! Integration tests for the `git-adr check-commit` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary git repository with one ADR and a staged change
+/// under `src/arch`.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    for args in [
+        &["init"][..],
+        &["config", "user.email", "test@example.com"],
+        &["config", "user.name", "Test User"],
+    ] {
+        StdCommand::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .expect("Failed to run git");
+    }
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Use PostgreSQL", "--no-edit"])
+        .assert()
+        .success();
+
+    std::fs::create_dir_all(path.join("src/arch")).expect("Failed to create src/arch");
+    std::fs::write(path.join("src/arch/db.rs"), "// database\n").expect("Failed to write file");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+
+    temp_dir
+}
+
+/// Run `check-commit --strict` on a message, checking commits under `paths`.
+fn check_commit(temp_dir: &TempDir, message: &str, paths: &str) -> assert_cmd::assert::Assert {
+    let message_file = temp_dir.path().join("COMMIT_MSG");
+    std::fs::write(&message_file, message).expect("Failed to write message");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "check-commit",
+            "--strict",
+            "--paths",
+            paths,
+            "--message-file",
+        ])
+        .arg(&message_file)
+        .assert()
+}
+
+#[test]
+fn test_check_commit_valid_reference() {
+    let temp_dir = setup_test_repo();
+
+    check_commit(&temp_dir, "Add connection pool (ADR-0001)\n", "src/arch/**").success();
+}
+
+#[test]
+fn test_check_commit_unknown_reference() {
+    let temp_dir = setup_test_repo();
+
+    check_commit(&temp_dir, "Add connection pool (ADR-0042)\n", "src/arch/**")
+        .failure()
+        .stderr(predicate::str::contains("unknown ADR(s): ADR-0042"));
+}
+
+#[test]
+fn test_check_commit_missing_reference() {
+    let temp_dir = setup_test_repo();
+
+    check_commit(&temp_dir, "Add connection pool\n", "src/arch/**")
+        .failure()
+        .stderr(predicate::str::contains("src/arch/db.rs"));
+
+    // Commits not touching the paths aren't checked
+    check_commit(&temp_dir, "Add connection pool\n", "docs/**").success();
+}
+
+#[test]
+fn test_hooks_install_commit_msg() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "hooks",
+            "install",
+            "--commit-msg",
+            "--paths",
+            "src/arch/**",
+            "--strict",
+        ])
+        .assert()
+        .success();
+
+    let hook = std::fs::read_to_string(temp_dir.path().join(".git/hooks/commit-msg"))
+        .expect("Hook should be installed");
+    assert!(
+        hook.contains(r#"git-adr check-commit --message-file "$1" --paths 'src/arch/**' --strict"#)
+    );
+}
+
+#[test]
+fn test_hooks_install_adr_guard() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["hooks", "install", "adr-guard"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("adr.architecturePaths is not set"));
+
+    let hook = std::fs::read_to_string(path.join(".git/hooks/commit-msg"))
+        .expect("Hook should be installed");
+    assert!(hook.contains("check-commit --message-file \"$1\" --architecture --strict"));
+
+    let message_file = path.join("COMMIT_MSG");
+    let guard = |message: &str| {
+        std::fs::write(&message_file, message).expect("Failed to write message");
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args([
+                "check-commit",
+                "--architecture",
+                "--strict",
+                "--message-file",
+            ])
+            .arg(&message_file)
+            .assert()
+    };
+
+    // Nothing counts as architecture until the globs are configured
+    guard("Add connection pool\n").success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "architecturePaths", "docs/**,src/arch/**"])
+        .assert()
+        .success();
+
+    guard("Add connection pool\n")
+        .failure()
+        .stderr(predicate::str::contains("src/arch/db.rs"));
+    guard("Add connection pool (ADR-0042)\n")
+        .failure()
+        .stderr(predicate::str::contains("unknown ADR(s): ADR-0042"));
+    guard("Add connection pool (ADR-0001)\n").success();
+}
@@ -0,0 +1,211 @@
+//! Integration tests for the `git-adr ci` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary initialized git repository.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+/// Attach a raw ADR note to a fresh commit.
+fn add_adr_note(temp_dir: &TempDir, content: &str) {
+    let path = temp_dir.path();
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "ADR commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    StdCommand::new("git")
+        .args(["notes", "--ref", "adr", "add", "-f", "-m", content, "HEAD"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add note");
+}
+
+/// Body with every section the default (nygard) format requires.
+const BODY: &str = "## Context\n\nWhy.\n\n## Decision\n\nWhat.\n\n## Consequences\n\nSo what.\n";
+
+#[test]
+fn test_ci_check_clean_repo() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        &format!("---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\n{BODY}"),
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "check"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("All ADR checks passed"));
+}
+
+#[test]
+fn test_ci_check_dangling_link() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        &format!(
+            "---\nid: ADR-0001\ntitle: First\nstatus: accepted\nlinks:\n- rel: relates-to\n  target: ADR-0099\n---\n\n{BODY}"
+        ),
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "check"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[links]"))
+        .stdout(predicate::str::contains("ADR-0099"))
+        .stderr(predicate::str::contains("1 problem(s)"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "check", "--allow", "links", "--format", "json"])
+        .output()
+        .expect("Failed to run ci check");
+    assert_eq!(output.status.code(), Some(0));
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be JSON");
+    assert_eq!(report["passed"], true);
+    assert_eq!(report["warnings"], 1);
+    assert_eq!(report["findings"][0]["category"], "links");
+}
+
+#[test]
+fn test_ci_check_sections_and_status() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\n## Context\n\nWhy.\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        &format!("---\nid: ADR-0002\ntitle: Second\nstatus: done\n---\n\n{BODY}"),
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "check"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[sections]"))
+        .stdout(predicate::str::contains("\"Decision Outcome\""))
+        .stdout(predicate::str::contains("[invalid]"))
+        .stdout(predicate::str::contains("ADR-0002"));
+}
+
+/// Run `ci generate --provider <provider>` and return the generated file.
+fn generate(temp_dir: &TempDir, provider: &str, file: &str) -> String {
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "generate", "--provider", provider])
+        .assert()
+        .success();
+
+    std::fs::read_to_string(temp_dir.path().join(file)).expect("File should be generated")
+}
+
+#[test]
+fn test_ci_generate_providers() {
+    let temp_dir = setup_test_repo();
+
+    let gitlab = generate(&temp_dir, "gitlab", ".gitlab-ci.yml");
+    assert!(gitlab.contains("stages:"));
+    assert!(gitlab.contains("git-adr ci check"));
+    // CI clones don't include notes, and a failed fetch must fail the job
+    assert!(gitlab.contains("git fetch origin 'refs/notes/*:refs/notes/*'"));
+    assert!(!gitlab.contains("|| true"));
+
+    let azure = generate(&temp_dir, "azure", "azure-pipelines.yml");
+    assert!(azure.contains("pool:"));
+    assert!(azure.contains("refs/notes/*"));
+    assert!(!azure.contains("|| true"));
+
+    let github = generate(&temp_dir, "github", ".github/workflows/adr.yml");
+    assert!(github.contains("runs-on:"));
+
+    let jenkins = generate(&temp_dir, "jenkins", "Jenkinsfile");
+    assert!(jenkins.contains("pipeline {"));
+    assert!(jenkins.contains("refs/notes/*"));
+    assert!(!jenkins.contains("|| true"));
+    assert!(jenkins.contains("./git-adr ci check"));
+    assert!(jenkins.contains("./git-adr export --format html"));
+}
+
+#[test]
+fn test_ci_generate_force() {
+    let temp_dir = setup_test_repo();
+    std::fs::write(temp_dir.path().join(".gitlab-ci.yml"), "existing\n")
+        .expect("Failed to write file");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "generate", "--provider", "gitlab"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Use --force to overwrite"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["ci", "generate", "--provider", "gitlab", "--force"])
+        .assert()
+        .success();
+    let gitlab = std::fs::read_to_string(temp_dir.path().join(".gitlab-ci.yml"))
+        .expect("File should be generated");
+    assert!(gitlab.contains("stages:"));
+}
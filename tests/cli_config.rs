@@ -66,6 +66,30 @@ fn test_config_list() {
         .stdout(predicate::str::contains("adr.prefix"));
 }
 
+#[test]
+fn test_config_list_shows_sources() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "set", "staleDays", "30"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("adr.staleDays = 30 (local)"))
+        .stdout(predicate::str::contains("adr.prefix = ADR- (local)"))
+        .stdout(predicate::str::contains(
+            "adr.maxBodySize = 10485760 (default)",
+        ))
+        .stdout(predicate::str::contains("adr.editor =  (default)"));
+}
+
 #[test]
 fn test_config_get() {
     let temp_dir = setup_test_repo();
@@ -119,9 +143,59 @@ fn test_config_set_unknown_key() {
     cmd.current_dir(temp_dir.path())
         .args(["config", "set", "unknownkey", "value"])
         .assert()
-        .success()
+        .failure()
         .stderr(predicate::str::contains("Unknown config key"))
-        .stderr(predicate::str::contains("Setting anyway"));
+        .stderr(predicate::str::contains("--raw"));
+}
+
+#[test]
+fn test_config_set_unknown_key_raw() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "set", "--raw", "unknownkey", "value"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Set adr.unknownkey = value"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "get", "unknownkey"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("value"));
+}
+
+#[test]
+fn test_config_set_rejects_invalid_values() {
+    let temp_dir = setup_test_repo();
+
+    for (key, value, message) in [
+        ("digits", "abc", "digits must be a number"),
+        ("prefix", "", "prefix must not be empty"),
+        ("format", "bogus", "unknown format 'bogus'"),
+        ("initialized", "maybe", "must be 'true' or 'false'"),
+    ] {
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(temp_dir.path())
+            .args(["config", "set", key, value])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(message));
+    }
+
+    // The stored values are untouched
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "get", "digits"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4"));
 }
 
 #[test]
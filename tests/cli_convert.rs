@@ -133,3 +133,211 @@ fn test_convert_adr_not_found() {
         .failure()
         .stderr(predicate::str::contains("ADR not found"));
 }
+
+#[test]
+fn test_convert_preserves_frontmatter() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Nygard ADR", "--template", "nygard"])
+        .assert()
+        .success();
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "edit",
+            "ADR-0002",
+            "--set-field",
+            "team=platform",
+            "--add-decider",
+            "Alice",
+        ])
+        .assert()
+        .success();
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["edit", "ADR-0002", "--add-decider", "Bob"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["convert", "ADR-0002", "--to", "madr", "--in-place"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--raw"])
+        .output()
+        .expect("Failed to run show");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("format: madr"), "{stdout}");
+    assert!(stdout.contains("team: platform"), "{stdout}");
+    assert!(stdout.contains("- Alice"), "{stdout}");
+    assert!(stdout.contains("- Bob"), "{stdout}");
+}
+
+#[test]
+fn test_convert_all() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    for (title, template) in [("Nygard ADR", "nygard"), ("Y-Statement ADR", "y-statement")] {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", title])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args(["new", title, "--template", template])
+            .assert()
+            .success();
+    }
+
+    // A dry run reports without saving
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["convert", "--all", "--to", "madr", "--dry-run"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "would convert ADR-0002 from nygard",
+        ))
+        .stderr(predicate::str::contains(
+            "2 would be converted, 1 already in madr format",
+        ));
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("format: nygard"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["convert", "--all", "--to", "madr"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "2 converted, 1 already in madr format",
+        ));
+
+    for id in ["ADR-0001", "ADR-0002", "ADR-0003"] {
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args(["show", id, "--format", "yaml"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("format: madr"));
+    }
+}
+
+#[test]
+fn test_convert_all_with_filters() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Second"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "new",
+            "Tagged ADR",
+            "--tag",
+            "legacy",
+            "--status",
+            "accepted",
+        ])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "convert", "--all", "--to", "nygard", "--tag", "legacy", "--status", "accepted",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "1 converted, 0 already in nygard format",
+        ));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("format: madr"));
+
+    // Filters and --dry-run only make sense with --all
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["convert", "ADR-0001", "--to", "nygard", "--tag", "legacy"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_convert_preserves_section_content() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Second"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    let content = "---\ntitle: Use PostgreSQL\nstatus: accepted\nformat: nygard\n---\n\n# Use PostgreSQL\n\n## Status\n\naccepted\n\n## Context\n\nOur services need ACID transactions and mature tooling.\n\n## Decision\n\nWe will use PostgreSQL 16.\n\n## Consequences\n\nThe platform team runs nightly backups.\n";
+    std::fs::write(path.join("nygard.md"), content).expect("Failed to write ADR");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Ignored", "--file", "nygard.md"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["convert", "ADR-0002", "--to", "madr", "--in-place"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("format: madr"))
+        .stdout(predicate::str::contains(
+            "## Context and Problem Statement\n\nOur services need ACID transactions and mature tooling.",
+        ))
+        .stdout(predicate::str::contains("We will use PostgreSQL 16."))
+        .stdout(predicate::str::contains(
+            "## Consequences\n\nThe platform team runs nightly backups.",
+        ))
+        .stdout(predicate::str::contains("Describe the context and problem statement").not());
+}
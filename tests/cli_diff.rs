@@ -0,0 +1,135 @@
+//! Integration tests for the `git-adr diff` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary git repository with an ADR.
+fn setup_test_repo_with_adr() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    // Initialize git repo
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    // Initialize ADR
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    // Create an ADR
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "new",
+            "Use PostgreSQL",
+            "--tag",
+            "database",
+            "--status",
+            "accepted",
+        ])
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+/// Rename ADR-0001 so it has a second revision.
+fn retitle(temp_dir: &TempDir) {
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--title", "Use MySQL"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_diff_latest_against_previous() {
+    let temp_dir = setup_test_repo_with_adr();
+    retitle(&temp_dir);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["diff", "ADR-0001", "--no-pager"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("-title: Use PostgreSQL"))
+        .stdout(predicate::str::contains("+title: Use MySQL"));
+}
+
+#[test]
+fn test_diff_json_hunks() {
+    let temp_dir = setup_test_repo_with_adr();
+    retitle(&temp_dir);
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["diff", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run diff");
+    assert!(output.status.success());
+
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    let hunks = value["hunks"].as_array().expect("hunks should be an array");
+    assert_eq!(hunks.len(), 1);
+    let lines = hunks[0]["lines"]
+        .as_array()
+        .expect("lines should be an array");
+    assert!(lines
+        .iter()
+        .any(|l| l["kind"] == "remove" && l["text"] == "title: Use PostgreSQL"));
+    assert!(lines
+        .iter()
+        .any(|l| l["kind"] == "add" && l["text"] == "title: Use MySQL"));
+}
+
+#[test]
+fn test_diff_unknown_revision() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["diff", "ADR-0001"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("has only 1 revision(s)"));
+}
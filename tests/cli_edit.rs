@@ -280,3 +280,461 @@ fn test_edit_invalid_status() {
         .failure()
         .stderr(predicate::str::contains("invalid status"));
 }
+
+#[test]
+fn test_edit_invalid_status_transition() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--status", "rejected"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--status", "accepted"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot change status"));
+}
+
+#[test]
+fn test_edit_allow_any_transition() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--status", "rejected"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "edit",
+            "ADR-0001",
+            "--status",
+            "accepted",
+            "--allow-any-transition",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("ADR updated"));
+}
+
+#[test]
+fn test_edit_body_with_editor() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_EDITOR", "echo 'Rewritten body' >")
+        .args(["edit", "ADR-0001", "--body"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Updated body"));
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Rewritten body"));
+}
+
+#[test]
+fn test_edit_body_editor_fails() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_EDITOR", "false")
+        .args(["edit", "ADR-0001", "--body", "--add-tag", "ignored"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("exited with"));
+
+    // Nothing should have been saved
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ignored").not());
+}
+
+#[test]
+fn test_edit_invalid_transition_lists_allowed() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--status", "deprecated"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "allowed: accepted, superseded, rejected",
+        ));
+}
+
+#[test]
+fn test_edit_force_transition() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--status", "deprecated", "--force"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("ADR updated"));
+}
+
+#[test]
+fn test_edit_accept_adds_decider() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--accept"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Added decider: Test User <test@example.com>",
+        ));
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""status": "accepted""#))
+        .stdout(predicate::str::contains("Test User <test@example.com>"));
+}
+
+#[test]
+fn test_edit_accept_explicit_decider_wins() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "edit",
+            "ADR-0001",
+            "--status",
+            "accepted",
+            "--add-decider",
+            "Architecture Board",
+        ])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["deciders"], serde_json::json!(["Architecture Board"]));
+}
+
+#[test]
+fn test_edit_remove_tag_case_insensitive() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--remove-tag", "ORIGINAL"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed tag: original"));
+}
+
+#[test]
+fn test_edit_interactive_needs_terminal() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--interactive"])
+        .write_stdin("1\n")
+        .assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("--interactive needs a terminal"));
+}
+
+#[test]
+fn test_edit_interactive_conflicts_with_quick_edits() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--interactive", "--add-tag", "api"])
+        .assert()
+        .failure();
+}
+
+/// Read the ADR frontmatter back via `show --format yaml`.
+fn read_frontmatter(temp_dir: &TempDir) -> serde_yaml::Value {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "yaml", "--metadata-only"])
+        .output()
+        .expect("Failed to run show");
+    serde_yaml::from_slice(&output.stdout).expect("Invalid YAML")
+}
+
+#[test]
+fn test_edit_set_field_typed_values() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "edit",
+            "ADR-0001",
+            "--set-field",
+            "priority=2",
+            "--set-field",
+            "reviewed=true",
+            "--set-field",
+            "team=platform",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Set field: priority = 2"));
+
+    let fm = read_frontmatter(&temp_dir);
+    assert_eq!(fm["priority"], serde_yaml::Value::from(2));
+    assert_eq!(fm["reviewed"], serde_yaml::Value::Bool(true));
+    assert_eq!(fm["team"], serde_yaml::Value::from("platform"));
+}
+
+#[test]
+fn test_edit_remove_field() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--set-field", "team=platform"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--remove-field", "team"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed field: team"));
+
+    let fm = read_frontmatter(&temp_dir);
+    assert!(fm.get("team").is_none());
+}
+
+#[test]
+fn test_edit_set_field_rejects_reserved_key() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--set-field", "status=accepted"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("dedicated option"));
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--set-field", "novalue"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected key=value"));
+}
+
+/// Create an ADR with `new` on a fresh commit.
+fn new_adr(temp_dir: &TempDir, args: &[&str]) {
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Next commit"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to commit");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .arg("new")
+        .args(args)
+        .assert()
+        .success();
+}
+
+/// The status of each ADR, via `list --format json`.
+fn statuses(temp_dir: &TempDir) -> Vec<(String, String)> {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--format", "json"])
+        .output()
+        .expect("Failed to run list");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    json.as_array()
+        .expect("Expected an array")
+        .iter()
+        .map(|adr| {
+            (
+                adr["id"].as_str().unwrap_or_default().to_string(),
+                adr["status"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_edit_bulk_status_by_tag() {
+    let temp_dir = setup_test_repo_with_adr();
+    new_adr(
+        &temp_dir,
+        &["Legacy API", "--status", "accepted", "--tag", "legacy"],
+    );
+    new_adr(
+        &temp_dir,
+        &["Legacy UI", "--status", "accepted", "--tag", "legacy"],
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "edit",
+            "--tag",
+            "legacy",
+            "--set-status",
+            "deprecated",
+            "--add-tag",
+            "retired",
+            "--yes",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Updated 2 ADR(s)"));
+
+    assert_eq!(
+        statuses(&temp_dir),
+        [
+            ("ADR-0001".to_string(), "proposed".to_string()),
+            ("ADR-0002".to_string(), "deprecated".to_string()),
+            ("ADR-0003".to_string(), "deprecated".to_string()),
+        ]
+    );
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0003", "--field", "tags"])
+        .assert()
+        .success()
+        .stdout("legacy,retired\n");
+
+    // The search index picks up the new tags
+    let index = StdCommand::new("git")
+        .args(["log", "-1", "-p", "refs/notes/adr-index"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to read index");
+    assert!(String::from_utf8_lossy(&index.stdout).contains("retired"));
+}
+
+#[test]
+fn test_edit_bulk_confirmation_declined() {
+    let temp_dir = setup_test_repo_with_adr();
+    new_adr(
+        &temp_dir,
+        &["Legacy API", "--status", "accepted", "--tag", "legacy"],
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["edit", "--tag", "legacy", "--status", "deprecated"])
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Apply these changes to 1 ADR(s)?"))
+        .stderr(predicate::str::contains("Aborted"));
+
+    assert_eq!(statuses(&temp_dir)[1].1, "accepted");
+}
+
+#[test]
+fn test_edit_bulk_validates_every_transition() {
+    let temp_dir = setup_test_repo_with_adr();
+    new_adr(
+        &temp_dir,
+        &["Legacy API", "--status", "accepted", "--tag", "original"],
+    );
+
+    // ADR-0001 is proposed and can't be deprecated, so neither ADR changes
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "edit",
+            "--tag",
+            "original",
+            "--status",
+            "deprecated",
+            "--yes",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot change status of ADR-0001"));
+
+    assert_eq!(statuses(&temp_dir)[1].1, "accepted");
+}
+
+#[test]
+fn test_edit_bulk_archived_adrs() {
+    let temp_dir = setup_test_repo_with_adr();
+    new_adr(&temp_dir, &["Legacy API", "--status", "accepted"]);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["rm", "ADR-0002", "--archive"])
+        .assert()
+        .success();
+
+    // Status changes need a restore first
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "--filter-status", "archived", "--accept", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("restore"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "--filter-status", "archived", "--add-tag", "legacy"])
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Updated 1 ADR(s)"));
+
+    // The tag landed on the archived copy, which stays archived
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--archived", "--tag", "legacy"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0002"));
+    assert_eq!(statuses(&temp_dir).len(), 1);
+}
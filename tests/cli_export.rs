@@ -375,7 +375,7 @@ We decided to use Rust."#;
 
     // Verify HTML contains code block conversion
     let content = std::fs::read_to_string(export_dir.join("ADR-0001.html")).unwrap();
-    assert!(content.contains("<pre><code>"));
+    assert!(content.contains(r#"<pre><code class="language-rust">"#));
     assert!(content.contains("</code></pre>"));
 }
 
@@ -557,7 +557,413 @@ fn main() {
 
     // Verify HTML contains code block with auto-closed tags
     let content = std::fs::read_to_string(export_dir.join("ADR-0001.html")).unwrap();
-    assert!(content.contains("<pre><code>"));
+    assert!(content.contains(r#"<pre><code class="language-rust">"#));
     // The unclosed code block should be auto-closed
     assert!(content.contains("</code></pre>"));
 }
+
+/// Export a single ADR with the given body to HTML and return the page.
+fn export_html_with_body(body: &str) -> String {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    let adr_content =
+        format!("---\nid: ADR-0001\ntitle: Formatting Decision\nstatus: proposed\n---\n\n{body}");
+    StdCommand::new("git")
+        .args([
+            "notes",
+            "--ref=adr",
+            "add",
+            "-f",
+            "-m",
+            &adr_content,
+            "HEAD",
+        ])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add note");
+
+    let export_dir = path.join("export-formatting");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "export",
+            "--output",
+            export_dir.to_str().unwrap(),
+            "--format",
+            "html",
+        ])
+        .assert()
+        .success();
+
+    std::fs::read_to_string(export_dir.join("ADR-0001.html")).unwrap()
+}
+
+#[test]
+fn test_export_html_bold() {
+    let content = export_html_with_body("We **must** keep latency low.");
+    assert!(content.contains("<strong>must</strong>"));
+}
+
+#[test]
+fn test_export_html_link() {
+    let content = export_html_with_body("See [the RFC](https://example.com/rfc) for details.");
+    assert!(content.contains(r#"<a href="https://example.com/rfc">the RFC</a>"#));
+}
+
+#[test]
+fn test_export_html_table() {
+    let content = export_html_with_body(
+        "| Option | Cost |\n|--------|------|\n| Postgres | Low |\n| Oracle | High |\n",
+    );
+    assert!(content.contains("<table>"));
+    assert!(content.contains("<th>Option</th>"));
+    assert!(content.contains("<td>Postgres</td>"));
+}
+
+#[test]
+fn test_export_html_code_fence_language() {
+    let content = export_html_with_body("```sql\nSELECT 1;\n```\n");
+    assert!(content.contains(r#"<pre><code class="language-sql">SELECT 1;"#));
+}
+
+/// Create a repository with three related ADRs, each on its own commit.
+fn setup_linked_adrs() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    let notes = [
+        "---\nid: ADR-0001\ntitle: Use MySQL\nstatus: superseded\nsuperseded_by: ADR-0002\n---\n\nOld.",
+        "---\nid: ADR-0002\ntitle: Use Postgres\nstatus: accepted\nsupersedes: ADR-0001\nlinks:\n- rel: relates-to\n  target: ADR-0003\n---\n\nNew.",
+        "---\nid: ADR-0003\ntitle: Use an ORM\nstatus: proposed\n---\n\nORM.",
+    ];
+    for note in notes {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", "ADR commit"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        StdCommand::new("git")
+            .args(["notes", "--ref=adr", "add", "-f", "-m", note, "HEAD"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to add note");
+    }
+
+    temp_dir
+}
+
+#[test]
+fn test_export_dot_graph() {
+    let temp_dir = setup_linked_adrs();
+    let export_dir = temp_dir.path().join("graph");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "export",
+            "--output",
+            export_dir.to_str().unwrap(),
+            "--format",
+            "dot",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("graph.dot"));
+
+    let content = std::fs::read_to_string(export_dir.join("graph.dot")).unwrap();
+    assert!(content.starts_with("digraph adrs {"));
+    assert!(
+        content.contains(r##""ADR-0002" [label="ADR-0002\nUse Postgres", fillcolor="#27ae60"];"##)
+    );
+
+    let edges: Vec<&str> = content.lines().filter(|l| l.contains("->")).collect();
+    assert_eq!(edges.len(), 2, "unexpected edges: {edges:?}");
+    assert_eq!(
+        edges
+            .iter()
+            .filter(|l| l.contains(r#""ADR-0002" -> "ADR-0001" [label="supersedes"]"#))
+            .count(),
+        1
+    );
+    assert_eq!(
+        edges
+            .iter()
+            .filter(|l| l.contains(r#""ADR-0002" -> "ADR-0003" [label="relates-to"]"#))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_export_mermaid_graph() {
+    let temp_dir = setup_linked_adrs();
+    let export_dir = temp_dir.path().join("graph");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "export",
+            "--output",
+            export_dir.to_str().unwrap(),
+            "--format",
+            "mermaid",
+        ])
+        .assert()
+        .success();
+
+    let content = std::fs::read_to_string(export_dir.join("graph.mmd")).unwrap();
+    assert!(content.starts_with("flowchart LR"));
+    assert!(content.contains(r#"ADR_0001["ADR-0001: Use MySQL"]:::superseded"#));
+
+    let edges: Vec<&str> = content.lines().filter(|l| l.contains("-->")).collect();
+    assert_eq!(edges.len(), 2, "unexpected edges: {edges:?}");
+    assert!(edges.contains(&"    ADR_0002 -->|supersedes| ADR_0001"));
+    assert!(edges.contains(&"    ADR_0002 -->|relates-to| ADR_0003"));
+}
+
+/// Run `export --single-file` and return the export directory.
+fn export_single_file(temp_dir: &TempDir, format: &str) -> std::path::PathBuf {
+    let export_dir = temp_dir.path().join("combined");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "export",
+            "--output",
+            export_dir.to_str().unwrap(),
+            "--format",
+            format,
+            "--single-file",
+        ])
+        .assert()
+        .success();
+
+    export_dir
+}
+
+#[test]
+fn test_export_single_file_markdown() {
+    let temp_dir = setup_linked_adrs();
+    let export_dir = export_single_file(&temp_dir, "markdown");
+
+    let files: Vec<_> = std::fs::read_dir(&export_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, vec!["adrs.md".to_string()]);
+
+    let content = std::fs::read_to_string(export_dir.join("adrs.md")).unwrap();
+    assert!(content.contains("- [ADR-0001: Use MySQL](#adr-0001-use-mysql)"));
+    assert!(content.contains("- [ADR-0003: Use an ORM](#adr-0003-use-an-orm)"));
+
+    // Sections follow the sorted ID order
+    let first = content.find("## ADR-0001: Use MySQL").unwrap();
+    let second = content.find("## ADR-0002: Use Postgres").unwrap();
+    let third = content.find("## ADR-0003: Use an ORM").unwrap();
+    assert!(first < second && second < third);
+    assert!(!content.contains("---\nid:"));
+}
+
+#[test]
+fn test_export_single_file_html_anchors() {
+    let temp_dir = setup_linked_adrs();
+    let export_dir = export_single_file(&temp_dir, "html");
+
+    assert!(!export_dir.join("index.html").exists());
+    let content = std::fs::read_to_string(export_dir.join("adrs.html")).unwrap();
+
+    let anchors: Vec<&str> = content
+        .split("href=\"#")
+        .skip(1)
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect();
+    assert_eq!(anchors.len(), 3);
+    for anchor in anchors {
+        assert!(
+            content.contains(&format!("<h2 id=\"{anchor}\">")),
+            "no heading for anchor {anchor}"
+        );
+    }
+}
+
+#[test]
+fn test_export_single_file_rejects_json() {
+    let temp_dir = setup_linked_adrs();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["export", "--format", "json", "--single-file"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--single-file"));
+}
+
+/// Export an Atom feed of the linked ADRs plus two dated ones and return it.
+fn export_atom_feed(extra_args: &[&str]) -> String {
+    let temp_dir = setup_linked_adrs();
+    let path = temp_dir.path();
+    let notes = [
+        "---\nid: ADR-0004\ntitle: Use <Redis> & caching\nstatus: accepted\ndate: 2024-01-15\nauthors:\n- Test User\n---\n\n## Context\n\nReads are slow.\n\nMore detail.",
+        "---\nid: ADR-0005\ntitle: Use Kafka\nstatus: proposed\ndate: 2025-06-01\ntags:\n- messaging\n---\n\nEvents everywhere.",
+    ];
+    for note in notes {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", "ADR commit"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        StdCommand::new("git")
+            .args(["notes", "--ref=adr", "add", "-f", "-m", note, "HEAD"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to add note");
+    }
+
+    let export_dir = path.join("feed");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["export", "--output", export_dir.to_str().unwrap()])
+        .args(["--format", "atom"])
+        .args(extra_args)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("atom.xml"));
+
+    std::fs::read_to_string(export_dir.join("atom.xml")).expect("Failed to read feed")
+}
+
+/// Check the feed is well-formed XML and return its entry IDs in order.
+fn atom_entry_ids(feed: &str) -> Vec<String> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(feed);
+    let mut ids = Vec::new();
+    let mut in_entry_id = false;
+    let mut depth = 0;
+    loop {
+        match reader.read_event().expect("Feed should be well-formed XML") {
+            Event::Start(tag) => {
+                depth += 1;
+                in_entry_id = depth == 3 && tag.name().as_ref() == b"id";
+            },
+            Event::End(_) => {
+                depth -= 1;
+                in_entry_id = false;
+            },
+            Event::Text(text) if in_entry_id => {
+                ids.push(String::from_utf8_lossy(&text).to_string());
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+    assert_eq!(depth, 0, "unclosed elements in feed");
+    ids
+}
+
+#[test]
+fn test_export_atom_feed() {
+    let feed = export_atom_feed(&[]);
+
+    assert!(feed.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+    assert_eq!(
+        atom_entry_ids(&feed),
+        [
+            "urn:git-adr:ADR-0005",
+            "urn:git-adr:ADR-0004",
+            "urn:git-adr:ADR-0001",
+            "urn:git-adr:ADR-0002",
+            "urn:git-adr:ADR-0003",
+        ]
+    );
+    assert!(feed.contains("<title>Use &lt;Redis&gt; &amp; caching</title>"));
+    assert!(feed.contains("<updated>2025-06-01T00:00:00Z</updated>"));
+    assert!(feed.contains(r#"<link rel="alternate" type="text/html" href="ADR-0004.html"/>"#));
+    assert!(feed.contains(r#"<category term="proposed" label="Status"/>"#));
+    assert!(feed.contains("<summary>Status: accepted. Reads are slow.</summary>"));
+}
+
+#[test]
+fn test_export_atom_feed_limit() {
+    let feed = export_atom_feed(&["--limit", "2"]);
+    assert_eq!(
+        atom_entry_ids(&feed),
+        ["urn:git-adr:ADR-0005", "urn:git-adr:ADR-0004"]
+    );
+}
+
+#[test]
+fn test_export_limit_requires_atom() {
+    let temp_dir = setup_linked_adrs();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["export", "--format", "json", "--limit", "2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--limit is only supported"));
+}
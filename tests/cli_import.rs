@@ -0,0 +1,488 @@
+//! Integration tests for the `git-adr import` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary git repository with ADR initialized.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+/// Export all ADRs as JSON and return the parsed `ADR-0001.json`, minus the commit.
+fn export_first_adr(repo: &Path) -> serde_json::Value {
+    let export_dir = repo.join("export-json");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(repo)
+        .args([
+            "export",
+            "--output",
+            export_dir.to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success();
+
+    let content = std::fs::read_to_string(export_dir.join("ADR-0001.json"))
+        .expect("Failed to read exported JSON");
+    let mut value: serde_json::Value = serde_json::from_str(&content).expect("Invalid JSON");
+    value
+        .as_object_mut()
+        .expect("ADR JSON should be an object")
+        .remove("commit");
+    value
+}
+
+#[test]
+fn test_import_json_round_trip() {
+    let source = setup_test_repo();
+    let full_adr = r#"---
+id: ADR-0001
+title: Use PostgreSQL
+status: accepted
+date: 2025-06-01
+tags:
+  - database
+authors:
+  - Alice <alice@example.com>
+deciders:
+  - Bob
+links:
+  - rel: relates
+    target: ADR-0002
+format: madr
+priority: high
+---
+
+## Context
+
+We need a relational database.
+"#;
+    std::fs::write(source.path().join("full_adr.md"), full_adr).expect("Failed to write ADR");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(source.path())
+        .args(["new", "Ignored", "--file", "full_adr.md"])
+        .assert()
+        .success();
+
+    let exported = export_first_adr(source.path());
+    let json_path = source.path().join("export-json").join("ADR-0001.json");
+
+    let target = setup_test_repo();
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(target.path())
+        .args(["import", json_path.to_str().unwrap()])
+        .assert()
+        .success();
+
+    let reexported = export_first_adr(target.path());
+    assert_eq!(exported["priority"], "high");
+    assert_eq!(exported["links"][0]["target"], "ADR-0002");
+    assert_eq!(exported, reexported);
+}
+
+/// Commit a file so each imported ADR is attached to its own commit.
+fn commit_file(repo: &Path, name: &str) {
+    std::fs::write(repo.join(name), name).expect("Failed to write file");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(repo)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", name])
+        .current_dir(repo)
+        .output()
+        .expect("Failed to commit");
+}
+
+#[test]
+fn test_import_directory_added_in_one_commit() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/adr-tools");
+
+    std::fs::create_dir_all(path.join("doc/adr")).expect("Failed to create directory");
+    for file in [
+        "0001-record-architecture-decisions.md",
+        "0002-use-mysql.md",
+        "0003-use-postgresql.md",
+    ] {
+        std::fs::copy(fixtures.join(file), path.join("doc/adr").join(file))
+            .expect("Failed to copy fixture");
+    }
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Add ADRs"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    // A dry run reports what a real run does
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["import", "doc/adr", "--dry-run"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("3 would be imported"))
+        .stderr(predicate::str::contains("0 would be skipped"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["import", "doc/adr"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("3 imported"))
+        .stderr(predicate::str::contains("0 skipped"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--format", "json"])
+        .output()
+        .expect("Failed to run list");
+    let adrs: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    let mut commits: Vec<_> = adrs
+        .as_array()
+        .expect("Output should be an array")
+        .iter()
+        .map(|adr| adr["commit"].as_str().expect("commit should be a string"))
+        .collect();
+    assert_eq!(commits.len(), 3);
+    commits.sort_unstable();
+    commits.dedup();
+    assert_eq!(commits.len(), 3, "every ADR needs its own commit");
+}
+
+#[test]
+fn test_import_adr_tools_links() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/adr-tools");
+
+    for file in [
+        "0001-record-architecture-decisions.md",
+        "0002-use-mysql.md",
+        "0003-use-postgresql.md",
+    ] {
+        commit_file(path, file);
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args(["import", fixtures.join(file).to_str().unwrap()])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Imported"));
+    }
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("status: superseded"))
+        .stdout(predicate::str::contains("superseded_by: ADR-0003"))
+        .stdout(predicate::str::contains("date: 2016-03-01"));
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(path)
+        .args(["show", "ADR-0003", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("status: accepted"))
+        .stdout(predicate::str::contains("supersedes: ADR-0002"))
+        .stdout(predicate::str::contains("rel: amends"))
+        .stdout(predicate::str::contains("target: ADR-0001"));
+}
+
+#[test]
+fn test_import_adr_tools_directory_tree() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/adr-tools");
+
+    // Commit each file on its own so every ADR lands on the commit that added it
+    for (file, dir) in [
+        ("0003-use-postgresql.md", "doc/adr/later"),
+        ("0001-record-architecture-decisions.md", "doc/adr"),
+        ("0002-use-mysql.md", "doc/adr"),
+    ] {
+        std::fs::create_dir_all(path.join(dir)).expect("Failed to create directory");
+        std::fs::copy(fixtures.join(file), path.join(dir).join(file))
+            .expect("Failed to copy fixture");
+        StdCommand::new("git")
+            .args(["add", "."])
+            .current_dir(path)
+            .output()
+            .expect("Failed to stage files");
+        StdCommand::new("git")
+            .args(["commit", "-m", file])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+    }
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["import", "doc/adr", "--prefix-map", "3=ADR-0010"])
+        .output()
+        .expect("Failed to run import");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("3 imported"), "{stderr}");
+    let first = stderr.find("ADR-0001").expect("ADR-0001 imported");
+    let second = stderr.find("ADR-0002").expect("ADR-0002 imported");
+    let third = stderr.find("ADR-0010").expect("ADR-0010 imported");
+    assert!(first < second && second < third, "{stderr}");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("status: superseded"))
+        .stdout(predicate::str::contains("superseded_by: ADR-0010"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0010", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("title: Use PostgreSQL"))
+        .stdout(predicate::str::contains("supersedes: ADR-0002"))
+        .stdout(predicate::str::contains("rel: amends"))
+        .stdout(predicate::str::contains("target: ADR-0001"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0010", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    let body = value["body"].as_str().expect("body should be a string");
+    assert!(body.contains("## Context"), "{body}");
+    assert!(!body.contains("## Status"), "{body}");
+    assert!(!body.contains("Date:"), "{body}");
+
+    // Importing again must not overwrite the ADRs already on those commits
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["import", "doc/adr", "--prefix-map", "3=ADR-0010"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("0 imported"))
+        .stderr(predicate::str::contains("3 deduped"));
+}
+
+/// Write `content` to `name` in the repository and import it with `extra` args.
+fn import_markdown_file(repo: &Path, name: &str, content: &str, extra: &[&str]) -> Command {
+    std::fs::write(repo.join(name), content).expect("Failed to write file");
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(repo).args(["import", name]).args(extra);
+    cmd
+}
+
+#[test]
+fn test_import_skips_duplicates() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Use PostgreSQL", "--no-edit"])
+        .assert()
+        .success();
+
+    // Same title modulo case and punctuation, no frontmatter ID
+    import_markdown_file(path, "pg.md", "# use postgresql!\n\nBody\n", &[])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Duplicate of ADR-0001"))
+        .stderr(predicate::str::contains("0 imported"))
+        .stderr(predicate::str::contains("1 deduped"));
+
+    import_markdown_file(
+        path,
+        "pg.md",
+        "# Use PostgreSQL\n\nBody\n",
+        &["--on-duplicate", "error"],
+    )
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("duplicates existing ADR ADR-0001"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--format", "oneline"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0002").not());
+}
+
+#[test]
+fn test_import_updates_duplicates() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Use PostgreSQL", "--no-edit"])
+        .assert()
+        .success();
+
+    let content =
+        "---\nid: ADR-0001\ntitle: Use PostgreSQL 16\nstatus: accepted\n---\n\nUpdated body\n";
+    import_markdown_file(path, "pg.md", content, &["--on-duplicate", "update"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Updated: ADR-0001"))
+        .stderr(predicate::str::contains("1 updated"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("title: Use PostgreSQL 16"))
+        .stdout(predicate::str::contains("status: accepted"))
+        .stdout(predicate::str::contains("Updated body"));
+}
+
+#[test]
+fn test_import_rejects_invalid_prefix_map() {
+    let temp_dir = setup_test_repo();
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/adr-tools");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "import",
+            fixtures.to_str().unwrap(),
+            "--prefix-map",
+            "three",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected NUMBER=ID"));
+}
+
+#[test]
+fn test_import_madr() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/madr");
+
+    for file in ["0001-use-markdown-adrs.md", "0002-use-log4brains.md"] {
+        commit_file(path, file);
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(path)
+            .args(["import", fixtures.join(file).to_str().unwrap()])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Imported"));
+    }
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(
+        value["title"],
+        "Use Markdown Architectural Decision Records"
+    );
+    assert_eq!(value["status"], "accepted");
+    assert_eq!(value["deciders"], serde_json::json!(["Alice", "Bob"]));
+    assert_eq!(value["tags"], serde_json::json!(["docs", "process"]));
+    assert!(value["date"].as_str().unwrap().starts_with("2023-04-12"));
+    let body = value["body"].as_str().unwrap();
+    assert!(
+        body.starts_with("## Context and Problem Statement"),
+        "{body}"
+    );
+    assert!(!body.contains("deciders:"), "{body}");
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(value["title"], "Use Log4brains to manage the ADRs");
+    assert_eq!(value["status"], "superseded");
+    assert_eq!(value["deciders"], serde_json::json!(["Carol"]));
+    assert!(value["date"].as_str().unwrap().starts_with("2023-05-02"));
+    let body = value["body"].as_str().unwrap();
+    assert!(!body.contains("Status:"), "{body}");
+    assert!(body.contains("## Decision Outcome"), "{body}");
+}
@@ -141,3 +141,231 @@ fn test_init_not_a_repo() {
         .failure()
         .stderr(predicate::str::contains("not a git repository"));
 }
+
+#[test]
+fn test_init_with_custom_namespace() {
+    let temp_dir = create_empty_repo();
+    let path = temp_dir.path();
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["init", "--namespace", "decisions"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Namespace: refs/notes/decisions"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Namespaced Decision", "--no-edit"])
+        .assert()
+        .success();
+
+    let custom = StdCommand::new("git")
+        .args(["notes", "--ref", "decisions", "list"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to list notes");
+    assert_eq!(String::from_utf8_lossy(&custom.stdout).lines().count(), 1);
+
+    // The default ref is never created
+    let default = StdCommand::new("git")
+        .args(["show-ref", "--verify", "refs/notes/adr"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to run show-ref");
+    assert!(!default.status.success());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Namespaced Decision"));
+}
+
+#[test]
+fn test_init_invalid_namespace() {
+    let temp_dir = create_empty_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["init", "--namespace", "bad..name"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid notes namespace"));
+}
+
+#[test]
+fn test_init_repairs_missing_notes_ref() {
+    let temp_dir = create_empty_repo();
+    let path = temp_dir.path();
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["init", "--prefix", "DEC-"])
+        .assert()
+        .success();
+
+    let show_ref = |path: &std::path::Path| {
+        StdCommand::new("git")
+            .args(["show-ref", "--verify", "refs/notes/adr"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to run show-ref")
+            .status
+            .success()
+    };
+    assert!(show_ref(path));
+
+    // Leave the config behind without the notes ref
+    StdCommand::new("git")
+        .args(["update-ref", "-d", "refs/notes/adr"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to delete notes ref");
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .arg("init")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("refs/notes/adr is missing"))
+        .stderr(predicate::str::contains("already initialized").not());
+    assert!(show_ref(path));
+
+    // The existing config survives the repair
+    let output = StdCommand::new("git")
+        .args(["config", "--get", "adr.prefix"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to get config");
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "DEC-");
+
+    // init and new now agree the repository is initialized
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("already initialized"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "After Repair", "--no-edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DEC-0001"));
+}
+
+#[test]
+fn test_init_write_config() {
+    let temp_dir = create_empty_repo();
+    let path = temp_dir.path();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["init", "--prefix", "DEC-", "--write-config"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(".adr.toml"));
+
+    let content = std::fs::read_to_string(path.join(".adr.toml")).expect("Failed to read");
+    assert!(content.contains("prefix = \"DEC-\""));
+    assert!(content.contains("digits = 4"));
+
+    // The file is the source of the shared settings, not local git config
+    let output = StdCommand::new("git")
+        .args(["config", "--get", "adr.prefix"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to get config");
+    assert!(String::from_utf8_lossy(&output.stdout).trim().is_empty());
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("adr.prefix = DEC- (.adr.toml)"));
+
+    // An existing file is only replaced with --force
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["init", "--write-config"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn test_init_uses_project_config() {
+    let temp_dir = create_empty_repo();
+    let path = temp_dir.path();
+
+    std::fs::write(
+        path.join(".adr.toml"),
+        "prefix = \"TEAM-\"\ndigits = 3\nformat = \"nygard\"\n",
+    )
+    .expect("Failed to write .adr.toml");
+    StdCommand::new("git")
+        .args(["add", ".adr.toml"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Add shared ADR settings"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .arg("init")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Prefix: TEAM-"));
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["new", "Shared Settings", "--no-edit"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created ADR: TEAM-001"));
+
+    // Local git config still overrides the file
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["config", "set", "prefix", "MINE-"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("adr.prefix = MINE- (local)"))
+        .stdout(predicate::str::contains("adr.digits = 3 (.adr.toml)"));
+}
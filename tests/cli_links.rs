@@ -0,0 +1,186 @@
+//! Integration tests for the `git-adr links` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary initialized git repository.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+/// Attach a raw ADR note to a fresh commit.
+fn add_adr_note(temp_dir: &TempDir, content: &str) {
+    let path = temp_dir.path();
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "ADR commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    StdCommand::new("git")
+        .args(["notes", "--ref", "adr", "add", "-f", "-m", content, "HEAD"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add note");
+}
+
+/// Run `git-adr links` with `args` and return its stdout.
+fn links_output(temp_dir: &TempDir, args: &[&str]) -> String {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .arg("links")
+        .args(args)
+        .output()
+        .expect("Failed to run links");
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_links_flat_list() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: superseded\nsuperseded_by: ADR-0002\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: accepted\nsupersedes: ADR-0001\nlinks:\n- rel: relates-to\n  target: ADR-0003\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0003\ntitle: Third\nstatus: accepted\n---\n\nBody\n",
+    );
+
+    let stdout = links_output(&temp_dir, &[]);
+    assert!(stdout.contains("ADR-0002 supersedes ADR-0001"));
+    assert!(stdout.contains("ADR-0002 relates-to ADR-0003"));
+    // Both sides of a supersession produce a single edge
+    assert_eq!(stdout.matches("supersedes").count(), 1);
+}
+
+#[test]
+fn test_links_tree_follows_supersession_chain() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: superseded\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: superseded\nsupersedes: ADR-0001\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0003\ntitle: Third\nstatus: accepted\nsupersedes: ADR-0002\n---\n\nBody\n",
+    );
+
+    let stdout = links_output(&temp_dir, &["--tree"]);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("ADR-0003 Third"));
+    assert!(lines[1].starts_with("└── supersedes ADR-0002 Second"));
+    assert!(lines[2].starts_with("    └── supersedes ADR-0001 First"));
+
+    // Starting from the middle only shows what it leads to
+    let stdout = links_output(&temp_dir, &["ADR-0002", "--tree"]);
+    assert!(stdout.starts_with("ADR-0002 Second"));
+    assert!(stdout.contains("ADR-0001"));
+    assert!(!stdout.contains("ADR-0003"));
+}
+
+#[test]
+fn test_links_tree_marks_cycles() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\nlinks:\n- rel: relates-to\n  target: ADR-0002\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: accepted\nlinks:\n- rel: relates-to\n  target: ADR-0001\n---\n\nBody\n",
+    );
+
+    let stdout = links_output(&temp_dir, &["--tree"]);
+    assert!(stdout.starts_with("ADR-0001 First"));
+    assert!(stdout.contains("└── relates-to ADR-0002 Second"));
+    assert!(stdout.contains("└── relates-to ADR-0001 First [accepted] (cycle)"));
+    assert_eq!(stdout.lines().count(), 3);
+}
+
+#[test]
+fn test_links_dot_format() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: superseded\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: accepted\nsupersedes: ADR-0001\n---\n\nBody\n",
+    );
+
+    let stdout = links_output(&temp_dir, &["--format", "dot"]);
+    assert!(stdout.contains("digraph adrs"));
+    assert!(stdout.contains("\"ADR-0002\" -> \"ADR-0001\" [label=\"supersedes\"];"));
+}
+
+#[test]
+fn test_links_none() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\nBody\n",
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .arg("links")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No links between ADRs"));
+}
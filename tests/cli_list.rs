@@ -107,6 +107,32 @@ fn test_list_empty_repo() {
         .stderr(predicate::str::contains("No ADRs found"));
 }
 
+#[test]
+fn test_list_empty_repo_structured_formats() {
+    let temp_dir = setup_test_repo();
+
+    for (format, expected) in [("json", "[]\n"), ("toml", "adrs = []\n"), ("yaml", "[]\n")] {
+        let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+        cmd.current_dir(temp_dir.path())
+            .args(["list", "--format", format])
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
+
+#[test]
+fn test_list_fetch_without_remote() {
+    let temp_dir = setup_test_repo();
+
+    // Fetching is best-effort, so a repository without remotes still lists
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["list", "--fetch"])
+        .assert()
+        .success();
+}
+
 #[test]
 fn test_list_with_adrs() {
     let temp_dir = setup_test_repo();
@@ -632,3 +658,384 @@ fn test_list_filter_since_rfc3339() {
         .success()
         .stdout(predicate::str::contains("ADR-0001"));
 }
+
+#[test]
+fn test_list_piped_output_bypasses_pager() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(temp_dir.path(), "ADR-0001", "Paged Decision", "proposed");
+
+    // stdout is captured (not a TTY), so the pager must not be used
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_PAGER", "sed s/^/PAGED:/")
+        .args(["list", "--format", "oneline"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Paged Decision"))
+        .stdout(predicate::str::contains("PAGED:").not());
+}
+
+/// Run `list --format oneline` with `extra` args and return the ADR IDs in output order.
+fn list_ids(path: &std::path::Path, extra: &[&str]) -> Vec<String> {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--format", "oneline", "--no-pager"])
+        .args(extra)
+        .output()
+        .expect("Failed to run list");
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().find(|w| w.starts_with("ADR-")))
+        .map(|id| id.trim_end_matches(':').to_string())
+        .collect()
+}
+
+#[test]
+fn test_list_sort() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    add_adr_note_with_date(path, "ADR-0001", "beta decision", "accepted", "2025-01-01");
+
+    std::fs::write(path.join("file1.txt"), "content").expect("Failed to write file");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Second commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    add_adr_note_with_date(path, "ADR-0002", "Alpha decision", "proposed", "2020-01-01");
+
+    assert_eq!(list_ids(path, &[]), ["ADR-0001", "ADR-0002"]);
+    // The 2020 ADR comes before the 2025 one
+    assert_eq!(
+        list_ids(path, &["--sort", "date"]),
+        ["ADR-0002", "ADR-0001"]
+    );
+    assert_eq!(
+        list_ids(path, &["--sort", "date", "--reverse"]),
+        ["ADR-0001", "ADR-0002"]
+    );
+    // Proposed comes before accepted in the lifecycle
+    assert_eq!(
+        list_ids(path, &["--sort", "status"]),
+        ["ADR-0002", "ADR-0001"]
+    );
+    assert_eq!(
+        list_ids(path, &["--sort", "title"]),
+        ["ADR-0002", "ADR-0001"]
+    );
+}
+
+#[test]
+fn test_list_stale() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+    for (id, status, date) in [
+        ("ADR-0001", "proposed", "2020-01-01"),
+        ("ADR-0002", "accepted", "2020-01-01"),
+        ("ADR-0003", "proposed", today.as_str()),
+    ] {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", id])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        add_adr_note_with_date(path, id, &format!("Decision {id}"), status, date);
+    }
+
+    // Only the old proposal is past the default 90 days
+    assert_eq!(list_ids(path, &["--stale"]), ["ADR-0001"]);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "staleDays", "100000"])
+        .assert()
+        .success();
+    assert!(list_ids(path, &["--stale"]).is_empty());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--stale", "--status", "accepted"])
+        .assert()
+        .failure();
+}
+
+/// Add three ADRs: two accepted and one proposed, one of them also tagged `db`.
+fn setup_grouped_repo() -> TempDir {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    for (id, status) in [
+        ("ADR-0001", "accepted"),
+        ("ADR-0002", "proposed"),
+        ("ADR-0003", "accepted"),
+    ] {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", id])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        add_adr_note(path, id, &format!("Decision {id}"), status);
+    }
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["edit", "ADR-0002", "--add-tag", "db"])
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+#[test]
+fn test_list_group_by_status() {
+    let temp_dir = setup_grouped_repo();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--group-by", "status", "--format", "oneline"])
+        .output()
+        .expect("Failed to run list");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Proposed comes before accepted in the lifecycle
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "proposed:");
+    assert!(lines[1].starts_with("ADR-0002"));
+    assert_eq!(lines[2], "");
+    assert_eq!(lines[3], "accepted:");
+    assert!(lines[4].starts_with("ADR-0001"));
+    assert!(lines[5].starts_with("ADR-0003"));
+
+    // The table format repeats the header in each group
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--group-by", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("accepted (2)"))
+        .stdout(predicate::str::contains("proposed (1)"))
+        .stdout(predicate::str::contains("3 ADR(s) found"));
+}
+
+#[test]
+fn test_list_group_by_tag_json() {
+    let temp_dir = setup_grouped_repo();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--group-by", "tag", "--format", "json"])
+        .output()
+        .expect("Failed to run list");
+    assert!(output.status.success());
+
+    let groups: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be JSON");
+    let groups = groups.as_array().expect("Groups should be an array");
+    let names: Vec<&str> = groups
+        .iter()
+        .map(|group| group["group"].as_str().unwrap_or_default())
+        .collect();
+    assert_eq!(names, ["db", "test"]);
+    let ids = |index: usize| -> Vec<String> {
+        groups[index]["adrs"]
+            .as_array()
+            .expect("Group should list its ADRs")
+            .iter()
+            .map(|adr| adr["id"].as_str().unwrap_or_default().to_string())
+            .collect()
+    };
+    assert_eq!(ids(0), ["ADR-0002"]);
+    assert_eq!(ids(1), ["ADR-0001", "ADR-0002", "ADR-0003"]);
+
+    // Status groups keep the lifecycle order rather than sorting by name
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--group-by", "status", "--format", "json"])
+        .output()
+        .expect("Failed to run list");
+    let groups: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be JSON");
+    assert_eq!(groups[0]["group"], "proposed");
+    assert_eq!(groups[1]["group"], "accepted");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--group-by", "tag", "--format", "csv"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_list_toml_and_yaml() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        temp_dir.path(),
+        "ADR-0001",
+        "Structured Decision",
+        "accepted",
+    );
+
+    let run = |format: &str| -> String {
+        let output = Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(temp_dir.path())
+            .args(["list", "--format", format])
+            .output()
+            .expect("Failed to run list");
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    let toml: toml::Value = toml::from_str(&run("toml")).expect("Output should be TOML");
+    let entry = &toml["adrs"][0];
+    assert_eq!(entry["id"].as_str(), Some("ADR-0001"));
+    assert_eq!(entry["status"].as_str(), Some("accepted"));
+    assert_eq!(entry["date"].as_str(), Some("2025-01-15"));
+    assert_eq!(entry["tags"][0].as_str(), Some("test"));
+
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(&run("yaml")).expect("Output should be YAML");
+    let entry = &yaml[0];
+    assert_eq!(entry["title"].as_str(), Some("Structured Decision"));
+    assert_eq!(entry["date"].as_str(), Some("2025-01-15"));
+    assert!(entry["commit"].as_str().is_some_and(|c| c.len() == 40));
+}
+
+#[test]
+fn test_list_relative_dates() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+    for (id, date) in [("ADR-0001", "2020-01-01"), ("ADR-0002", today.as_str())] {
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", id])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        add_adr_note_with_date(path, id, &format!("Decision {id}"), "accepted", date);
+    }
+
+    assert_eq!(list_ids(path, &["--since", "2w"]), ["ADR-0002"]);
+    assert_eq!(list_ids(path, &["--since", "today"]), ["ADR-0002"]);
+    assert_eq!(list_ids(path, &["--until", "1y"]), ["ADR-0001"]);
+    assert_eq!(list_ids(path, &["--until", "yesterday"]), ["ADR-0001"]);
+}
+
+#[test]
+fn test_list_no_color() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+    add_adr_note(path, "ADR-0001", "First Decision", "accepted");
+
+    // CLICOLOR_FORCE would otherwise color piped output
+    let no_color_env = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .env("CLICOLOR_FORCE", "1")
+        .env("NO_COLOR", "1")
+        .arg("list")
+        .output()
+        .expect("Failed to run list");
+    let no_color_flag = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .env("CLICOLOR_FORCE", "1")
+        .env_remove("NO_COLOR")
+        .args(["list", "--no-color"])
+        .output()
+        .expect("Failed to run list");
+
+    for output in [no_color_env, no_color_flag] {
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("ADR-0001"));
+        assert!(!stdout.contains('\x1b'), "ANSI codes in: {stdout:?}");
+    }
+}
+
+#[test]
+fn test_list_limit_and_offset() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    for n in 1..=10 {
+        let id = format!("ADR-{n:04}");
+        let status = if n % 2 == 0 { "accepted" } else { "proposed" };
+        StdCommand::new("git")
+            .args(["commit", "--allow-empty", "-m", &id])
+            .current_dir(path)
+            .output()
+            .expect("Failed to commit");
+        add_adr_note(path, &id, &format!("Decision {id}"), status);
+    }
+
+    assert_eq!(
+        list_ids(path, &["--limit", "3", "--offset", "3"]),
+        ["ADR-0004", "ADR-0005", "ADR-0006"]
+    );
+
+    // Filters apply before the window
+    assert_eq!(
+        list_ids(
+            path,
+            &["--status", "accepted", "--limit", "2", "--offset", "1"]
+        ),
+        ["ADR-0004", "ADR-0006"]
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--format", "json", "--limit", "3", "--offset", "8"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Showing").not())
+        .stderr(predicate::str::contains("Showing 9–10 of 10 ADR(s)"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--offset", "20"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "No ADRs past offset 20 (10 total)",
+        ));
+
+    // JSON consumers still get a document for a page past the end
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--format", "json", "--offset", "20"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["-q", "list", "--offset", "20"])
+        .assert()
+        .success()
+        .stderr("");
+}
@@ -0,0 +1,129 @@
+//! Integration tests for the `git-adr metrics` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary initialized git repository with two ADRs.
+fn setup_test_repo_with_adrs() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    for args in [
+        vec!["init"],
+        vec!["config", "user.email", "test@example.com"],
+        vec!["config", "user.name", "Test User"],
+        vec!["commit", "--allow-empty", "-m", "Initial commit"],
+    ] {
+        StdCommand::new("git")
+            .args(&args)
+            .current_dir(path)
+            .output()
+            .expect("Failed to run git");
+    }
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "new",
+            "Use PostgreSQL",
+            "--status",
+            "accepted",
+            "--tag",
+            "database",
+        ])
+        .assert()
+        .success();
+
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Second commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "new",
+            "Use MySQL",
+            "--supersedes",
+            "1",
+            "--tag",
+            "say \"hi\"",
+        ])
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+#[test]
+fn test_metrics_json_output() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .arg("metrics")
+        .output()
+        .expect("Failed to run metrics");
+    assert!(output.status.success());
+    let metrics: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(metrics["summary"]["total_adrs"], 2);
+    assert_eq!(metrics["summary"]["with_links"], 2);
+    assert_eq!(metrics["summary"]["stale_proposed"], 0);
+}
+
+#[test]
+fn test_metrics_prometheus_output() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["metrics", "--format", "prometheus"])
+        .output()
+        .expect("Failed to run metrics");
+    assert!(output.status.success());
+    let text = String::from_utf8(output.stdout).expect("Output should be UTF-8");
+
+    assert!(text
+        .contains("# HELP adr_total Total number of ADRs.\n# TYPE adr_total gauge\nadr_total 2\n"));
+    assert!(text.contains("adr_by_status{status=\"superseded\"} 1\n"));
+    assert!(text.contains("adr_by_status{status=\"rejected\"} 0\n"));
+    assert!(text.contains("adr_by_tag{tag=\"say \\\"hi\\\"\"} 1\n"));
+    assert!(text.contains("adr_with_links_total 2\n"));
+    assert!(text.contains("adr_stale_proposed_total 0\n"));
+
+    // Every line is a comment or a `name{labels} value` sample
+    let sample = regex::Regex::new(r#"^[a-z_]+(\{[a-z]+="([^"\\]|\\.)*"\})? -?[0-9.]+$"#).unwrap();
+    for line in text.lines().filter(|line| !line.starts_with('#')) {
+        assert!(sample.is_match(line), "invalid sample line: {line}");
+    }
+}
+
+#[test]
+fn test_metrics_rejects_unknown_format() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["metrics", "--format", "xml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
@@ -81,6 +81,31 @@ fn test_new_creates_adr() {
         .stdout(predicate::str::contains("Use PostgreSQL"));
 }
 
+#[test]
+fn test_new_quiet_hides_progress() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["--quiet", "new", "Quiet decision"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created ADR").not());
+}
+
+#[test]
+fn test_new_verbose_traces_git_commands() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["new", "Verbose decision", "--verbose"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("git notes"))
+        .stderr(predicate::str::contains("Created ADR: ADR-0001"));
+}
+
 #[test]
 fn test_new_with_status() {
     let temp_dir = setup_test_repo();
@@ -206,10 +231,28 @@ fn test_new_without_init() {
     cmd.current_dir(path)
         .args(["new", "Some Decision"])
         .assert()
-        .failure()
+        .code(3)
         .stderr(predicate::str::contains("not initialized"));
 }
 
+#[test]
+fn test_new_invalid_status_exit_code() {
+    let temp_dir = setup_test_repo();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Some Decision", "--status", "wip"])
+        .args(["--error-format", "json"])
+        .output()
+        .expect("Failed to run new");
+    assert_eq!(output.status.code(), Some(5));
+
+    let error: serde_json::Value =
+        serde_json::from_slice(&output.stderr).expect("Error should be JSON");
+    assert_eq!(error["kind"], "InvalidStatus");
+}
+
 #[test]
 fn test_new_with_deciders() {
     let temp_dir = setup_test_repo();
@@ -239,6 +282,51 @@ fn test_new_with_deciders() {
         .stdout(predicate::str::contains("Bob"));
 }
 
+#[test]
+fn test_new_uses_default_deciders() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "set", "default-deciders", "Alice,Bob"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Decision with Default Deciders"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(json["deciders"], serde_json::json!(["Alice", "Bob"]));
+
+    // Explicit deciders replace the defaults
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Carol's Decision", "--deciders", "Carol"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0002", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(json["deciders"], serde_json::json!(["Carol"]));
+}
+
 #[test]
 fn test_new_with_link_to_commit() {
     let temp_dir = setup_test_repo();
@@ -326,6 +414,29 @@ Content from the file with frontmatter.
         .stdout(predicate::str::contains("from-file"));
 }
 
+#[test]
+fn test_new_rejects_body_over_max_size() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "maxBodySize", "64"])
+        .assert()
+        .success();
+
+    std::fs::write(path.join("body.md"), "x".repeat(65)).expect("Failed to write body");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Oversized Decision", "--file", "body.md"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("content too large: 65 bytes"));
+}
+
 #[test]
 fn test_new_with_template() {
     let temp_dir = setup_test_repo();
@@ -346,3 +457,257 @@ fn test_new_with_template() {
         .success()
         .stdout(predicate::str::contains("Context and Problem Statement"));
 }
+
+#[test]
+fn test_new_with_template_fields() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args([
+            "new",
+            "Pick a database",
+            "--template",
+            "madr",
+            "--field",
+            r#"options=["PostgreSQL","SQLite"]"#,
+            "--field",
+            "chosen_option=PostgreSQL",
+        ])
+        .assert()
+        .success();
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("* PostgreSQL"))
+        .stdout(predicate::str::contains("* SQLite"))
+        .stdout(predicate::str::contains("Chosen option: \"PostgreSQL\""))
+        .stdout(predicate::str::contains("Option 1").not());
+}
+
+#[test]
+fn test_new_with_invalid_field() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["new", "Bad field", "--field", "no-equals-sign"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected key=value"));
+}
+
+#[test]
+fn test_new_with_edit() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_EDITOR", "echo 'Filled in by editor' >>")
+        .args(["new", "Edited Decision", "--edit"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created ADR: ADR-0001"));
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Filled in by editor"));
+}
+
+#[test]
+fn test_new_with_configured_editor() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "set", "editor", "echo 'From adr.editor' >>"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env_remove("GIT_EDITOR")
+        .args(["new", "Configured Editor", "--edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("From adr.editor"));
+}
+
+#[test]
+fn test_new_with_edit_empty_aborts() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .env("GIT_EDITOR", ": >")
+        .args(["new", "Abandoned Decision", "--edit"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("empty content"));
+
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(temp_dir.path())
+        .args(["list", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Abandoned Decision").not());
+}
+
+#[test]
+fn test_new_defaults_author_to_git_identity() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Authored Decision"])
+        .assert()
+        .success();
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test User <test@example.com>"));
+}
+
+#[test]
+fn test_new_explicit_author() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Authored Decision", "--author", "Alice"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["authors"], serde_json::json!(["Alice"]));
+}
+
+#[test]
+fn test_new_supersedes() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Use MySQL", "--status", "accepted"])
+        .assert()
+        .success();
+
+    // The new ADR needs its own commit
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Second commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Use PostgreSQL", "--supersedes", "1"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created ADR: ADR-0002"))
+        .stderr(predicate::str::contains(
+            "Updated ADR-0001 status to superseded",
+        ));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("status: superseded"))
+        .stdout(predicate::str::contains("superseded_by: ADR-0002"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0002", "--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("supersedes: ADR-0001"));
+}
+
+#[test]
+fn test_new_supersedes_unknown_adr() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Use PostgreSQL", "--supersedes", "ADR-0099"])
+        .assert()
+        .failure();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["list", "--format", "oneline"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Use PostgreSQL").not());
+}
+
+#[test]
+fn test_new_with_slug_id_scheme() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["config", "set", "idScheme", "slug"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Coverage Target: 95 Percent"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Created ADR: adr-coverage-target-95-percent",
+        ));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "coverage-target", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"id\": \"adr-coverage-target-95-percent\"",
+        ));
+}
@@ -0,0 +1,162 @@
+//! Integration tests for the `git-adr onboard` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary initialized git repository.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+#[test]
+fn test_onboard_yes_creates_seed_adr() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["onboard", "--yes"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Created ADR: ADR-0001"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should be valid JSON");
+    assert_eq!(json["title"], "Use Architecture Decision Records");
+    assert_eq!(json["status"], "accepted");
+    let body = json["body"].as_str().expect("Should have a body");
+    assert!(body.contains("We will use Architecture Decision Records"));
+
+    // With ADRs present, onboarding shows them instead of seeding again
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["onboard", "--yes", "--non-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Use Architecture Decision Records",
+        ))
+        .stderr(predicate::str::contains("Created ADR").not());
+}
+
+#[test]
+fn test_onboard_seed_uses_configured_template() {
+    let temp_dir = setup_test_repo();
+    StdCommand::new("git")
+        .args(["config", "adr.format", "madr"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to set format");
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["onboard", "--yes"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--raw"])
+        .output()
+        .expect("Failed to run show");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("## Context and Problem Statement"));
+    assert!(stdout.contains("We need to record the architectural decisions"));
+}
+
+#[test]
+fn test_onboard_without_seed() {
+    let temp_dir = setup_test_repo();
+
+    for args in [
+        &["onboard", "--no-seed"][..],
+        &["onboard", "--non-interactive"][..],
+    ] {
+        let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+        cmd.current_dir(temp_dir.path())
+            .args(args)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("doesn't have any ADRs yet"))
+            .stderr(predicate::str::contains("Created ADR").not());
+    }
+
+    // Declining the prompt leaves the repository empty too
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .arg("onboard")
+        .write_stdin("n\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[y/N]"))
+        .stderr(predicate::str::contains("Created ADR").not());
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["list", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0001").not());
+}
+
+#[test]
+fn test_onboard_yes_conflicts_with_no_seed() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["onboard", "--yes", "--no-seed"])
+        .assert()
+        .failure();
+}
@@ -155,3 +155,129 @@ fn test_rm_interactive_default_abort() {
         .success()
         .stderr(predicate::str::contains("Aborted"));
 }
+
+#[test]
+fn test_rm_archive_and_restore() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["rm", "ADR-0001", "--archive"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("ADR archived: ADR-0001"));
+
+    // The note moved to the archive ref
+    let count_notes = |notes_ref: &str| {
+        let output = StdCommand::new("git")
+            .args(["notes", "--ref", notes_ref, "list"])
+            .current_dir(temp_dir.path())
+            .output()
+            .expect("Failed to list notes");
+        String::from_utf8_lossy(&output.stdout).lines().count()
+    };
+    assert_eq!(count_notes("adr"), 0);
+    assert_eq!(count_notes("adr-archive"), 1);
+
+    // Hidden from the default list
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(temp_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No ADRs found"));
+
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(temp_dir.path())
+        .args(["list", "--archived"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0001"))
+        .stdout(predicate::str::contains("archived"));
+
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(temp_dir.path())
+        .args(["list", "--status", "archived"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0001"));
+
+    let mut restore_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    restore_cmd
+        .current_dir(temp_dir.path())
+        .args(["restore", "ADR-0001"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("status: proposed"));
+    assert_eq!(count_notes("adr"), 1);
+    assert_eq!(count_notes("adr-archive"), 0);
+
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(temp_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0001"));
+}
+
+#[test]
+fn test_restore_not_archived() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["restore", "ADR-0001"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not archived"));
+}
+
+#[test]
+fn test_rm_dry_run_reports_backlinks() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    // A second ADR linking to the first
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Second commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    StdCommand::new("git")
+        .args([
+            "notes",
+            "--ref",
+            "adr",
+            "add",
+            "-f",
+            "-m",
+            "---\nid: ADR-0002\ntitle: Follow-up\nstatus: proposed\nlinks:\n- rel: relates-to\n  target: ADR-0001\n---\n\nBody.",
+            "HEAD",
+        ])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add note");
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["rm", "ADR-0001", "--dry-run"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Dry run: would remove ADR-0001"))
+        .stderr(predicate::str::contains(
+            "ADR-0002 (links: relates-to) would point at a missing ADR",
+        ));
+
+    // Nothing was removed
+    let mut list_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    list_cmd
+        .current_dir(path)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0001"));
+}
@@ -247,3 +247,125 @@ fn test_search_case_sensitive_regex() {
         .success()
         .stdout(predicate::str::contains("ADR-0001"));
 }
+
+/// Run a JSON search and parse its output.
+fn search_json(temp_dir: &TempDir, args: &[&str]) -> Vec<serde_json::Value> {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .arg("search")
+        .args(args)
+        .arg("--json")
+        .output()
+        .expect("Failed to run search");
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("Search output should be JSON")
+}
+
+#[test]
+fn test_search_json() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    let hits = search_json(&temp_dir, &["redis"]);
+    assert_eq!(hits.len(), 1);
+    let hit = &hits[0];
+    assert_eq!(hit["id"], "ADR-0002");
+    assert_eq!(hit["title"], "Use Redis for caching");
+    assert_eq!(hit["status"], "proposed");
+    assert!(hit["score"].as_u64().expect("score should be a number") >= 3);
+    assert!(hit["snippet"]
+        .as_str()
+        .expect("snippet should be a string")
+        .contains("Redis"));
+}
+
+#[test]
+fn test_search_json_limit() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    let hits = search_json(&temp_dir, &["Use", "--limit", "2"]);
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|hit| hit["score"].as_u64() > Some(0)));
+}
+
+#[test]
+fn test_search_json_no_match() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    assert!(search_json(&temp_dir, &["nonexistent"]).is_empty());
+}
+
+#[test]
+fn test_search_piped_output_is_plain() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "redis"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Redis"))
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_search_whole_word() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    // "Post" is only part of "PostgreSQL"
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "Post", "--word"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No matches found"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "redis", "-w"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ADR-0002"));
+}
+
+#[test]
+fn test_search_invalid_regex() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "(", "--regex"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid search pattern"));
+}
+
+#[test]
+fn test_search_semantic() {
+    let temp_dir = setup_test_repo_with_adrs();
+
+    // No ADR contains "cached", but the Redis one is about caching
+    assert!(search_json(&temp_dir, &["cached"]).is_empty());
+    let hits = search_json(&temp_dir, &["cached", "--semantic"]);
+    assert_eq!(hits[0]["id"], "ADR-0002");
+    assert!(hits[0]["score"].as_f64().expect("score should be a number") > 0.0);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "cached", "--semantic"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Use Redis for caching"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["search", "cached", "--semantic", "--regex"])
+        .assert()
+        .failure();
+}
@@ -94,6 +94,18 @@ fn test_show_by_partial_id() {
         .stdout(predicate::str::contains("Use PostgreSQL"));
 }
 
+#[test]
+fn test_show_by_bare_number() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["show", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Use PostgreSQL"));
+}
+
 #[test]
 fn test_show_json_format() {
     let temp_dir = setup_test_repo_with_adr();
@@ -131,7 +143,9 @@ fn test_show_metadata_only() {
         .success()
         .stdout(predicate::str::contains("ID:"))
         .stdout(predicate::str::contains("Title:"))
-        .stdout(predicate::str::contains("Status:"));
+        .stdout(predicate::str::contains("Status:"))
+        .stdout(predicate::str::contains("Words:"))
+        .stdout(predicate::str::contains("min read"));
 }
 
 #[test]
@@ -146,6 +160,37 @@ fn test_show_not_found() {
         .stderr(predicate::str::contains("ADR not found"));
 }
 
+#[test]
+fn test_show_not_found_error_json() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-9999", "--error-format", "json"])
+        .output()
+        .expect("Failed to run show");
+    assert_eq!(output.status.code(), Some(4));
+
+    let error: serde_json::Value =
+        serde_json::from_slice(&output.stderr).expect("Error should be JSON");
+    assert_eq!(error["kind"], "AdrNotFound");
+    assert!(error["error"].as_str().unwrap().contains("ADR-9999"));
+}
+
+#[test]
+fn test_show_not_a_repository_exit_code() {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001"])
+        .assert()
+        .code(6)
+        .stderr(predicate::str::contains("not a git repository"));
+}
+
 #[test]
 fn test_show_json_metadata_only() {
     let temp_dir = setup_test_repo_with_adr();
@@ -158,6 +203,8 @@ fn test_show_json_metadata_only() {
         .stdout(predicate::str::contains(r#""id": "ADR-0001""#))
         .stdout(predicate::str::contains(r#""title": "Use PostgreSQL""#))
         .stdout(predicate::str::contains(r#""status": "accepted""#))
+        .stdout(predicate::str::contains(r#""word_count""#))
+        .stdout(predicate::str::contains(r#""reading_minutes""#))
         // metadata_only should NOT include body
         .stdout(predicate::str::contains("body").not());
 }
@@ -174,3 +221,216 @@ fn test_show_yaml_metadata_only() {
         .stdout(predicate::str::contains("title: Use PostgreSQL"))
         .stdout(predicate::str::contains("status: accepted"));
 }
+
+#[test]
+fn test_show_piped_output_is_raw() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    // stdout is captured (not a TTY), so rendering falls back to raw markdown
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["show", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("---"))
+        .stdout(predicate::str::contains("\u{1b}[").not());
+}
+
+#[test]
+fn test_show_raw_flag() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--raw"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("---"))
+        .stdout(predicate::str::contains("id: ADR-0001"));
+}
+
+#[test]
+fn test_show_note_history() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json", "--metadata-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            r#""note_author": "Test User <test@example.com>""#,
+        ))
+        .stdout(predicate::str::contains(r#""last_modified": "20"#));
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--metadata-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Modified:"))
+        .stdout(predicate::str::contains("by Test User <test@example.com>"));
+}
+
+#[test]
+fn test_show_history() {
+    let temp_dir = setup_test_repo_with_adr();
+    let path = temp_dir.path();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["edit", "ADR-0001", "--title", "Use MySQL"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--history", "--no-pager"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 revision(s) of ADR ADR-0001"))
+        .stdout(predicate::str::contains("-title: Use PostgreSQL"))
+        .stdout(predicate::str::contains("+title: Use MySQL"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["show", "ADR-0001", "--history", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    assert!(output.status.success());
+    let revisions: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    let revisions = revisions.as_array().expect("history should be an array");
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0]["author"], "Test User <test@example.com>");
+    assert!(revisions[0]["diff"]
+        .as_str()
+        .unwrap()
+        .contains("+title: Use MySQL"));
+    assert!(revisions[1]["diff"]
+        .as_str()
+        .unwrap()
+        .contains("+title: Use PostgreSQL"));
+}
+
+/// Run `show ADR-0001 --field <name>`.
+fn show_field(temp_dir: &TempDir, name: &str) -> std::process::Output {
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--field", name])
+        .output()
+        .expect("Failed to run show")
+}
+
+#[test]
+fn test_show_field() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["edit", "ADR-0001", "--add-tag", "storage"])
+        .args(["--set-field", "team=platform"])
+        .assert()
+        .success();
+
+    for (name, expected) in [
+        ("status", "accepted\n"),
+        ("title", "Use PostgreSQL\n"),
+        ("tags", "database,storage\n"),
+        ("team", "platform\n"),
+        ("id", "ADR-0001\n"),
+    ] {
+        let output = show_field(&temp_dir, name);
+        assert!(output.status.success(), "{name}");
+        assert_eq!(String::from_utf8_lossy(&output.stdout), expected, "{name}");
+    }
+
+    let date = show_field(&temp_dir, "date");
+    let date = String::from_utf8_lossy(&date.stdout);
+    assert!(chrono::NaiveDate::parse_from_str(date.trim_end(), "%Y-%m-%d").is_ok());
+}
+
+#[test]
+fn test_show_field_missing() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let output = show_field(&temp_dir, "superseded_by");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--field", "superseded_by"])
+        .args(["--error-format", "json"])
+        .output()
+        .expect("Failed to run show");
+    assert_eq!(output.status.code(), Some(2));
+    let error: serde_json::Value =
+        serde_json::from_slice(&output.stderr).expect("Error should be JSON");
+    assert_eq!(error["kind"], "FieldNotFound");
+}
+
+#[test]
+fn test_show_multiple() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Use Redis", "--no-edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "ADR-0002", "--metadata-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Use PostgreSQL"))
+        .stdout(predicate::str::contains("Use Redis"))
+        .stdout(predicate::str::contains("─"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "ADR-0002", "--history"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_show_all_json() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Use Redis", "--no-edit"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "--all", "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    assert!(output.status.success());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be JSON");
+    let ids: Vec<_> = json
+        .as_array()
+        .expect("Output should be an array")
+        .iter()
+        .map(|adr| adr["id"].as_str().unwrap_or_default())
+        .collect();
+    assert_eq!(ids, ["ADR-0001", "ADR-0002"]);
+}
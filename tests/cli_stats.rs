@@ -133,7 +133,8 @@ fn test_stats_text_output() {
         .stdout(predicate::str::contains("ADR Statistics"))
         .stdout(predicate::str::contains("Total ADRs:"))
         .stdout(predicate::str::contains("3"))
-        .stdout(predicate::str::contains("By Status:"));
+        .stdout(predicate::str::contains("By Status:"))
+        .stdout(predicate::str::contains("Total words"));
 }
 
 #[test]
@@ -147,7 +148,9 @@ fn test_stats_json_output() {
         .success()
         .stdout(predicate::str::contains(r#""total": 3"#))
         .stdout(predicate::str::contains(r#""by_status""#))
-        .stdout(predicate::str::contains(r#""by_tag""#));
+        .stdout(predicate::str::contains(r#""by_tag""#))
+        .stdout(predicate::str::contains(r#""words""#))
+        .stdout(predicate::str::contains(r#""reading_minutes""#));
 }
 
 #[test]
@@ -213,3 +216,140 @@ fn test_stats_empty_repo() {
         .stdout(predicate::str::contains("Total ADRs:"))
         .stdout(predicate::str::contains("0"));
 }
+
+#[test]
+fn test_stats_proposal_aging() {
+    let temp_dir = setup_test_repo_with_adrs();
+    let path = temp_dir.path();
+
+    std::fs::write(path.join("file3.txt"), "content3").expect("Failed to write file");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Fourth commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    let old_proposal =
+        "---\ntitle: Old Proposal\nstatus: proposed\ndate: 2020-01-01\n---\n\nStill open.\n";
+    std::fs::write(path.join("old.md"), old_proposal).expect("Failed to write ADR");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Ignored", "--file", "old.md"])
+        .assert()
+        .success();
+
+    // "Second Decision" is proposed today, so only the 2020 proposal is stale
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["stats", "--format", "json", "--stale-days", "30"])
+        .output()
+        .expect("Failed to run stats");
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(stats["stale_proposals"], 1);
+    assert_eq!(stats["stale_proposal_ids"], serde_json::json!(["ADR-0004"]));
+    assert!(stats["oldest_proposal_days"].as_i64().unwrap() > 2000);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Proposal Aging:"))
+        .stdout(predicate::str::contains("ADR-0004"));
+}
+
+#[test]
+fn test_stats_by_author_and_window() {
+    let temp_dir = setup_test_repo_with_adrs();
+    let path = temp_dir.path();
+
+    std::fs::write(path.join("file3.txt"), "content3").expect("Failed to write file");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Fourth commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    let old_decision = "---\ntitle: Old Decision\nstatus: accepted\ndate: 2020-01-01\nauthors:\n- Alice\ndeciders:\n- Alice\n- Bob\n---\n\nDecided long ago.\n";
+    std::fs::write(path.join("old.md"), old_decision).expect("Failed to write ADR");
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Ignored", "--file", "old.md"])
+        .assert()
+        .success();
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["stats", "--format", "json", "--by-author"])
+        .output()
+        .expect("Failed to run stats");
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(stats["total"], 4);
+    assert_eq!(
+        stats["by_author"]["Test User <test@example.com>"]["authored"],
+        3
+    );
+    assert_eq!(stats["by_author"]["Alice"]["authored"], 1);
+    assert_eq!(stats["by_author"]["Alice"]["decided"], 1);
+    assert_eq!(stats["by_author"]["Bob"]["decided"], 1);
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args([
+            "stats",
+            "--format",
+            "json",
+            "--by-author",
+            "--until",
+            "2021-01-01",
+        ])
+        .output()
+        .expect("Failed to run stats");
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(stats["total"], 1);
+    assert_eq!(
+        stats["by_author"],
+        serde_json::json!({
+            "Alice": { "authored": 1, "decided": 1 },
+            "Bob": { "authored": 0, "decided": 1 },
+        })
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["stats", "--by-author", "--since", "2021-01-01"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("By Author:"))
+        .stdout(predicate::str::contains(
+            "Test User <test@example.com> 3 authored, 0 decided",
+        ))
+        .stdout(predicate::str::contains("Alice").not());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["stats", "--since", "last week"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid date format"));
+}
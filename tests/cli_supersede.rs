@@ -146,3 +146,71 @@ fn test_supersede_not_found() {
         .failure()
         .stderr(predicate::str::contains("ADR not found"));
 }
+
+#[test]
+fn test_supersede_dry_run() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["supersede", "ADR-0001", "Improved Decision", "--dry-run"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Would create ADR-0002: Improved Decision (proposed)",
+        ))
+        .stderr(predicate::str::contains(
+            "Would change ADR-0001 status: accepted → superseded",
+        ))
+        .stderr(predicate::str::contains(
+            "Would link ADR-0002 supersedes ADR-0001",
+        ));
+
+    // Nothing was written
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""status": "accepted""#));
+
+    let mut show_new_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_new_cmd
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0002"])
+        .assert()
+        .failure();
+}
+
+/// Read an ADR as JSON via `show`.
+fn show_json(temp_dir: &TempDir, id: &str) -> serde_json::Value {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", id, "--format", "json"])
+        .output()
+        .expect("Failed to run show");
+    serde_json::from_slice(&output.stdout).expect("Invalid JSON")
+}
+
+#[test]
+fn test_supersede_copy_body() {
+    let temp_dir = setup_test_repo_with_adr();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["supersede", "ADR-0001", "Improved Decision", "--copy-body"])
+        .assert()
+        .success();
+
+    let old = show_json(&temp_dir, "ADR-0001");
+    let new = show_json(&temp_dir, "ADR-0002");
+    let old_body = old["body"].as_str().unwrap();
+    let new_body = new["body"].as_str().unwrap();
+
+    assert!(new_body.starts_with("> Supersedes ADR-0001: Original Decision"));
+    assert!(new_body.contains(old_body.trim()));
+    assert_eq!(old["status"], "superseded");
+}
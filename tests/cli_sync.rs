@@ -149,3 +149,175 @@ fn test_sync_custom_remote() {
         .success()
         .stderr(predicate::str::contains("Syncing with remote: upstream"));
 }
+
+/// Clone the remote into a second working copy with git-adr initialized.
+fn clone_remote(remote_dir: &TempDir) -> TempDir {
+    let clone_dir = TempDir::new().expect("Failed to create clone directory");
+    let path = clone_dir.path();
+
+    StdCommand::new("git")
+        .args([
+            "clone",
+            "--branch",
+            "main",
+            remote_dir.path().to_str().unwrap(),
+            ".",
+        ])
+        .current_dir(path)
+        .output()
+        .expect("Failed to clone remote");
+
+    for (key, value) in [
+        ("user.email", "other@example.com"),
+        ("user.name", "Other User"),
+    ] {
+        StdCommand::new("git")
+            .args(["config", key, value])
+            .current_dir(path)
+            .output()
+            .expect("Failed to set git user");
+    }
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    clone_dir
+}
+
+#[test]
+fn test_sync_pull_into_fresh_clone() {
+    let (local_dir, remote_dir) = setup_test_repo_with_remote();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(local_dir.path())
+        .args(["sync", "--push"])
+        .assert()
+        .success();
+
+    let clone_dir = clone_remote(&remote_dir);
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(clone_dir.path())
+        .args(["sync", "--pull"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Fetched and merged ADR notes"));
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(clone_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test ADR"));
+}
+
+#[test]
+fn test_sync_conflict_reports_commit() {
+    let (local_dir, remote_dir) = setup_test_repo_with_remote();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(local_dir.path())
+        .args(["sync", "--push"])
+        .assert()
+        .success();
+
+    // Change the same ADR on both sides
+    let clone_dir = clone_remote(&remote_dir);
+    for args in [
+        vec!["sync", "--pull"],
+        vec!["edit", "ADR-0001", "--title", "Title from clone"],
+        vec!["sync", "--push"],
+    ] {
+        Command::cargo_bin("git-adr")
+            .expect("Failed to find binary")
+            .current_dir(clone_dir.path())
+            .args(&args)
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(local_dir.path())
+        .args(["edit", "ADR-0001", "--title", "Title from local"])
+        .assert()
+        .success();
+
+    let head = StdCommand::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(local_dir.path())
+        .output()
+        .expect("Failed to resolve HEAD");
+    let head = String::from_utf8_lossy(&head.stdout).trim().to_string();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(local_dir.path())
+        .arg("sync")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("notes merge conflict"))
+        .stderr(predicate::str::contains(head));
+}
+
+#[test]
+fn test_read_commands_fetch_notes() {
+    let (local_dir, remote_dir) = setup_test_repo_with_remote();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(local_dir.path())
+        .args(["sync", "--push"])
+        .assert()
+        .success();
+
+    let clone_dir = clone_remote(&remote_dir);
+
+    // Notes aren't fetched by a plain clone
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(clone_dir.path())
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test ADR").not());
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(clone_dir.path())
+        .args(["list", "--fetch"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test ADR"));
+}
+
+#[test]
+fn test_auto_fetch_config() {
+    let (local_dir, remote_dir) = setup_test_repo_with_remote();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(local_dir.path())
+        .args(["sync", "--push"])
+        .assert()
+        .success();
+
+    let clone_dir = clone_remote(&remote_dir);
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(clone_dir.path())
+        .args(["config", "set", "autoFetch", "true"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(clone_dir.path())
+        .args(["show", "ADR-0001", "--no-pager"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test ADR"));
+}
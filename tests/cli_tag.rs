@@ -0,0 +1,181 @@
+//! Integration tests for the `git-adr tag` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary git repository with ADR notes initialized.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    StdCommand::new("git")
+        .args(["config", "adr.initialized", "true"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set adr.initialized");
+
+    temp_dir
+}
+
+/// Add an ADR note with `tags` on a new commit.
+fn add_adr_note(path: &std::path::Path, id: &str, title: &str, tags: &[&str]) {
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", title])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+
+    let tags: String = tags.iter().flat_map(|t| ["  - ", t, "\n"]).collect();
+    let content = format!(
+        "---\nid: {id}\ntitle: {title}\nstatus: proposed\ntags:\n{tags}---\n\n## Context\n\nTest ADR content.\n"
+    );
+
+    StdCommand::new("git")
+        .args(["notes", "--ref", "adr", "add", "-f", "-m", &content, "HEAD"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add ADR note");
+}
+
+fn list_tag(path: &std::path::Path, tag: &str) -> String {
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["list", "--tag", tag])
+        .output()
+        .expect("Failed to run list");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_tag_list_counts() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    add_adr_note(path, "ADR-0001", "Use PostgreSQL", &["database", "api"]);
+    add_adr_note(path, "ADR-0002", "Use Redis", &["database", "cache"]);
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["tag", "list", "--format", "json"])
+        .output()
+        .expect("Failed to run tag list");
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "tag": "database", "count": 2 },
+            { "tag": "api", "count": 1 },
+            { "tag": "cache", "count": 1 },
+        ])
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["tag", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("database  2\n"));
+}
+
+#[test]
+fn test_tag_rename() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    add_adr_note(path, "ADR-0001", "Use PostgreSQL", &["db", "api"]);
+    add_adr_note(path, "ADR-0002", "Use Redis", &["db", "database"]);
+    add_adr_note(path, "ADR-0003", "Use React", &["ui"]);
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["tag", "rename", "db", "database"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Retagged 2 ADR(s)"));
+
+    assert!(!list_tag(path, "db").contains("ADR-"));
+    let database = list_tag(path, "database");
+    assert!(database.contains("ADR-0001"));
+    assert!(database.contains("ADR-0002"));
+    assert!(!database.contains("ADR-0003"));
+
+    // ADR-0002 already had the new tag, so it only keeps one copy
+    let mut show = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show.current_dir(path)
+        .args(["show", "ADR-0002", "--field", "tags"])
+        .assert()
+        .success()
+        .stdout("database\n");
+}
+
+#[test]
+fn test_tag_merge() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    add_adr_note(path, "ADR-0001", "Use PostgreSQL", &["db"]);
+    add_adr_note(path, "ADR-0002", "Use Redis", &["databases", "cache"]);
+    add_adr_note(path, "ADR-0003", "Use React", &["ui"]);
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["tag", "merge", "db", "databases", "--into", "database"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Retagged 2 ADR(s)"));
+
+    assert!(!list_tag(path, "db").contains("ADR-"));
+    assert!(!list_tag(path, "databases").contains("ADR-"));
+    let database = list_tag(path, "database");
+    assert!(database.contains("ADR-0001"));
+    assert!(database.contains("ADR-0002"));
+}
+
+#[test]
+fn test_tag_rename_unknown_tag() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    add_adr_note(path, "ADR-0001", "Use PostgreSQL", &["db"]);
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["tag", "rename", "missing", "other"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("WARN: No ADRs tagged missing"));
+
+    // Warnings are logged, so --quiet hides them
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["--quiet", "tag", "rename", "missing", "other"])
+        .assert()
+        .success()
+        .stderr("");
+}
@@ -0,0 +1,143 @@
+//! Integration tests for the `git-adr templates` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary git repository with ADR initialized.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    // Initialize git repo
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    // Configure git user for commits
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    // Create initial commit
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    // Initialize ADR using init command
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+#[test]
+fn test_templates_list_builtin() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["templates", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("nygard"))
+        .stdout(predicate::str::contains("madr"))
+        .stdout(predicate::str::contains("business-case"))
+        .stdout(predicate::str::contains("built-in"))
+        .stdout(predicate::str::contains("custom").not());
+}
+
+#[test]
+fn test_templates_list_custom_dir() {
+    let temp_dir = setup_test_repo();
+    let path = temp_dir.path();
+
+    std::fs::create_dir(path.join("adr-templates")).expect("Failed to create templates dir");
+    std::fs::write(
+        path.join("adr-templates").join("lightweight.md"),
+        "{# Two-section team template #}\n# {{ title }}\n\n## Why\n",
+    )
+    .expect("Failed to write template");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["config", "set", "templatesDir", "adr-templates"])
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(path)
+        .args(["templates", "adr-formats"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("lightweight"))
+        .stdout(predicate::str::contains("custom"))
+        .stdout(predicate::str::contains("Two-section team template"));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .args(["new", "Light Decision", "--template", "lightweight"])
+        .assert()
+        .success();
+
+    let mut show_cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    show_cmd
+        .current_dir(path)
+        .args(["show", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Why"));
+}
+
+#[test]
+fn test_templates_vars() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["templates", "vars", "nygard"])
+        .assert()
+        .success()
+        .stdout("title\nstatus\ncontext\ndecision\nconsequences\n");
+}
+
+#[test]
+fn test_templates_vars_unknown() {
+    let temp_dir = setup_test_repo();
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .args(["templates", "vars", "nonexistent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown template"));
+}
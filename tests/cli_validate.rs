@@ -0,0 +1,339 @@
+//! Integration tests for the `git-adr validate` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// Create a temporary initialized git repository.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    std::fs::write(path.join("README.md"), "# Test Repo\n").expect("Failed to write README");
+    StdCommand::new("git")
+        .args(["add", "."])
+        .current_dir(path)
+        .output()
+        .expect("Failed to stage files");
+    StdCommand::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+/// Attach a raw ADR note to a fresh commit.
+fn add_adr_note(temp_dir: &TempDir, content: &str) {
+    let path = temp_dir.path();
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "ADR commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to commit");
+    StdCommand::new("git")
+        .args(["notes", "--ref", "adr", "add", "-f", "-m", content, "HEAD"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to add note");
+}
+
+#[test]
+fn test_validate_no_issues() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\nBody\n",
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .arg("validate")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All ADR links are valid"));
+
+    // --quiet hides progress, not the result
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["-q", "validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All ADR links are valid"));
+}
+
+#[test]
+fn test_validate_reports_broken_links() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: accepted\nsupersedes: ADR-0001\nlinks:\n- rel: relates-to\n  target: ADR-0099\n---\n\nBody\n",
+    );
+
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path())
+        .arg("validate")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("[dangling]"))
+        .stdout(predicate::str::contains("ADR-0099"))
+        .stdout(predicate::str::contains("[asymmetric]"))
+        .stderr(predicate::str::contains("2 link issue(s) found"));
+}
+
+#[test]
+fn test_validate_json_output() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: superseded\nsuperseded_by: ADR-0005\n---\n\nBody\n",
+    );
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--format", "json"])
+        .output()
+        .expect("Failed to run validate");
+    assert!(!output.status.success());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["issues"][0]["adr_id"], "ADR-0001");
+    assert_eq!(json["issues"][0]["field"], "superseded_by");
+    assert_eq!(json["issues"][0]["target"], "ADR-0005");
+    assert_eq!(json["issues"][0]["kind"], "dangling");
+}
+
+#[test]
+fn test_validate_duplicate_titles() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: Use Postgres\nstatus: accepted\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: use  postgres\nstatus: proposed\n---\n\nBody\n",
+    );
+
+    // Duplicates are only checked on request
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .arg("validate")
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--duplicates"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "ADR-0001, ADR-0002 [duplicate] share the title \"use postgres\"",
+        ))
+        .stderr(predicate::str::contains("1 duplicate title(s) found"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--duplicates", "--format", "json"])
+        .output()
+        .expect("Failed to run validate");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["duplicates"][0]["title"], "use postgres");
+    assert_eq!(
+        json["duplicates"][0]["ids"],
+        serde_json::json!(["ADR-0001", "ADR-0002"])
+    );
+}
+
+#[test]
+fn test_validate_numbering_gaps() {
+    let temp_dir = setup_test_repo();
+    for id in ["ADR-0001", "ADR-0002", "ADR-0004"] {
+        add_adr_note(
+            &temp_dir,
+            &format!("---\nid: {id}\ntitle: Decision {id}\nstatus: accepted\n---\n\nBody\n"),
+        );
+    }
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--gaps"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "ADR-0003 [gap] is missing from the ID sequence",
+        ))
+        .stderr(predicate::str::contains("1 numbering gap(s) found"));
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--gaps", "--format", "json"])
+        .output()
+        .expect("Failed to run validate");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["gaps"], serde_json::json!(["ADR-0003"]));
+}
+
+#[test]
+fn test_validate_sections_filled() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\nformat: nygard\n---\n\n## Context\n\nWe need ACID.\n\n## Decision\n\nUse PostgreSQL.\n\n## Consequences\n\nRun backups.\n",
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--sections"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "All required sections are filled in",
+        ));
+}
+
+#[test]
+fn test_validate_sections_placeholder() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Use PostgreSQL", "--template", "nygard", "--no-edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--sections"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("[placeholder]"))
+        .stdout(predicate::str::contains("\"Consequences\""))
+        .stderr(predicate::str::contains("3 incomplete section(s)"));
+}
+
+#[test]
+fn test_validate_metadata_complete() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\ndate: 2025-01-15\n---\n\nBody\n",
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--metadata"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All ADR metadata is complete"));
+}
+
+#[test]
+fn test_validate_metadata_json() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: superseded\ndate: 2025-01-15\n---\n\nBody\n",
+    );
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0002\ntitle: Second\nstatus: accepted\n---\n\nBody\n",
+    );
+
+    let output = Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--metadata", "--format", "json"])
+        .output()
+        .expect("Failed to run validate");
+    assert!(!output.status.success());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["valid"], false);
+    let kinds: Vec<(&str, &str)> = json["metadata"]
+        .as_array()
+        .expect("metadata should be an array")
+        .iter()
+        .map(|issue| {
+            (
+                issue["adr_id"].as_str().unwrap(),
+                issue["kind"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("ADR-0001", "missing-superseded-by"),
+            ("ADR-0002", "missing-date"),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_all_runs_metadata_checks() {
+    let temp_dir = setup_test_repo();
+    add_adr_note(
+        &temp_dir,
+        "---\nid: ADR-0001\ntitle: First\nstatus: accepted\n---\n\nBody\n",
+    );
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["validate", "--all"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("[missing-date]"))
+        .stderr(predicate::str::contains("metadata issue(s)"));
+}
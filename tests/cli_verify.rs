@@ -0,0 +1,284 @@
+//! Integration tests for ADR signing and the `git-adr verify` command.
+
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+/// A throwaway GPG home with a passphrase-less signing key.
+struct GpgHome(TempDir);
+
+impl GpgHome {
+    /// Create the key, or `None` when gpg is not installed.
+    fn new() -> Option<Self> {
+        let home = TempDir::new().expect("Failed to create temp directory");
+        let status = StdCommand::new("gpg")
+            .env("GNUPGHOME", home.path())
+            .args([
+                "--batch",
+                "--passphrase",
+                "",
+                "--quick-gen-key",
+                "Test User <test@example.com>",
+                "ed25519",
+                "sign",
+                "never",
+            ])
+            .output()
+            .ok()?;
+        status.status.success().then_some(Self(home))
+    }
+
+    fn path(&self) -> &Path {
+        self.0.path()
+    }
+}
+
+impl Drop for GpgHome {
+    fn drop(&mut self) {
+        let _ = StdCommand::new("gpgconf")
+            .env("GNUPGHOME", self.path())
+            .args(["--kill", "gpg-agent"])
+            .output();
+    }
+}
+
+/// Create a temporary initialized git repository.
+fn setup_test_repo() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let path = temp_dir.path();
+
+    StdCommand::new("git")
+        .args(["init"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to init git repo");
+
+    StdCommand::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user email");
+
+    StdCommand::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to set git user name");
+
+    StdCommand::new("git")
+        .args(["commit", "--allow-empty", "-m", "Initial commit"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to create initial commit");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(path)
+        .arg("init")
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+fn enable_signing(path: &Path) {
+    StdCommand::new("git")
+        .args(["config", "adr.sign", "true"])
+        .current_dir(path)
+        .output()
+        .expect("Failed to enable signing");
+}
+
+#[test]
+fn test_verify_signed_adr() {
+    let Some(gpg) = GpgHome::new() else {
+        return;
+    };
+    let temp_dir = setup_test_repo();
+    enable_signing(temp_dir.path());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["new", "Signed Decision", "--no-edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["verify", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Good signature on ADR-0001 from Test User <test@example.com>",
+        ));
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["show", "ADR-0001", "--verify", "--no-pager"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Good signature from Test User <test@example.com>",
+        ))
+        .stdout(predicate::str::contains("Signed Decision"));
+
+    // The notes commit itself carries the signature
+    let status = StdCommand::new("git")
+        .args(["log", "-1", "--format=%G?", "refs/notes/adr"])
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .output()
+        .expect("Failed to read notes commit signature");
+    assert_eq!(String::from_utf8_lossy(&status.stdout).trim(), "G");
+
+    // Edits are re-signed
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["edit", "ADR-0001", "--add-tag", "security"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["verify", "ADR-0001"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["show", "ADR-0001", "--verify", "--no-pager"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Good signature from"));
+}
+
+#[test]
+fn test_verify_tampered_adr() {
+    let Some(gpg) = GpgHome::new() else {
+        return;
+    };
+    let temp_dir = setup_test_repo();
+    enable_signing(temp_dir.path());
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["new", "Signed Decision", "--no-edit"])
+        .assert()
+        .success();
+
+    // Rewrite the note behind git-adr's back
+    StdCommand::new("git")
+        .args([
+            "notes",
+            "--ref",
+            "adr",
+            "add",
+            "-f",
+            "-m",
+            "---\nid: ADR-0001\ntitle: Tampered\nstatus: accepted\n---\n\nChanged.",
+            "HEAD",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to rewrite note");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["verify", "ADR-0001"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("bad or unverifiable signature"));
+
+    // The rewrite went in as an unsigned notes commit
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .env("GNUPGHOME", gpg.path())
+        .args(["show", "ADR-0001", "--verify", "--no-pager"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ADR-0001 is not signed"));
+}
+
+#[test]
+fn test_verify_unsigned_adr() {
+    let temp_dir = setup_test_repo();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Unsigned Decision", "--no-edit"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["verify", "ADR-0001"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ADR-0001 is not signed"));
+
+    // Showing still works without --verify, but not with it
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--no-pager"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["show", "ADR-0001", "--verify", "--no-pager"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ADR-0001 is not signed"));
+}
+
+#[test]
+fn test_signing_failure_is_reported() {
+    let temp_dir = setup_test_repo();
+    enable_signing(temp_dir.path());
+    StdCommand::new("git")
+        .args(["config", "gpg.program", "false"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to set gpg.program");
+
+    Command::cargo_bin("git-adr")
+        .expect("Failed to find binary")
+        .current_dir(temp_dir.path())
+        .args(["new", "Signed Decision", "--no-edit"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("check your GPG setup"));
+
+    // The unsigned note is not left behind
+    let notes = StdCommand::new("git")
+        .args(["notes", "--ref", "adr", "list"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to list notes");
+    assert!(String::from_utf8_lossy(&notes.stdout).trim().is_empty());
+}
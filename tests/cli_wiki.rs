@@ -0,0 +1,239 @@
+//! Integration tests for the `git-adr wiki` command.
+
+#![cfg(feature = "wiki")]
+#![allow(deprecated)]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command as StdCommand;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = StdCommand::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("Failed to run git");
+    assert!(output.status.success(), "git {args:?} failed");
+}
+
+/// Create a repository with an ADR whose `origin` is a GitHub project, and
+/// a local wiki repository that GitHub URLs are redirected to.
+fn setup_test_repo_with_wiki() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let root = temp_dir.path();
+
+    let seed = root.join("seed");
+    std::fs::create_dir(&seed).expect("Failed to create directory");
+    git(&seed, &["init", "--quiet"]);
+    std::fs::write(seed.join("Home.md"), "Welcome").expect("Failed to write page");
+    git(&seed, &["add", "."]);
+    git(
+        &seed,
+        &[
+            "-c",
+            "user.name=Wiki",
+            "-c",
+            "user.email=wiki@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "Home",
+        ],
+    );
+    std::fs::create_dir(root.join("acme")).expect("Failed to create directory");
+    git(
+        root,
+        &[
+            "clone",
+            "--quiet",
+            "--bare",
+            "seed",
+            "acme/widgets.wiki.git",
+        ],
+    );
+
+    std::fs::write(
+        root.join("gitconfig"),
+        format!(
+            "[url \"{}/\"]\n\tinsteadOf = https://github.com/\n",
+            root.display()
+        ),
+    )
+    .expect("Failed to write git config");
+
+    let repo = root.join("repo");
+    std::fs::create_dir(&repo).expect("Failed to create directory");
+    git(&repo, &["init", "--quiet"]);
+    git(&repo, &["config", "user.email", "test@example.com"]);
+    git(&repo, &["config", "user.name", "Test User"]);
+    git(
+        &repo,
+        &[
+            "remote",
+            "add",
+            "origin",
+            "https://github.com/acme/widgets.git",
+        ],
+    );
+    git(
+        &repo,
+        &["commit", "--quiet", "--allow-empty", "-m", "Initial commit"],
+    );
+
+    git_adr(&temp_dir).arg("init").assert().success();
+    git_adr(&temp_dir)
+        .args(["new", "Use Rust", "--no-edit"])
+        .assert()
+        .success();
+
+    temp_dir
+}
+
+fn git_adr(temp_dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("git-adr").expect("Failed to find binary");
+    cmd.current_dir(temp_dir.path().join("repo"))
+        .env("GIT_CONFIG_GLOBAL", temp_dir.path().join("gitconfig"))
+        .env_remove("GITHUB_TOKEN");
+    cmd
+}
+
+/// Clone the wiki, apply `edit` to the checkout and push it.
+fn edit_wiki(temp_dir: &TempDir, edit: impl FnOnce(&Path)) {
+    let checkout = temp_dir.path().join("checkout");
+    git(
+        temp_dir.path(),
+        &["clone", "--quiet", "acme/widgets.wiki.git", "checkout"],
+    );
+    edit(&checkout);
+    git(
+        &checkout,
+        &[
+            "-c",
+            "user.name=Wiki",
+            "-c",
+            "user.email=wiki@example.com",
+            "commit",
+            "--quiet",
+            "-am",
+            "Edit",
+        ],
+    );
+    git(&checkout, &["push", "--quiet"]);
+}
+
+fn wiki_page(temp_dir: &TempDir) -> String {
+    let output = StdCommand::new("git")
+        .args(["show", "HEAD:ADR-0001.md"])
+        .current_dir(temp_dir.path().join("acme/widgets.wiki.git"))
+        .output()
+        .expect("Failed to run git");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_wiki_push_creates_pages() {
+    let temp_dir = setup_test_repo_with_wiki();
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("github wiki (acme/widgets)"))
+        .stderr(predicate::str::contains("1 pushed"));
+    assert!(wiki_page(&temp_dir).contains("## Context"));
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("1 unchanged"));
+}
+
+#[test]
+fn test_wiki_edit_blocks_push_until_pulled() {
+    let temp_dir = setup_test_repo_with_wiki();
+    git_adr(&temp_dir).args(["wiki", "push"]).assert().success();
+    edit_wiki(&temp_dir, |checkout| {
+        let page = checkout.join("ADR-0001.md");
+        let content = std::fs::read_to_string(&page).expect("Failed to read page");
+        std::fs::write(page, format!("{content}\nEdited on the wiki.\n"))
+            .expect("Failed to write page");
+    });
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("wiki page was edited"))
+        .stderr(predicate::str::contains("--force"));
+
+    git_adr(&temp_dir)
+        .args(["wiki", "pull"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("1 pulled"));
+    git_adr(&temp_dir)
+        .args(["show", "ADR-0001"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Edited on the wiki."));
+}
+
+#[test]
+fn test_wiki_push_force_overwrites_wiki_edits() {
+    let temp_dir = setup_test_repo_with_wiki();
+    git_adr(&temp_dir).args(["wiki", "push"]).assert().success();
+    edit_wiki(&temp_dir, |checkout| {
+        std::fs::write(checkout.join("ADR-0001.md"), "Replaced.\n").expect("Failed to write page");
+    });
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push", "--force"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("1 pushed"));
+    assert!(wiki_page(&temp_dir).contains("## Context"));
+}
+
+#[test]
+fn test_wiki_unknown_provider() {
+    let temp_dir = setup_test_repo_with_wiki();
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push", "--provider", "mediawiki"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown wiki provider: mediawiki"));
+}
+
+#[test]
+fn test_wiki_settings_from_config() {
+    let temp_dir = setup_test_repo_with_wiki();
+    git(
+        &temp_dir.path().join("repo"),
+        &[
+            "remote",
+            "set-url",
+            "origin",
+            "https://example.com/other.git",
+        ],
+    );
+    for (key, value) in [
+        ("wiki.platform", "github"),
+        ("wiki.repository", "acme/widgets"),
+    ] {
+        git_adr(&temp_dir)
+            .args(["config", "set", key, value])
+            .assert()
+            .success();
+    }
+
+    git_adr(&temp_dir)
+        .args(["wiki", "push"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("github wiki (acme/widgets)"))
+        .stderr(predicate::str::contains("1 pushed"));
+}